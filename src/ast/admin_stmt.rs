@@ -0,0 +1,48 @@
+use crate::ast::expr_node::ExprNode;
+use crate::ast::table_name::TableName;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// AdminStmtType is the type for admin statement.
+#[derive(
+    Debug,
+    Drive,
+    DriveMut,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+)]
+pub enum AdminStmtType {
+    #[default]
+    ShowDdl,
+    CheckTable,
+    ShowDdlJobs,
+    CancelDdlJobs,
+}
+
+// AdminStmt is the struct for Admin statement.
+// See https://docs.pingcap.com/tidb/stable/sql-statement-admin
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct AdminStmt {
+    #[drive(skip)]
+    pub span: Span,
+    pub tp: AdminStmtType,
+    pub tables: Vec<TableName>,
+    #[drive(skip)]
+    pub job_ids: Vec<i64>,
+    // JobNumber is the number of jobs `ADMIN SHOW DDL JOBS n` shows, it's 0
+    // if omitted.
+    #[drive(skip)]
+    pub job_number: i64,
+    pub where_clause: Option<ExprNode>,
+}
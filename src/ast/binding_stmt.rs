@@ -0,0 +1,35 @@
+use crate::ast::statement::Statement;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// CreateBindingStmt creates sql binding hint.
+// See https://docs.pingcap.com/tidb/stable/sql-statement-create-binding
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct CreateBindingStmt {
+    #[drive(skip)]
+    pub span: Span,
+    #[drive(skip)]
+    pub global_scope: bool,
+    // OriginNode is None for `CREATE BINDING USING ...`, the binding is
+    // created for the statement the hinted one normalizes to.
+    pub origin_node: Option<Box<Statement>>,
+    pub hinted_node: Box<Statement>,
+}
+
+// DropBindingStmt deletes sql binding hint.
+// See https://docs.pingcap.com/tidb/stable/sql-statement-drop-binding
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct DropBindingStmt {
+    #[drive(skip)]
+    pub span: Span,
+    #[drive(skip)]
+    pub global_scope: bool,
+    pub origin_node: Box<Statement>,
+    pub hinted_node: Option<Box<Statement>>,
+}
@@ -0,0 +1,16 @@
+use crate::ast::expr_node::FuncCallExpr;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// CallStmt represents a call procedure query node.
+// See https://dev.mysql.com/doc/refman/5.7/en/call.html
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct CallStmt {
+    #[drive(skip)]
+    pub span: Span,
+    pub procedure: Option<Box<FuncCallExpr>>,
+}
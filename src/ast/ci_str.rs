@@ -1,4 +1,9 @@
-#[derive(Debug, Default, Clone)]
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub struct CIStr {
     pub origin: String,
     pub lower: String,
@@ -13,3 +18,33 @@ impl CIStr {
         }
     }
 }
+
+// CIStr is serialized as its original string, the lower case form is
+// rebuilt when it is read back. It's wrapped as a newtype so serializers
+// like the structural key one can tell it from the other strings.
+impl Serialize for CIStr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct("CIStr", &self.origin)
+    }
+}
+
+impl<'de> Deserialize<'de> for CIStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<CIStr, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(CIStr::new(&s))
+    }
+}
+
+impl JsonSchema for CIStr {
+    fn schema_name() -> String {
+        String::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        String::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
@@ -1,8 +1,19 @@
 use crate::ast::ci_str::CIStr;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct ColumnName {
+    #[drive(skip)]
+    pub span: Span,
+    #[drive(skip)]
     pub schema: CIStr,
+    #[drive(skip)]
     pub table: CIStr,
+    #[drive(skip)]
     pub name: CIStr,
 }
@@ -1,4 +1,10 @@
-#[derive(Debug)]
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub enum ASTType {
     Terminated,
     Enclosed,
@@ -11,8 +17,11 @@ pub const FULLTEXT_SEARCH_MODIFIER_BOOLEAN_MODE: isize = 1;
 pub const FULLTEXT_SEARCH_MODIFIER_MODE_MASK: isize = 0xF;
 pub const FULLTEXT_SEARCH_MODIFIER_WITH_QUERY_EXPANSION: isize = 1 << 4;
 
-#[derive(Debug, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct FulltextSearchModifier {
+    #[drive(skip)]
     pub v: isize,
 }
 
@@ -1,9 +1,16 @@
 use crate::ast::ci_str::CIStr;
 use crate::ast::subquery_expr::SubQueryExpr;
-use derive_visitor::Drive;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Drive)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct CommonTableExpression {
+    #[drive(skip)]
+    pub span: Span,
     #[drive(skip)]
     pub name: CIStr,
     pub query: Option<SubQueryExpr>,
@@ -0,0 +1,16 @@
+use crate::ast::expr_node::ExprNode;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// DoStmt is the struct for DO statement.
+// See https://dev.mysql.com/doc/refman/5.7/en/do.html
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct DoStmt {
+    #[drive(skip)]
+    pub span: Span,
+    pub exprs: Vec<ExprNode>,
+}
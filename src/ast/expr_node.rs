@@ -1,21 +1,28 @@
 use crate::ast::ci_str::CIStr;
 use crate::ast::column_name::ColumnName;
 use crate::ast::common::FulltextSearchModifier;
+use crate::ast::field_type::FieldType;
 use crate::ast::functions::{GetFormatSelectorType, TimeUnitType, TrimDirectionType};
 use crate::ast::op_code;
 use crate::ast::order_by_clause::OrderByClause;
 use crate::ast::subquery_expr::SubQueryExpr;
 use crate::ast::table_name::TableName;
 use crate::ast::window_spec::WindowSpec;
+use crate::common::span::Span;
 use bigdecimal::BigDecimal;
-use derive_visitor::Drive;
+use derive_visitor::{Drive, DriveMut};
+use ordered_float::OrderedFloat;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Drive, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(tag = "type", content = "data")]
 pub enum ExprNode {
     #[default]
     #[drive(skip)]
     Default,
-    #[drive(skip)]
     ColumnNameExpr(ColumnNameExpr),
     SubQueryExpr(SubQueryExpr),
     RowExpr(RowExpr),
@@ -25,13 +32,9 @@ pub enum ExprNode {
     UnaryOperationExpr(UnaryOperationExpr),
     MatchAgainst(MatchAgainst),
     FuncCallExpr(FuncCallExpr),
-    #[drive(skip)]
     TimeUnitExpr(TimeUnitExpr),
-    #[drive(skip)]
     ValueExpr(ValueExpr),
-    #[drive(skip)]
     TrimDirectionExpr(TrimDirectionExpr),
-    #[drive(skip)]
     GetFormatSelectorExpr(GetFormatSelectorExpr),
     TableNameExpr(TableNameExpr),
     SetCollationExpr(SetCollationExpr),
@@ -39,10 +42,26 @@ pub enum ExprNode {
     PositionExpr(PositionExpr),
     ParamMarkerExpr(ParamMarkerExpr),
     AggregateFuncExpr(AggregateFuncExpr),
+    CaseExpr(CaseExpr),
+    FuncCastExpr(FuncCastExpr),
+    PatternInExpr(PatternInExpr),
+    BetweenExpr(BetweenExpr),
+    PatternLikeOrIlikeExpr(PatternLikeOrIlikeExpr),
+    PatternRegexpExpr(PatternRegexpExpr),
+    SoundsLikeExpr(SoundsLikeExpr),
+    MemberOfExpr(MemberOfExpr),
+    IsNullExpr(IsNullExpr),
+    IsTruthExpr(IsTruthExpr),
+    CompareSubqueryExpr(CompareSubqueryExpr),
+    ParenthesesExpr(ParenthesesExpr),
 }
 
-#[derive(Debug, Drive, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct VariableExpr {
+    #[drive(skip)]
+    pub span: Span,
     #[drive(skip)]
     pub name: String,
     #[drive(skip)]
@@ -51,12 +70,29 @@ pub struct VariableExpr {
     pub is_system: bool,
     #[drive(skip)]
     pub explicit_scope: bool,
+    // Scope is the scope written before the name of a system variable, e.g.
+    // the PERSIST of `@@persist.x`, which is global too.
+    #[drive(skip)]
+    pub scope: VariableScope,
     pub value: Option<Box<ExprNode>>,
 }
 
-#[derive(Debug, Drive, Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub enum VariableScope {
+    #[default]
+    None,
+    Global,
+    Session,
+    Persist,
+    PersistOnly,
+}
+
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct BinaryOperationExpr {
     #[drive(skip)]
+    pub span: Span,
     // Op is the operator code for BinaryOperation.
     pub op: op_code::OpCode,
     // L is the left expression in BinaryOperation.
@@ -67,8 +103,12 @@ pub struct BinaryOperationExpr {
 
 // ExistsSubqueryExpr is the expression for "exists (select ...)".
 // See https://dev.mysql.com/doc/refman/5.7/en/exists-and-not-exists-subqueries.html
-#[derive(Debug, Drive, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct ExistsSubqueryExpr {
+    #[drive(skip)]
+    pub span: Span,
     // Sel is the subquery, may be rewritten to other type of expression.
     pub sel: Option<Box<ExprNode>>,
     // Not is true, the expression is "not exists".
@@ -77,43 +117,75 @@ pub struct ExistsSubqueryExpr {
 }
 
 // UnaryOperationExpr is the expression for unary operator.
-#[derive(Debug, Drive, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct UnaryOperationExpr {
-    // Op is the operator opcode.
     #[drive(skip)]
+    pub span: Span,
+    // Op is the operator opcode.
     pub op: op_code::OpCode,
     // V is the unary expression.
     pub v: Option<Box<ExprNode>>,
 }
 
-#[derive(Debug, Default)]
+// ParenthesesExpr is the parentheses' expression.
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct ParenthesesExpr {
+    #[drive(skip)]
+    pub span: Span,
+    // Expr is the expression in parentheses.
+    pub expr: Option<Box<ExprNode>>,
+}
+
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct ColumnNameExpr {
+    #[drive(skip)]
+    pub span: Span,
     pub name: ColumnName,
 }
 
 // MatchAgainst is the expression for matching against fulltext index.
-#[derive(Debug, Drive, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct MatchAgainst {
-    // ColumnNames are the columns to match.
     #[drive(skip)]
+    pub span: Span,
+    // ColumnNames are the columns to match.
     pub column_names: Vec<ColumnName>,
     // Against
     pub against: Option<Box<ExprNode>>,
     // Modifier
-    #[drive(skip)]
     pub modifier: FulltextSearchModifier,
 }
 
-#[derive(Debug, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub enum FuncCallExprType {
     #[default]
     Keyword,
     Generic,
+    // JsonExtractArrow is `col->'$.path'`, the short form of
+    // JSON_EXTRACT(col, '$.path').
+    JsonExtractArrow,
+    // JsonUnquoteArrow is `col->>'$.path'`, the short form of
+    // JSON_UNQUOTE(JSON_EXTRACT(col, '$.path')). Only the outer JSON_UNQUOTE
+    // call is marked.
+    JsonUnquoteArrow,
 }
 // FuncCallExpr is for function expression.
-#[derive(Debug, Drive, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct FuncCallExpr {
     #[drive(skip)]
+    pub span: Span,
     pub tp: FuncCallExprType,
     #[drive(skip)]
     pub schema: CIStr,
@@ -125,13 +197,18 @@ pub struct FuncCallExpr {
 }
 
 // TimeUnitExpr is an expression representing a time or timestamp unit.
-#[derive(Debug)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct TimeUnitExpr {
+    #[drive(skip)]
+    pub span: Span,
     // Unit is the time or timestamp unit.
     pub unit: TimeUnitType,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", content = "data")]
 pub enum ValueExprData {
     #[default]
     Default,
@@ -140,8 +217,8 @@ pub enum ValueExprData {
     Isize(isize),
     I64(i64),
     U64(u64),
-    F32(f32),
-    F64(f64),
+    F32(OrderedFloat<f32>),
+    F64(OrderedFloat<f64>),
     String(String),
     Bytes(Vec<u8>),
     Decimal(BigDecimal),
@@ -151,7 +228,8 @@ pub enum ValueExprData {
     Other(String),
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", content = "data")]
 pub enum ValueExprKind {
     #[default]
     Default,
@@ -160,32 +238,58 @@ pub enum ValueExprKind {
     Isize(isize),
     I64(i64),
     U64(u64),
-    F32(f32),
-    F64(f64),
+    F32(OrderedFloat<f32>),
+    F64(OrderedFloat<f64>),
     String,
     Bytes(Vec<u8>),
     Decimal(BigDecimal),
     BinaryLiteral,
-    BitLiteral,
-    HexLiteral,
+    // BitLiteral holds the bytes of `0b101` or `B'101'`.
+    BitLiteral(Vec<u8>),
+    // HexLiteral holds the bytes of `0x0A` or `X'0A'`.
+    HexLiteral(Vec<u8>),
     Other,
 }
 
-#[derive(Debug, Default)]
+// LiteralPrefix is the prefix of a literal that its charset and kind don't
+// tell, it's kept to restore the literal as it was written.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub enum LiteralPrefix {
+    #[default]
+    None,
+    // National is the N of `N'abc'`.
+    National,
+    // Binary is the `_binary` introducer of `_binary 0xFF` or `_binary B'101'`.
+    Binary,
+}
+
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct ValueExpr {
+    #[drive(skip)]
+    pub span: Span,
+    #[drive(skip)]
     pub s: String,
+    #[drive(skip)]
     pub kind: ValueExprKind,
+    #[drive(skip)]
     pub charset: String,
+    #[drive(skip)]
     pub collation: String,
+    #[drive(skip)]
+    pub prefix: LiteralPrefix,
 }
 
 impl ValueExpr {
     pub fn new(s: &str, kind: ValueExprKind, charset: &str, collation: &str) -> Self {
         ValueExpr {
+            span: None,
             s: s.to_string(),
             kind,
             charset: charset.to_string(),
             collation: collation.to_string(),
+            prefix: LiteralPrefix::None,
         }
     }
 
@@ -199,36 +303,60 @@ impl ValueExpr {
     }
 }
 
-#[derive(Debug, Drive)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct RowExpr {
+    #[drive(skip)]
+    pub span: Span,
     pub values: Vec<ExprNode>,
 }
 
-#[derive(Debug)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct TrimDirectionExpr {
+    #[drive(skip)]
+    pub span: Span,
     pub direction: TrimDirectionType,
 }
 
-#[derive(Debug)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct GetFormatSelectorExpr {
+    #[drive(skip)]
+    pub span: Span,
     pub selector: GetFormatSelectorType,
 }
 
-#[derive(Debug, Drive)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct TableNameExpr {
+    #[drive(skip)]
+    pub span: Span,
     pub name: TableName,
 }
 
-#[derive(Debug, Drive)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct SetCollationExpr {
+    #[drive(skip)]
+    pub span: Span,
     pub expr: Option<Box<ExprNode>>,
     #[drive(skip)]
     pub collate: String,
 }
 
 // WindowFuncExpr represents window function expression.
-#[derive(Debug, Drive, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct WindowFuncExpr {
+    #[drive(skip)]
+    pub span: Span,
     // Name is the function name.
     #[drive(skip)]
     pub name: String,
@@ -253,8 +381,12 @@ pub struct WindowFuncExpr {
 // PositionExpr is the expression for order by and group by position.
 // MySQL use position expression started from 1, it looks a little confused inner.
 // maybe later we will use 0 at first.
-#[derive(Debug, Drive, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct PositionExpr {
+    #[drive(skip)]
+    pub span: Span,
     // N is the position, started from 1 now.
     #[drive(skip)]
     pub n: isize,
@@ -262,8 +394,12 @@ pub struct PositionExpr {
     pub p: Option<Box<ExprNode>>,
 }
 
-#[derive(Debug, Drive, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct ParamMarkerExpr {
+    #[drive(skip)]
+    pub span: Span,
     #[drive(skip)]
     pub offset: isize,
     #[drive(skip)]
@@ -280,8 +416,12 @@ pub struct ParamMarkerExpr {
 }
 
 // AggregateFuncExpr represents aggregate function expression.
-#[derive(Debug, Drive, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct AggregateFuncExpr {
+    #[drive(skip)]
+    pub span: Span,
     // F is the function name.
     #[drive(skip)]
     pub f: String,
@@ -294,3 +434,231 @@ pub struct AggregateFuncExpr {
     pub distinct: bool,
     pub order: Option<OrderByClause>,
 }
+
+// CaseExpr is the case expression.
+// See https://dev.mysql.com/doc/refman/8.0/en/flow-control-functions.html#operator_case
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct CaseExpr {
+    #[drive(skip)]
+    pub span: Span,
+    // Value is the compare value expression, None for the searched case.
+    pub value: Option<Box<ExprNode>>,
+    // WhenClauses is the condition check expression.
+    pub when_clauses: Vec<WhenClause>,
+    // ElseClause is the else result expression.
+    pub else_clause: Option<Box<ExprNode>>,
+}
+
+// WhenClause is the when clause in Case expression for "when condition then result".
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct WhenClause {
+    #[drive(skip)]
+    pub span: Span,
+    // Expr is the condition expression in WhenClause.
+    pub expr: Box<ExprNode>,
+    // Result is the result expression in WhenClause.
+    pub result: Box<ExprNode>,
+}
+
+// CastFunctionType is the type for cast function.
+#[derive(
+    Debug,
+    Drive,
+    DriveMut,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+)]
+pub enum CastFunctionType {
+    // CastFunction is CAST function.
+    #[default]
+    CastFunction,
+    // CastConvertFunction is CONVERT function.
+    CastConvertFunction,
+    // CastBinaryOperator is BINARY operator.
+    CastBinaryOperator,
+}
+
+// FuncCastExpr is the cast function converting value to another type, e.g:
+// cast(expr AS signed)
+// See https://dev.mysql.com/doc/refman/8.0/en/cast-functions.html
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct FuncCastExpr {
+    #[drive(skip)]
+    pub span: Span,
+    // Expr is the expression to be converted.
+    pub expr: Option<Box<ExprNode>>,
+    // Tp is the conversion type.
+    pub tp: FieldType,
+    // FunctionType is either Cast, Convert or Binary.
+    pub function_type: CastFunctionType,
+    // ExplicitCharSet is true when charset is explicit indicated.
+    #[drive(skip)]
+    pub explicit_charset: bool,
+}
+
+// PatternInExpr is the expression for in operator, like "expr in (1, 2, 3)" or "expr in (select c from t)".
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct PatternInExpr {
+    #[drive(skip)]
+    pub span: Span,
+    // Expr is the value expression to be compared.
+    pub expr: Option<Box<ExprNode>>,
+    // List is the list expression in compare list.
+    pub list: Vec<ExprNode>,
+    // Not is true, the expression is "not in".
+    #[drive(skip)]
+    pub not: bool,
+    // Sel is the subquery, may be rewritten to other type of expression.
+    pub sel: Option<Box<ExprNode>>,
+}
+
+// BetweenExpr is for "between and" or "not between and" expression.
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct BetweenExpr {
+    #[drive(skip)]
+    pub span: Span,
+    // Expr is the expression to be checked.
+    pub expr: Option<Box<ExprNode>>,
+    // Left is the expression for minimal value in the range.
+    pub left: Option<Box<ExprNode>>,
+    // Right is the expression for maximum value in the range.
+    pub right: Option<Box<ExprNode>>,
+    // Not is true, the expression is "not between and".
+    #[drive(skip)]
+    pub not: bool,
+}
+
+// PatternLikeOrIlikeExpr is the expression for like operator, e.g, expr like "%123%"
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct PatternLikeOrIlikeExpr {
+    #[drive(skip)]
+    pub span: Span,
+    // Expr is the expression to be checked.
+    pub expr: Option<Box<ExprNode>>,
+    // Pattern is the like expression.
+    pub pattern: Option<Box<ExprNode>>,
+    // Not is true, the expression is "not like".
+    #[drive(skip)]
+    pub not: bool,
+    // Escape is the escape character, '\\' by default, it's None for
+    // `ESCAPE ''` which turns escaping off.
+    #[drive(skip)]
+    pub escape: Option<char>,
+    // IsLike is false for ILIKE, the case insensitive like.
+    #[drive(skip)]
+    pub is_like: bool,
+}
+
+// PatternRegexpExpr is the pattern expression for pattern match.
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct PatternRegexpExpr {
+    #[drive(skip)]
+    pub span: Span,
+    // Expr is the expression to be checked.
+    pub expr: Option<Box<ExprNode>>,
+    // Pattern is the expression for pattern.
+    pub pattern: Option<Box<ExprNode>>,
+    // Not is true, the expression is "not rlike",
+    #[drive(skip)]
+    pub not: bool,
+}
+
+// SoundsLikeExpr is the expression for `expr SOUNDS LIKE pattern`, which is
+// the same as `SOUNDEX(expr) = SOUNDEX(pattern)`.
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct SoundsLikeExpr {
+    #[drive(skip)]
+    pub span: Span,
+    // Expr is the expression to be checked.
+    pub expr: Option<Box<ExprNode>>,
+    // Pattern is the expression compared by sound.
+    pub pattern: Option<Box<ExprNode>>,
+}
+
+// MemberOfExpr is the expression for `expr MEMBER OF (json_array)`.
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct MemberOfExpr {
+    #[drive(skip)]
+    pub span: Span,
+    // Expr is the value looked for.
+    pub expr: Option<Box<ExprNode>>,
+    // Json is the JSON array searched.
+    pub json: Option<Box<ExprNode>>,
+}
+
+// IsNullExpr is the expression for null check.
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct IsNullExpr {
+    #[drive(skip)]
+    pub span: Span,
+    // Expr is the expression to be checked.
+    pub expr: Option<Box<ExprNode>>,
+    // Not is true, the expression is "is not null".
+    #[drive(skip)]
+    pub not: bool,
+}
+
+// IsTruthExpr is the expression for true/false check.
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct IsTruthExpr {
+    #[drive(skip)]
+    pub span: Span,
+    // Expr is the expression to be checked.
+    pub expr: Option<Box<ExprNode>>,
+    // Not is true, the expression is "is not true/false".
+    #[drive(skip)]
+    pub not: bool,
+    // True indicates checking true or false.
+    #[drive(skip)]
+    pub true_val: i64,
+}
+
+// CompareSubqueryExpr is the expression for "expr cmp (select ...)".
+// See https://dev.mysql.com/doc/refman/5.7/en/comparisons-using-subqueries.html
+// See https://dev.mysql.com/doc/refman/5.7/en/any-in-some-subqueries.html
+// See https://dev.mysql.com/doc/refman/5.7/en/all-subqueries.html
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct CompareSubqueryExpr {
+    #[drive(skip)]
+    pub span: Span,
+    // L is the left expression
+    pub l: Option<Box<ExprNode>>,
+    // Op is the comparison opcode.
+    pub op: op_code::OpCode,
+    // R is the subquery for right expression, may be rewritten to other type of expression.
+    pub r: Option<Box<ExprNode>>,
+    // All is true, we should compare all records in subquery.
+    #[drive(skip)]
+    pub all: bool,
+}
@@ -0,0 +1,105 @@
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// DataType is the type of a column or of a cast target.
+// See https://dev.mysql.com/doc/refman/8.0/en/data-types.html
+#[derive(
+    Debug,
+    Drive,
+    DriveMut,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Hash,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+)]
+pub enum DataType {
+    #[default]
+    Unspecified,
+    TinyInt,
+    SmallInt,
+    MediumInt,
+    Int,
+    BigInt,
+    Decimal,
+    Float,
+    Double,
+    Bit,
+    Date,
+    DateTime,
+    Timestamp,
+    Time,
+    Year,
+    Char,
+    VarChar,
+    Binary,
+    VarBinary,
+    TinyBlob,
+    Blob,
+    MediumBlob,
+    LongBlob,
+    TinyText,
+    Text,
+    MediumText,
+    LongText,
+    Enum,
+    Set,
+    Json,
+    Geometry,
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+}
+
+// FieldType is the type of a column definition, a JSON_TABLE column, the
+// target of CAST and CONVERT, etc.
+#[derive(
+    Debug, Drive, DriveMut, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct FieldType {
+    pub tp: DataType,
+    // Flen is the length or the display width, e.g. the `10` of `CHAR(10)`.
+    #[drive(skip)]
+    pub flen: Option<u64>,
+    // Decimal is the scale of DECIMAL or the fractional seconds precision of
+    // DATETIME, TIME and TIMESTAMP.
+    #[drive(skip)]
+    pub decimal: Option<u64>,
+    #[drive(skip)]
+    pub unsigned: bool,
+    #[drive(skip)]
+    pub zerofill: bool,
+    // Binary is true for the `BINARY` attribute of character types, it means
+    // the binary collation of the charset.
+    #[drive(skip)]
+    pub binary: bool,
+    #[drive(skip)]
+    pub charset: String,
+    #[drive(skip)]
+    pub collate: String,
+    // Elems is the elements of ENUM and SET.
+    #[drive(skip)]
+    pub elems: Vec<String>,
+    // Array is true for the cast target of a multi-valued index, e.g.
+    // `CAST(j->'$.tags' AS CHAR(10) ARRAY)`.
+    #[drive(skip)]
+    pub array: bool,
+}
+
+impl FieldType {
+    pub fn new(tp: DataType) -> Self {
+        FieldType {
+            tp,
+            ..Default::default()
+        }
+    }
+}
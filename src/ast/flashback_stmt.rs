@@ -0,0 +1,55 @@
+use crate::ast::ci_str::CIStr;
+use crate::ast::expr_node::ExprNode;
+use crate::ast::table_name::TableName;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// FlashBackTableStmt is a statement to restore a dropped or truncated table.
+// See https://docs.pingcap.com/tidb/stable/sql-statement-flashback-table
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct FlashBackTableStmt {
+    #[drive(skip)]
+    pub span: Span,
+    pub table: TableName,
+    // NewName is the name given by `TO new_name`, it's empty if omitted.
+    #[drive(skip)]
+    pub new_name: CIStr,
+}
+
+// FlashBackDatabaseStmt is a statement to restore a dropped database.
+// See https://docs.pingcap.com/tidb/stable/sql-statement-flashback-database
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct FlashBackDatabaseStmt {
+    #[drive(skip)]
+    pub span: Span,
+    #[drive(skip)]
+    pub db_name: CIStr,
+    #[drive(skip)]
+    pub new_name: CIStr,
+}
+
+// FlashBackToTimestampStmt is a statement to restore the cluster, a
+// database or tables to their state at a timestamp or TSO.
+// See https://docs.pingcap.com/tidb/stable/sql-statement-flashback-cluster
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct FlashBackToTimestampStmt {
+    #[drive(skip)]
+    pub span: Span,
+    // FlashbackTS is the timestamp of `TO TIMESTAMP`, FlashbackTSO is set
+    // instead by `TO TSO`.
+    pub flashback_ts: Option<ExprNode>,
+    #[drive(skip)]
+    pub flashback_tso: u64,
+    // Tables and DBName are empty when the whole cluster is restored.
+    pub tables: Vec<TableName>,
+    #[drive(skip)]
+    pub db_name: CIStr,
+}
@@ -0,0 +1,758 @@
+use std::collections::VecDeque;
+
+use crate::ast::expr_node::{CaseExpr, ExprNode, PatternInExpr};
+use crate::ast::node::Node;
+use crate::ast::op_code::OpCode;
+use crate::ast::restore::{
+    restore_sql_with_dialect, restore_window_spec_details, Restore, RestoreCtx, RestoreFlags,
+    RESTORE_KEYWORD_LOWERCASE, RESTORE_KEYWORD_UPPERCASE, RESTORE_NAME_QUOTES_WHEN_NEEDED,
+    RESTORE_STRING_SINGLE_QUOTES,
+};
+use crate::ast::result_set_node::ResultSetNode;
+use crate::ast::select_field::{Field, SelectField};
+use crate::ast::select_lock_info::SelectLockType;
+use crate::ast::select_stmt::{SelectStmt, SelectStmtKind};
+use crate::ast::set_opr_stmt::{SetOprSelectList, SetOprStmt};
+use crate::ast::spanned::Spanned;
+use crate::ast::statement::Statement;
+use crate::ast::table_refs_clause::{JoinType, TableRefsClause};
+use crate::ast::with_clause::WithClause;
+use crate::common::error::{ParseError, Result};
+use crate::common::span::Span;
+use crate::mysql::consts::SqlMode;
+use crate::parser::input::Dialect;
+use crate::parser::parser::{parse_sql, tokenize_sql_with_dialect};
+use crate::parser::token::Token;
+
+// KeywordCase is the case of the keywords written by the formatter.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum KeywordCase {
+    #[default]
+    Upper,
+    Lower,
+}
+
+// FormatConfig controls the layout of the formatted SQL. Unlike the restore
+// flags, which only decide how each token is spelled, it decides where the
+// lines break and how they are indented.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatConfig {
+    // IndentWidth is the number of spaces of one indent level.
+    pub indent_width: usize,
+    // MaxWidth is the line width beyond which long IN lists, CASE expressions
+    // and AND/OR conditions are wrapped.
+    pub max_width: usize,
+    // FieldPerLine writes every select field on a line of its own.
+    pub field_per_line: bool,
+    // AlignJoins pads the joined tables of a FROM clause so that their ON
+    // conditions start at the same column.
+    pub align_joins: bool,
+    // CommaFirst puts the separating comma of a broken list at the start of
+    // the next line instead of the end of the previous one.
+    pub comma_first: bool,
+    pub keyword_case: KeywordCase,
+}
+
+impl Default for FormatConfig {
+    fn default() -> Self {
+        FormatConfig {
+            indent_width: 4,
+            max_width: 80,
+            field_per_line: true,
+            align_joins: true,
+            comma_first: false,
+            keyword_case: KeywordCase::Upper,
+        }
+    }
+}
+
+impl FormatConfig {
+    // RestoreFlags returns the flags used for the parts of the statement
+    // written on a single line.
+    fn restore_flags(&self) -> RestoreFlags {
+        let keyword_case = match self.keyword_case {
+            KeywordCase::Upper => RESTORE_KEYWORD_UPPERCASE,
+            KeywordCase::Lower => RESTORE_KEYWORD_LOWERCASE,
+        };
+        keyword_case | RESTORE_STRING_SINGLE_QUOTES | RESTORE_NAME_QUOTES_WHEN_NEEDED
+    }
+}
+
+// FormatStatement formats the statement with the layout of the config, for
+// the default dialect.
+pub fn format_statement(stmt: &Statement, config: &FormatConfig) -> Result<String> {
+    let mut formatter = Formatter::new(config, Dialect::default(), VecDeque::new());
+    formatter.format_statement(stmt)?;
+    Ok(formatter.finish())
+}
+
+// FormatSql parses and formats a single statement. The comments of the
+// source are kept, each on a line of its own before the clause or list item
+// that follows it.
+pub fn format_sql(sql: &str, dialect: Dialect, config: &FormatConfig) -> Result<String> {
+    let tokens = tokenize_sql_with_dialect(sql, dialect, SqlMode::default())?;
+    let stmt = parse_sql(&tokens, dialect)?;
+    let mut formatter = Formatter::new(config, dialect, source_comments(sql, &tokens));
+    formatter.format_statement(&stmt)?;
+    Ok(formatter.finish())
+}
+
+struct Comment {
+    start: usize,
+    text: String,
+}
+
+// SourceComments collects the comments skipped by the tokenizer, they are
+// the only non-blank text between two tokens.
+fn source_comments(sql: &str, tokens: &[Token]) -> VecDeque<Comment> {
+    let mut comments = VecDeque::new();
+    let mut pos = 0;
+    for token in tokens {
+        let start = token.span.start();
+        if start > pos {
+            if let Some(gap) = sql.get(pos..start) {
+                collect_comments(gap, pos, &mut comments);
+            }
+        }
+        pos = pos.max(token.span.end());
+    }
+    comments
+}
+
+fn collect_comments(gap: &str, offset: usize, comments: &mut VecDeque<Comment>) {
+    let mut i = 0;
+    while i < gap.len() {
+        let rest = &gap[i..];
+        let text = rest.trim_start();
+        if text.is_empty() {
+            break;
+        }
+        i += rest.len() - text.len();
+        let len = match text.find("*/") {
+            Some(end) if text.starts_with("/*") => end + 2,
+            _ if text.starts_with("--") || text.starts_with('#') => {
+                text.find('\n').unwrap_or(text.len())
+            }
+            _ => {
+                // The rest of an executable comment whose content is
+                // tokenized, e.g. its `/*!80000` opening or closing `*/`.
+                i += text.find(char::is_whitespace).unwrap_or(text.len());
+                continue;
+            }
+        };
+        comments.push_back(Comment {
+            start: offset + i,
+            text: text[..len].trim_end().to_string(),
+        });
+        i += len;
+    }
+}
+
+struct Formatter<'a> {
+    config: &'a FormatConfig,
+    ctx: RestoreCtx,
+    // The source comments not written yet, in source order.
+    comments: VecDeque<Comment>,
+}
+
+impl<'a> Formatter<'a> {
+    fn new(config: &'a FormatConfig, dialect: Dialect, comments: VecDeque<Comment>) -> Self {
+        Formatter {
+            config,
+            ctx: RestoreCtx::with_dialect(config.restore_flags(), dialect, SqlMode::default()),
+            comments,
+        }
+    }
+
+    fn finish(mut self) -> String {
+        while let Some(comment) = self.comments.pop_front() {
+            self.line_break(0);
+            self.ctx.write_plain(&comment.text);
+        }
+        self.ctx.out
+    }
+
+    fn flat<T: Restore>(&self, node: &T) -> Result<String> {
+        restore_sql_with_dialect(node, self.ctx.flags, self.ctx.dialect, self.ctx.sql_mode)
+    }
+
+    fn column(&self) -> usize {
+        let line_start = self.ctx.out.rfind('\n').map_or(0, |i| i + 1);
+        self.ctx.out[line_start..].chars().count()
+    }
+
+    // Fits reports whether the text can be written at the end of the current
+    // line without exceeding the max width.
+    fn fits(&self, text: &str) -> bool {
+        self.column() + text.chars().count() <= self.config.max_width
+    }
+
+    fn line_break(&mut self, level: usize) {
+        let trimmed_len = self.ctx.out.trim_end_matches(' ').len();
+        self.ctx.out.truncate(trimmed_len);
+        self.ctx.write_plain("\n");
+        self.ctx
+            .write_plain(&" ".repeat(level * self.config.indent_width));
+    }
+
+    // NewLine starts a line at the indent level for the node at `span`, the
+    // source comments before the node are written on lines of their own
+    // first.
+    fn new_line(&mut self, level: usize, span: Span) {
+        self.line_break(level);
+        self.write_comments(level, span);
+    }
+
+    fn write_comments(&mut self, level: usize, span: Span) {
+        let pos = match span {
+            Some(span) => span.start(),
+            None => return,
+        };
+        while self.comments.front().is_some_and(|c| c.start < pos) {
+            let comment = self.comments.pop_front().unwrap();
+            self.ctx.write_plain(&comment.text);
+            self.line_break(level);
+        }
+    }
+
+    fn format_statement(&mut self, stmt: &Statement) -> Result<()> {
+        self.write_comments(0, stmt.span());
+        match stmt {
+            Statement::SelectStmt(stmt) => self.format_select(stmt, 0),
+            Statement::SetOprStmt(stmt) => self.format_set_opr(stmt, 0),
+            stmt => stmt.restore(&mut self.ctx),
+        }
+    }
+
+    fn format_query(&mut self, query: &ResultSetNode, level: usize) -> Result<()> {
+        match query {
+            ResultSetNode::SelectStmt(stmt) => self.format_select(stmt, level),
+            ResultSetNode::SetOprStmt(stmt) => self.format_set_opr(stmt, level),
+            query => query.restore(&mut self.ctx),
+        }
+    }
+
+    // FormatSubquery writes the query in parentheses, indented one level
+    // deeper than the parentheses.
+    fn format_subquery(&mut self, query: &ResultSetNode, level: usize) -> Result<()> {
+        self.ctx.write_plain("(");
+        self.new_line(level + 1, query.span());
+        self.format_query(query, level + 1)?;
+        self.line_break(level);
+        self.ctx.write_plain(")");
+        Ok(())
+    }
+
+    fn format_with(&mut self, with: &WithClause, level: usize) -> Result<()> {
+        self.ctx.write_keyword("WITH ");
+        if with.is_recursive {
+            self.ctx.write_keyword("RECURSIVE ");
+        }
+        for (i, cte) in with.ctes.iter().enumerate() {
+            if i > 0 {
+                self.ctx.write_plain(",");
+                self.new_line(level, cte.span);
+            }
+            self.ctx.write_name(&cte.name.origin);
+            if !cte.col_name_list.is_empty() {
+                self.ctx.write_plain(" (");
+                for (j, name) in cte.col_name_list.iter().enumerate() {
+                    if j > 0 {
+                        self.ctx.write_plain(", ");
+                    }
+                    self.ctx.write_name(&name.origin);
+                }
+                self.ctx.write_plain(")");
+            }
+            self.ctx.write_keyword(" AS ");
+            match cte.query.as_ref().and_then(|query| query.query.as_ref()) {
+                Some(query) => self.format_subquery(query, level)?,
+                None => {
+                    return Err(ParseError(
+                        cte.span,
+                        "failed to format, the common table expression has no query".to_string(),
+                    ))
+                }
+            }
+        }
+        self.new_line(level, None);
+        Ok(())
+    }
+
+    fn format_select(&mut self, stmt: &SelectStmt, level: usize) -> Result<()> {
+        // TABLE and VALUES statements are short, they are written on one
+        // line.
+        if !matches!(stmt.kind, SelectStmtKind::SelectStmtKindSelect) {
+            return stmt.restore(&mut self.ctx);
+        }
+
+        if stmt.with_before_braces {
+            if let Some(with) = &stmt.with {
+                self.format_with(with, level)?;
+            }
+        }
+        if stmt.is_in_braces {
+            self.ctx.write_plain("(");
+        }
+        if !stmt.with_before_braces {
+            if let Some(with) = &stmt.with {
+                self.format_with(with, level)?;
+            }
+        }
+
+        self.ctx.write_keyword("SELECT");
+        let mut opts =
+            RestoreCtx::with_dialect(self.ctx.flags, self.ctx.dialect, self.ctx.sql_mode);
+        stmt.restore_select_opts(&mut opts)?;
+        if !opts.out.is_empty() {
+            self.ctx.write_plain(" ");
+            self.ctx.write_plain(opts.out.trim_end());
+        }
+        self.format_fields(&stmt.fields, level)?;
+
+        if let Some(from) = &stmt.from {
+            self.new_line(level, from.span);
+            self.ctx.write_keyword("FROM ");
+            self.format_table_refs(from, level)?;
+        }
+        if let Some(where_clause) = &stmt.where_clause {
+            self.new_line(level, where_clause.span());
+            self.ctx.write_keyword("WHERE ");
+            self.format_expr(where_clause, level + 1)?;
+        }
+        if let Some(group_by) = &stmt.group_by {
+            self.new_line(level, group_by.span);
+            group_by.restore(&mut self.ctx)?;
+        }
+        if let Some(having) = &stmt.having {
+            self.new_line(level, having.span());
+            self.ctx.write_keyword("HAVING ");
+            self.format_expr(having, level + 1)?;
+        }
+        for (i, spec) in stmt.window_specs.iter().enumerate() {
+            if i == 0 {
+                self.new_line(level, spec.span);
+                self.ctx.write_keyword("WINDOW ");
+            } else {
+                self.ctx.write_plain(",");
+                self.new_line(level + 1, spec.span);
+            }
+            self.ctx.write_name(&spec.name.origin);
+            self.ctx.write_keyword(" AS ");
+            self.ctx.write_plain("(");
+            restore_window_spec_details(spec, &mut self.ctx)?;
+            self.ctx.write_plain(")");
+        }
+        if let Some(order_by) = &stmt.order_by {
+            self.new_line(level, order_by.span);
+            order_by.restore(&mut self.ctx)?;
+        }
+        if let Some(limit) = &stmt.limit {
+            self.new_line(level, limit.span);
+            limit.restore(&mut self.ctx)?;
+        }
+        if let Some(lock_info) = &stmt.lock_info {
+            if !matches!(lock_info.lock_type, SelectLockType::SelectLockNone) {
+                self.new_line(level, lock_info.span);
+                lock_info.restore(&mut self.ctx)?;
+            }
+        }
+        if let Some(select_into_opt) = &stmt.select_into_opt {
+            self.new_line(level, select_into_opt.span);
+            select_into_opt.restore(&mut self.ctx)?;
+        }
+        if stmt.is_in_braces {
+            self.ctx.write_plain(")");
+        }
+        Ok(())
+    }
+
+    fn format_fields(&mut self, fields: &[SelectField], level: usize) -> Result<()> {
+        if !self.config.field_per_line {
+            // The fields fill the lines up to the max width.
+            for (i, field) in fields.iter().enumerate() {
+                let text = self.flat(field)?;
+                if i > 0 {
+                    self.ctx.write_plain(",");
+                }
+                if i == 0 || self.fits(&format!(" {}", text)) {
+                    self.ctx.write_plain(" ");
+                    self.write_comments(level + 1, field.span);
+                } else {
+                    self.new_line(level + 1, field.span);
+                }
+                self.format_field(field, level + 1)?;
+            }
+            return Ok(());
+        }
+
+        for (i, field) in fields.iter().enumerate() {
+            self.new_line(level + 1, field.span);
+            if self.config.comma_first {
+                self.ctx.write_plain(if i == 0 { "  " } else { ", " });
+            }
+            self.format_field(field, level + 1)?;
+            if !self.config.comma_first && i + 1 < fields.len() {
+                self.ctx.write_plain(",");
+            }
+        }
+        Ok(())
+    }
+
+    fn format_field(&mut self, field: &SelectField, level: usize) -> Result<()> {
+        match &field.field {
+            Field::Expr(expr) => self.format_expr(expr, level + 1)?,
+            _ => return field.restore(&mut self.ctx),
+        }
+        if !field.as_name.origin.is_empty() {
+            self.ctx.write_keyword(" AS ");
+            self.ctx.write_name(&field.as_name.origin);
+        }
+        Ok(())
+    }
+
+    // FormatTableRefs writes the first table after `FROM` and every joined
+    // table on a line of its own.
+    fn format_table_refs(&mut self, refs: &TableRefsClause, level: usize) -> Result<()> {
+        if refs.explicit_parens {
+            return refs.restore(&mut self.ctx);
+        }
+        let mut first = refs.left.as_ref();
+        let mut joins = vec![];
+        collect_joins(refs, &mut first, &mut joins);
+
+        // The width of `JOIN table` of the joins with an ON condition, the
+        // conditions are aligned after the widest one.
+        let mut on_column = 0;
+        if self.config.align_joins {
+            for join in &joins {
+                if join.is_comma_join() || matches!(join.on.as_ref(), ExprNode::Default) {
+                    continue;
+                }
+                if let Some(right) = &join.right {
+                    if !is_derived_table(right) {
+                        let width =
+                            join_keyword(join).len() + 1 + self.flat(right)?.chars().count();
+                        on_column = on_column.max(width);
+                    }
+                }
+            }
+        }
+
+        self.format_table_factor(first, level + 1)?;
+        for join in joins {
+            let right = match &join.right {
+                Some(right) => right.as_ref(),
+                None => continue,
+            };
+            if join.is_comma_join() {
+                if self.config.comma_first {
+                    self.new_line(level + 1, right.span());
+                    self.ctx.write_plain(", ");
+                } else {
+                    self.ctx.write_plain(",");
+                    self.new_line(level + 1, right.span());
+                }
+                self.format_table_factor(right, level + 1)?;
+                continue;
+            }
+
+            self.new_line(level + 1, right.span());
+            let start = self.column();
+            self.ctx.write_keyword(&join_keyword(join));
+            self.ctx.write_plain(" ");
+            self.format_table_factor(right, level + 1)?;
+            if !matches!(join.on.as_ref(), ExprNode::Default) {
+                let width = self.column() - start;
+                if on_column > width && !is_derived_table(right) {
+                    self.ctx.write_plain(&" ".repeat(on_column - width));
+                }
+                self.ctx.write_keyword(" ON ");
+                self.format_expr(&join.on, level + 2)?;
+            }
+            if !join.using.is_empty() {
+                self.ctx.write_keyword(" USING ");
+                self.ctx.write_plain("(");
+                for (i, column) in join.using.iter().enumerate() {
+                    if i > 0 {
+                        self.ctx.write_plain(", ");
+                    }
+                    column.restore(&mut self.ctx)?;
+                }
+                self.ctx.write_plain(")");
+            }
+        }
+        Ok(())
+    }
+
+    fn format_table_factor(&mut self, node: &ResultSetNode, level: usize) -> Result<()> {
+        match node {
+            ResultSetNode::TableSource(source) if is_derived_table(node) => {
+                self.format_subquery(&source.source, level)?;
+                if !source.as_name.origin.is_empty() {
+                    self.ctx.write_keyword(" AS ");
+                    self.ctx.write_name(&source.as_name.origin);
+                }
+                Ok(())
+            }
+            node => node.restore(&mut self.ctx),
+        }
+    }
+
+    fn format_set_opr(&mut self, stmt: &SetOprStmt, level: usize) -> Result<()> {
+        if stmt.is_in_braces {
+            self.ctx.write_plain("(");
+        }
+        if let Some(with) = &stmt.with {
+            self.format_with(with, level)?;
+        }
+        match &stmt.select_list {
+            Some(list) => self.format_set_opr_list(list, level)?,
+            None => return stmt.restore(&mut self.ctx),
+        }
+        if let Some(order_by) = &stmt.order_by {
+            self.new_line(level, order_by.span);
+            order_by.restore(&mut self.ctx)?;
+        }
+        if let Some(limit) = &stmt.limit {
+            self.new_line(level, limit.span);
+            limit.restore(&mut self.ctx)?;
+        }
+        if stmt.is_in_braces {
+            self.ctx.write_plain(")");
+        }
+        Ok(())
+    }
+
+    // FormatSetOprList writes the operands of a set operation at the same
+    // indent level, the operators on lines of their own between them.
+    fn format_set_opr_list(&mut self, list: &SetOprSelectList, level: usize) -> Result<()> {
+        if let Some(with) = &list.with {
+            self.format_with(with, level)?;
+        }
+        for (i, node) in list.selects.iter().enumerate() {
+            let after_set_operator = match node {
+                Node::Statement(Statement::SelectStmt(stmt)) => &stmt.after_set_operator,
+                Node::SetOprSelectList(list) => &list.after_set_operator,
+                node => return node.restore(&mut self.ctx),
+            };
+            if i > 0 {
+                self.line_break(level);
+                match after_set_operator {
+                    Some(op) => op.restore(&mut self.ctx)?,
+                    None => {
+                        return Err(ParseError(
+                            node.span(),
+                            "failed to format, the set operator is missing".to_string(),
+                        ))
+                    }
+                }
+                self.new_line(level, node.span());
+            }
+            match node {
+                Node::Statement(Statement::SelectStmt(stmt)) => self.format_select(stmt, level)?,
+                Node::SetOprSelectList(list) => {
+                    self.ctx.write_plain("(");
+                    self.format_set_opr_list(list, level)?;
+                    self.ctx.write_plain(")");
+                }
+                node => node.restore(&mut self.ctx)?,
+            }
+        }
+        if let Some(order_by) = &list.order_by {
+            self.new_line(level, order_by.span);
+            order_by.restore(&mut self.ctx)?;
+        }
+        if let Some(limit) = &list.limit {
+            self.new_line(level, limit.span);
+            limit.restore(&mut self.ctx)?;
+        }
+        Ok(())
+    }
+
+    // FormatExpr writes the expression on the current line if it fits, or
+    // else breaks the AND/OR conditions, CASE expressions, IN lists and
+    // subqueries in it, looking through the parentheses, NOT, IS and EXISTS
+    // around them. `level` is the indent level of the broken lines.
+    fn format_expr(&mut self, expr: &ExprNode, level: usize) -> Result<()> {
+        let text = self.flat(expr)?;
+        if self.fits(&text) {
+            self.ctx.write_plain(&text);
+            return Ok(());
+        }
+        match expr {
+            ExprNode::BinaryOperationExpr(node)
+                if matches!(
+                    node.op,
+                    OpCode::LogicAnd | OpCode::LogicOr | OpCode::LogicXor
+                ) =>
+            {
+                match (&node.l, &node.r) {
+                    (Some(l), Some(r)) => {
+                        self.format_expr(l, level)?;
+                        self.new_line(level, r.span());
+                        node.op.restore(&mut self.ctx)?;
+                        self.ctx.write_plain(" ");
+                        self.format_expr(r, level)
+                    }
+                    _ => expr.restore(&mut self.ctx),
+                }
+            }
+            // The conditions inside the parentheses are indented one level
+            // deeper than the ones around them.
+            ExprNode::ParenthesesExpr(node) => match &node.expr {
+                Some(inner) => {
+                    self.ctx.write_plain("(");
+                    self.format_expr(inner, level + 1)?;
+                    self.ctx.write_plain(")");
+                    Ok(())
+                }
+                None => expr.restore(&mut self.ctx),
+            },
+            ExprNode::UnaryOperationExpr(node) if node.op == OpCode::Not => match &node.v {
+                Some(v) => {
+                    self.ctx.write_keyword("NOT ");
+                    self.format_expr(v, level)
+                }
+                None => expr.restore(&mut self.ctx),
+            },
+            ExprNode::IsNullExpr(node) => match &node.expr {
+                Some(inner) => {
+                    self.format_expr(inner, level)?;
+                    self.ctx
+                        .write_keyword(if node.not { " IS NOT NULL" } else { " IS NULL" });
+                    Ok(())
+                }
+                None => expr.restore(&mut self.ctx),
+            },
+            ExprNode::IsTruthExpr(node) => match &node.expr {
+                Some(inner) => {
+                    self.format_expr(inner, level)?;
+                    self.ctx
+                        .write_keyword(if node.not { " IS NOT " } else { " IS " });
+                    self.ctx
+                        .write_keyword(if node.true_val > 0 { "TRUE" } else { "FALSE" });
+                    Ok(())
+                }
+                None => expr.restore(&mut self.ctx),
+            },
+            ExprNode::ExistsSubqueryExpr(node) => match &node.sel {
+                Some(sel) => {
+                    if node.not {
+                        self.ctx.write_keyword("NOT ");
+                    }
+                    self.ctx.write_keyword("EXISTS ");
+                    self.format_expr(sel, level)
+                }
+                None => expr.restore(&mut self.ctx),
+            },
+            ExprNode::CaseExpr(node) => self.format_case(node, level),
+            ExprNode::PatternInExpr(node) => self.format_in(node, level),
+            ExprNode::SubQueryExpr(node) => match &node.query {
+                Some(query) => self.format_subquery(query, level),
+                None => expr.restore(&mut self.ctx),
+            },
+            _ => {
+                self.ctx.write_plain(&text);
+                Ok(())
+            }
+        }
+    }
+
+    fn format_case(&mut self, expr: &CaseExpr, level: usize) -> Result<()> {
+        self.ctx.write_keyword("CASE");
+        if let Some(value) = &expr.value {
+            self.ctx.write_plain(" ");
+            self.format_expr(value, level + 1)?;
+        }
+        for when_clause in &expr.when_clauses {
+            self.new_line(level, when_clause.span);
+            self.ctx.write_keyword("WHEN ");
+            self.format_expr(&when_clause.expr, level + 1)?;
+            self.ctx.write_keyword(" THEN ");
+            self.format_expr(&when_clause.result, level + 1)?;
+        }
+        if let Some(else_clause) = &expr.else_clause {
+            self.new_line(level, else_clause.span());
+            self.ctx.write_keyword("ELSE ");
+            self.format_expr(else_clause, level + 1)?;
+        }
+        self.line_break(level.saturating_sub(1));
+        self.ctx.write_keyword("END");
+        Ok(())
+    }
+
+    // FormatIn fills the lines with the values of the IN list up to the max
+    // width.
+    fn format_in(&mut self, expr: &PatternInExpr, level: usize) -> Result<()> {
+        let left = match &expr.expr {
+            Some(left) => left,
+            None => return expr.restore(&mut self.ctx),
+        };
+        self.format_expr(left, level)?;
+        self.ctx
+            .write_keyword(if expr.not { " NOT IN " } else { " IN " });
+        if let Some(sel) = &expr.sel {
+            return self.format_expr(sel, level);
+        }
+        self.ctx.write_plain("(");
+        for (i, value) in expr.list.iter().enumerate() {
+            let text = self.flat(value)?;
+            if i > 0 {
+                self.ctx.write_plain(",");
+                if self.fits(&format!(" {},", text)) {
+                    self.ctx.write_plain(" ");
+                } else {
+                    self.line_break(level);
+                }
+            }
+            self.ctx.write_plain(&text);
+        }
+        self.ctx.write_plain(")");
+        Ok(())
+    }
+}
+
+// CollectJoins flattens the left-deep join tree of a FROM clause, `first` is
+// set to the leftmost table and `joins` to the joins of the following ones.
+fn collect_joins<'b>(
+    refs: &'b TableRefsClause,
+    first: &mut &'b ResultSetNode,
+    joins: &mut Vec<&'b TableRefsClause>,
+) {
+    match refs.left.as_ref() {
+        ResultSetNode::TableRefsClause(left) if !left.explicit_parens => {
+            collect_joins(left, first, joins)
+        }
+        left => *first = left,
+    }
+    if refs.right.is_some() {
+        joins.push(refs);
+    }
+}
+
+fn is_derived_table(node: &ResultSetNode) -> bool {
+    matches!(
+        node,
+        ResultSetNode::TableSource(source)
+            if matches!(source.source.as_ref(), ResultSetNode::SelectStmt(_) | ResultSetNode::SetOprStmt(_))
+    )
+}
+
+fn join_keyword(join: &TableRefsClause) -> String {
+    let mut keyword = String::new();
+    if join.natural_join {
+        keyword.push_str("NATURAL ");
+    }
+    match join.join_type {
+        JoinType::LeftJoin => keyword.push_str("LEFT "),
+        JoinType::RightJoin => keyword.push_str("RIGHT "),
+        JoinType::CrossJoin => {}
+    }
+    keyword.push_str(if join.straight_join {
+        "STRAIGHT_JOIN"
+    } else {
+        "JOIN"
+    });
+    keyword
+}
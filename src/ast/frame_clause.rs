@@ -1,11 +1,16 @@
 use crate::ast::expr_node::ExprNode;
 use crate::ast::functions::TimeUnitType;
-use derive_visitor::Drive;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 // FrameType is the type of window function frame.
 // Window function frame types.
 // MySQL only supports `ROWS` and `RANGES`.
-#[derive(Debug)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub enum FrameType {
     Rows,
     Ranges,
@@ -13,16 +18,21 @@ pub enum FrameType {
 }
 
 // FrameClause represents frame clause.
-#[derive(Debug, Drive)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct FrameClause {
     #[drive(skip)]
+    pub span: Span,
     pub tp: FrameType,
     pub extent: Option<FrameExtent>,
 }
 
 // FrameType is the type of window function frame bound.
 // Frame bound types.
-#[derive(Debug)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub enum BoundType {
     Following,
     Preceding,
@@ -30,21 +40,27 @@ pub enum BoundType {
 }
 
 // FrameBound represents frame bound.
-#[derive(Debug, Drive)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct FrameBound {
     #[drive(skip)]
+    pub span: Span,
     pub tp: BoundType,
     #[drive(skip)]
     pub un_bounded: bool,
     pub expr: Option<Box<ExprNode>>,
     // `Unit` is used to indicate the units in which the `Expr` should be interpreted.
     // For example: '2:30' MINUTE_SECOND.
-    #[drive(skip)]
     pub unit: TimeUnitType,
 }
 
-#[derive(Debug, Drive)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct FrameExtent {
+    #[drive(skip)]
+    pub span: Span,
     pub start: Option<FrameBound>,
     pub end: Option<FrameBound>,
 }
@@ -1,5 +1,11 @@
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
 // TimeUnitType is the type for time and timestamp units.
-#[derive(Debug)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub enum TimeUnitType {
     // TimeUnitInvalid is a placeholder for an invalid time or timestamp unit
     TimeUnitInvalid,
@@ -45,6 +51,76 @@ pub enum TimeUnitType {
     TimeUnitYearMonth,
 }
 
+impl std::fmt::Display for TimeUnitType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeUnitType::TimeUnitInvalid => {
+                write!(f, "")
+            }
+            TimeUnitType::TimeUnitMicrosecond => {
+                write!(f, "MICROSECOND")
+            }
+            TimeUnitType::TimeUnitSecond => {
+                write!(f, "SECOND")
+            }
+            TimeUnitType::TimeUnitMinute => {
+                write!(f, "MINUTE")
+            }
+            TimeUnitType::TimeUnitHour => {
+                write!(f, "HOUR")
+            }
+            TimeUnitType::TimeUnitDay => {
+                write!(f, "DAY")
+            }
+            TimeUnitType::TimeUnitWeek => {
+                write!(f, "WEEK")
+            }
+            TimeUnitType::TimeUnitMonth => {
+                write!(f, "MONTH")
+            }
+            TimeUnitType::TimeUnitQuarter => {
+                write!(f, "QUARTER")
+            }
+            TimeUnitType::TimeUnitYear => {
+                write!(f, "YEAR")
+            }
+            TimeUnitType::TimeUnitSecondMicrosecond => {
+                write!(f, "SECOND_MICROSECOND")
+            }
+            TimeUnitType::TimeUnitMinuteMicrosecond => {
+                write!(f, "MINUTE_MICROSECOND")
+            }
+            TimeUnitType::TimeUnitMinuteSecond => {
+                write!(f, "MINUTE_SECOND")
+            }
+            TimeUnitType::TimeUnitHourMicrosecond => {
+                write!(f, "HOUR_MICROSECOND")
+            }
+            TimeUnitType::TimeUnitHourSecond => {
+                write!(f, "HOUR_SECOND")
+            }
+            TimeUnitType::TimeUnitHourMinute => {
+                write!(f, "HOUR_MINUTE")
+            }
+            TimeUnitType::TimeUnitDayMicrosecond => {
+                write!(f, "DAY_MICROSECOND")
+            }
+            TimeUnitType::TimeUnitDaySecond => {
+                write!(f, "DAY_SECOND")
+            }
+            TimeUnitType::TimeUnitDayMinute => {
+                write!(f, "DAY_MINUTE")
+            }
+            TimeUnitType::TimeUnitDayHour => {
+                write!(f, "DAY_HOUR")
+            }
+            TimeUnitType::TimeUnitYearMonth => {
+                write!(f, "YEAR_MONTH")
+            }
+        }
+    }
+}
+
 // List scalar function names.
 pub const LOGIC_AND: &str = "and";
 pub const CAST: &str = "cast";
@@ -390,7 +466,9 @@ pub const NEXT_VAL: &str = "nextval";
 pub const LAST_VAL: &str = "lastval";
 pub const SET_VAL: &str = "setval";
 
-#[derive(Debug)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub enum TrimDirectionType {
     BothDefault,
     Both,
@@ -402,22 +480,24 @@ impl std::fmt::Display for TrimDirectionType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             TrimDirectionType::BothDefault => {
-                write!(f, "{}", "BOTH")
+                write!(f, "BOTH")
             }
             TrimDirectionType::Both => {
-                write!(f, "{}", "BOTH")
+                write!(f, "BOTH")
             }
             TrimDirectionType::Leading => {
-                write!(f, "{}", "LEADING")
+                write!(f, "LEADING")
             }
             TrimDirectionType::Trailing => {
-                write!(f, "{}", "TRAILING")
+                write!(f, "TRAILING")
             }
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub enum GetFormatSelectorType {
     Date,
     Time,
@@ -428,13 +508,13 @@ impl std::fmt::Display for GetFormatSelectorType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             GetFormatSelectorType::Date => {
-                write!(f, "{}", "DATE")
+                write!(f, "DATE")
             }
             GetFormatSelectorType::Time => {
-                write!(f, "{}", "TIME")
+                write!(f, "TIME")
             }
             GetFormatSelectorType::Datetime => {
-                write!(f, "{}", "DATETIME")
+                write!(f, "DATETIME")
             }
         }
     }
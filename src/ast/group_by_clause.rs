@@ -1,9 +1,16 @@
 use crate::ast::expr_node::ExprNode;
-use derive_visitor::Drive;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 // ByItem represents an item in order by or group by.
-#[derive(Debug, Drive)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct ByItem {
+    #[drive(skip)]
+    pub span: Span,
     pub expr: Option<Box<ExprNode>>,
     #[drive(skip)]
     pub desc: bool,
@@ -12,8 +19,12 @@ pub struct ByItem {
 }
 
 // GroupByClause represents group by clause.
-#[derive(Debug, Drive)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct GroupByClause {
+    #[drive(skip)]
+    pub span: Span,
     pub items: Vec<ByItem>,
     #[drive(skip)]
     pub rollup: bool,
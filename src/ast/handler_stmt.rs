@@ -0,0 +1,57 @@
+use crate::ast::ci_str::CIStr;
+use crate::ast::expr_node::ExprNode;
+use crate::ast::limit::Limit;
+use crate::ast::op_code::OpCode;
+use crate::ast::table_name::TableName;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// HandlerStmtType is the type of HANDLER statement.
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub enum HandlerStmtType {
+    #[default]
+    Open,
+    Read,
+    Close,
+}
+
+// HandlerReadDirection is the direction used by `HANDLER ... READ`.
+// PREV and LAST are only valid when an index is given.
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub enum HandlerReadDirection {
+    First,
+    Next,
+    Prev,
+    Last,
+}
+
+// HandlerStmt represents HANDLER statement.
+// See https://dev.mysql.com/doc/refman/8.0/en/handler.html
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct HandlerStmt {
+    #[drive(skip)]
+    pub span: Span,
+    pub tp: HandlerStmtType,
+    pub table: TableName,
+    // AsName is the alias given by `HANDLER t OPEN AS alias`.
+    #[drive(skip)]
+    pub as_name: CIStr,
+    // IndexName is empty when reading in natural row order.
+    #[drive(skip)]
+    pub index_name: CIStr,
+    // ReadDirection is set for `READ [index] {FIRST | NEXT | PREV | LAST}`.
+    pub read_direction: Option<HandlerReadDirection>,
+    // Op and Values are set for `READ index {= | <= | >= | < | >} (value, ...)`.
+    pub op: Option<OpCode>,
+    pub values: Vec<ExprNode>,
+    pub where_clause: Option<ExprNode>,
+    pub limit: Option<Limit>,
+}
@@ -1,6 +1,10 @@
 use crate::ast::ci_str::CIStr;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum IndexHintType {
     HintUse,
     HintIgnore,
@@ -9,7 +13,7 @@ pub enum IndexHintType {
     HintNoOrderIndex,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum IndexHintScope {
     HintForScan,
     HintForJoin,
@@ -17,8 +21,11 @@ pub enum IndexHintScope {
     HintForGroupBy,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct IndexHint {
+    #[drive(skip)]
+    pub span: Span,
+    #[drive(skip)]
     pub index_names: Vec<CIStr>,
     pub hint_type: IndexHintType,
     pub hint_scope: IndexHintScope,
@@ -0,0 +1,86 @@
+use crate::ast::statement::Statement;
+use crate::common::error::{ParseError, Result};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// AST_JSON_VERSION is the version of the JSON schema written by `to_json`.
+// It is bumped whenever a node is renamed, or a field is removed or changes
+// its type, so consumers can tell which schema a document was written with.
+pub const AST_JSON_VERSION: u32 = 1;
+
+// A statement is written as a versioned document:
+//
+//     {"version": 1, "statement": {"type": "SelectStmt", "data": {...}}}
+//
+// Inside the document:
+//   - structs are objects keyed by their field names;
+//   - enums whose variants carry data are tagged as {"type": <variant>,
+//     "data": <payload>}, enums without data are the variant name string;
+//   - CIStr is its original string and BigDecimal is a decimal string;
+//   - spans are {"start": .., "end": ..} byte offsets into the source, or
+//     null for nodes built without one;
+//   - the shared `Rc` clauses (WITH, ORDER BY, LIMIT) are written in place.
+#[derive(Serialize)]
+struct DocumentRef<'a> {
+    version: u32,
+    statement: &'a Statement,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[schemars(rename = "AstDocument")]
+struct Document {
+    // Version is the AST_JSON_VERSION the document was written with, it's
+    // checked through DocumentHeader and kept here for the schema.
+    #[allow(dead_code)]
+    version: u32,
+    statement: Statement,
+}
+
+#[derive(Deserialize)]
+struct DocumentHeader {
+    version: u32,
+}
+
+// to_json serializes the statement to a compact JSON document.
+pub fn to_json(stmt: &Statement) -> Result<String> {
+    serde_json::to_string(&document(stmt)).map_err(json_error)
+}
+
+// to_json_pretty serializes the statement to an indented JSON document.
+pub fn to_json_pretty(stmt: &Statement) -> Result<String> {
+    serde_json::to_string_pretty(&document(stmt)).map_err(json_error)
+}
+
+// from_json reads back a document written by `to_json`, documents of
+// another schema version are rejected.
+pub fn from_json(json: &str) -> Result<Statement> {
+    let header: DocumentHeader = serde_json::from_str(json).map_err(json_error)?;
+    if header.version != AST_JSON_VERSION {
+        return Err(ParseError(
+            None,
+            format!(
+                "unsupported AST JSON version {}, expected {}",
+                header.version, AST_JSON_VERSION
+            ),
+        ));
+    }
+    let doc: Document = serde_json::from_str(json).map_err(json_error)?;
+    Ok(doc.statement)
+}
+
+// json_schema returns the JSON Schema of the documents written by `to_json`.
+pub fn json_schema() -> String {
+    let schema = schemars::schema_for!(Document);
+    serde_json::to_string_pretty(&schema).unwrap()
+}
+
+fn document(stmt: &Statement) -> DocumentRef<'_> {
+    DocumentRef {
+        version: AST_JSON_VERSION,
+        statement: stmt,
+    }
+}
+
+fn json_error(err: serde_json::Error) -> ParseError {
+    ParseError(None, format!("invalid AST JSON: {}", err))
+}
@@ -0,0 +1,85 @@
+use crate::ast::ci_str::CIStr;
+use crate::ast::expr_node::ExprNode;
+use crate::ast::field_type::FieldType;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// JsonTable is the JSON_TABLE table function, which extracts the data of a
+// JSON document as a relational table.
+// See https://dev.mysql.com/doc/refman/8.0/en/json-table-functions.html
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct JsonTable {
+    #[drive(skip)]
+    pub span: Span,
+    // Expr is the JSON document.
+    pub expr: Box<ExprNode>,
+    // Path is the row path applied to the document.
+    #[drive(skip)]
+    pub path: String,
+    pub columns: Vec<JsonTableColumn>,
+}
+
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub enum JsonTableColumnKind {
+    // `name FOR ORDINALITY`
+    ForOrdinality,
+    // `name type PATH path [on_empty] [on_error]`
+    Path,
+    // `name type EXISTS PATH path`
+    ExistsPath,
+    // `NESTED [PATH] path COLUMNS (...)`
+    Nested,
+}
+
+// JsonTableOnResponse is the behavior of a column when the path has no
+// match (ON EMPTY) or the value can't be converted (ON ERROR).
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(tag = "type", content = "data")]
+pub enum JsonTableOnResponse {
+    Null,
+    Error,
+    Default(#[drive(skip)] String),
+}
+
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct JsonTableColumn {
+    #[drive(skip)]
+    pub span: Span,
+    pub kind: JsonTableColumnKind,
+    // Name is empty for NESTED PATH columns.
+    #[drive(skip)]
+    pub name: CIStr,
+    // Tp is None for FOR ORDINALITY and NESTED PATH columns.
+    pub tp: Option<FieldType>,
+    #[drive(skip)]
+    pub path: String,
+    pub on_empty: Option<JsonTableOnResponse>,
+    pub on_error: Option<JsonTableOnResponse>,
+    // NestedColumns is filled only for NESTED PATH columns.
+    pub nested_columns: Vec<JsonTableColumn>,
+}
+
+impl JsonTableColumn {
+    pub fn new(kind: JsonTableColumnKind, name: CIStr) -> Self {
+        JsonTableColumn {
+            span: None,
+            kind,
+            name,
+            tp: None,
+            path: String::new(),
+            on_empty: None,
+            on_error: None,
+            nested_columns: vec![],
+        }
+    }
+}
@@ -1,9 +1,14 @@
 use crate::ast::expr_node::ExprNode;
-use derive_visitor::Drive;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 // Limit is the limit clause.
-#[derive(Debug, Drive)]
+#[derive(Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct Limit {
+    #[drive(skip)]
+    pub span: Span,
     pub count: Box<ExprNode>,
     pub offset: Box<ExprNode>,
 }
\ No newline at end of file
@@ -1,6 +1,14 @@
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
 // LinesClause represents lines references clause in load data statement.
-#[derive(Debug)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct LinesClause {
+    #[drive(skip)]
     pub starting: Option<String>,
+    #[drive(skip)]
     pub terminated: Option<String>,
-}
\ No newline at end of file
+}
@@ -1,31 +1,52 @@
+pub mod admin_stmt;
+pub mod binding_stmt;
+pub mod call_stmt;
 pub mod ci_str;
 pub mod column_name;
 pub mod common;
 pub mod common_table_expression;
+pub mod do_stmt;
 pub mod expr_node;
+pub mod field_type;
+pub mod flashback_stmt;
+pub mod format;
 pub mod frame_clause;
 pub mod functions;
 pub mod group_by_clause;
+pub mod handler_stmt;
 pub mod index_hint;
+pub mod json;
+pub mod json_table;
 pub mod limit;
 pub mod line_clause;
 pub mod node;
 pub mod op_code;
 pub mod order_by_clause;
 pub mod partition_by_clause;
+pub mod placement_policy_stmt;
 pub mod quote;
+pub mod restore;
 pub mod result_set_node;
 pub mod select_field;
 pub mod select_into_option;
 pub mod select_lock_info;
 pub mod select_stmt;
+pub mod sequence_stmt;
+pub mod set_config_stmt;
 pub mod set_opr_stmt;
+pub mod show_stmt;
+pub mod spanned;
+pub mod split_region_stmt;
 pub mod statement;
+pub mod structural;
 pub mod subquery_expr;
+pub mod system_time;
 pub mod table_name;
 pub mod table_optimizer_hint;
 pub mod table_refs_clause;
 pub mod table_sample;
 pub mod table_source;
+pub mod user_identity;
+pub mod visitor;
 pub mod window_spec;
 pub mod with_clause;
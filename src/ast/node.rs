@@ -2,9 +2,14 @@ use crate::ast::result_set_node::ResultSetNode;
 use crate::ast::select_stmt::SelectStmt;
 use crate::ast::set_opr_stmt::SetOprSelectList;
 use crate::ast::statement::Statement;
-use derive_visitor::Drive;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Drive)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(tag = "type", content = "data")]
 pub enum Node {
     Statement(Statement),
     ResultSetNode(ResultSetNode),
@@ -1,9 +1,16 @@
 use crate::ast::group_by_clause::ByItem;
-use derive_visitor::Drive;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 // OrderByClause represents order by clause.
-#[derive(Debug, Drive, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct OrderByClause {
+    #[drive(skip)]
+    pub span: Span,
     pub items: Vec<ByItem>,
     #[drive(skip)]
     pub for_union: bool,
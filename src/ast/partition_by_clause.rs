@@ -1,7 +1,14 @@
 use crate::ast::group_by_clause::ByItem;
-use derive_visitor::Drive;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Drive)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct PartitionByClause {
+    #[drive(skip)]
+    pub span: Span,
     pub items: Vec<ByItem>,
 }
@@ -0,0 +1,86 @@
+use crate::ast::ci_str::CIStr;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// PlacementOptionType is the type of a placement option.
+#[derive(
+    Debug, Drive, DriveMut, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub enum PlacementOptionType {
+    PrimaryRegion,
+    Regions,
+    Followers,
+    Voters,
+    Learners,
+    Schedule,
+    Constraints,
+    LeaderConstraints,
+    LearnerConstraints,
+    FollowerConstraints,
+    VoterConstraints,
+    SurvivalPreferences,
+}
+
+// PlacementOption is used for parsing placement option.
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct PlacementOption {
+    #[drive(skip)]
+    pub span: Span,
+    pub tp: PlacementOptionType,
+    // StrValue is set for the options taking a string, UintValue for
+    // FOLLOWERS, VOTERS and LEARNERS.
+    #[drive(skip)]
+    pub str_value: String,
+    #[drive(skip)]
+    pub uint_value: u64,
+}
+
+// CreatePlacementPolicyStmt is a statement to create a placement policy.
+// See https://docs.pingcap.com/tidb/stable/sql-statement-create-placement-policy
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct CreatePlacementPolicyStmt {
+    #[drive(skip)]
+    pub span: Span,
+    #[drive(skip)]
+    pub or_replace: bool,
+    #[drive(skip)]
+    pub if_not_exists: bool,
+    #[drive(skip)]
+    pub policy_name: CIStr,
+    pub placement_options: Vec<PlacementOption>,
+}
+
+// AlterPlacementPolicyStmt is a statement to alter a placement policy.
+// See https://docs.pingcap.com/tidb/stable/sql-statement-alter-placement-policy
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct AlterPlacementPolicyStmt {
+    #[drive(skip)]
+    pub span: Span,
+    #[drive(skip)]
+    pub if_exists: bool,
+    #[drive(skip)]
+    pub policy_name: CIStr,
+    pub placement_options: Vec<PlacementOption>,
+}
+
+// DropPlacementPolicyStmt is a statement to drop a placement policy.
+// See https://docs.pingcap.com/tidb/stable/sql-statement-drop-placement-policy
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct DropPlacementPolicyStmt {
+    #[drive(skip)]
+    pub span: Span,
+    #[drive(skip)]
+    pub if_exists: bool,
+    #[drive(skip)]
+    pub policy_name: CIStr,
+}
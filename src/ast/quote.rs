@@ -29,11 +29,6 @@ pub fn ident_needs_quote(ident: &str) -> bool {
         return true;
     }
 
-    // avoid quote the special identifier "~" which is an available stage name
-    if ident == "~" {
-        return false;
-    }
-
     let mut chars = ident.chars();
     let first = chars.next().unwrap();
     if !first.is_ascii_alphabetic() && first != '_' {
@@ -0,0 +1,2557 @@
+use crate::ast::admin_stmt::{AdminStmt, AdminStmtType};
+use crate::ast::binding_stmt::{CreateBindingStmt, DropBindingStmt};
+use crate::ast::call_stmt::CallStmt;
+use crate::ast::ci_str::CIStr;
+use crate::ast::column_name::ColumnName;
+use crate::ast::common_table_expression::CommonTableExpression;
+use crate::ast::do_stmt::DoStmt;
+use crate::ast::expr_node::{
+    AggregateFuncExpr, BetweenExpr, BinaryOperationExpr, CaseExpr, CastFunctionType,
+    ColumnNameExpr, CompareSubqueryExpr, ExistsSubqueryExpr, ExprNode, FuncCallExpr,
+    FuncCallExprType, FuncCastExpr, GetFormatSelectorExpr, IsNullExpr, IsTruthExpr, LiteralPrefix,
+    MatchAgainst, MemberOfExpr, ParamMarkerExpr, ParenthesesExpr, PatternInExpr,
+    PatternLikeOrIlikeExpr, PatternRegexpExpr, PositionExpr, RowExpr, SetCollationExpr,
+    SoundsLikeExpr, TableNameExpr, TimeUnitExpr, TrimDirectionExpr, UnaryOperationExpr, ValueExpr,
+    ValueExprKind, VariableExpr, VariableScope, WhenClause, WindowFuncExpr,
+};
+use crate::ast::field_type::{DataType, FieldType};
+use crate::ast::flashback_stmt::{
+    FlashBackDatabaseStmt, FlashBackTableStmt, FlashBackToTimestampStmt,
+};
+use crate::ast::frame_clause::{BoundType, FrameBound, FrameClause, FrameExtent, FrameType};
+use crate::ast::functions;
+use crate::ast::functions::TimeUnitType;
+use crate::ast::group_by_clause::{ByItem, GroupByClause};
+use crate::ast::handler_stmt::{HandlerReadDirection, HandlerStmt, HandlerStmtType};
+use crate::ast::index_hint::{IndexHint, IndexHintScope, IndexHintType};
+use crate::ast::json_table::{
+    JsonTable, JsonTableColumn, JsonTableColumnKind, JsonTableOnResponse,
+};
+use crate::ast::limit::Limit;
+use crate::ast::line_clause::LinesClause;
+use crate::ast::node::Node;
+use crate::ast::op_code::OpCode;
+use crate::ast::order_by_clause::OrderByClause;
+use crate::ast::partition_by_clause::PartitionByClause;
+use crate::ast::placement_policy_stmt::{
+    AlterPlacementPolicyStmt, CreatePlacementPolicyStmt, DropPlacementPolicyStmt, PlacementOption,
+    PlacementOptionType,
+};
+use crate::ast::quote::{ident_needs_quote, QuotedIdent};
+use crate::ast::result_set_node::ResultSetNode;
+use crate::ast::select_field::{Field, FieldsClause, SelectField, WildCardField};
+use crate::ast::select_into_option::{SelectIntoOption, SelectIntoType};
+use crate::ast::select_lock_info::{SelectLockInfo, SelectLockType};
+use crate::ast::select_stmt::{SelectStmt, SelectStmtKind};
+use crate::ast::sequence_stmt::{
+    AlterSequenceStmt, CreateSequenceStmt, DropSequenceStmt, SequenceOption, SequenceOptionType,
+};
+use crate::ast::set_config_stmt::SetConfigStmt;
+use crate::ast::set_opr_stmt::{SetOprSelectList, SetOprStmt, SetOprType};
+use crate::ast::show_stmt::{ShowStmt, ShowStmtType};
+use crate::ast::split_region_stmt::{SplitOption, SplitRegionStmt};
+use crate::ast::statement::Statement;
+use crate::ast::subquery_expr::SubQueryExpr;
+use crate::ast::system_time::{HistoryPoint, SystemTimeClause};
+use crate::ast::table_name::TableName;
+use crate::ast::table_optimizer_hint::{HintData, HintTable, TableOptimizerHint};
+use crate::ast::table_refs_clause::{JoinType, TableRefsClause};
+use crate::ast::table_sample::{SampleClauseUnitType, SampleMethodType, TableSample};
+use crate::ast::table_source::TableSource;
+use crate::ast::user_identity::UserIdentity;
+use crate::ast::window_spec::WindowSpec;
+use crate::ast::with_clause::WithClause;
+use crate::charset::charset::CHARSET_UTF8MB4;
+use crate::common::error::{ParseError, Result};
+use crate::common::escape::escape_string;
+use crate::common::misc::is_in_token_map;
+use crate::mysql::consts::{PriorityEnum, SqlMode};
+use crate::parser::input::Dialect;
+use std::rc::Rc;
+
+// RestoreFlags is the set of flags that control how an AST node is written
+// back to SQL text. The flags shared with TiDB have the same values.
+// See https://github.com/pingcap/tidb/blob/master/pkg/parser/format/format.go
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RestoreFlags(pub u64);
+
+pub const RESTORE_STRING_SINGLE_QUOTES: RestoreFlags = RestoreFlags(1 << 0);
+pub const RESTORE_STRING_DOUBLE_QUOTES: RestoreFlags = RestoreFlags(1 << 1);
+pub const RESTORE_STRING_ESCAPE_BACKSLASH: RestoreFlags = RestoreFlags(1 << 2);
+pub const RESTORE_KEYWORD_UPPERCASE: RestoreFlags = RestoreFlags(1 << 3);
+pub const RESTORE_KEYWORD_LOWERCASE: RestoreFlags = RestoreFlags(1 << 4);
+pub const RESTORE_NAME_UPPERCASE: RestoreFlags = RestoreFlags(1 << 5);
+pub const RESTORE_NAME_LOWERCASE: RestoreFlags = RestoreFlags(1 << 6);
+pub const RESTORE_NAME_DOUBLE_QUOTES: RestoreFlags = RestoreFlags(1 << 7);
+pub const RESTORE_NAME_BACK_QUOTES: RestoreFlags = RestoreFlags(1 << 8);
+pub const RESTORE_STRING_WITHOUT_CHARSET: RestoreFlags = RestoreFlags(1 << 11);
+pub const RESTORE_STRING_WITHOUT_DEFAULT_CHARSET: RestoreFlags = RestoreFlags(1 << 12);
+pub const RESTORE_WITHOUT_OPTIMIZER_HINTS: RestoreFlags = RestoreFlags(1 << 16);
+pub const RESTORE_WITHOUT_INDEX_HINTS: RestoreFlags = RestoreFlags(1 << 17);
+pub const RESTORE_STRIP_DEFINER: RestoreFlags = RestoreFlags(1 << 18);
+pub const RESTORE_NAME_QUOTES_WHEN_NEEDED: RestoreFlags = RestoreFlags(1 << 19);
+
+// DEFAULT_RESTORE_FLAGS writes uppercase keywords, single quoted strings and
+// back quoted names, the same as TiDB.
+pub const DEFAULT_RESTORE_FLAGS: RestoreFlags = RestoreFlags(
+    RESTORE_STRING_SINGLE_QUOTES.0 | RESTORE_KEYWORD_UPPERCASE.0 | RESTORE_NAME_BACK_QUOTES.0,
+);
+
+impl RestoreFlags {
+    fn has(&self, flag: RestoreFlags) -> bool {
+        self.0 & flag.0 != 0
+    }
+
+    // HasStringSingleQuotesFlag detects if 'RESTORE_STRING_SINGLE_QUOTES' is set.
+    pub fn has_string_single_quotes_flag(&self) -> bool {
+        self.has(RESTORE_STRING_SINGLE_QUOTES)
+    }
+
+    // HasStringDoubleQuotesFlag detects if 'RESTORE_STRING_DOUBLE_QUOTES' is set.
+    pub fn has_string_double_quotes_flag(&self) -> bool {
+        self.has(RESTORE_STRING_DOUBLE_QUOTES)
+    }
+
+    // HasStringEscapeBackslashFlag detects if 'RESTORE_STRING_ESCAPE_BACKSLASH' is set.
+    pub fn has_string_escape_backslash_flag(&self) -> bool {
+        self.has(RESTORE_STRING_ESCAPE_BACKSLASH)
+    }
+
+    // HasKeyWordUppercaseFlag detects if 'RESTORE_KEYWORD_UPPERCASE' is set.
+    pub fn has_keyword_uppercase_flag(&self) -> bool {
+        self.has(RESTORE_KEYWORD_UPPERCASE)
+    }
+
+    // HasKeyWordLowercaseFlag detects if 'RESTORE_KEYWORD_LOWERCASE' is set.
+    pub fn has_keyword_lowercase_flag(&self) -> bool {
+        self.has(RESTORE_KEYWORD_LOWERCASE)
+    }
+
+    // HasNameUppercaseFlag detects if 'RESTORE_NAME_UPPERCASE' is set.
+    pub fn has_name_uppercase_flag(&self) -> bool {
+        self.has(RESTORE_NAME_UPPERCASE)
+    }
+
+    // HasNameLowercaseFlag detects if 'RESTORE_NAME_LOWERCASE' is set.
+    pub fn has_name_lowercase_flag(&self) -> bool {
+        self.has(RESTORE_NAME_LOWERCASE)
+    }
+
+    // HasNameDoubleQuotesFlag detects if 'RESTORE_NAME_DOUBLE_QUOTES' is set.
+    pub fn has_name_double_quotes_flag(&self) -> bool {
+        self.has(RESTORE_NAME_DOUBLE_QUOTES)
+    }
+
+    // HasNameBackQuotesFlag detects if 'RESTORE_NAME_BACK_QUOTES' is set.
+    pub fn has_name_back_quotes_flag(&self) -> bool {
+        self.has(RESTORE_NAME_BACK_QUOTES)
+    }
+
+    // HasNameQuotesWhenNeededFlag detects if 'RESTORE_NAME_QUOTES_WHEN_NEEDED'
+    // is set, names are back quoted only if they are keywords or contain
+    // characters that can't start or appear in a bare identifier.
+    pub fn has_name_quotes_when_needed_flag(&self) -> bool {
+        self.has(RESTORE_NAME_QUOTES_WHEN_NEEDED)
+    }
+
+    // HasStringWithoutCharsetFlag detects if 'RESTORE_STRING_WITHOUT_CHARSET' is set.
+    pub fn has_string_without_charset_flag(&self) -> bool {
+        self.has(RESTORE_STRING_WITHOUT_CHARSET)
+    }
+
+    // HasStringWithoutDefaultCharsetFlag detects if
+    // 'RESTORE_STRING_WITHOUT_DEFAULT_CHARSET' is set.
+    pub fn has_string_without_default_charset_flag(&self) -> bool {
+        self.has(RESTORE_STRING_WITHOUT_DEFAULT_CHARSET)
+    }
+
+    // HasWithoutOptimizerHintsFlag detects if 'RESTORE_WITHOUT_OPTIMIZER_HINTS' is set.
+    pub fn has_without_optimizer_hints_flag(&self) -> bool {
+        self.has(RESTORE_WITHOUT_OPTIMIZER_HINTS)
+    }
+
+    // HasWithoutIndexHintsFlag detects if 'RESTORE_WITHOUT_INDEX_HINTS' is set.
+    pub fn has_without_index_hints_flag(&self) -> bool {
+        self.has(RESTORE_WITHOUT_INDEX_HINTS)
+    }
+
+    // HasStripDefinerFlag detects if 'RESTORE_STRIP_DEFINER' is set, the
+    // DEFINER clause of views, routines, triggers and events is omitted.
+    pub fn has_strip_definer_flag(&self) -> bool {
+        self.has(RESTORE_STRIP_DEFINER)
+    }
+}
+
+impl std::ops::BitOr for RestoreFlags {
+    type Output = RestoreFlags;
+
+    fn bitor(self, rhs: RestoreFlags) -> RestoreFlags {
+        RestoreFlags(self.0 | rhs.0)
+    }
+}
+
+// RestoreCtx is the context of restoring, the SQL text is appended to Out.
+pub struct RestoreCtx {
+    pub flags: RestoreFlags,
+    // Dialect is the server the restored text is written for, it decides the
+    // syntax where the servers differ, e.g. of the optimizer hints.
+    pub dialect: Dialect,
+    // SqlMode is the sql_mode the restored text is read with, it decides how
+    // the strings are escaped.
+    pub sql_mode: SqlMode,
+    pub out: String,
+}
+
+impl RestoreCtx {
+    pub fn new(flags: RestoreFlags) -> Self {
+        Self::with_sql_mode(flags, SqlMode::default())
+    }
+
+    pub fn with_sql_mode(flags: RestoreFlags, sql_mode: SqlMode) -> Self {
+        Self::with_dialect(flags, Dialect::default(), sql_mode)
+    }
+
+    pub fn with_dialect(flags: RestoreFlags, dialect: Dialect, sql_mode: SqlMode) -> Self {
+        RestoreCtx {
+            flags,
+            dialect,
+            sql_mode,
+            out: String::new(),
+        }
+    }
+
+    // WriteKeyWord writes the keyword in the case required by the flags.
+    pub fn write_keyword(&mut self, keyword: &str) {
+        if self.flags.has_keyword_uppercase_flag() {
+            self.out.push_str(&keyword.to_uppercase());
+        } else if self.flags.has_keyword_lowercase_flag() {
+            self.out.push_str(&keyword.to_lowercase());
+        } else {
+            self.out.push_str(keyword);
+        }
+    }
+
+    // WriteName writes the name of a schema, table, column, etc., quoted as
+    // required by the flags.
+    pub fn write_name(&mut self, name: &str) {
+        let name = if self.flags.has_name_uppercase_flag() {
+            name.to_uppercase()
+        } else if self.flags.has_name_lowercase_flag() {
+            name.to_lowercase()
+        } else {
+            name.to_string()
+        };
+
+        if self.flags.has_name_quotes_when_needed_flag()
+            && !ident_needs_quote(&name)
+            && !is_in_token_map(&name.to_uppercase())
+        {
+            self.out.push_str(&name);
+        } else if self.flags.has_name_double_quotes_flag() {
+            self.out.push_str(&QuotedIdent(&name, '"').to_string());
+        } else if self.flags.has_name_back_quotes_flag()
+            || self.flags.has_name_quotes_when_needed_flag()
+        {
+            self.out.push_str(&QuotedIdent(&name, '`').to_string());
+        } else {
+            self.out.push_str(&name);
+        }
+    }
+
+    // WriteString writes the string quoted as required by the flags, the
+    // quote character is escaped by doubling it. The backslash and the control
+    // characters are escaped too, unless the target sql_mode has
+    // NO_BACKSLASH_ESCAPES, where a backslash is an ordinary character.
+    pub fn write_string(&mut self, s: &str) {
+        let s = if !self.sql_mode.has_no_backslash_escapes_mode() {
+            escape_string(s)
+        } else if self.flags.has_string_escape_backslash_flag() {
+            s.replace('\\', "\\\\")
+        } else {
+            s.to_string()
+        };
+
+        if self.flags.has_string_single_quotes_flag() {
+            self.out.push('\'');
+            self.out.push_str(&s.replace('\'', "''"));
+            self.out.push('\'');
+        } else if self.flags.has_string_double_quotes_flag() {
+            self.out.push('"');
+            self.out.push_str(&s.replace('"', "\"\""));
+            self.out.push('"');
+        } else {
+            self.out.push_str(&s);
+        }
+    }
+
+    // WritePlain writes the text as is.
+    pub fn write_plain(&mut self, s: &str) {
+        self.out.push_str(s);
+    }
+}
+
+// Restore is implemented by the AST nodes which can be written back to SQL
+// text, parsing the restored text gives the same node.
+// See https://github.com/pingcap/tidb/blob/master/pkg/parser/ast/ast.go
+pub trait Restore {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()>;
+}
+
+impl<T: Restore> Restore for Box<T> {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        self.as_ref().restore(ctx)
+    }
+}
+
+impl<T: Restore> Restore for Rc<T> {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        self.as_ref().restore(ctx)
+    }
+}
+
+// RestoreSql restores the node to SQL text with the flags.
+pub fn restore_sql<T: Restore>(node: &T, flags: RestoreFlags) -> Result<String> {
+    restore_sql_with_sql_mode(node, flags, SqlMode::default())
+}
+
+// RestoreSqlWithSqlMode restores the node to SQL text with the flags, to be
+// read by a server running with `sql_mode`.
+pub fn restore_sql_with_sql_mode<T: Restore>(
+    node: &T,
+    flags: RestoreFlags,
+    sql_mode: SqlMode,
+) -> Result<String> {
+    restore_sql_with_dialect(node, flags, Dialect::default(), sql_mode)
+}
+
+// RestoreSqlWithDialect restores the node to SQL text with the flags, to be
+// read by the server of `dialect` running with `sql_mode`.
+pub fn restore_sql_with_dialect<T: Restore>(
+    node: &T,
+    flags: RestoreFlags,
+    dialect: Dialect,
+    sql_mode: SqlMode,
+) -> Result<String> {
+    let mut ctx = RestoreCtx::with_dialect(flags, dialect, sql_mode);
+    node.restore(&mut ctx)?;
+    Ok(ctx.out)
+}
+
+fn restore_list<T: Restore>(ctx: &mut RestoreCtx, nodes: &[T], sep: &str) -> Result<()> {
+    for (i, node) in nodes.iter().enumerate() {
+        if i > 0 {
+            ctx.write_plain(sep);
+        }
+        node.restore(ctx)?;
+    }
+    Ok(())
+}
+
+fn required<'a, T>(node: &'a Option<T>, what: &str) -> Result<&'a T> {
+    node.as_ref()
+        .ok_or_else(|| ParseError(None, format!("failed to restore, the {} is missing", what)))
+}
+
+fn unsupported(what: &str) -> ParseError {
+    ParseError(None, format!("failed to restore, unsupported {}", what))
+}
+
+impl Restore for CIStr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_name(&self.origin);
+        Ok(())
+    }
+}
+
+impl Restore for ExprNode {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        match self {
+            ExprNode::Default => Err(unsupported("empty expression")),
+            ExprNode::ColumnNameExpr(expr) => expr.restore(ctx),
+            ExprNode::SubQueryExpr(expr) => expr.restore(ctx),
+            ExprNode::RowExpr(expr) => expr.restore(ctx),
+            ExprNode::VariableExpr(expr) => expr.restore(ctx),
+            ExprNode::BinaryOperationExpr(expr) => expr.restore(ctx),
+            ExprNode::ExistsSubqueryExpr(expr) => expr.restore(ctx),
+            ExprNode::UnaryOperationExpr(expr) => expr.restore(ctx),
+            ExprNode::MatchAgainst(expr) => expr.restore(ctx),
+            ExprNode::FuncCallExpr(expr) => expr.restore(ctx),
+            ExprNode::TimeUnitExpr(expr) => expr.restore(ctx),
+            ExprNode::ValueExpr(expr) => expr.restore(ctx),
+            ExprNode::TrimDirectionExpr(expr) => expr.restore(ctx),
+            ExprNode::GetFormatSelectorExpr(expr) => expr.restore(ctx),
+            ExprNode::TableNameExpr(expr) => expr.restore(ctx),
+            ExprNode::SetCollationExpr(expr) => expr.restore(ctx),
+            ExprNode::WindowFuncExpr(expr) => expr.restore(ctx),
+            ExprNode::PositionExpr(expr) => expr.restore(ctx),
+            ExprNode::ParamMarkerExpr(expr) => expr.restore(ctx),
+            ExprNode::AggregateFuncExpr(expr) => expr.restore(ctx),
+            ExprNode::CaseExpr(expr) => expr.restore(ctx),
+            ExprNode::FuncCastExpr(expr) => expr.restore(ctx),
+            ExprNode::PatternInExpr(expr) => expr.restore(ctx),
+            ExprNode::BetweenExpr(expr) => expr.restore(ctx),
+            ExprNode::PatternLikeOrIlikeExpr(expr) => expr.restore(ctx),
+            ExprNode::PatternRegexpExpr(expr) => expr.restore(ctx),
+            ExprNode::SoundsLikeExpr(expr) => expr.restore(ctx),
+            ExprNode::MemberOfExpr(expr) => expr.restore(ctx),
+            ExprNode::IsNullExpr(expr) => expr.restore(ctx),
+            ExprNode::IsTruthExpr(expr) => expr.restore(ctx),
+            ExprNode::CompareSubqueryExpr(expr) => expr.restore(ctx),
+            ExprNode::ParenthesesExpr(expr) => expr.restore(ctx),
+        }
+    }
+}
+
+impl Restore for OpCode {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        let (text, is_keyword) = match self {
+            OpCode::LogicAnd => ("AND", true),
+            OpCode::LogicOr => ("OR", true),
+            OpCode::LogicXor => ("XOR", true),
+            OpCode::IntDiv => ("DIV", true),
+            OpCode::Not => ("NOT", true),
+            OpCode::LeftShift => ("<<", false),
+            OpCode::RightShift => (">>", false),
+            OpCode::GE => (">=", false),
+            OpCode::LE => ("<=", false),
+            OpCode::EQ => ("=", false),
+            OpCode::NE => ("!=", false),
+            OpCode::LT => ("<", false),
+            OpCode::GT => (">", false),
+            OpCode::Plus => ("+", false),
+            OpCode::Minus => ("-", false),
+            OpCode::And => ("&", false),
+            OpCode::Or => ("|", false),
+            OpCode::Mod => ("%", false),
+            OpCode::Xor => ("^", false),
+            OpCode::Div => ("/", false),
+            OpCode::Mul => ("*", false),
+            OpCode::Not2 => ("!", false),
+            OpCode::BitNeg => ("~", false),
+            OpCode::NullEQ => ("<=>", false),
+            op => return Err(unsupported(&format!("operator {:?}", op))),
+        };
+        if is_keyword {
+            ctx.write_keyword(text);
+        } else {
+            ctx.write_plain(text);
+        }
+        Ok(())
+    }
+}
+
+impl Restore for VariableExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        if self.is_system {
+            ctx.write_plain("@@");
+            let scope = match self.scope {
+                VariableScope::None => None,
+                VariableScope::Global => Some("GLOBAL"),
+                VariableScope::Session => Some("SESSION"),
+                VariableScope::Persist => Some("PERSIST"),
+                VariableScope::PersistOnly => Some("PERSIST_ONLY"),
+            };
+            if let Some(scope) = scope {
+                ctx.write_keyword(scope);
+                ctx.write_plain(".");
+            }
+            ctx.write_plain(&self.name);
+        } else {
+            ctx.write_plain("@");
+            ctx.write_name(&self.name);
+        }
+        if let Some(value) = &self.value {
+            ctx.write_plain(" := ");
+            value.restore(ctx)?;
+        }
+        Ok(())
+    }
+}
+
+impl Restore for BinaryOperationExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        required(&self.l, "left operand")?.restore(ctx)?;
+        ctx.write_plain(" ");
+        self.op.restore(ctx)?;
+        ctx.write_plain(" ");
+        required(&self.r, "right operand")?.restore(ctx)
+    }
+}
+
+impl Restore for ExistsSubqueryExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        if self.not {
+            ctx.write_keyword("NOT ");
+        }
+        ctx.write_keyword("EXISTS ");
+        required(&self.sel, "subquery")?.restore(ctx)
+    }
+}
+
+impl Restore for UnaryOperationExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        let v = required(&self.v, "operand")?;
+        self.op.restore(ctx)?;
+        match (&self.op, v.as_ref()) {
+            (OpCode::Not, _) => ctx.write_plain(" "),
+            // `- -a` is not written as `--a`, which starts a comment.
+            (OpCode::Minus | OpCode::Plus, ExprNode::UnaryOperationExpr(inner))
+                if matches!(inner.op, OpCode::Minus | OpCode::Plus) =>
+            {
+                ctx.write_plain(" ")
+            }
+            _ => {}
+        }
+        v.restore(ctx)
+    }
+}
+
+impl Restore for ColumnName {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        if !self.schema.origin.is_empty() {
+            ctx.write_name(&self.schema.origin);
+            ctx.write_plain(".");
+        }
+        if !self.table.origin.is_empty() {
+            ctx.write_name(&self.table.origin);
+            ctx.write_plain(".");
+        }
+        ctx.write_name(&self.name.origin);
+        Ok(())
+    }
+}
+
+impl Restore for ColumnNameExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        self.name.restore(ctx)
+    }
+}
+
+impl Restore for MatchAgainst {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("MATCH");
+        ctx.write_plain(" (");
+        restore_list(ctx, &self.column_names, ", ")?;
+        ctx.write_plain(") ");
+        ctx.write_keyword("AGAINST");
+        ctx.write_plain(" (");
+        required(&self.against, "search string")?.restore(ctx)?;
+        if self.modifier.is_bool_mode() {
+            ctx.write_keyword(" IN BOOLEAN MODE");
+        } else if self.modifier.with_query_expansion() {
+            ctx.write_keyword(" WITH QUERY EXPANSION");
+        }
+        ctx.write_plain(")");
+        Ok(())
+    }
+}
+
+impl Restore for FuncCallExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        match self.tp {
+            FuncCallExprType::JsonExtractArrow => return restore_json_arrow(self, "->", ctx),
+            FuncCallExprType::JsonUnquoteArrow => {
+                return match self.args.first() {
+                    Some(ExprNode::FuncCallExpr(extract)) => {
+                        restore_json_arrow(extract, "->>", ctx)
+                    }
+                    _ => Err(unsupported("JSON unquote arrow")),
+                };
+            }
+            _ => {}
+        }
+
+        let args = &self.args;
+        let lower = self.fn_name.lower.as_str();
+        match lower {
+            functions::DATE_LITERAL | functions::TIME_LITERAL | functions::TIMESTAMP_LITERAL => {
+                ctx.write_keyword(match lower {
+                    functions::DATE_LITERAL => "DATE ",
+                    functions::TIME_LITERAL => "TIME ",
+                    _ => "TIMESTAMP ",
+                });
+                return restore_list(ctx, args, ", ");
+            }
+            functions::NEXT_VAL => {
+                ctx.write_keyword("NEXT VALUE FOR ");
+                return restore_list(ctx, args, ", ");
+            }
+            functions::JSON_MEMBER_OF if args.len() == 2 => {
+                args[0].restore(ctx)?;
+                ctx.write_keyword(" MEMBER OF ");
+                ctx.write_plain("(");
+                args[1].restore(ctx)?;
+                ctx.write_plain(")");
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        let name = match lower {
+            functions::INSERT_FUNC => "INSERT",
+            functions::PASSWORD_FUNC => "PASSWORD",
+            functions::CHAR_FUNC => "CHAR",
+            _ => self.fn_name.origin.as_str(),
+        };
+        if !self.schema.origin.is_empty() {
+            ctx.write_name(&self.schema.origin);
+            ctx.write_plain(".");
+            ctx.write_name(name);
+        } else if matches!(self.tp, FuncCallExprType::Generic) || ident_needs_quote(name) {
+            ctx.write_name(name);
+        } else {
+            ctx.write_keyword(name);
+        }
+
+        ctx.write_plain("(");
+        match (lower, args.as_slice()) {
+            // The last argument of CHAR() is the charset of the result.
+            (functions::CHAR_FUNC, [args @ .., _]) => restore_list(ctx, args, ", ")?,
+            (functions::CONVERT, [expr, ExprNode::ValueExpr(charset)]) => {
+                expr.restore(ctx)?;
+                ctx.write_keyword(" USING ");
+                ctx.write_plain(&charset.s);
+            }
+            (functions::EXTRACT, [unit, expr]) => {
+                unit.restore(ctx)?;
+                ctx.write_keyword(" FROM ");
+                expr.restore(ctx)?;
+            }
+            (functions::POSITION, [sub_str, str]) => {
+                sub_str.restore(ctx)?;
+                ctx.write_keyword(" IN ");
+                str.restore(ctx)?;
+            }
+            (functions::TRIM, [str, rem_str]) => {
+                rem_str.restore(ctx)?;
+                ctx.write_keyword(" FROM ");
+                str.restore(ctx)?;
+            }
+            (functions::TRIM, [str, rem_str, direction]) => {
+                direction.restore(ctx)?;
+                ctx.write_plain(" ");
+                rem_str.restore(ctx)?;
+                ctx.write_keyword(" FROM ");
+                str.restore(ctx)?;
+            }
+            (functions::WEIGHT_STRING, [str, ExprNode::ValueExpr(tp), len]) => {
+                str.restore(ctx)?;
+                ctx.write_keyword(" AS ");
+                ctx.write_keyword(&tp.s);
+                ctx.write_plain("(");
+                len.restore(ctx)?;
+                ctx.write_plain(")");
+            }
+            (
+                functions::ADD_DATE
+                | functions::SUB_DATE
+                | functions::DATE_ADD
+                | functions::DATE_SUB,
+                [date, interval, unit],
+            ) => {
+                date.restore(ctx)?;
+                ctx.write_plain(", ");
+                ctx.write_keyword("INTERVAL ");
+                interval.restore(ctx)?;
+                ctx.write_plain(" ");
+                unit.restore(ctx)?;
+            }
+            _ => restore_list(ctx, args, ", ")?,
+        }
+        ctx.write_plain(")");
+        Ok(())
+    }
+}
+
+// RestoreJsonArrow restores `column->path` or `column->>path` from the
+// JSON_EXTRACT call of the arrow.
+fn restore_json_arrow(extract: &FuncCallExpr, arrow: &str, ctx: &mut RestoreCtx) -> Result<()> {
+    match extract.args.as_slice() {
+        [column, path] => {
+            column.restore(ctx)?;
+            ctx.write_plain(arrow);
+            path.restore(ctx)
+        }
+        _ => Err(unsupported("JSON arrow")),
+    }
+}
+
+impl Restore for TimeUnitExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        if let TimeUnitType::TimeUnitInvalid = self.unit {
+            return Err(unsupported("time unit"));
+        }
+        ctx.write_keyword(&self.unit.to_string());
+        Ok(())
+    }
+}
+
+impl Restore for ValueExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        match &self.kind {
+            ValueExprKind::String => {
+                let skip_charset = self.charset.is_empty()
+                    || ctx.flags.has_string_without_charset_flag()
+                    || (ctx.flags.has_string_without_default_charset_flag()
+                        && self.charset == CHARSET_UTF8MB4);
+                if !skip_charset {
+                    if self.prefix == LiteralPrefix::National {
+                        ctx.write_keyword("N");
+                    } else {
+                        ctx.write_plain("_");
+                        ctx.write_keyword(&self.charset);
+                    }
+                }
+                ctx.write_string(&self.s);
+            }
+            ValueExprKind::None => ctx.write_keyword("NULL"),
+            ValueExprKind::Bool(true) => ctx.write_keyword("TRUE"),
+            ValueExprKind::Bool(false) => ctx.write_keyword("FALSE"),
+            ValueExprKind::Default => return Err(unsupported("empty value")),
+            // Numbers, hexadecimal and bit-value literals keep the source text.
+            _ => {
+                if self.prefix == LiteralPrefix::Binary
+                    && !ctx.flags.has_string_without_charset_flag()
+                {
+                    ctx.write_plain("_");
+                    ctx.write_keyword("binary");
+                    ctx.write_plain(" ");
+                }
+                ctx.write_plain(&self.s)
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Restore for ParenthesesExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_plain("(");
+        required(&self.expr, "expression")?.restore(ctx)?;
+        ctx.write_plain(")");
+        Ok(())
+    }
+}
+
+impl Restore for RowExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("ROW");
+        ctx.write_plain("(");
+        restore_list(ctx, &self.values, ", ")?;
+        ctx.write_plain(")");
+        Ok(())
+    }
+}
+
+impl Restore for TrimDirectionExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword(&self.direction.to_string());
+        Ok(())
+    }
+}
+
+impl Restore for GetFormatSelectorExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword(&self.selector.to_string());
+        Ok(())
+    }
+}
+
+impl Restore for TableNameExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        self.name.restore(ctx)
+    }
+}
+
+impl Restore for SetCollationExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        required(&self.expr, "expression")?.restore(ctx)?;
+        ctx.write_keyword(" COLLATE ");
+        ctx.write_plain(&self.collate);
+        Ok(())
+    }
+}
+
+impl Restore for WindowFuncExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword(&self.name);
+        ctx.write_plain("(");
+        if self.distinct {
+            ctx.write_keyword("DISTINCT ");
+        }
+        restore_func_args(ctx, &self.name, &self.args, &None)?;
+        ctx.write_plain(")");
+        if self.from_last {
+            ctx.write_keyword(" FROM LAST");
+        }
+        if self.ignore_null {
+            ctx.write_keyword(" IGNORE NULLS");
+        }
+        ctx.write_keyword(" OVER ");
+        required(&self.spec, "window specification")?.restore(ctx)
+    }
+}
+
+// RestoreFuncArgs restores the arguments of an aggregate or window function,
+// the last argument of GROUP_CONCAT is the separator.
+fn restore_func_args(
+    ctx: &mut RestoreCtx,
+    name: &str,
+    args: &[ExprNode],
+    order: &Option<OrderByClause>,
+) -> Result<()> {
+    match args.split_last() {
+        Some((separator, args)) if name.eq_ignore_ascii_case("group_concat") => {
+            restore_list(ctx, args, ", ")?;
+            if let Some(order) = order {
+                ctx.write_plain(" ");
+                order.restore(ctx)?;
+            }
+            ctx.write_keyword(" SEPARATOR ");
+            separator.restore(ctx)
+        }
+        _ => restore_list(ctx, args, ", "),
+    }
+}
+
+// Restore writes the window specification of `OVER`, a window name or the
+// details in parentheses.
+impl Restore for WindowSpec {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        if !self.name.origin.is_empty() {
+            ctx.write_name(&self.name.origin);
+            return Ok(());
+        }
+        ctx.write_plain("(");
+        restore_window_spec_details(self, ctx)?;
+        ctx.write_plain(")");
+        Ok(())
+    }
+}
+
+pub(crate) fn restore_window_spec_details(spec: &WindowSpec, ctx: &mut RestoreCtx) -> Result<()> {
+    let mut sep = "";
+    if !spec.references.origin.is_empty() {
+        ctx.write_name(&spec.references.origin);
+        sep = " ";
+    }
+    if let Some(partition_by) = &spec.partition_by {
+        ctx.write_plain(sep);
+        partition_by.restore(ctx)?;
+        sep = " ";
+    }
+    if let Some(order_by) = &spec.order_by {
+        ctx.write_plain(sep);
+        order_by.restore(ctx)?;
+        sep = " ";
+    }
+    if let Some(frame) = &spec.frame {
+        ctx.write_plain(sep);
+        frame.restore(ctx)?;
+    }
+    Ok(())
+}
+
+impl Restore for PartitionByClause {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("PARTITION BY ");
+        restore_list(ctx, &self.items, ", ")
+    }
+}
+
+impl Restore for FrameClause {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword(match self.tp {
+            FrameType::Rows => "ROWS ",
+            FrameType::Ranges => "RANGE ",
+            FrameType::Groups => "GROUPS ",
+        });
+        required(&self.extent, "frame extent")?.restore(ctx)
+    }
+}
+
+impl Restore for FrameExtent {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("BETWEEN ");
+        required(&self.start, "frame start")?.restore(ctx)?;
+        ctx.write_keyword(" AND ");
+        required(&self.end, "frame end")?.restore(ctx)
+    }
+}
+
+impl Restore for FrameBound {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        if let BoundType::CurrentRow = self.tp {
+            ctx.write_keyword("CURRENT ROW");
+            return Ok(());
+        }
+        if self.un_bounded {
+            ctx.write_keyword("UNBOUNDED");
+        } else if let TimeUnitType::TimeUnitInvalid = self.unit {
+            required(&self.expr, "frame bound")?.restore(ctx)?;
+        } else {
+            ctx.write_keyword("INTERVAL ");
+            required(&self.expr, "frame bound")?.restore(ctx)?;
+            ctx.write_plain(" ");
+            ctx.write_keyword(&self.unit.to_string());
+        }
+        ctx.write_keyword(match self.tp {
+            BoundType::Preceding => " PRECEDING",
+            _ => " FOLLOWING",
+        });
+        Ok(())
+    }
+}
+
+impl Restore for PositionExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        match &self.p {
+            Some(p) => p.restore(ctx),
+            None => {
+                ctx.write_plain(&self.n.to_string());
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Restore for ParamMarkerExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_plain("?");
+        Ok(())
+    }
+}
+
+impl Restore for AggregateFuncExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword(&self.f);
+        ctx.write_plain("(");
+        if self.distinct {
+            ctx.write_keyword("DISTINCT ");
+        }
+        restore_func_args(ctx, &self.f, &self.args, &self.order)?;
+        ctx.write_plain(")");
+        Ok(())
+    }
+}
+
+impl Restore for CaseExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("CASE");
+        if let Some(value) = &self.value {
+            ctx.write_plain(" ");
+            value.restore(ctx)?;
+        }
+        for clause in &self.when_clauses {
+            ctx.write_plain(" ");
+            clause.restore(ctx)?;
+        }
+        if let Some(else_clause) = &self.else_clause {
+            ctx.write_keyword(" ELSE ");
+            else_clause.restore(ctx)?;
+        }
+        ctx.write_keyword(" END");
+        Ok(())
+    }
+}
+
+impl Restore for WhenClause {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("WHEN ");
+        self.expr.restore(ctx)?;
+        ctx.write_keyword(" THEN ");
+        self.result.restore(ctx)
+    }
+}
+
+impl Restore for FuncCastExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        let expr = required(&self.expr, "expression")?;
+        match self.function_type {
+            CastFunctionType::CastFunction => {
+                ctx.write_keyword("CAST");
+                ctx.write_plain("(");
+                expr.restore(ctx)?;
+                ctx.write_keyword(" AS ");
+                restore_cast_type(&self.tp, ctx)?;
+                ctx.write_plain(")");
+            }
+            CastFunctionType::CastConvertFunction => {
+                ctx.write_keyword("CONVERT");
+                ctx.write_plain("(");
+                expr.restore(ctx)?;
+                ctx.write_plain(", ");
+                restore_cast_type(&self.tp, ctx)?;
+                ctx.write_plain(")");
+            }
+            CastFunctionType::CastBinaryOperator => {
+                ctx.write_keyword("BINARY ");
+                expr.restore(ctx)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// RestoreCastType restores the target type of CAST and CONVERT, which is
+// named differently from the column types, e.g. `SIGNED` and `CHAR`.
+fn restore_cast_type(tp: &FieldType, ctx: &mut RestoreCtx) -> Result<()> {
+    match tp.tp {
+        DataType::VarBinary if tp.charset == "binary" => {
+            ctx.write_keyword("BINARY");
+            restore_field_len(tp, ctx);
+        }
+        DataType::VarChar => {
+            ctx.write_keyword("CHAR");
+            restore_field_len(tp, ctx);
+            restore_string_options(tp, ctx);
+        }
+        DataType::BigInt => {
+            ctx.write_keyword(if tp.unsigned { "UNSIGNED" } else { "SIGNED" });
+        }
+        _ => tp.restore(ctx)?,
+    }
+    if tp.array {
+        ctx.write_keyword(" ARRAY");
+    }
+    Ok(())
+}
+
+fn restore_field_len(tp: &FieldType, ctx: &mut RestoreCtx) {
+    if let Some(flen) = tp.flen {
+        ctx.write_plain(&format!("({})", flen));
+    }
+}
+
+fn restore_string_options(tp: &FieldType, ctx: &mut RestoreCtx) {
+    if tp.binary {
+        ctx.write_keyword(" BINARY");
+    }
+    if !tp.charset.is_empty() {
+        ctx.write_keyword(" CHARACTER SET ");
+        ctx.write_plain(&tp.charset);
+    }
+    if !tp.collate.is_empty() {
+        ctx.write_keyword(" COLLATE ");
+        ctx.write_plain(&tp.collate);
+    }
+}
+
+// Restore writes the column type, the element type of a cast is restored by
+// restore_cast_type.
+impl Restore for FieldType {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        let name = match self.tp {
+            DataType::Unspecified => return Err(unsupported("unspecified type")),
+            DataType::TinyInt => "TINYINT",
+            DataType::SmallInt => "SMALLINT",
+            DataType::MediumInt => "MEDIUMINT",
+            DataType::Int => "INT",
+            DataType::BigInt => "BIGINT",
+            DataType::Decimal => "DECIMAL",
+            DataType::Float => "FLOAT",
+            DataType::Double => "DOUBLE",
+            DataType::Bit => "BIT",
+            DataType::Date => "DATE",
+            DataType::DateTime => "DATETIME",
+            DataType::Timestamp => "TIMESTAMP",
+            DataType::Time => "TIME",
+            DataType::Year => "YEAR",
+            DataType::Char => "CHAR",
+            DataType::VarChar => "VARCHAR",
+            DataType::Binary => "BINARY",
+            DataType::VarBinary => "VARBINARY",
+            DataType::TinyBlob => "TINYBLOB",
+            DataType::Blob => "BLOB",
+            DataType::MediumBlob => "MEDIUMBLOB",
+            DataType::LongBlob => "LONGBLOB",
+            DataType::TinyText => "TINYTEXT",
+            DataType::Text => "TEXT",
+            DataType::MediumText => "MEDIUMTEXT",
+            DataType::LongText => "LONGTEXT",
+            DataType::Enum => "ENUM",
+            DataType::Set => "SET",
+            DataType::Json => "JSON",
+            DataType::Geometry => "GEOMETRY",
+            DataType::Point => "POINT",
+            DataType::LineString => "LINESTRING",
+            DataType::Polygon => "POLYGON",
+            DataType::MultiPoint => "MULTIPOINT",
+            DataType::MultiLineString => "MULTILINESTRING",
+            DataType::MultiPolygon => "MULTIPOLYGON",
+            DataType::GeometryCollection => "GEOMETRYCOLLECTION",
+        };
+        ctx.write_keyword(name);
+
+        match self.tp {
+            DataType::Decimal | DataType::Float | DataType::Double => {
+                if let Some(flen) = self.flen {
+                    ctx.write_plain(&format!("({}", flen));
+                    if let Some(decimal) = self.decimal {
+                        ctx.write_plain(&format!(", {}", decimal));
+                    }
+                    ctx.write_plain(")");
+                }
+            }
+            DataType::DateTime | DataType::Timestamp | DataType::Time => {
+                if let Some(decimal) = self.decimal {
+                    ctx.write_plain(&format!("({})", decimal));
+                }
+            }
+            DataType::Enum | DataType::Set => {
+                ctx.write_plain("(");
+                for (i, elem) in self.elems.iter().enumerate() {
+                    if i > 0 {
+                        ctx.write_plain(", ");
+                    }
+                    ctx.write_string(elem);
+                }
+                ctx.write_plain(")");
+            }
+            _ => restore_field_len(self, ctx),
+        }
+
+        if self.unsigned {
+            ctx.write_keyword(" UNSIGNED");
+        }
+        if self.zerofill {
+            ctx.write_keyword(" ZEROFILL");
+        }
+        restore_string_options(self, ctx);
+        Ok(())
+    }
+}
+
+impl Restore for PatternInExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        required(&self.expr, "expression")?.restore(ctx)?;
+        ctx.write_keyword(if self.not { " NOT IN " } else { " IN " });
+        match &self.sel {
+            Some(sel) => sel.restore(ctx),
+            None => {
+                ctx.write_plain("(");
+                restore_list(ctx, &self.list, ", ")?;
+                ctx.write_plain(")");
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Restore for BetweenExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        required(&self.expr, "expression")?.restore(ctx)?;
+        ctx.write_keyword(if self.not {
+            " NOT BETWEEN "
+        } else {
+            " BETWEEN "
+        });
+        required(&self.left, "lower bound")?.restore(ctx)?;
+        ctx.write_keyword(" AND ");
+        required(&self.right, "upper bound")?.restore(ctx)
+    }
+}
+
+// Restore writes `[expr] [NOT] LIKE pattern [ESCAPE 'c']`, the expression is
+// absent in `SHOW ... LIKE`.
+impl Restore for PatternLikeOrIlikeExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        if let Some(expr) = &self.expr {
+            expr.restore(ctx)?;
+            ctx.write_plain(" ");
+        }
+        if self.not {
+            ctx.write_keyword("NOT ");
+        }
+        ctx.write_keyword(if self.is_like { "LIKE " } else { "ILIKE " });
+        required(&self.pattern, "pattern")?.restore(ctx)?;
+        match self.escape {
+            Some('\\') => {}
+            Some(escape) => {
+                ctx.write_keyword(" ESCAPE ");
+                ctx.write_string(&escape.to_string());
+            }
+            None => {
+                ctx.write_keyword(" ESCAPE ");
+                ctx.write_string("");
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Restore for PatternRegexpExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        required(&self.expr, "expression")?.restore(ctx)?;
+        ctx.write_keyword(if self.not { " NOT REGEXP " } else { " REGEXP " });
+        required(&self.pattern, "pattern")?.restore(ctx)
+    }
+}
+
+impl Restore for SoundsLikeExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        required(&self.expr, "expression")?.restore(ctx)?;
+        ctx.write_keyword(" SOUNDS LIKE ");
+        required(&self.pattern, "pattern")?.restore(ctx)
+    }
+}
+
+impl Restore for MemberOfExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        required(&self.expr, "expression")?.restore(ctx)?;
+        ctx.write_keyword(" MEMBER OF ");
+        ctx.write_plain("(");
+        required(&self.json, "JSON array")?.restore(ctx)?;
+        ctx.write_plain(")");
+        Ok(())
+    }
+}
+
+impl Restore for IsNullExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        required(&self.expr, "expression")?.restore(ctx)?;
+        ctx.write_keyword(if self.not { " IS NOT NULL" } else { " IS NULL" });
+        Ok(())
+    }
+}
+
+impl Restore for IsTruthExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        required(&self.expr, "expression")?.restore(ctx)?;
+        ctx.write_keyword(if self.not { " IS NOT " } else { " IS " });
+        ctx.write_keyword(if self.true_val > 0 { "TRUE" } else { "FALSE" });
+        Ok(())
+    }
+}
+
+impl Restore for CompareSubqueryExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        required(&self.l, "left operand")?.restore(ctx)?;
+        ctx.write_plain(" ");
+        self.op.restore(ctx)?;
+        ctx.write_keyword(if self.all { " ALL " } else { " ANY " });
+        required(&self.r, "subquery")?.restore(ctx)
+    }
+}
+
+impl Restore for SubQueryExpr {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_plain("(");
+        required(&self.query, "query")?.restore(ctx)?;
+        ctx.write_plain(")");
+        Ok(())
+    }
+}
+
+impl Restore for Statement {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        match self {
+            Statement::SelectStmt(stmt) => stmt.restore(ctx),
+            Statement::SetOprStmt(stmt) => stmt.restore(ctx),
+            Statement::CallStmt(stmt) => stmt.restore(ctx),
+            Statement::DoStmt(stmt) => stmt.restore(ctx),
+            Statement::HandlerStmt(stmt) => stmt.restore(ctx),
+            Statement::CreateSequenceStmt(stmt) => stmt.restore(ctx),
+            Statement::AlterSequenceStmt(stmt) => stmt.restore(ctx),
+            Statement::DropSequenceStmt(stmt) => stmt.restore(ctx),
+            Statement::AdminStmt(stmt) => stmt.restore(ctx),
+            Statement::CreateBindingStmt(stmt) => stmt.restore(ctx),
+            Statement::DropBindingStmt(stmt) => stmt.restore(ctx),
+            Statement::ShowStmt(stmt) => stmt.restore(ctx),
+            Statement::SplitRegionStmt(stmt) => stmt.restore(ctx),
+            Statement::FlashBackTableStmt(stmt) => stmt.restore(ctx),
+            Statement::FlashBackDatabaseStmt(stmt) => stmt.restore(ctx),
+            Statement::FlashBackToTimestampStmt(stmt) => stmt.restore(ctx),
+            Statement::CreatePlacementPolicyStmt(stmt) => stmt.restore(ctx),
+            Statement::AlterPlacementPolicyStmt(stmt) => stmt.restore(ctx),
+            Statement::DropPlacementPolicyStmt(stmt) => stmt.restore(ctx),
+            Statement::SetConfigStmt(stmt) => stmt.restore(ctx),
+        }
+    }
+}
+
+impl Restore for ResultSetNode {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        match self {
+            ResultSetNode::TableRefsClause(node) => node.restore(ctx),
+            ResultSetNode::SelectStmt(node) => node.restore(ctx),
+            ResultSetNode::TableName(node) => node.restore(ctx),
+            ResultSetNode::TableSource(node) => node.restore(ctx),
+            ResultSetNode::SetOprStmt(node) => node.restore(ctx),
+            ResultSetNode::JsonTable(node) => node.restore(ctx),
+            ResultSetNode::Lateral(node) => {
+                ctx.write_keyword("LATERAL ");
+                ctx.write_plain("(");
+                node.restore(ctx)?;
+                ctx.write_plain(")");
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Restore for Node {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        match self {
+            Node::Statement(node) => node.restore(ctx),
+            Node::ResultSetNode(node) => node.restore(ctx),
+            Node::SetOprSelectList(node) => node.restore(ctx),
+        }
+    }
+}
+
+impl Restore for SelectStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        if self.with_before_braces {
+            if let Some(with) = &self.with {
+                with.restore(ctx)?;
+                ctx.write_plain(" ");
+            }
+        }
+        if self.is_in_braces {
+            ctx.write_plain("(");
+        }
+        if !self.with_before_braces {
+            if let Some(with) = &self.with {
+                with.restore(ctx)?;
+                ctx.write_plain(" ");
+            }
+        }
+
+        match self.kind {
+            SelectStmtKind::SelectStmtKindSelect => self.restore_select(ctx)?,
+            SelectStmtKind::SelectStmtKindTable => {
+                ctx.write_keyword("TABLE ");
+                required(&self.from, "table")?.restore(ctx)?;
+            }
+            SelectStmtKind::SelectStmtKindValues => {
+                ctx.write_keyword("VALUES ");
+                restore_list(ctx, &self.lists, ", ")?;
+            }
+        }
+
+        if let Some(order_by) = &self.order_by {
+            ctx.write_plain(" ");
+            order_by.restore(ctx)?;
+        }
+        if let Some(limit) = &self.limit {
+            ctx.write_plain(" ");
+            limit.restore(ctx)?;
+        }
+        if let Some(lock_info) = &self.lock_info {
+            if !matches!(lock_info.lock_type, SelectLockType::SelectLockNone) {
+                ctx.write_plain(" ");
+                lock_info.restore(ctx)?;
+            }
+        }
+        if let Some(select_into_opt) = &self.select_into_opt {
+            ctx.write_plain(" ");
+            select_into_opt.restore(ctx)?;
+        }
+        if self.is_in_braces {
+            ctx.write_plain(")");
+        }
+        Ok(())
+    }
+}
+
+impl SelectStmt {
+    // RestoreSelect restores `SELECT ... FROM ... WHERE ...` up to the
+    // WINDOW clause.
+    fn restore_select(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("SELECT ");
+        self.restore_select_opts(ctx)?;
+        restore_list(ctx, &self.fields, ", ")?;
+        if let Some(from) = &self.from {
+            ctx.write_keyword(" FROM ");
+            from.restore(ctx)?;
+        }
+        if let Some(where_clause) = &self.where_clause {
+            ctx.write_keyword(" WHERE ");
+            where_clause.restore(ctx)?;
+        }
+        if let Some(group_by) = &self.group_by {
+            ctx.write_plain(" ");
+            group_by.restore(ctx)?;
+        }
+        if let Some(having) = &self.having {
+            ctx.write_keyword(" HAVING ");
+            having.restore(ctx)?;
+        }
+        if !self.window_specs.is_empty() {
+            ctx.write_keyword(" WINDOW ");
+            for (i, spec) in self.window_specs.iter().enumerate() {
+                if i > 0 {
+                    ctx.write_plain(", ");
+                }
+                ctx.write_name(&spec.name.origin);
+                ctx.write_keyword(" AS ");
+                ctx.write_plain("(");
+                restore_window_spec_details(spec, ctx)?;
+                ctx.write_plain(")");
+            }
+        }
+        Ok(())
+    }
+
+    // RestoreSelectOpts restores the hints and options after `SELECT`, each
+    // followed by a space.
+    pub(crate) fn restore_select_opts(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        let opts = &self.select_stmt_opts;
+        if !opts.table_hints.is_empty() && !ctx.flags.has_without_optimizer_hints_flag() {
+            ctx.write_plain("/*+ ");
+            // MySQL reads the hints separated by spaces, and TiDB by commas.
+            let sep = if ctx.dialect.is_tidb() { ", " } else { " " };
+            restore_list(ctx, &opts.table_hints, sep)?;
+            ctx.write_plain(" */ ");
+        }
+        match opts.priority {
+            PriorityEnum::LowPriority => ctx.write_keyword("LOW_PRIORITY "),
+            PriorityEnum::HighPriority => ctx.write_keyword("HIGH_PRIORITY "),
+            PriorityEnum::DelayedPriority => ctx.write_keyword("DELAYED "),
+            PriorityEnum::NoPriority => {}
+        }
+        if opts.sql_small_result {
+            ctx.write_keyword("SQL_SMALL_RESULT ");
+        }
+        if opts.sql_big_result {
+            ctx.write_keyword("SQL_BIG_RESULT ");
+        }
+        if opts.sql_buffer_result {
+            ctx.write_keyword("SQL_BUFFER_RESULT ");
+        }
+        if !opts.sql_cache {
+            ctx.write_keyword("SQL_NO_CACHE ");
+        }
+        if opts.calc_found_rows {
+            ctx.write_keyword("SQL_CALC_FOUND_ROWS ");
+        }
+        if opts.distinct {
+            ctx.write_keyword("DISTINCT ");
+        } else if opts.explicit_all {
+            ctx.write_keyword("ALL ");
+        }
+        if opts.straight_join {
+            ctx.write_keyword("STRAIGHT_JOIN ");
+        }
+        Ok(())
+    }
+}
+
+impl Restore for SelectField {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        match &self.field {
+            Field::WildCardField(field) => field.restore(ctx)?,
+            Field::Expr(expr) => expr.restore(ctx)?,
+            Field::Unkonw => return Err(unsupported("select field")),
+        }
+        if !self.as_name.origin.is_empty() {
+            ctx.write_keyword(" AS ");
+            ctx.write_name(&self.as_name.origin);
+        }
+        Ok(())
+    }
+}
+
+impl Restore for WildCardField {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        if !self.schema.origin.is_empty() {
+            ctx.write_name(&self.schema.origin);
+            ctx.write_plain(".");
+        }
+        if !self.table.origin.is_empty() {
+            ctx.write_name(&self.table.origin);
+            ctx.write_plain(".");
+        }
+        ctx.write_plain("*");
+        Ok(())
+    }
+}
+
+impl Restore for TableOptimizerHint {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        let name = self.hint_name.lower.as_str();
+        ctx.write_keyword(&self.hint_name.origin);
+        ctx.write_plain("(");
+        if name == "qb_name" {
+            ctx.write_name(&self.qb_name.origin);
+            ctx.write_plain(")");
+            return Ok(());
+        }
+
+        let mut sep = "";
+        if !self.qb_name.origin.is_empty() {
+            ctx.write_plain("@");
+            ctx.write_name(&self.qb_name.origin);
+            sep = " ";
+        }
+        match &self.hint_data {
+            Some(HintData::Uint64(n)) => ctx.write_plain(&format!("{}{}", sep, n)),
+            Some(HintData::Int64(n)) if name == "memory_quota" => {
+                ctx.write_plain(&format!("{}{} ", sep, n / 1024 / 1024));
+                ctx.write_keyword("MB");
+            }
+            Some(HintData::Int64(n)) => ctx.write_plain(&format!("{}{}", sep, n)),
+            Some(HintData::Bool(b)) => {
+                ctx.write_plain(sep);
+                ctx.write_keyword(if *b { "TRUE" } else { "FALSE" });
+            }
+            Some(HintData::CIStr(data)) if name == "read_from_storage" => {
+                ctx.write_plain(sep);
+                ctx.write_keyword(&data.origin);
+                ctx.write_plain("[");
+                restore_list(ctx, &self.tables, ", ")?;
+                ctx.write_plain("]");
+            }
+            Some(HintData::CIStr(data)) if name == "resource_group" => {
+                ctx.write_name(&data.origin);
+            }
+            Some(HintData::CIStr(data)) => {
+                ctx.write_plain(sep);
+                ctx.write_keyword(&data.origin);
+            }
+            Some(HintData::HintTimeRange(time_range)) => {
+                ctx.write_string(&time_range.from);
+                ctx.write_plain(", ");
+                ctx.write_string(&time_range.to);
+            }
+            Some(HintData::HintSetVar(set_var)) => {
+                ctx.write_plain(&set_var.var_name);
+                ctx.write_plain(" = ");
+                ctx.write_string(&set_var.value);
+            }
+            None if matches!(name, "semijoin" | "no_semijoin" | "subquery") => {
+                ctx.write_plain(sep);
+                for (i, strategy) in self.tables.iter().enumerate() {
+                    if i > 0 {
+                        ctx.write_plain(", ");
+                    }
+                    ctx.write_keyword(&strategy.table_name.origin);
+                }
+            }
+            None => {
+                ctx.write_plain(sep);
+                restore_list(ctx, &self.tables, ", ")?;
+                // MySQL separates the table and the indexes by a space, e.g.
+                // `INDEX(t1 idx1, idx2)`, and TiDB by a comma.
+                for (i, index) in self.indexes.iter().enumerate() {
+                    if i == 0 && !ctx.dialect.is_tidb() {
+                        ctx.write_plain(" ");
+                    } else {
+                        ctx.write_plain(", ");
+                    }
+                    ctx.write_name(&index.origin);
+                }
+            }
+        }
+        ctx.write_plain(")");
+        Ok(())
+    }
+}
+
+impl Restore for HintTable {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        if !self.db_name.origin.is_empty() {
+            ctx.write_name(&self.db_name.origin);
+            ctx.write_plain(".");
+        }
+        ctx.write_name(&self.table_name.origin);
+        if !self.qb_name.origin.is_empty() {
+            ctx.write_plain("@");
+            ctx.write_name(&self.qb_name.origin);
+        }
+        if !self.partition_list.is_empty() {
+            ctx.write_keyword(" PARTITION");
+            ctx.write_plain("(");
+            restore_list(ctx, &self.partition_list, ", ")?;
+            ctx.write_plain(")");
+        }
+        Ok(())
+    }
+}
+
+impl Restore for TableRefsClause {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        if self.explicit_parens {
+            ctx.write_plain("(");
+        }
+        self.left.restore(ctx)?;
+        if let Some(right) = &self.right {
+            if self.is_comma_join() {
+                ctx.write_plain(", ");
+                right.restore(ctx)?;
+            } else {
+                if self.natural_join {
+                    ctx.write_keyword(" NATURAL");
+                }
+                match self.join_type {
+                    JoinType::LeftJoin => ctx.write_keyword(" LEFT"),
+                    JoinType::RightJoin => ctx.write_keyword(" RIGHT"),
+                    JoinType::CrossJoin => {}
+                }
+                ctx.write_keyword(if self.straight_join {
+                    " STRAIGHT_JOIN "
+                } else {
+                    " JOIN "
+                });
+                right.restore(ctx)?;
+                if !matches!(self.on.as_ref(), ExprNode::Default) {
+                    ctx.write_keyword(" ON ");
+                    self.on.restore(ctx)?;
+                }
+                if !self.using.is_empty() {
+                    ctx.write_keyword(" USING ");
+                    ctx.write_plain("(");
+                    restore_list(ctx, &self.using, ", ")?;
+                    ctx.write_plain(")");
+                }
+            }
+        }
+        if self.explicit_parens {
+            ctx.write_plain(")");
+        }
+        Ok(())
+    }
+}
+
+impl TableRefsClause {
+    // IsCommaJoin reports whether the join is written with a comma. A JOIN
+    // only takes a table factor on its right side, so a cross join without
+    // condition whose right side is a join, or whose left side is already
+    // written with commas, is restored as a comma list.
+    pub(crate) fn is_comma_join(&self) -> bool {
+        let is_cross_join = matches!(self.join_type, JoinType::CrossJoin)
+            && !self.natural_join
+            && !self.straight_join
+            && matches!(self.on.as_ref(), ExprNode::Default)
+            && self.using.is_empty();
+        if !is_cross_join {
+            return false;
+        }
+        let right_is_join = matches!(
+            self.right.as_deref(),
+            Some(ResultSetNode::TableRefsClause(join)) if !join.explicit_parens && join.right.is_some()
+        );
+        let left_is_comma_join = matches!(
+            self.left.as_ref(),
+            ResultSetNode::TableRefsClause(join) if !join.explicit_parens && join.is_comma_join()
+        );
+        right_is_join || left_is_comma_join
+    }
+}
+
+impl Restore for TableSource {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        match self.source.as_ref() {
+            ResultSetNode::TableName(name) => {
+                name.restore(ctx)?;
+                if !name.partition_names.is_empty() {
+                    ctx.write_keyword(" PARTITION");
+                    ctx.write_plain("(");
+                    restore_list(ctx, &name.partition_names, ", ")?;
+                    ctx.write_plain(")");
+                }
+                if let Some(system_time) = &name.system_time {
+                    ctx.write_keyword(" FOR SYSTEM_TIME ");
+                    system_time.restore(ctx)?;
+                }
+                self.restore_as_name(ctx);
+                if let Some(as_of) = &name.as_of {
+                    ctx.write_keyword(" AS OF TIMESTAMP ");
+                    as_of.restore(ctx)?;
+                }
+                if !ctx.flags.has_without_index_hints_flag() {
+                    for hint in &name.index_hints {
+                        ctx.write_plain(" ");
+                        hint.restore(ctx)?;
+                    }
+                }
+                if let Some(table_sample) = &name.table_sample {
+                    ctx.write_plain(" ");
+                    table_sample.restore(ctx)?;
+                }
+                return Ok(());
+            }
+            source @ (ResultSetNode::SelectStmt(_) | ResultSetNode::SetOprStmt(_)) => {
+                ctx.write_plain("(");
+                source.restore(ctx)?;
+                ctx.write_plain(")");
+            }
+            source => source.restore(ctx)?,
+        }
+        self.restore_as_name(ctx);
+        Ok(())
+    }
+}
+
+impl TableSource {
+    fn restore_as_name(&self, ctx: &mut RestoreCtx) {
+        if !self.as_name.origin.is_empty() {
+            ctx.write_keyword(" AS ");
+            ctx.write_name(&self.as_name.origin);
+        }
+    }
+}
+
+// Restore writes `[schema.]name` only, the partitions, hints, etc. are
+// written by the table source.
+impl Restore for TableName {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        if !self.schema.origin.is_empty() {
+            ctx.write_name(&self.schema.origin);
+            ctx.write_plain(".");
+        }
+        ctx.write_name(&self.name.origin);
+        Ok(())
+    }
+}
+
+impl Restore for SystemTimeClause {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        match self {
+            SystemTimeClause::AsOf(point) => {
+                ctx.write_keyword("AS OF ");
+                point.restore(ctx)
+            }
+            SystemTimeClause::Between(start, end) => {
+                ctx.write_keyword("BETWEEN ");
+                start.restore(ctx)?;
+                ctx.write_keyword(" AND ");
+                end.restore(ctx)
+            }
+            SystemTimeClause::FromTo(start, end) => {
+                ctx.write_keyword("FROM ");
+                start.restore(ctx)?;
+                ctx.write_keyword(" TO ");
+                end.restore(ctx)
+            }
+            SystemTimeClause::All => {
+                ctx.write_keyword("ALL");
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Restore for HistoryPoint {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword(if self.transaction {
+            "TRANSACTION "
+        } else {
+            "TIMESTAMP "
+        });
+        self.expr.restore(ctx)
+    }
+}
+
+impl Restore for IndexHint {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword(match self.hint_type {
+            IndexHintType::HintUse => "USE INDEX",
+            IndexHintType::HintIgnore => "IGNORE INDEX",
+            IndexHintType::HintForce => "FORCE INDEX",
+            _ => return Err(unsupported("index hint type")),
+        });
+        match self.hint_scope {
+            IndexHintScope::HintForJoin => ctx.write_keyword(" FOR JOIN"),
+            IndexHintScope::HintForOrderBy => ctx.write_keyword(" FOR ORDER BY"),
+            IndexHintScope::HintForGroupBy => ctx.write_keyword(" FOR GROUP BY"),
+            IndexHintScope::HintForScan => {}
+        }
+        ctx.write_plain(" (");
+        for (i, name) in self.index_names.iter().enumerate() {
+            if i > 0 {
+                ctx.write_plain(", ");
+            }
+            if name.lower == "primary" {
+                ctx.write_keyword("PRIMARY");
+            } else {
+                ctx.write_name(&name.origin);
+            }
+        }
+        ctx.write_plain(")");
+        Ok(())
+    }
+}
+
+impl Restore for TableSample {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("TABLESAMPLE ");
+        match self.sample_method {
+            SampleMethodType::SampleMethodTypeSystem => ctx.write_keyword("SYSTEM "),
+            SampleMethodType::SampleMethodTypeBernoulli => ctx.write_keyword("BERNOULLI "),
+            SampleMethodType::SampleMethodTypeTiDBRegion => ctx.write_keyword("REGIONS "),
+            SampleMethodType::SampleMethodTypeNone => {}
+        }
+        ctx.write_plain("(");
+        if !matches!(self.expr.as_ref(), ExprNode::Default) {
+            self.expr.restore(ctx)?;
+            match self.sample_clause_unit {
+                SampleClauseUnitType::SampleClauseUnitTypeRow => ctx.write_keyword(" ROWS"),
+                SampleClauseUnitType::SampleClauseUnitTypePercent => ctx.write_keyword(" PERCENT"),
+                SampleClauseUnitType::SampleClauseUnitTypeDefault => {}
+            }
+        }
+        ctx.write_plain(")");
+        if !matches!(self.repeatable_seed.as_ref(), ExprNode::Default) {
+            ctx.write_keyword(" REPEATABLE");
+            ctx.write_plain("(");
+            self.repeatable_seed.restore(ctx)?;
+            ctx.write_plain(")");
+        }
+        Ok(())
+    }
+}
+
+impl Restore for JsonTable {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("JSON_TABLE");
+        ctx.write_plain("(");
+        self.expr.restore(ctx)?;
+        ctx.write_plain(", ");
+        ctx.write_string(&self.path);
+        ctx.write_keyword(" COLUMNS ");
+        ctx.write_plain("(");
+        restore_list(ctx, &self.columns, ", ")?;
+        ctx.write_plain("))");
+        Ok(())
+    }
+}
+
+impl Restore for JsonTableColumn {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        if let JsonTableColumnKind::Nested = self.kind {
+            ctx.write_keyword("NESTED PATH ");
+            ctx.write_string(&self.path);
+            ctx.write_keyword(" COLUMNS ");
+            ctx.write_plain("(");
+            restore_list(ctx, &self.nested_columns, ", ")?;
+            ctx.write_plain(")");
+            return Ok(());
+        }
+
+        ctx.write_name(&self.name.origin);
+        if let JsonTableColumnKind::ForOrdinality = self.kind {
+            ctx.write_keyword(" FOR ORDINALITY");
+            return Ok(());
+        }
+        ctx.write_plain(" ");
+        required(&self.tp, "column type")?.restore(ctx)?;
+        ctx.write_keyword(match self.kind {
+            JsonTableColumnKind::ExistsPath => " EXISTS PATH ",
+            _ => " PATH ",
+        });
+        ctx.write_string(&self.path);
+        if let Some(on_empty) = &self.on_empty {
+            ctx.write_plain(" ");
+            on_empty.restore(ctx)?;
+            ctx.write_keyword(" ON EMPTY");
+        }
+        if let Some(on_error) = &self.on_error {
+            ctx.write_plain(" ");
+            on_error.restore(ctx)?;
+            ctx.write_keyword(" ON ERROR");
+        }
+        Ok(())
+    }
+}
+
+impl Restore for JsonTableOnResponse {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        match self {
+            JsonTableOnResponse::Null => ctx.write_keyword("NULL"),
+            JsonTableOnResponse::Error => ctx.write_keyword("ERROR"),
+            JsonTableOnResponse::Default(value) => {
+                ctx.write_keyword("DEFAULT ");
+                ctx.write_string(value);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Restore for SetOprStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        if self.is_in_braces {
+            ctx.write_plain("(");
+        }
+        if let Some(with) = &self.with {
+            with.restore(ctx)?;
+            ctx.write_plain(" ");
+        }
+        required(&self.select_list, "select list")?.restore(ctx)?;
+        if let Some(order_by) = &self.order_by {
+            ctx.write_plain(" ");
+            order_by.restore(ctx)?;
+        }
+        if let Some(limit) = &self.limit {
+            ctx.write_plain(" ");
+            limit.restore(ctx)?;
+        }
+        if self.is_in_braces {
+            ctx.write_plain(")");
+        }
+        Ok(())
+    }
+}
+
+impl Restore for SetOprSelectList {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        if let Some(with) = &self.with {
+            with.restore(ctx)?;
+            ctx.write_plain(" ");
+        }
+        for (i, node) in self.selects.iter().enumerate() {
+            let (after_set_operator, is_list) = match node {
+                Node::Statement(Statement::SelectStmt(stmt)) => (&stmt.after_set_operator, false),
+                Node::SetOprSelectList(list) => (&list.after_set_operator, true),
+                _ => return Err(unsupported("set operation operand")),
+            };
+            if i > 0 {
+                ctx.write_plain(" ");
+                required(after_set_operator, "set operator")?.restore(ctx)?;
+                ctx.write_plain(" ");
+            }
+            if is_list {
+                ctx.write_plain("(");
+            }
+            node.restore(ctx)?;
+            if is_list {
+                ctx.write_plain(")");
+            }
+        }
+        if let Some(order_by) = &self.order_by {
+            ctx.write_plain(" ");
+            order_by.restore(ctx)?;
+        }
+        if let Some(limit) = &self.limit {
+            ctx.write_plain(" ");
+            limit.restore(ctx)?;
+        }
+        Ok(())
+    }
+}
+
+impl Restore for SetOprType {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword(match self {
+            SetOprType::Union => "UNION",
+            SetOprType::UnionAll => "UNION ALL",
+            SetOprType::Except => "EXCEPT",
+            SetOprType::ExceptAll => "EXCEPT ALL",
+            SetOprType::Intersect => "INTERSECT",
+            SetOprType::IntersectAll => "INTERSECT ALL",
+        });
+        Ok(())
+    }
+}
+
+impl Restore for WithClause {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("WITH ");
+        if self.is_recursive {
+            ctx.write_keyword("RECURSIVE ");
+        }
+        restore_list(ctx, &self.ctes, ", ")
+    }
+}
+
+impl Restore for CommonTableExpression {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_name(&self.name.origin);
+        if !self.col_name_list.is_empty() {
+            ctx.write_plain(" (");
+            restore_list(ctx, &self.col_name_list, ", ")?;
+            ctx.write_plain(")");
+        }
+        ctx.write_keyword(" AS ");
+        required(&self.query, "query")?.restore(ctx)
+    }
+}
+
+impl Restore for GroupByClause {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("GROUP BY ");
+        restore_list(ctx, &self.items, ", ")?;
+        if self.rollup {
+            ctx.write_keyword(" WITH ROLLUP");
+        }
+        Ok(())
+    }
+}
+
+impl Restore for OrderByClause {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("ORDER BY ");
+        restore_list(ctx, &self.items, ", ")
+    }
+}
+
+impl Restore for ByItem {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        required(&self.expr, "expression")?.restore(ctx)?;
+        if self.desc {
+            ctx.write_keyword(" DESC");
+        } else if !self.null_order {
+            ctx.write_keyword(" ASC");
+        }
+        Ok(())
+    }
+}
+
+impl Restore for Limit {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("LIMIT ");
+        if !matches!(self.offset.as_ref(), ExprNode::Default) {
+            self.offset.restore(ctx)?;
+            ctx.write_plain(", ");
+        }
+        self.count.restore(ctx)
+    }
+}
+
+impl Restore for SelectLockInfo {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword(match self.lock_type {
+            SelectLockType::SelectLockNone => return Ok(()),
+            SelectLockType::SelectLockForUpdate
+            | SelectLockType::SelectLockForUpdateNoWait
+            | SelectLockType::SelectLockForUpdateWaitN
+            | SelectLockType::SelectLockForUpdateSkipLocked => "FOR UPDATE",
+            SelectLockType::SelectLockForShare
+            | SelectLockType::SelectLockForShareNoWait
+            | SelectLockType::SelectLockForShareSkipLocked => "FOR SHARE",
+        });
+        if !self.tables.is_empty() {
+            ctx.write_keyword(" OF ");
+            restore_list(ctx, &self.tables, ", ")?;
+        }
+        match self.lock_type {
+            SelectLockType::SelectLockForUpdateNoWait
+            | SelectLockType::SelectLockForShareNoWait => ctx.write_keyword(" NOWAIT"),
+            SelectLockType::SelectLockForUpdateWaitN => {
+                ctx.write_keyword(" WAIT ");
+                ctx.write_plain(&self.wait_sec.to_string());
+            }
+            SelectLockType::SelectLockForUpdateSkipLocked
+            | SelectLockType::SelectLockForShareSkipLocked => ctx.write_keyword(" SKIP LOCKED"),
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl Restore for SelectIntoOption {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        match self.tp {
+            SelectIntoType::SelectIntoOutfile => ctx.write_keyword("INTO OUTFILE "),
+            SelectIntoType::SelectIntoDumpfile => ctx.write_keyword("INTO DUMPFILE "),
+            SelectIntoType::SelectIntoVars => return Err(unsupported("SELECT INTO variables")),
+        }
+        ctx.write_string(&self.file_name);
+        if let Some(fields_info) = &self.fields_info {
+            ctx.write_plain(" ");
+            fields_info.restore(ctx)?;
+        }
+        if let Some(lines_info) = &self.lines_info {
+            ctx.write_plain(" ");
+            lines_info.restore(ctx)?;
+        }
+        Ok(())
+    }
+}
+
+impl Restore for FieldsClause {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("FIELDS");
+        if let Some(terminated) = &self.terminated {
+            ctx.write_keyword(" TERMINATED BY ");
+            ctx.write_string(terminated);
+        }
+        if let Some(enclosed) = &self.enclosed {
+            if self.opt_enclosed {
+                ctx.write_keyword(" OPTIONALLY");
+            }
+            ctx.write_keyword(" ENCLOSED BY ");
+            ctx.write_string(enclosed);
+        }
+        if let Some(escaped) = &self.escaped {
+            ctx.write_keyword(" ESCAPED BY ");
+            ctx.write_string(escaped);
+        }
+        if let Some(defined_null_by) = &self.defined_null_by {
+            ctx.write_keyword(" DEFINED NULL BY ");
+            ctx.write_string(defined_null_by);
+            if self.null_value_opt_enclosed {
+                ctx.write_keyword(" OPTIONALLY ENCLOSED");
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Restore for LinesClause {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("LINES");
+        if let Some(starting) = &self.starting {
+            ctx.write_keyword(" STARTING BY ");
+            ctx.write_string(starting);
+        }
+        if let Some(terminated) = &self.terminated {
+            ctx.write_keyword(" TERMINATED BY ");
+            ctx.write_string(terminated);
+        }
+        Ok(())
+    }
+}
+
+impl Restore for CallStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("CALL ");
+        required(&self.procedure, "procedure")?.restore(ctx)
+    }
+}
+
+impl Restore for DoStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("DO ");
+        restore_list(ctx, &self.exprs, ", ")
+    }
+}
+
+impl Restore for HandlerStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("HANDLER ");
+        self.table.restore(ctx)?;
+        match self.tp {
+            HandlerStmtType::Open => {
+                ctx.write_keyword(" OPEN");
+                if !self.as_name.origin.is_empty() {
+                    ctx.write_keyword(" AS ");
+                    ctx.write_name(&self.as_name.origin);
+                }
+                return Ok(());
+            }
+            HandlerStmtType::Close => {
+                ctx.write_keyword(" CLOSE");
+                return Ok(());
+            }
+            HandlerStmtType::Read => ctx.write_keyword(" READ"),
+        }
+        if !self.index_name.origin.is_empty() {
+            ctx.write_plain(" ");
+            ctx.write_name(&self.index_name.origin);
+        }
+        if let Some(op) = &self.op {
+            ctx.write_plain(" ");
+            op.restore(ctx)?;
+            ctx.write_plain(" (");
+            restore_list(ctx, &self.values, ", ")?;
+            ctx.write_plain(")");
+        } else {
+            ctx.write_keyword(match required(&self.read_direction, "read direction")? {
+                HandlerReadDirection::First => " FIRST",
+                HandlerReadDirection::Next => " NEXT",
+                HandlerReadDirection::Prev => " PREV",
+                HandlerReadDirection::Last => " LAST",
+            });
+        }
+        if let Some(where_clause) = &self.where_clause {
+            ctx.write_keyword(" WHERE ");
+            where_clause.restore(ctx)?;
+        }
+        if let Some(limit) = &self.limit {
+            ctx.write_plain(" ");
+            limit.restore(ctx)?;
+        }
+        Ok(())
+    }
+}
+
+impl Restore for CreateSequenceStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("CREATE ");
+        if self.or_replace {
+            ctx.write_keyword("OR REPLACE ");
+        }
+        if self.temporary {
+            ctx.write_keyword("TEMPORARY ");
+        }
+        ctx.write_keyword("SEQUENCE ");
+        if self.if_not_exists {
+            ctx.write_keyword("IF NOT EXISTS ");
+        }
+        self.name.restore(ctx)?;
+        for option in &self.seq_options {
+            ctx.write_plain(" ");
+            option.restore(ctx)?;
+        }
+        Ok(())
+    }
+}
+
+impl Restore for AlterSequenceStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("ALTER SEQUENCE ");
+        if self.if_exists {
+            ctx.write_keyword("IF EXISTS ");
+        }
+        self.name.restore(ctx)?;
+        for option in &self.seq_options {
+            ctx.write_plain(" ");
+            option.restore(ctx)?;
+        }
+        Ok(())
+    }
+}
+
+impl Restore for DropSequenceStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("DROP ");
+        if self.temporary {
+            ctx.write_keyword("TEMPORARY ");
+        }
+        ctx.write_keyword("SEQUENCE ");
+        if self.if_exists {
+            ctx.write_keyword("IF EXISTS ");
+        }
+        restore_list(ctx, &self.sequences, ", ")
+    }
+}
+
+impl Restore for SequenceOption {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        let (keyword, has_value) = match self.tp {
+            SequenceOptionType::IncrementBy => ("INCREMENT BY ", true),
+            SequenceOptionType::StartWith => ("START WITH ", true),
+            SequenceOptionType::NoMinValue => ("NO MINVALUE", false),
+            SequenceOptionType::MinValue => ("MINVALUE ", true),
+            SequenceOptionType::NoMaxValue => ("NO MAXVALUE", false),
+            SequenceOptionType::MaxValue => ("MAXVALUE ", true),
+            SequenceOptionType::NoCache => ("NOCACHE", false),
+            SequenceOptionType::Cache => ("CACHE ", true),
+            SequenceOptionType::NoCycle => ("NOCYCLE", false),
+            SequenceOptionType::Cycle => ("CYCLE", false),
+            SequenceOptionType::Restart => ("RESTART", false),
+            SequenceOptionType::RestartWith => ("RESTART WITH ", true),
+        };
+        ctx.write_keyword(keyword);
+        if has_value {
+            ctx.write_plain(&self.int_value.to_string());
+        }
+        Ok(())
+    }
+}
+
+impl Restore for AdminStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("ADMIN ");
+        match self.tp {
+            AdminStmtType::ShowDdl => ctx.write_keyword("SHOW DDL"),
+            AdminStmtType::ShowDdlJobs => {
+                ctx.write_keyword("SHOW DDL JOBS");
+                if self.job_number != 0 {
+                    ctx.write_plain(&format!(" {}", self.job_number));
+                }
+                if let Some(where_clause) = &self.where_clause {
+                    ctx.write_keyword(" WHERE ");
+                    where_clause.restore(ctx)?;
+                }
+            }
+            AdminStmtType::CheckTable => {
+                ctx.write_keyword("CHECK TABLE ");
+                restore_list(ctx, &self.tables, ", ")?;
+            }
+            AdminStmtType::CancelDdlJobs => {
+                ctx.write_keyword("CANCEL DDL JOBS ");
+                let job_ids: Vec<String> = self.job_ids.iter().map(|id| id.to_string()).collect();
+                ctx.write_plain(&job_ids.join(", "));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn restore_binding_scope(global_scope: bool, ctx: &mut RestoreCtx) {
+    ctx.write_keyword(if global_scope { "GLOBAL " } else { "SESSION " });
+}
+
+impl Restore for CreateBindingStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("CREATE ");
+        restore_binding_scope(self.global_scope, ctx);
+        ctx.write_keyword("BINDING ");
+        if let Some(origin_node) = &self.origin_node {
+            ctx.write_keyword("FOR ");
+            origin_node.restore(ctx)?;
+            ctx.write_plain(" ");
+        }
+        ctx.write_keyword("USING ");
+        self.hinted_node.restore(ctx)
+    }
+}
+
+impl Restore for DropBindingStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("DROP ");
+        restore_binding_scope(self.global_scope, ctx);
+        ctx.write_keyword("BINDING FOR ");
+        self.origin_node.restore(ctx)?;
+        if let Some(hinted_node) = &self.hinted_node {
+            ctx.write_keyword(" USING ");
+            hinted_node.restore(ctx)?;
+        }
+        Ok(())
+    }
+}
+
+impl Restore for ShowStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("SHOW ");
+        match self.tp {
+            ShowStmtType::Bindings => {
+                restore_binding_scope(self.global_scope, ctx);
+                ctx.write_keyword("BINDINGS");
+                if let Some(pattern) = &self.pattern {
+                    ctx.write_plain(" ");
+                    pattern.restore(ctx)?;
+                }
+            }
+            ShowStmtType::Regions => {
+                ctx.write_keyword("TABLE ");
+                let table = required(&self.table, "table")?;
+                table.restore(ctx)?;
+                if !table.partition_names.is_empty() {
+                    ctx.write_keyword(" PARTITION");
+                    ctx.write_plain("(");
+                    restore_list(ctx, &table.partition_names, ", ")?;
+                    ctx.write_plain(")");
+                }
+                if !self.index_name.origin.is_empty() {
+                    ctx.write_keyword(" INDEX ");
+                    ctx.write_name(&self.index_name.origin);
+                }
+                ctx.write_keyword(" REGIONS");
+            }
+            ShowStmtType::Grants => {
+                ctx.write_keyword("GRANTS");
+                if let Some(user) = &self.user {
+                    ctx.write_keyword(" FOR ");
+                    user.restore(ctx)?;
+                }
+            }
+        }
+        if let Some(where_clause) = &self.where_clause {
+            ctx.write_keyword(" WHERE ");
+            where_clause.restore(ctx)?;
+        }
+        Ok(())
+    }
+}
+
+impl Restore for UserIdentity {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        if self.current_user {
+            ctx.write_keyword("CURRENT_USER");
+            return Ok(());
+        }
+        ctx.write_name(&self.username);
+        if !self.hostname.is_empty() {
+            ctx.write_plain("@");
+            ctx.write_name(&self.hostname);
+        }
+        Ok(())
+    }
+}
+
+impl Restore for SplitRegionStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("SPLIT ");
+        if self.split_syntax_opt.has_region_for {
+            ctx.write_keyword("REGION FOR ");
+        }
+        if self.split_syntax_opt.has_partition {
+            ctx.write_keyword("PARTITION ");
+        }
+        ctx.write_keyword("TABLE ");
+        self.table.restore(ctx)?;
+        if !self.partition_names.is_empty() {
+            ctx.write_keyword(" PARTITION");
+            ctx.write_plain("(");
+            restore_list(ctx, &self.partition_names, ", ")?;
+            ctx.write_plain(")");
+        }
+        if !self.index_name.origin.is_empty() {
+            ctx.write_keyword(" INDEX ");
+            ctx.write_name(&self.index_name.origin);
+        }
+        ctx.write_plain(" ");
+        self.split_opt.restore(ctx)
+    }
+}
+
+impl Restore for SplitOption {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        if self.value_lists.is_empty() {
+            ctx.write_keyword("BETWEEN ");
+            ctx.write_plain("(");
+            restore_list(ctx, &self.lower, ", ")?;
+            ctx.write_plain(")");
+            ctx.write_keyword(" AND ");
+            ctx.write_plain("(");
+            restore_list(ctx, &self.upper, ", ")?;
+            ctx.write_plain(")");
+            ctx.write_keyword(" REGIONS ");
+            ctx.write_plain(&self.num.to_string());
+            return Ok(());
+        }
+        ctx.write_keyword("BY ");
+        for (i, values) in self.value_lists.iter().enumerate() {
+            if i > 0 {
+                ctx.write_plain(", ");
+            }
+            ctx.write_plain("(");
+            restore_list(ctx, values, ", ")?;
+            ctx.write_plain(")");
+        }
+        Ok(())
+    }
+}
+
+impl Restore for FlashBackTableStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("FLASHBACK TABLE ");
+        self.table.restore(ctx)?;
+        if !self.new_name.origin.is_empty() {
+            ctx.write_keyword(" TO ");
+            ctx.write_name(&self.new_name.origin);
+        }
+        Ok(())
+    }
+}
+
+impl Restore for FlashBackDatabaseStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("FLASHBACK DATABASE ");
+        ctx.write_name(&self.db_name.origin);
+        if !self.new_name.origin.is_empty() {
+            ctx.write_keyword(" TO ");
+            ctx.write_name(&self.new_name.origin);
+        }
+        Ok(())
+    }
+}
+
+impl Restore for FlashBackToTimestampStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("FLASHBACK ");
+        if !self.tables.is_empty() {
+            ctx.write_keyword("TABLE ");
+            restore_list(ctx, &self.tables, ", ")?;
+        } else if !self.db_name.origin.is_empty() {
+            ctx.write_keyword("DATABASE ");
+            ctx.write_name(&self.db_name.origin);
+        } else {
+            ctx.write_keyword("CLUSTER");
+        }
+        match &self.flashback_ts {
+            Some(flashback_ts) => {
+                ctx.write_keyword(" TO TIMESTAMP ");
+                flashback_ts.restore(ctx)
+            }
+            None => {
+                ctx.write_keyword(" TO TSO ");
+                ctx.write_plain(&self.flashback_tso.to_string());
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Restore for PlacementOption {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        let key = match self.tp {
+            PlacementOptionType::PrimaryRegion => "PRIMARY_REGION",
+            PlacementOptionType::Regions => "REGIONS",
+            PlacementOptionType::Followers => "FOLLOWERS",
+            PlacementOptionType::Voters => "VOTERS",
+            PlacementOptionType::Learners => "LEARNERS",
+            PlacementOptionType::Schedule => "SCHEDULE",
+            PlacementOptionType::Constraints => "CONSTRAINTS",
+            PlacementOptionType::LeaderConstraints => "LEADER_CONSTRAINTS",
+            PlacementOptionType::LearnerConstraints => "LEARNER_CONSTRAINTS",
+            PlacementOptionType::FollowerConstraints => "FOLLOWER_CONSTRAINTS",
+            PlacementOptionType::VoterConstraints => "VOTER_CONSTRAINTS",
+            PlacementOptionType::SurvivalPreferences => "SURVIVAL_PREFERENCES",
+        };
+        ctx.write_keyword(key);
+        ctx.write_plain(" = ");
+        match self.tp {
+            PlacementOptionType::Followers
+            | PlacementOptionType::Voters
+            | PlacementOptionType::Learners => ctx.write_plain(&self.uint_value.to_string()),
+            _ => ctx.write_string(&self.str_value),
+        }
+        Ok(())
+    }
+}
+
+impl Restore for CreatePlacementPolicyStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("CREATE ");
+        if self.or_replace {
+            ctx.write_keyword("OR REPLACE ");
+        }
+        ctx.write_keyword("PLACEMENT POLICY ");
+        if self.if_not_exists {
+            ctx.write_keyword("IF NOT EXISTS ");
+        }
+        ctx.write_name(&self.policy_name.origin);
+        for option in &self.placement_options {
+            ctx.write_plain(" ");
+            option.restore(ctx)?;
+        }
+        Ok(())
+    }
+}
+
+impl Restore for AlterPlacementPolicyStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("ALTER PLACEMENT POLICY ");
+        if self.if_exists {
+            ctx.write_keyword("IF EXISTS ");
+        }
+        ctx.write_name(&self.policy_name.origin);
+        for option in &self.placement_options {
+            ctx.write_plain(" ");
+            option.restore(ctx)?;
+        }
+        Ok(())
+    }
+}
+
+impl Restore for DropPlacementPolicyStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("DROP PLACEMENT POLICY ");
+        if self.if_exists {
+            ctx.write_keyword("IF EXISTS ");
+        }
+        ctx.write_name(&self.policy_name.origin);
+        Ok(())
+    }
+}
+
+impl Restore for SetConfigStmt {
+    fn restore(&self, ctx: &mut RestoreCtx) -> Result<()> {
+        ctx.write_keyword("SET CONFIG ");
+        if self.instance.is_empty() {
+            ctx.write_keyword(&self.tp);
+        } else {
+            ctx.write_string(&self.instance);
+        }
+        ctx.write_plain(" ");
+        ctx.write_plain(&self.name);
+        ctx.write_plain(" = ");
+        self.value.restore(ctx)
+    }
+}
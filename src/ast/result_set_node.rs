@@ -1,15 +1,25 @@
+use crate::ast::json_table::JsonTable;
 use crate::ast::select_stmt::SelectStmt;
 use crate::ast::set_opr_stmt::SetOprStmt;
 use crate::ast::table_name::TableName;
 use crate::ast::table_refs_clause::TableRefsClause;
 use crate::ast::table_source::TableSource;
-use derive_visitor::Drive;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Drive)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(tag = "type", content = "data")]
 pub enum ResultSetNode {
     TableRefsClause(Box<TableRefsClause>),
     SelectStmt(Box<SelectStmt>),
     TableName(Box<TableName>),
     TableSource(Box<TableSource>),
     SetOprStmt(Box<SetOprStmt>),
+    JsonTable(Box<JsonTable>),
+    // Lateral is a derived table which may refer to the preceding tables of
+    // the same FROM clause, e.g. `LATERAL (SELECT ...) AS dt`.
+    Lateral(Box<ResultSetNode>),
 }
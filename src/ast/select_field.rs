@@ -1,21 +1,33 @@
 use crate::ast::ci_str::CIStr;
 use crate::ast::common::ASTType;
 use crate::ast::expr_node::ExprNode;
-use derive_visitor::Drive;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 // WildCardField is a special type of select field content.
-#[derive(Debug, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct WildCardField {
+    #[drive(skip)]
+    pub span: Span,
+    #[drive(skip)]
     pub table: CIStr,
+    #[drive(skip)]
     pub schema: CIStr,
 }
 
-#[derive(Debug, Drive, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(tag = "type", content = "data")]
+#[allow(clippy::large_enum_variant)]
 pub enum Field {
     #[default]
     Unkonw,
     // WildCard is not nil, Expr will be nil.
-    #[drive(skip)]
     WildCardField(WildCardField),
     // Expr is not nil, WildCard will be nil.
     Expr(ExprNode),
@@ -24,8 +36,12 @@ pub enum Field {
 // SelectField represents fields in select statement.
 // There are two type of select field: wildcard
 // and expression with optional alias name.
-#[derive(Debug, Drive, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct SelectField {
+    #[drive(skip)]
+    pub span: Span,
     // Offset is used to get original text.
     #[drive(skip)]
     pub offset: isize,
@@ -44,20 +60,32 @@ pub struct SelectField {
     pub auxiliary_col_in_order_by: bool,
 }
 
-#[derive(Debug)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct FieldItem {
     pub tp: ASTType,
+    #[drive(skip)]
     pub value: String,
+    #[drive(skip)]
     pub opt_enclosed: bool,
 }
 
 // FieldsClause represents fields references clause in load data statement.
-#[derive(Debug)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct FieldsClause {
+    #[drive(skip)]
     pub terminated: Option<String>,
+    #[drive(skip)]
     pub enclosed: Option<String>, // length always <= 1 if not nil, see parser.y
-    pub escaped: Option<String>,  // length always <= 1 if not nil, see parser.y
+    #[drive(skip)]
+    pub escaped: Option<String>, // length always <= 1 if not nil, see parser.y
+    #[drive(skip)]
     pub opt_enclosed: bool,
+    #[drive(skip)]
     pub defined_null_by: Option<String>,
+    #[drive(skip)]
     pub null_value_opt_enclosed: bool,
 }
@@ -1,16 +1,27 @@
 use crate::ast::line_clause::LinesClause;
 use crate::ast::select_field::FieldsClause;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub enum SelectIntoType {
     SelectIntoOutfile,
     SelectIntoDumpfile,
     SelectIntoVars,
 }
 
-#[derive(Debug)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct SelectIntoOption {
+    #[drive(skip)]
+    pub span: Span,
     pub tp: SelectIntoType,
+    #[drive(skip)]
     pub file_name: String,
     pub fields_info: Option<FieldsClause>,
     pub lines_info: Option<LinesClause>,
@@ -1,9 +1,12 @@
 use crate::ast::table_name::TableName;
-use derive_visitor::Drive;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 // SelectLockType is the lock type for SelectStmt.
 // Select lock types.
-#[derive(Debug)]
+#[derive(Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum SelectLockType {
     SelectLockNone,
     SelectLockForUpdate,
@@ -15,9 +18,10 @@ pub enum SelectLockType {
     SelectLockForShareSkipLocked,
 }
 
-#[derive(Debug, Drive)]
+#[derive(Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct SelectLockInfo {
     #[drive(skip)]
+    pub span: Span,
     pub lock_type: SelectLockType,
     #[drive(skip)]
     pub wait_sec: u64,
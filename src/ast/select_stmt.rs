@@ -10,11 +10,16 @@ use crate::ast::table_optimizer_hint::TableOptimizerHint;
 use crate::ast::table_refs_clause::TableRefsClause;
 use crate::ast::window_spec::WindowSpec;
 use crate::ast::with_clause::WithClause;
+use crate::common::span::Span;
 use crate::mysql;
-use derive_visitor::Drive;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
-#[derive(Debug, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub enum SelectStmtKind {
     #[default]
     SelectStmtKindSelect,
@@ -23,25 +28,39 @@ pub enum SelectStmtKind {
 }
 
 // SelectStmtOpts wrap around select hints and switches
-#[derive(Debug, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct SelectStmtOpts {
+    #[drive(skip)]
     pub distinct: bool,
+    #[drive(skip)]
     pub sql_big_result: bool,
+    #[drive(skip)]
     pub sql_buffer_result: bool,
+    #[drive(skip)]
     pub sql_cache: bool,
+    #[drive(skip)]
     pub sql_small_result: bool,
+    #[drive(skip)]
     pub calc_found_rows: bool,
+    #[drive(skip)]
     pub straight_join: bool,
+    #[drive(skip)]
     pub priority: mysql::consts::PriorityEnum,
     pub table_hints: Vec<TableOptimizerHint>,
+    #[drive(skip)]
     pub explicit_all: bool,
 }
 
 // SelectStmt represents the select query node.
 // See https://dev.mysql.com/doc/refman/5.7/en/select.html
-#[derive(Debug, Drive, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct SelectStmt {
     #[drive(skip)]
+    pub span: Span,
     pub select_stmt_opts: SelectStmtOpts,
     // From is the from clause of the query.
     pub from: Option<TableRefsClause>,
@@ -56,8 +75,10 @@ pub struct SelectStmt {
     // WindowSpecs is the window specification list.
     pub window_specs: Vec<WindowSpec>,
     // OrderBy is the ordering expression list.
+    #[drive(with = "crate::ast::visitor::drive_rc")]
     pub order_by: Option<Rc<OrderByClause>>,
     // Limit is the limit clause.
+    #[drive(with = "crate::ast::visitor::drive_rc")]
     pub limit: Option<Rc<Limit>>,
     // LockInfo is the lock type
     pub lock_info: Option<SelectLockInfo>,
@@ -72,16 +93,14 @@ pub struct SelectStmt {
     #[drive(skip)]
     pub query_block_offset: isize,
     // SelectIntoOpt is the select-into option.
-    #[drive(skip)]
     pub select_into_opt: Option<SelectIntoOption>,
     // AfterSetOperator indicates the SelectStmt after which type of set operator
-    #[drive(skip)]
     pub after_set_operator: Option<SetOprType>,
     // Kind refer to three kind of statement: SelectStmt, TableStmt and ValuesStmt
-    #[drive(skip)]
     pub kind: SelectStmtKind,
     // Lists is filled only when Kind == SelectStmtKindValues
     pub lists: Vec<RowExpr>,
+    #[drive(with = "crate::ast::visitor::drive_rc")]
     pub with: Option<Rc<WithClause>>,
     // AsViewSchema indicates if this stmt provides the schema for the view. It is only used when creating the view
     #[drive(skip)]
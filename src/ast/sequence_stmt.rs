@@ -0,0 +1,88 @@
+use crate::ast::table_name::TableName;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// SequenceOptionType is the type of a sequence option.
+#[derive(
+    Debug, Drive, DriveMut, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub enum SequenceOptionType {
+    IncrementBy,
+    StartWith,
+    NoMinValue,
+    MinValue,
+    NoMaxValue,
+    MaxValue,
+    NoCache,
+    Cache,
+    NoCycle,
+    Cycle,
+    // Restart is `RESTART` of ALTER SEQUENCE, the sequence restarts from its
+    // start value.
+    Restart,
+    RestartWith,
+}
+
+// SequenceOption is used for parsing sequence option from SQL.
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct SequenceOption {
+    #[drive(skip)]
+    pub span: Span,
+    pub tp: SequenceOptionType,
+    // IntValue is the value of the options that take one, e.g. the `2` of
+    // `INCREMENT BY 2`, it's 0 for the others.
+    #[drive(skip)]
+    pub int_value: i64,
+}
+
+// CreateSequenceStmt is a statement to create a Sequence.
+// See https://mariadb.com/kb/en/create-sequence/
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct CreateSequenceStmt {
+    #[drive(skip)]
+    pub span: Span,
+    // OrReplace is `CREATE OR REPLACE SEQUENCE`, it's a MariaDB extension.
+    #[drive(skip)]
+    pub or_replace: bool,
+    #[drive(skip)]
+    pub temporary: bool,
+    #[drive(skip)]
+    pub if_not_exists: bool,
+    pub name: TableName,
+    pub seq_options: Vec<SequenceOption>,
+}
+
+// AlterSequenceStmt is a statement to alter sequence option.
+// See https://mariadb.com/kb/en/alter-sequence/
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct AlterSequenceStmt {
+    #[drive(skip)]
+    pub span: Span,
+    #[drive(skip)]
+    pub if_exists: bool,
+    pub name: TableName,
+    pub seq_options: Vec<SequenceOption>,
+}
+
+// DropSequenceStmt is a statement to drop a Sequence.
+// See https://mariadb.com/kb/en/drop-sequence/
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct DropSequenceStmt {
+    #[drive(skip)]
+    pub span: Span,
+    #[drive(skip)]
+    pub temporary: bool,
+    #[drive(skip)]
+    pub if_exists: bool,
+    pub sequences: Vec<TableName>,
+}
@@ -0,0 +1,26 @@
+use crate::ast::expr_node::ExprNode;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// SetConfigStmt is the statement to set cluster configs.
+// See https://docs.pingcap.com/tidb/stable/sql-statement-set-config
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct SetConfigStmt {
+    #[drive(skip)]
+    pub span: Span,
+    // Type is the lower case component type, e.g. `tikv`, `pd` or `tidb`,
+    // it's empty if Instance is set.
+    #[drive(skip)]
+    pub tp: String,
+    // Instance is the address of a single instance, e.g. `127.0.0.1:20180`.
+    #[drive(skip)]
+    pub instance: String,
+    // Name is the config item, e.g. `log.level`.
+    #[drive(skip)]
+    pub name: String,
+    pub value: Box<ExprNode>,
+}
@@ -2,34 +2,53 @@ use crate::ast::limit::Limit;
 use crate::ast::node::Node;
 use crate::ast::order_by_clause::OrderByClause;
 use crate::ast::with_clause::WithClause;
-use derive_visitor::Drive;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 
 // SetOprStmt represents "union/except/intersect statement"
 // See https://dev.mysql.com/doc/refman/5.7/en/union.html
 // See https://mariadb.com/kb/en/intersect/
 // See https://mariadb.com/kb/en/except/
-#[derive(Debug, Drive, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct SetOprStmt {
+    #[drive(skip)]
+    pub span: Span,
     #[drive(skip)]
     pub is_in_braces: bool,
     pub select_list: Option<SetOprSelectList>,
+    #[drive(with = "crate::ast::visitor::drive_rc")]
     pub order_by: Option<Rc<OrderByClause>>,
+    #[drive(with = "crate::ast::visitor::drive_rc")]
     pub limit: Option<Rc<Limit>>,
+    #[drive(with = "crate::ast::visitor::drive_rc")]
     pub with: Option<Rc<WithClause>>,
 }
 
 // SetOprSelectList represents the SelectStmt/TableStmt/ValuesStmt list in a union statement.
-#[derive(Debug, Drive, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct SetOprSelectList {
+    #[drive(skip)]
+    pub span: Span,
+    #[drive(with = "crate::ast::visitor::drive_rc")]
     pub with: Option<Rc<WithClause>>,
     pub after_set_operator: Option<SetOprType>,
     pub selects: Vec<Node>,
+    #[drive(with = "crate::ast::visitor::drive_rc")]
     pub limit: Option<Rc<Limit>>,
+    #[drive(with = "crate::ast::visitor::drive_rc")]
     pub order_by: Option<Rc<OrderByClause>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub enum SetOprType {
     Union,
     UnionAll,
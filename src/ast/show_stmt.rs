@@ -0,0 +1,54 @@
+use crate::ast::ci_str::CIStr;
+use crate::ast::expr_node::{ExprNode, PatternLikeOrIlikeExpr};
+use crate::ast::table_name::TableName;
+use crate::ast::user_identity::UserIdentity;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// ShowStmtType is the type for SHOW statement.
+#[derive(
+    Debug,
+    Drive,
+    DriveMut,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+)]
+pub enum ShowStmtType {
+    // Bindings is `SHOW [GLOBAL | SESSION] BINDINGS` of TiDB.
+    #[default]
+    Bindings,
+    // Regions is `SHOW TABLE t [INDEX idx] REGIONS` of TiDB.
+    Regions,
+    Grants,
+}
+
+// ShowStmt is a statement to provide information about databases, tables,
+// columns and so on.
+// See https://dev.mysql.com/doc/refman/8.0/en/show.html
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct ShowStmt {
+    #[drive(skip)]
+    pub span: Span,
+    pub tp: ShowStmtType,
+    #[drive(skip)]
+    pub global_scope: bool,
+    pub table: Option<TableName>,
+    #[drive(skip)]
+    pub index_name: CIStr,
+    // User is the account of `SHOW GRANTS FOR user`.
+    pub user: Option<UserIdentity>,
+    // Pattern is the `LIKE 'pattern'` filter, its Expr is None.
+    pub pattern: Option<PatternLikeOrIlikeExpr>,
+    pub where_clause: Option<ExprNode>,
+}
@@ -0,0 +1,363 @@
+use crate::ast::admin_stmt::AdminStmt;
+use crate::ast::binding_stmt::{CreateBindingStmt, DropBindingStmt};
+use crate::ast::call_stmt::CallStmt;
+use crate::ast::column_name::ColumnName;
+use crate::ast::common_table_expression::CommonTableExpression;
+use crate::ast::do_stmt::DoStmt;
+use crate::ast::expr_node::{
+    AggregateFuncExpr, BetweenExpr, BinaryOperationExpr, CaseExpr, ColumnNameExpr,
+    CompareSubqueryExpr, ExistsSubqueryExpr, ExprNode, FuncCallExpr, FuncCastExpr,
+    GetFormatSelectorExpr, IsNullExpr, IsTruthExpr, MatchAgainst, MemberOfExpr, ParamMarkerExpr,
+    ParenthesesExpr, PatternInExpr, PatternLikeOrIlikeExpr, PatternRegexpExpr, PositionExpr,
+    RowExpr, SetCollationExpr, SoundsLikeExpr, TableNameExpr, TimeUnitExpr, TrimDirectionExpr,
+    UnaryOperationExpr, ValueExpr, VariableExpr, WhenClause, WindowFuncExpr,
+};
+use crate::ast::flashback_stmt::{
+    FlashBackDatabaseStmt, FlashBackTableStmt, FlashBackToTimestampStmt,
+};
+use crate::ast::frame_clause::{FrameBound, FrameClause, FrameExtent};
+use crate::ast::group_by_clause::{ByItem, GroupByClause};
+use crate::ast::handler_stmt::HandlerStmt;
+use crate::ast::index_hint::IndexHint;
+use crate::ast::json_table::{JsonTable, JsonTableColumn};
+use crate::ast::limit::Limit;
+use crate::ast::node::Node;
+use crate::ast::order_by_clause::OrderByClause;
+use crate::ast::partition_by_clause::PartitionByClause;
+use crate::ast::placement_policy_stmt::{
+    AlterPlacementPolicyStmt, CreatePlacementPolicyStmt, DropPlacementPolicyStmt, PlacementOption,
+};
+use crate::ast::result_set_node::ResultSetNode;
+use crate::ast::select_field::{Field, SelectField, WildCardField};
+use crate::ast::select_into_option::SelectIntoOption;
+use crate::ast::select_lock_info::SelectLockInfo;
+use crate::ast::select_stmt::SelectStmt;
+use crate::ast::sequence_stmt::{
+    AlterSequenceStmt, CreateSequenceStmt, DropSequenceStmt, SequenceOption,
+};
+use crate::ast::set_config_stmt::SetConfigStmt;
+use crate::ast::set_opr_stmt::{SetOprSelectList, SetOprStmt};
+use crate::ast::show_stmt::ShowStmt;
+use crate::ast::split_region_stmt::{SplitOption, SplitRegionStmt};
+use crate::ast::statement::Statement;
+use crate::ast::subquery_expr::SubQueryExpr;
+use crate::ast::system_time::HistoryPoint;
+use crate::ast::table_name::TableName;
+use crate::ast::table_optimizer_hint::TableOptimizerHint;
+use crate::ast::table_refs_clause::TableRefsClause;
+use crate::ast::table_sample::TableSample;
+use crate::ast::table_source::TableSource;
+use crate::ast::user_identity::UserIdentity;
+use crate::ast::window_spec::WindowSpec;
+use crate::ast::with_clause::WithClause;
+use crate::common::span::Span;
+
+// Spanned is implemented by every AST node, the span is the range of the
+// source text which the node is parsed from, it's None for the nodes that are
+// built by hand.
+pub trait Spanned {
+    fn span(&self) -> Span;
+
+    fn set_span(&mut self, span: Span);
+}
+
+impl<T: Spanned> Spanned for Box<T> {
+    fn span(&self) -> Span {
+        self.as_ref().span()
+    }
+
+    fn set_span(&mut self, span: Span) {
+        self.as_mut().set_span(span)
+    }
+}
+
+macro_rules! impl_spanned {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Spanned for $ty {
+                fn span(&self) -> Span {
+                    self.span
+                }
+
+                fn set_span(&mut self, span: Span) {
+                    self.span = span;
+                }
+            }
+        )*
+    };
+}
+
+impl_spanned!(
+    AdminStmt,
+    CreateBindingStmt,
+    DropBindingStmt,
+    CallStmt,
+    ColumnName,
+    CommonTableExpression,
+    DoStmt,
+    VariableExpr,
+    BinaryOperationExpr,
+    ExistsSubqueryExpr,
+    UnaryOperationExpr,
+    ColumnNameExpr,
+    MatchAgainst,
+    FuncCallExpr,
+    TimeUnitExpr,
+    ValueExpr,
+    RowExpr,
+    TrimDirectionExpr,
+    GetFormatSelectorExpr,
+    TableNameExpr,
+    SetCollationExpr,
+    WindowFuncExpr,
+    PositionExpr,
+    ParamMarkerExpr,
+    AggregateFuncExpr,
+    CaseExpr,
+    WhenClause,
+    FuncCastExpr,
+    PatternInExpr,
+    BetweenExpr,
+    PatternLikeOrIlikeExpr,
+    PatternRegexpExpr,
+    SoundsLikeExpr,
+    MemberOfExpr,
+    IsNullExpr,
+    IsTruthExpr,
+    CompareSubqueryExpr,
+    ParenthesesExpr,
+    FlashBackTableStmt,
+    FlashBackDatabaseStmt,
+    FlashBackToTimestampStmt,
+    FrameClause,
+    FrameBound,
+    FrameExtent,
+    ByItem,
+    GroupByClause,
+    HandlerStmt,
+    IndexHint,
+    JsonTable,
+    JsonTableColumn,
+    Limit,
+    OrderByClause,
+    PartitionByClause,
+    PlacementOption,
+    CreatePlacementPolicyStmt,
+    AlterPlacementPolicyStmt,
+    DropPlacementPolicyStmt,
+    WildCardField,
+    SelectField,
+    SelectIntoOption,
+    SelectLockInfo,
+    SelectStmt,
+    SequenceOption,
+    CreateSequenceStmt,
+    AlterSequenceStmt,
+    DropSequenceStmt,
+    SetConfigStmt,
+    SetOprStmt,
+    SetOprSelectList,
+    ShowStmt,
+    SplitOption,
+    SplitRegionStmt,
+    SubQueryExpr,
+    HistoryPoint,
+    TableName,
+    TableOptimizerHint,
+    TableRefsClause,
+    TableSample,
+    TableSource,
+    UserIdentity,
+    WindowSpec,
+    WithClause
+);
+
+impl Spanned for ExprNode {
+    fn span(&self) -> Span {
+        match self {
+            ExprNode::ColumnNameExpr(node) => node.span(),
+            ExprNode::SubQueryExpr(node) => node.span(),
+            ExprNode::RowExpr(node) => node.span(),
+            ExprNode::VariableExpr(node) => node.span(),
+            ExprNode::BinaryOperationExpr(node) => node.span(),
+            ExprNode::ExistsSubqueryExpr(node) => node.span(),
+            ExprNode::UnaryOperationExpr(node) => node.span(),
+            ExprNode::MatchAgainst(node) => node.span(),
+            ExprNode::FuncCallExpr(node) => node.span(),
+            ExprNode::TimeUnitExpr(node) => node.span(),
+            ExprNode::ValueExpr(node) => node.span(),
+            ExprNode::TrimDirectionExpr(node) => node.span(),
+            ExprNode::GetFormatSelectorExpr(node) => node.span(),
+            ExprNode::TableNameExpr(node) => node.span(),
+            ExprNode::SetCollationExpr(node) => node.span(),
+            ExprNode::WindowFuncExpr(node) => node.span(),
+            ExprNode::PositionExpr(node) => node.span(),
+            ExprNode::ParamMarkerExpr(node) => node.span(),
+            ExprNode::AggregateFuncExpr(node) => node.span(),
+            ExprNode::CaseExpr(node) => node.span(),
+            ExprNode::FuncCastExpr(node) => node.span(),
+            ExprNode::PatternInExpr(node) => node.span(),
+            ExprNode::BetweenExpr(node) => node.span(),
+            ExprNode::PatternLikeOrIlikeExpr(node) => node.span(),
+            ExprNode::PatternRegexpExpr(node) => node.span(),
+            ExprNode::SoundsLikeExpr(node) => node.span(),
+            ExprNode::MemberOfExpr(node) => node.span(),
+            ExprNode::IsNullExpr(node) => node.span(),
+            ExprNode::IsTruthExpr(node) => node.span(),
+            ExprNode::CompareSubqueryExpr(node) => node.span(),
+            ExprNode::ParenthesesExpr(node) => node.span(),
+            ExprNode::Default => None,
+        }
+    }
+
+    fn set_span(&mut self, span: Span) {
+        match self {
+            ExprNode::ColumnNameExpr(node) => node.set_span(span),
+            ExprNode::SubQueryExpr(node) => node.set_span(span),
+            ExprNode::RowExpr(node) => node.set_span(span),
+            ExprNode::VariableExpr(node) => node.set_span(span),
+            ExprNode::BinaryOperationExpr(node) => node.set_span(span),
+            ExprNode::ExistsSubqueryExpr(node) => node.set_span(span),
+            ExprNode::UnaryOperationExpr(node) => node.set_span(span),
+            ExprNode::MatchAgainst(node) => node.set_span(span),
+            ExprNode::FuncCallExpr(node) => node.set_span(span),
+            ExprNode::TimeUnitExpr(node) => node.set_span(span),
+            ExprNode::ValueExpr(node) => node.set_span(span),
+            ExprNode::TrimDirectionExpr(node) => node.set_span(span),
+            ExprNode::GetFormatSelectorExpr(node) => node.set_span(span),
+            ExprNode::TableNameExpr(node) => node.set_span(span),
+            ExprNode::SetCollationExpr(node) => node.set_span(span),
+            ExprNode::WindowFuncExpr(node) => node.set_span(span),
+            ExprNode::PositionExpr(node) => node.set_span(span),
+            ExprNode::ParamMarkerExpr(node) => node.set_span(span),
+            ExprNode::AggregateFuncExpr(node) => node.set_span(span),
+            ExprNode::CaseExpr(node) => node.set_span(span),
+            ExprNode::FuncCastExpr(node) => node.set_span(span),
+            ExprNode::PatternInExpr(node) => node.set_span(span),
+            ExprNode::BetweenExpr(node) => node.set_span(span),
+            ExprNode::PatternLikeOrIlikeExpr(node) => node.set_span(span),
+            ExprNode::PatternRegexpExpr(node) => node.set_span(span),
+            ExprNode::SoundsLikeExpr(node) => node.set_span(span),
+            ExprNode::MemberOfExpr(node) => node.set_span(span),
+            ExprNode::IsNullExpr(node) => node.set_span(span),
+            ExprNode::IsTruthExpr(node) => node.set_span(span),
+            ExprNode::CompareSubqueryExpr(node) => node.set_span(span),
+            ExprNode::ParenthesesExpr(node) => node.set_span(span),
+            ExprNode::Default => {}
+        }
+    }
+}
+
+impl Spanned for Statement {
+    fn span(&self) -> Span {
+        match self {
+            Statement::SelectStmt(node) => node.span(),
+            Statement::SetOprStmt(node) => node.span(),
+            Statement::CallStmt(node) => node.span(),
+            Statement::DoStmt(node) => node.span(),
+            Statement::HandlerStmt(node) => node.span(),
+            Statement::CreateSequenceStmt(node) => node.span(),
+            Statement::AlterSequenceStmt(node) => node.span(),
+            Statement::DropSequenceStmt(node) => node.span(),
+            Statement::AdminStmt(node) => node.span(),
+            Statement::CreateBindingStmt(node) => node.span(),
+            Statement::DropBindingStmt(node) => node.span(),
+            Statement::ShowStmt(node) => node.span(),
+            Statement::SplitRegionStmt(node) => node.span(),
+            Statement::FlashBackTableStmt(node) => node.span(),
+            Statement::FlashBackDatabaseStmt(node) => node.span(),
+            Statement::FlashBackToTimestampStmt(node) => node.span(),
+            Statement::CreatePlacementPolicyStmt(node) => node.span(),
+            Statement::AlterPlacementPolicyStmt(node) => node.span(),
+            Statement::DropPlacementPolicyStmt(node) => node.span(),
+            Statement::SetConfigStmt(node) => node.span(),
+        }
+    }
+
+    fn set_span(&mut self, span: Span) {
+        match self {
+            Statement::SelectStmt(node) => node.set_span(span),
+            Statement::SetOprStmt(node) => node.set_span(span),
+            Statement::CallStmt(node) => node.set_span(span),
+            Statement::DoStmt(node) => node.set_span(span),
+            Statement::HandlerStmt(node) => node.set_span(span),
+            Statement::CreateSequenceStmt(node) => node.set_span(span),
+            Statement::AlterSequenceStmt(node) => node.set_span(span),
+            Statement::DropSequenceStmt(node) => node.set_span(span),
+            Statement::AdminStmt(node) => node.set_span(span),
+            Statement::CreateBindingStmt(node) => node.set_span(span),
+            Statement::DropBindingStmt(node) => node.set_span(span),
+            Statement::ShowStmt(node) => node.set_span(span),
+            Statement::SplitRegionStmt(node) => node.set_span(span),
+            Statement::FlashBackTableStmt(node) => node.set_span(span),
+            Statement::FlashBackDatabaseStmt(node) => node.set_span(span),
+            Statement::FlashBackToTimestampStmt(node) => node.set_span(span),
+            Statement::CreatePlacementPolicyStmt(node) => node.set_span(span),
+            Statement::AlterPlacementPolicyStmt(node) => node.set_span(span),
+            Statement::DropPlacementPolicyStmt(node) => node.set_span(span),
+            Statement::SetConfigStmt(node) => node.set_span(span),
+        }
+    }
+}
+
+impl Spanned for ResultSetNode {
+    fn span(&self) -> Span {
+        match self {
+            ResultSetNode::TableRefsClause(node) => node.span(),
+            ResultSetNode::SelectStmt(node) => node.span(),
+            ResultSetNode::TableName(node) => node.span(),
+            ResultSetNode::TableSource(node) => node.span(),
+            ResultSetNode::SetOprStmt(node) => node.span(),
+            ResultSetNode::JsonTable(node) => node.span(),
+            ResultSetNode::Lateral(node) => node.span(),
+        }
+    }
+
+    fn set_span(&mut self, span: Span) {
+        match self {
+            ResultSetNode::TableRefsClause(node) => node.set_span(span),
+            ResultSetNode::SelectStmt(node) => node.set_span(span),
+            ResultSetNode::TableName(node) => node.set_span(span),
+            ResultSetNode::TableSource(node) => node.set_span(span),
+            ResultSetNode::SetOprStmt(node) => node.set_span(span),
+            ResultSetNode::JsonTable(node) => node.set_span(span),
+            ResultSetNode::Lateral(node) => node.set_span(span),
+        }
+    }
+}
+
+impl Spanned for Node {
+    fn span(&self) -> Span {
+        match self {
+            Node::Statement(node) => node.span(),
+            Node::ResultSetNode(node) => node.span(),
+            Node::SetOprSelectList(node) => node.span(),
+        }
+    }
+
+    fn set_span(&mut self, span: Span) {
+        match self {
+            Node::Statement(node) => node.set_span(span),
+            Node::ResultSetNode(node) => node.set_span(span),
+            Node::SetOprSelectList(node) => node.set_span(span),
+        }
+    }
+}
+
+impl Spanned for Field {
+    fn span(&self) -> Span {
+        match self {
+            Field::WildCardField(node) => node.span(),
+            Field::Expr(node) => node.span(),
+            Field::Unkonw => None,
+        }
+    }
+
+    fn set_span(&mut self, span: Span) {
+        match self {
+            Field::WildCardField(node) => node.set_span(span),
+            Field::Expr(node) => node.set_span(span),
+            Field::Unkonw => {}
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::ast::ci_str::CIStr;
+use crate::ast::expr_node::ExprNode;
+use crate::ast::table_name::TableName;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// SplitSyntaxOption is the optional `REGION FOR` and `PARTITION` words of
+// SPLIT, they don't change the meaning of the statement.
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct SplitSyntaxOption {
+    #[drive(skip)]
+    pub has_region_for: bool,
+    #[drive(skip)]
+    pub has_partition: bool,
+}
+
+// SplitOption is either `BETWEEN (lower) AND (upper) REGIONS num`, which
+// splits the range evenly, or `BY (value), ...`, which splits at the values.
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct SplitOption {
+    #[drive(skip)]
+    pub span: Span,
+    pub lower: Vec<ExprNode>,
+    pub upper: Vec<ExprNode>,
+    #[drive(skip)]
+    pub num: i64,
+    pub value_lists: Vec<Vec<ExprNode>>,
+}
+
+// SplitRegionStmt splits the regions of a table or an index.
+// See https://docs.pingcap.com/tidb/stable/sql-statement-split-region
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct SplitRegionStmt {
+    #[drive(skip)]
+    pub span: Span,
+    pub table: TableName,
+    // IndexName is empty when the table rows are split.
+    #[drive(skip)]
+    pub index_name: CIStr,
+    #[drive(skip)]
+    pub partition_names: Vec<CIStr>,
+    pub split_syntax_opt: SplitSyntaxOption,
+    pub split_opt: SplitOption,
+}
@@ -1,9 +1,47 @@
+use crate::ast::admin_stmt::AdminStmt;
+use crate::ast::binding_stmt::{CreateBindingStmt, DropBindingStmt};
+use crate::ast::call_stmt::CallStmt;
+use crate::ast::do_stmt::DoStmt;
+use crate::ast::flashback_stmt::{
+    FlashBackDatabaseStmt, FlashBackTableStmt, FlashBackToTimestampStmt,
+};
+use crate::ast::handler_stmt::HandlerStmt;
+use crate::ast::placement_policy_stmt::{
+    AlterPlacementPolicyStmt, CreatePlacementPolicyStmt, DropPlacementPolicyStmt,
+};
 use crate::ast::select_stmt::SelectStmt;
+use crate::ast::sequence_stmt::{AlterSequenceStmt, CreateSequenceStmt, DropSequenceStmt};
+use crate::ast::set_config_stmt::SetConfigStmt;
 use crate::ast::set_opr_stmt::SetOprStmt;
-use derive_visitor::Drive;
+use crate::ast::show_stmt::ShowStmt;
+use crate::ast::split_region_stmt::SplitRegionStmt;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Drive)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(tag = "type", content = "data")]
 pub enum Statement {
     SelectStmt(Box<SelectStmt>),
     SetOprStmt(Box<SetOprStmt>),
+    CallStmt(Box<CallStmt>),
+    DoStmt(Box<DoStmt>),
+    HandlerStmt(Box<HandlerStmt>),
+    CreateSequenceStmt(Box<CreateSequenceStmt>),
+    AlterSequenceStmt(Box<AlterSequenceStmt>),
+    DropSequenceStmt(Box<DropSequenceStmt>),
+    AdminStmt(Box<AdminStmt>),
+    CreateBindingStmt(Box<CreateBindingStmt>),
+    DropBindingStmt(Box<DropBindingStmt>),
+    ShowStmt(Box<ShowStmt>),
+    SplitRegionStmt(Box<SplitRegionStmt>),
+    FlashBackTableStmt(Box<FlashBackTableStmt>),
+    FlashBackDatabaseStmt(Box<FlashBackDatabaseStmt>),
+    FlashBackToTimestampStmt(Box<FlashBackToTimestampStmt>),
+    CreatePlacementPolicyStmt(Box<CreatePlacementPolicyStmt>),
+    AlterPlacementPolicyStmt(Box<AlterPlacementPolicyStmt>),
+    DropPlacementPolicyStmt(Box<DropPlacementPolicyStmt>),
+    SetConfigStmt(Box<SetConfigStmt>),
 }
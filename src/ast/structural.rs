@@ -0,0 +1,478 @@
+use serde::ser::{self, Serialize};
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+
+// The derived `PartialEq` and `Hash` of the AST nodes are strict: two queries
+// that only differ in whitespace, comments or identifier case still differ in
+// their spans and original text. The structural mode below ignores:
+//   - the `span` of every node;
+//   - the source positions kept by select fields and parameter markers;
+//   - the original case of identifiers and of the aggregate and window
+//     function names, they compare by their lower case;
+//   - the spelling of the literals whose value is parsed, so `1` equals `01`
+//     and `0x0A` equals `X'0A'`.
+//
+// It works on the serde form of the nodes, so every node serializable to
+// JSON has it.
+
+// IGNORED_FIELDS are the source positions kept outside of the spans.
+const IGNORED_FIELDS: &[(&str, &str)] = &[
+    ("SelectField", "offset"),
+    ("ParamMarkerExpr", "offset"),
+    ("ParamMarkerExpr", "token_index"),
+    ("ParamMarkerExpr", "start_pos"),
+    ("ParamMarkerExpr", "end_pos"),
+];
+
+// CASE_INSENSITIVE_FIELDS are the names kept as plain strings that compare by
+// their lower case like a CIStr.
+const CASE_INSENSITIVE_FIELDS: &[(&str, &str)] =
+    &[("AggregateFuncExpr", "f"), ("WindowFuncExpr", "name")];
+
+// PARSED_LITERAL_KINDS are the `ValueExprKind`s carrying the parsed value of
+// the literal, or NULL, for them the `ValueExpr.s` text is only its spelling.
+const PARSED_LITERAL_KINDS: &[&str] = &[
+    "None",
+    "Bool",
+    "Isize",
+    "I64",
+    "U64",
+    "F32",
+    "F64",
+    "Decimal",
+    "Bytes",
+    "BitLiteral",
+    "HexLiteral",
+];
+
+pub trait StructuralEq {
+    // structural_key encodes the node without the ignored fields, two nodes
+    // are structurally equal when their keys are equal.
+    fn structural_key(&self) -> Vec<u8>;
+
+    fn structural_eq(&self, other: &Self) -> bool {
+        self.structural_key() == other.structural_key()
+    }
+
+    fn structural_hash<H: Hasher>(&self, state: &mut H) {
+        self.structural_key().hash(state)
+    }
+}
+
+impl<T: Serialize + ?Sized> StructuralEq for T {
+    fn structural_key(&self) -> Vec<u8> {
+        let mut serializer = KeySerializer::default();
+        self.serialize(&mut serializer)
+            .expect("AST nodes always serialize");
+        serializer.out
+    }
+}
+
+// Structural wraps a node to compare and hash it structurally, e.g. to use a
+// statement as a `HashMap` key regardless of how the query was written.
+#[derive(Debug, Clone)]
+pub struct Structural<T>(pub T);
+
+impl<T: Serialize> PartialEq for Structural<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.structural_eq(&other.0)
+    }
+}
+
+impl<T: Serialize> Eq for Structural<T> {}
+
+impl<T: Serialize> Hash for Structural<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.structural_hash(state)
+    }
+}
+
+#[derive(Debug)]
+struct KeyError(String);
+
+impl Display for KeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for KeyError {}
+
+impl ser::Error for KeyError {
+    fn custom<T: Display>(msg: T) -> Self {
+        KeyError(msg.to_string())
+    }
+}
+
+// KeySerializer writes every value as a tag byte and its payload, compound
+// values are closed by an end byte, so the encoding is unambiguous.
+#[derive(Default)]
+struct KeySerializer {
+    out: Vec<u8>,
+    // lower is set while the string of a CIStr is written.
+    lower: bool,
+    // literal_kind is the last `ValueExprKind` variant written.
+    literal_kind: Option<&'static str>,
+}
+
+impl KeySerializer {
+    fn tag(&mut self, tag: u8) {
+        self.out.push(tag);
+    }
+
+    fn bytes(&mut self, tag: u8, v: &[u8]) {
+        self.out.push(tag);
+        self.out.extend_from_slice(&(v.len() as u64).to_le_bytes());
+        self.out.extend_from_slice(v);
+    }
+
+    fn compound(&mut self, tag: u8, name: &'static str) -> Compound<'_> {
+        self.bytes(tag, name.as_bytes());
+        Compound {
+            ser: self,
+            name,
+            literal_text: None,
+        }
+    }
+}
+
+type KeyResult = Result<(), KeyError>;
+
+impl<'a> ser::Serializer for &'a mut KeySerializer {
+    type Ok = ();
+    type Error = KeyError;
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn serialize_bool(self, v: bool) -> KeyResult {
+        self.out.extend_from_slice(&[b'b', v as u8]);
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> KeyResult {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i16(self, v: i16) -> KeyResult {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i32(self, v: i32) -> KeyResult {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i64(self, v: i64) -> KeyResult {
+        self.tag(b'i');
+        self.out.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_i128(self, v: i128) -> KeyResult {
+        self.tag(b'I');
+        self.out.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> KeyResult {
+        self.serialize_u64(v as u64)
+    }
+
+    fn serialize_u16(self, v: u16) -> KeyResult {
+        self.serialize_u64(v as u64)
+    }
+
+    fn serialize_u32(self, v: u32) -> KeyResult {
+        self.serialize_u64(v as u64)
+    }
+
+    fn serialize_u64(self, v: u64) -> KeyResult {
+        self.tag(b'u');
+        self.out.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_u128(self, v: u128) -> KeyResult {
+        self.tag(b'U');
+        self.out.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> KeyResult {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_f64(self, v: f64) -> KeyResult {
+        self.tag(b'f');
+        self.out.extend_from_slice(&v.to_bits().to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> KeyResult {
+        self.tag(b'c');
+        self.out.extend_from_slice(&(v as u32).to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_str(self, v: &str) -> KeyResult {
+        if std::mem::take(&mut self.lower) {
+            self.bytes(b's', v.to_lowercase().as_bytes());
+        } else {
+            self.bytes(b's', v.as_bytes());
+        }
+        Ok(())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> KeyResult {
+        self.bytes(b'y', v);
+        Ok(())
+    }
+
+    fn serialize_none(self) -> KeyResult {
+        self.tag(b'n');
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> KeyResult {
+        self.tag(b'S');
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> KeyResult {
+        self.tag(b'0');
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> KeyResult {
+        self.bytes(b'z', name.as_bytes());
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> KeyResult {
+        if name == "ValueExprKind" {
+            self.literal_kind = Some(variant);
+        }
+        self.bytes(b'v', variant.as_bytes());
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> KeyResult {
+        self.lower = name == "CIStr";
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> KeyResult {
+        self.bytes(b'V', variant.as_bytes());
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Compound<'a>, KeyError> {
+        Ok(self.compound(b'[', ""))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Compound<'a>, KeyError> {
+        Ok(self.compound(b'(', ""))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>, KeyError> {
+        Ok(self.compound(b'(', name))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>, KeyError> {
+        Ok(self.compound(b'(', variant))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Compound<'a>, KeyError> {
+        Ok(self.compound(b'm', ""))
+    }
+
+    fn serialize_struct(self, name: &'static str, _len: usize) -> Result<Compound<'a>, KeyError> {
+        Ok(self.compound(b'{', name))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>, KeyError> {
+        Ok(self.compound(b'{', variant))
+    }
+}
+
+struct Compound<'a> {
+    ser: &'a mut KeySerializer,
+    name: &'static str,
+    // literal_text is where the `s` of a ValueExpr was written, it's dropped
+    // once the kind shows the value is parsed.
+    literal_text: Option<(usize, usize)>,
+}
+
+impl Compound<'_> {
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> KeyResult {
+        value.serialize(&mut *self.ser)
+    }
+
+    fn field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> KeyResult {
+        if key == "span" || IGNORED_FIELDS.contains(&(self.name, key)) {
+            return Ok(());
+        }
+        let start = self.ser.out.len();
+        self.ser.bytes(b'k', key.as_bytes());
+        self.ser.lower = CASE_INSENSITIVE_FIELDS.contains(&(self.name, key));
+        value.serialize(&mut *self.ser)?;
+        if self.name == "ValueExpr" {
+            match key {
+                "s" => self.literal_text = Some((start, self.ser.out.len())),
+                "kind" => {
+                    let kind = self.ser.literal_kind.take();
+                    if let (Some((start, end)), Some(kind)) = (self.literal_text, kind) {
+                        if PARSED_LITERAL_KINDS.contains(&kind) {
+                            self.ser.out.drain(start..end);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn end(self) -> KeyResult {
+        self.ser.tag(b'.');
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = KeyError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> KeyResult {
+        self.element(value)
+    }
+
+    fn end(self) -> KeyResult {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = KeyError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> KeyResult {
+        self.element(value)
+    }
+
+    fn end(self) -> KeyResult {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = KeyError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> KeyResult {
+        self.element(value)
+    }
+
+    fn end(self) -> KeyResult {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = KeyError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> KeyResult {
+        self.element(value)
+    }
+
+    fn end(self) -> KeyResult {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = KeyError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> KeyResult {
+        self.element(key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> KeyResult {
+        self.element(value)
+    }
+
+    fn end(self) -> KeyResult {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = KeyError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> KeyResult {
+        self.field(key, value)
+    }
+
+    fn end(self) -> KeyResult {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = KeyError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> KeyResult {
+        self.field(key, value)
+    }
+
+    fn end(self) -> KeyResult {
+        Compound::end(self)
+    }
+}
@@ -1,9 +1,16 @@
 use crate::ast::result_set_node::ResultSetNode;
-use derive_visitor::Drive;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 // SubqueryExpr represents a subquery.
-#[derive(Debug, Drive, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct SubQueryExpr {
+    #[drive(skip)]
+    pub span: Span,
     // Query is the query SelectNode.
     pub query: Option<ResultSetNode>,
     #[drive(skip)]
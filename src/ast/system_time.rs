@@ -0,0 +1,35 @@
+use crate::ast::expr_node::ExprNode;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// HistoryPoint is a point in the history of a system-versioned table, it's a
+// timestamp unless TRANSACTION is given.
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct HistoryPoint {
+    #[drive(skip)]
+    pub span: Span,
+    #[drive(skip)]
+    pub transaction: bool,
+    pub expr: Box<ExprNode>,
+}
+
+// SystemTimeClause is the `FOR SYSTEM_TIME` clause that queries the history
+// of a system-versioned table, it's a MariaDB extension.
+// See https://mariadb.com/kb/en/system-versioned-tables/
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(tag = "type", content = "data")]
+pub enum SystemTimeClause {
+    // AsOf is `AS OF point`.
+    AsOf(HistoryPoint),
+    // Between is `BETWEEN start AND end`, the end is included.
+    Between(HistoryPoint, HistoryPoint),
+    // FromTo is `FROM start TO end`, the end is excluded.
+    FromTo(HistoryPoint, HistoryPoint),
+    All,
+}
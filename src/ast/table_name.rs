@@ -1,17 +1,24 @@
 use crate::ast::ci_str::CIStr;
 use crate::ast::expr_node::ExprNode;
 use crate::ast::index_hint::IndexHint;
+use crate::ast::system_time::SystemTimeClause;
 use crate::ast::table_sample::TableSample;
-use derive_visitor::Drive;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Drive)]
+#[derive(
+    Debug, Default, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct TableName {
+    #[drive(skip)]
+    pub span: Span,
     #[drive(skip)]
     pub schema: CIStr,
     #[drive(skip)]
     pub name: CIStr,
 
-    #[drive(skip)]
     pub index_hints: Vec<IndexHint>,
     #[drive(skip)]
     pub partition_names: Vec<CIStr>,
@@ -19,6 +26,8 @@ pub struct TableName {
 
     // AS OF is used to see the data as it was at a specific point in time.
     pub as_of: Option<Box<ExprNode>>,
+    // SystemTime is the `FOR SYSTEM_TIME` clause of MariaDB.
+    pub system_time: Option<Box<SystemTimeClause>>,
 
     // IsAlias is true if this table name is an alias.
     //  sometime, we need to distinguish the table name is an alias or not.
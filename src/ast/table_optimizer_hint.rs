@@ -1,43 +1,69 @@
 use crate::ast::ci_str::CIStr;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 // HintTimeRange is the payload of `TIME_RANGE` hint
-#[derive(Debug, Clone)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct HintTimeRange {
+    #[drive(skip)]
     pub from: String,
+    #[drive(skip)]
     pub to: String,
 }
 
 // HintSetVar is the payload of `SET_VAR` hint
-#[derive(Debug, Clone)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct HintSetVar {
+    #[drive(skip)]
     pub var_name: String,
+    #[drive(skip)]
     pub value: String,
 }
 
 // HintTable is table in the hint. It may have query block info.
-#[derive(Debug, Clone)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct HintTable {
+    #[drive(skip)]
     pub db_name: CIStr,
+    #[drive(skip)]
     pub table_name: CIStr,
+    #[drive(skip)]
     pub qb_name: CIStr,
+    #[drive(skip)]
     pub partition_list: Vec<CIStr>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(tag = "type", content = "data")]
 pub enum HintData {
-    Int64(i64),
-    Uint64(u64),
-    Bool(bool),
-    CIStr(CIStr),
+    Int64(#[drive(skip)] i64),
+    Uint64(#[drive(skip)] u64),
+    Bool(#[drive(skip)] bool),
+    CIStr(#[drive(skip)] CIStr),
     HintTimeRange(HintTimeRange),
     HintSetVar(HintSetVar),
 }
 
-#[derive(Debug, Clone)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct TableOptimizerHint {
+    #[drive(skip)]
+    pub span: Span,
     // HintName is the name or alias of the table(s) which the hint will affect.
     // Table hints has no schema info
     // It allows only table name or alias (if table has an alias)
+    #[drive(skip)]
     pub hint_name: CIStr,
     // HintData is the payload of the hint. The actual type of this field
     // is defined differently as according `HintName`. Define as following:
@@ -54,9 +80,13 @@ pub struct TableOptimizerHint {
     // - READ_FROM_STORAGE   => CIStr
     // - USE_TOJA            => bool
     // - NTH_PLAN            => int64
-    pub hint_data: HintData,
+    //
+    // It is None for the hints without payload, e.g. table level hints.
+    pub hint_data: Option<HintData>,
     // QBName is the default effective query block of this hint.
+    #[drive(skip)]
     pub qb_name: CIStr,
     pub tables: Vec<HintTable>,
+    #[drive(skip)]
     pub indexes: Vec<CIStr>,
 }
@@ -1,9 +1,16 @@
+use crate::ast::ci_str::CIStr;
 use crate::ast::column_name::ColumnName;
 use crate::ast::expr_node::ExprNode;
 use crate::ast::result_set_node::ResultSetNode;
-use derive_visitor::Drive;
+use crate::ast::table_source::TableSource;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub enum JoinType {
     // CrossJoin is cross join type.
     CrossJoin,
@@ -13,19 +20,21 @@ pub enum JoinType {
     RightJoin,
 }
 
-#[derive(Debug, Drive)]
+#[derive(
+    Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct TableRefsClause {
+    #[drive(skip)]
+    pub span: Span,
     // Left table can be TableSource or JoinNode.
     pub left: Box<ResultSetNode>,
     // Right table can be TableSource or JoinNode or nil.
     pub right: Option<Box<ResultSetNode>>,
     // Tp represents join type.
-    #[drive(skip)]
     pub join_type: JoinType,
     // On represents join on condition.
     pub on: Box<ExprNode>,
     // Using represents join using clause.
-    #[drive(skip)]
     pub using: Vec<ColumnName>,
     // NaturalJoin represents join is natural join.
     #[drive(skip)]
@@ -36,3 +45,36 @@ pub struct TableRefsClause {
     #[drive(skip)]
     pub explicit_parens: bool,
 }
+
+impl TableRefsClause {
+    // new_table_source builds a clause which only holds a single table source,
+    // e.g. the `t` of `TABLE t`.
+    pub fn new_table_source(source: ResultSetNode, as_name: CIStr) -> Self {
+        let source = ResultSetNode::TableSource(Box::new(TableSource {
+            span: None,
+            source: Box::new(source),
+            as_name,
+        }));
+        Self::new_join(source, None, JoinType::CrossJoin)
+    }
+
+    // new_join builds a join without condition, the right table is None
+    // when the clause only holds the left table.
+    pub fn new_join(
+        left: ResultSetNode,
+        right: Option<ResultSetNode>,
+        join_type: JoinType,
+    ) -> Self {
+        TableRefsClause {
+            span: None,
+            left: Box::new(left),
+            right: right.map(Box::new),
+            join_type,
+            on: Box::new(ExprNode::Default),
+            using: vec![],
+            natural_join: false,
+            straight_join: false,
+            explicit_parens: false,
+        }
+    }
+}
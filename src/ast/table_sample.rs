@@ -1,7 +1,10 @@
 use crate::ast::expr_node::ExprNode;
-use derive_visitor::Drive;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum SampleMethodType {
     SampleMethodTypeNone,
     SampleMethodTypeSystem,
@@ -9,19 +12,19 @@ pub enum SampleMethodType {
     SampleMethodTypeTiDBRegion,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum SampleClauseUnitType {
     SampleClauseUnitTypeDefault,
     SampleClauseUnitTypeRow,
     SampleClauseUnitTypePercent,
 }
 
-#[derive(Debug, Drive)]
+#[derive(Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct TableSample {
     #[drive(skip)]
+    pub span: Span,
     pub sample_method: SampleMethodType,
     pub expr: Box<ExprNode>,
-    #[drive(skip)]
     pub sample_clause_unit: SampleClauseUnitType,
     pub repeatable_seed: Box<ExprNode>,
 }
\ No newline at end of file
@@ -1,9 +1,14 @@
 use crate::ast::ci_str::CIStr;
 use crate::ast::result_set_node::ResultSetNode;
-use derive_visitor::Drive;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Drive)]
+#[derive(Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct TableSource {
+    #[drive(skip)]
+    pub span: Span,
     pub source: Box<ResultSetNode>,
     #[drive(skip)]
     pub as_name: CIStr,
@@ -0,0 +1,24 @@
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// UserIdentity represents username and hostname of an account, e.g.
+// `'root'@'%'`.
+// See https://dev.mysql.com/doc/refman/8.0/en/account-names.html
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct UserIdentity {
+    #[drive(skip)]
+    pub span: Span,
+    #[drive(skip)]
+    pub username: String,
+    // Hostname is lower case, it's `%` if omitted.
+    #[drive(skip)]
+    pub hostname: String,
+    // CurrentUser is true for `CURRENT_USER` or `CURRENT_USER()`, the
+    // username and hostname are empty then.
+    #[drive(skip)]
+    pub current_user: bool,
+}
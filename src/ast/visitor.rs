@@ -0,0 +1,401 @@
+use std::any::{Any, TypeId};
+use std::rc::Rc;
+
+use derive_visitor::{Drive, DriveMut, Event};
+
+use crate::ast::admin_stmt::{AdminStmt, AdminStmtType};
+use crate::ast::binding_stmt::{CreateBindingStmt, DropBindingStmt};
+use crate::ast::call_stmt::CallStmt;
+use crate::ast::column_name::ColumnName;
+use crate::ast::common::{ASTType, FulltextSearchModifier};
+use crate::ast::common_table_expression::CommonTableExpression;
+use crate::ast::do_stmt::DoStmt;
+use crate::ast::expr_node::{
+    AggregateFuncExpr, BetweenExpr, BinaryOperationExpr, CaseExpr, CastFunctionType,
+    ColumnNameExpr, CompareSubqueryExpr, ExistsSubqueryExpr, ExprNode, FuncCallExpr,
+    FuncCallExprType, FuncCastExpr, GetFormatSelectorExpr, IsNullExpr, IsTruthExpr, MatchAgainst,
+    MemberOfExpr, ParamMarkerExpr, ParenthesesExpr, PatternInExpr, PatternLikeOrIlikeExpr,
+    PatternRegexpExpr, PositionExpr, RowExpr, SetCollationExpr, SoundsLikeExpr, TableNameExpr,
+    TimeUnitExpr, TrimDirectionExpr, UnaryOperationExpr, ValueExpr, VariableExpr, WhenClause,
+    WindowFuncExpr,
+};
+use crate::ast::field_type::{DataType, FieldType};
+use crate::ast::flashback_stmt::{
+    FlashBackDatabaseStmt, FlashBackTableStmt, FlashBackToTimestampStmt,
+};
+use crate::ast::frame_clause::{BoundType, FrameBound, FrameClause, FrameExtent, FrameType};
+use crate::ast::functions::{GetFormatSelectorType, TimeUnitType, TrimDirectionType};
+use crate::ast::group_by_clause::{ByItem, GroupByClause};
+use crate::ast::handler_stmt::{HandlerReadDirection, HandlerStmt, HandlerStmtType};
+use crate::ast::index_hint::{IndexHint, IndexHintScope, IndexHintType};
+use crate::ast::json_table::{
+    JsonTable, JsonTableColumn, JsonTableColumnKind, JsonTableOnResponse,
+};
+use crate::ast::limit::Limit;
+use crate::ast::line_clause::LinesClause;
+use crate::ast::node::Node;
+use crate::ast::op_code::OpCode;
+use crate::ast::order_by_clause::OrderByClause;
+use crate::ast::partition_by_clause::PartitionByClause;
+use crate::ast::placement_policy_stmt::{
+    AlterPlacementPolicyStmt, CreatePlacementPolicyStmt, DropPlacementPolicyStmt, PlacementOption,
+    PlacementOptionType,
+};
+use crate::ast::result_set_node::ResultSetNode;
+use crate::ast::select_field::{Field, FieldItem, FieldsClause, SelectField, WildCardField};
+use crate::ast::select_into_option::{SelectIntoOption, SelectIntoType};
+use crate::ast::select_lock_info::{SelectLockInfo, SelectLockType};
+use crate::ast::select_stmt::{SelectStmt, SelectStmtKind, SelectStmtOpts};
+use crate::ast::sequence_stmt::{
+    AlterSequenceStmt, CreateSequenceStmt, DropSequenceStmt, SequenceOption, SequenceOptionType,
+};
+use crate::ast::set_config_stmt::SetConfigStmt;
+use crate::ast::set_opr_stmt::{SetOprSelectList, SetOprStmt, SetOprType};
+use crate::ast::show_stmt::{ShowStmt, ShowStmtType};
+use crate::ast::split_region_stmt::{SplitOption, SplitRegionStmt, SplitSyntaxOption};
+use crate::ast::statement::Statement;
+use crate::ast::subquery_expr::SubQueryExpr;
+use crate::ast::system_time::{HistoryPoint, SystemTimeClause};
+use crate::ast::table_name::TableName;
+use crate::ast::table_optimizer_hint::{
+    HintData, HintSetVar, HintTable, HintTimeRange, TableOptimizerHint,
+};
+use crate::ast::table_refs_clause::{JoinType, TableRefsClause};
+use crate::ast::table_sample::{SampleClauseUnitType, SampleMethodType, TableSample};
+use crate::ast::table_source::TableSource;
+use crate::ast::user_identity::UserIdentity;
+use crate::ast::window_spec::WindowSpec;
+use crate::ast::with_clause::WithClause;
+
+// VisitAction is returned by the enter hooks of `Visitor` and `VisitorMut`
+// to control the walk.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum VisitAction {
+    // Continue visits the children of the node.
+    #[default]
+    Continue,
+    // SkipChildren skips the children of the node, the leave hook of the
+    // node is still called.
+    SkipChildren,
+    // Stop ends the walk, no more hooks are called.
+    Stop,
+}
+
+// VisitorHooks declares `Visitor` and `VisitorMut` with an enter and a leave
+// hook per node kind, and the functions dispatching a driven item to them.
+macro_rules! visitor_hooks {
+    ($($node:ident: $enter:ident, $leave:ident;)*) => {
+        // Visitor is a typed visitor of the AST, like the `Visitor` of TiDB.
+        // Enter is called before the children of a node are visited and
+        // leave after, every hook does nothing by default.
+        pub trait Visitor {
+            $(
+                fn $enter(&mut self, _node: &$node) -> VisitAction {
+                    VisitAction::Continue
+                }
+
+                fn $leave(&mut self, _node: &$node) {}
+            )*
+        }
+
+        // VisitorMut is the `Visitor` that changes the nodes in place. A node
+        // replaced in its enter hook has the children of the new node
+        // visited.
+        pub trait VisitorMut {
+            $(
+                fn $enter(&mut self, _node: &mut $node) -> VisitAction {
+                    VisitAction::Continue
+                }
+
+                fn $leave(&mut self, _node: &mut $node) {}
+            )*
+        }
+
+        fn enter<V: Visitor + ?Sized>(visitor: &mut V, item: &dyn Any) -> VisitAction {
+            $(
+                if let Some(node) = item.downcast_ref::<$node>() {
+                    return visitor.$enter(node);
+                }
+            )*
+            VisitAction::Continue
+        }
+
+        fn leave<V: Visitor + ?Sized>(visitor: &mut V, item: &dyn Any) {
+            $(
+                if let Some(node) = item.downcast_ref::<$node>() {
+                    return visitor.$leave(node);
+                }
+            )*
+        }
+
+        fn enter_mut<V: VisitorMut + ?Sized>(visitor: &mut V, item: &mut dyn Any) -> VisitAction {
+            $(
+                if let Some(node) = item.downcast_mut::<$node>() {
+                    return visitor.$enter(node);
+                }
+            )*
+            VisitAction::Continue
+        }
+
+        fn leave_mut<V: VisitorMut + ?Sized>(visitor: &mut V, item: &mut dyn Any) {
+            $(
+                if let Some(node) = item.downcast_mut::<$node>() {
+                    return visitor.$leave(node);
+                }
+            )*
+        }
+    };
+}
+
+visitor_hooks! {
+    AdminStmtType: enter_admin_stmt_type, leave_admin_stmt_type;
+    AdminStmt: enter_admin_stmt, leave_admin_stmt;
+    CreateBindingStmt: enter_create_binding_stmt, leave_create_binding_stmt;
+    DropBindingStmt: enter_drop_binding_stmt, leave_drop_binding_stmt;
+    CallStmt: enter_call_stmt, leave_call_stmt;
+    ColumnName: enter_column_name, leave_column_name;
+    ASTType: enter_ast_type, leave_ast_type;
+    FulltextSearchModifier: enter_fulltext_search_modifier, leave_fulltext_search_modifier;
+    CommonTableExpression: enter_common_table_expression, leave_common_table_expression;
+    DoStmt: enter_do_stmt, leave_do_stmt;
+    ExprNode: enter_expr_node, leave_expr_node;
+    VariableExpr: enter_variable_expr, leave_variable_expr;
+    BinaryOperationExpr: enter_binary_operation_expr, leave_binary_operation_expr;
+    ExistsSubqueryExpr: enter_exists_subquery_expr, leave_exists_subquery_expr;
+    UnaryOperationExpr: enter_unary_operation_expr, leave_unary_operation_expr;
+    ColumnNameExpr: enter_column_name_expr, leave_column_name_expr;
+    MatchAgainst: enter_match_against, leave_match_against;
+    FuncCallExprType: enter_func_call_expr_type, leave_func_call_expr_type;
+    FuncCallExpr: enter_func_call_expr, leave_func_call_expr;
+    TimeUnitExpr: enter_time_unit_expr, leave_time_unit_expr;
+    ValueExpr: enter_value_expr, leave_value_expr;
+    RowExpr: enter_row_expr, leave_row_expr;
+    TrimDirectionExpr: enter_trim_direction_expr, leave_trim_direction_expr;
+    GetFormatSelectorExpr: enter_get_format_selector_expr, leave_get_format_selector_expr;
+    TableNameExpr: enter_table_name_expr, leave_table_name_expr;
+    SetCollationExpr: enter_set_collation_expr, leave_set_collation_expr;
+    WindowFuncExpr: enter_window_func_expr, leave_window_func_expr;
+    PositionExpr: enter_position_expr, leave_position_expr;
+    ParamMarkerExpr: enter_param_marker_expr, leave_param_marker_expr;
+    AggregateFuncExpr: enter_aggregate_func_expr, leave_aggregate_func_expr;
+    CaseExpr: enter_case_expr, leave_case_expr;
+    WhenClause: enter_when_clause, leave_when_clause;
+    CastFunctionType: enter_cast_function_type, leave_cast_function_type;
+    FuncCastExpr: enter_func_cast_expr, leave_func_cast_expr;
+    PatternInExpr: enter_pattern_in_expr, leave_pattern_in_expr;
+    BetweenExpr: enter_between_expr, leave_between_expr;
+    PatternLikeOrIlikeExpr: enter_pattern_like_or_ilike_expr, leave_pattern_like_or_ilike_expr;
+    PatternRegexpExpr: enter_pattern_regexp_expr, leave_pattern_regexp_expr;
+    SoundsLikeExpr: enter_sounds_like_expr, leave_sounds_like_expr;
+    MemberOfExpr: enter_member_of_expr, leave_member_of_expr;
+    IsNullExpr: enter_is_null_expr, leave_is_null_expr;
+    IsTruthExpr: enter_is_truth_expr, leave_is_truth_expr;
+    CompareSubqueryExpr: enter_compare_subquery_expr, leave_compare_subquery_expr;
+    ParenthesesExpr: enter_parentheses_expr, leave_parentheses_expr;
+    DataType: enter_data_type, leave_data_type;
+    FieldType: enter_field_type, leave_field_type;
+    FlashBackTableStmt: enter_flash_back_table_stmt, leave_flash_back_table_stmt;
+    FlashBackDatabaseStmt: enter_flash_back_database_stmt, leave_flash_back_database_stmt;
+    FlashBackToTimestampStmt: enter_flash_back_to_timestamp_stmt, leave_flash_back_to_timestamp_stmt;
+    FrameType: enter_frame_type, leave_frame_type;
+    FrameClause: enter_frame_clause, leave_frame_clause;
+    BoundType: enter_bound_type, leave_bound_type;
+    FrameBound: enter_frame_bound, leave_frame_bound;
+    FrameExtent: enter_frame_extent, leave_frame_extent;
+    TimeUnitType: enter_time_unit_type, leave_time_unit_type;
+    TrimDirectionType: enter_trim_direction_type, leave_trim_direction_type;
+    GetFormatSelectorType: enter_get_format_selector_type, leave_get_format_selector_type;
+    ByItem: enter_by_item, leave_by_item;
+    GroupByClause: enter_group_by_clause, leave_group_by_clause;
+    HandlerStmtType: enter_handler_stmt_type, leave_handler_stmt_type;
+    HandlerReadDirection: enter_handler_read_direction, leave_handler_read_direction;
+    HandlerStmt: enter_handler_stmt, leave_handler_stmt;
+    IndexHintType: enter_index_hint_type, leave_index_hint_type;
+    IndexHintScope: enter_index_hint_scope, leave_index_hint_scope;
+    IndexHint: enter_index_hint, leave_index_hint;
+    JsonTable: enter_json_table, leave_json_table;
+    JsonTableColumnKind: enter_json_table_column_kind, leave_json_table_column_kind;
+    JsonTableOnResponse: enter_json_table_on_response, leave_json_table_on_response;
+    JsonTableColumn: enter_json_table_column, leave_json_table_column;
+    Limit: enter_limit, leave_limit;
+    LinesClause: enter_lines_clause, leave_lines_clause;
+    Node: enter_node, leave_node;
+    OpCode: enter_op_code, leave_op_code;
+    OrderByClause: enter_order_by_clause, leave_order_by_clause;
+    PartitionByClause: enter_partition_by_clause, leave_partition_by_clause;
+    PlacementOptionType: enter_placement_option_type, leave_placement_option_type;
+    PlacementOption: enter_placement_option, leave_placement_option;
+    CreatePlacementPolicyStmt: enter_create_placement_policy_stmt, leave_create_placement_policy_stmt;
+    AlterPlacementPolicyStmt: enter_alter_placement_policy_stmt, leave_alter_placement_policy_stmt;
+    DropPlacementPolicyStmt: enter_drop_placement_policy_stmt, leave_drop_placement_policy_stmt;
+    ResultSetNode: enter_result_set_node, leave_result_set_node;
+    WildCardField: enter_wild_card_field, leave_wild_card_field;
+    Field: enter_field, leave_field;
+    SelectField: enter_select_field, leave_select_field;
+    FieldItem: enter_field_item, leave_field_item;
+    FieldsClause: enter_fields_clause, leave_fields_clause;
+    SelectIntoType: enter_select_into_type, leave_select_into_type;
+    SelectIntoOption: enter_select_into_option, leave_select_into_option;
+    SelectLockType: enter_select_lock_type, leave_select_lock_type;
+    SelectLockInfo: enter_select_lock_info, leave_select_lock_info;
+    SelectStmtKind: enter_select_stmt_kind, leave_select_stmt_kind;
+    SelectStmtOpts: enter_select_stmt_opts, leave_select_stmt_opts;
+    SelectStmt: enter_select_stmt, leave_select_stmt;
+    SequenceOptionType: enter_sequence_option_type, leave_sequence_option_type;
+    SequenceOption: enter_sequence_option, leave_sequence_option;
+    CreateSequenceStmt: enter_create_sequence_stmt, leave_create_sequence_stmt;
+    AlterSequenceStmt: enter_alter_sequence_stmt, leave_alter_sequence_stmt;
+    DropSequenceStmt: enter_drop_sequence_stmt, leave_drop_sequence_stmt;
+    SetConfigStmt: enter_set_config_stmt, leave_set_config_stmt;
+    SetOprStmt: enter_set_opr_stmt, leave_set_opr_stmt;
+    SetOprSelectList: enter_set_opr_select_list, leave_set_opr_select_list;
+    SetOprType: enter_set_opr_type, leave_set_opr_type;
+    ShowStmtType: enter_show_stmt_type, leave_show_stmt_type;
+    ShowStmt: enter_show_stmt, leave_show_stmt;
+    SplitSyntaxOption: enter_split_syntax_option, leave_split_syntax_option;
+    SplitOption: enter_split_option, leave_split_option;
+    SplitRegionStmt: enter_split_region_stmt, leave_split_region_stmt;
+    Statement: enter_statement, leave_statement;
+    SubQueryExpr: enter_sub_query_expr, leave_sub_query_expr;
+    HistoryPoint: enter_history_point, leave_history_point;
+    SystemTimeClause: enter_system_time_clause, leave_system_time_clause;
+    TableName: enter_table_name, leave_table_name;
+    HintTimeRange: enter_hint_time_range, leave_hint_time_range;
+    HintSetVar: enter_hint_set_var, leave_hint_set_var;
+    HintTable: enter_hint_table, leave_hint_table;
+    HintData: enter_hint_data, leave_hint_data;
+    TableOptimizerHint: enter_table_optimizer_hint, leave_table_optimizer_hint;
+    JoinType: enter_join_type, leave_join_type;
+    TableRefsClause: enter_table_refs_clause, leave_table_refs_clause;
+    SampleMethodType: enter_sample_method_type, leave_sample_method_type;
+    SampleClauseUnitType: enter_sample_clause_unit_type, leave_sample_clause_unit_type;
+    TableSample: enter_table_sample, leave_table_sample;
+    TableSource: enter_table_source, leave_table_source;
+    UserIdentity: enter_user_identity, leave_user_identity;
+    WindowSpec: enter_window_spec, leave_window_spec;
+    WithClause: enter_with_clause, leave_with_clause;
+}
+
+// Accept walks a visitor through a node and all of its descendants, like
+// `Node.Accept` of TiDB.
+pub trait Accept {
+    fn accept<V: Visitor + ?Sized>(&self, visitor: &mut V);
+
+    fn accept_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V);
+}
+
+impl<T: Drive + DriveMut> Accept for T {
+    fn accept<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        self.drive(&mut Walker::new(visitor));
+    }
+
+    fn accept_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        self.drive_mut(&mut Walker::new(visitor));
+    }
+}
+
+// Walker adapts a typed visitor to the `derive_visitor` visitors driven by
+// the nodes.
+struct Walker<'a, V: ?Sized> {
+    visitor: &'a mut V,
+    // The node whose children are skipped, identified by its address and
+    // type, as an enum and its first variant may share the address.
+    skipping: Option<(*const (), TypeId)>,
+    stopped: bool,
+}
+
+impl<'a, V: ?Sized> Walker<'a, V> {
+    fn new(visitor: &'a mut V) -> Self {
+        Walker {
+            visitor,
+            skipping: None,
+            stopped: false,
+        }
+    }
+
+    // Filter reports whether the event of the item is passed to the
+    // visitor.
+    fn filter(&mut self, id: (*const (), TypeId), event: &Event) -> bool {
+        if self.stopped {
+            return false;
+        }
+        match self.skipping {
+            Some(skipping) if skipping == id && matches!(event, Event::Exit) => {
+                self.skipping = None;
+                true
+            }
+            Some(_) => false,
+            None => true,
+        }
+    }
+
+    fn on_enter(&mut self, id: (*const (), TypeId), action: VisitAction) {
+        match action {
+            VisitAction::Continue => {}
+            VisitAction::SkipChildren => self.skipping = Some(id),
+            VisitAction::Stop => self.stopped = true,
+        }
+    }
+}
+
+impl<V: Visitor + ?Sized> derive_visitor::Visitor for Walker<'_, V> {
+    fn visit(&mut self, item: &dyn Any, event: Event) {
+        let id = (item as *const dyn Any as *const (), (*item).type_id());
+        if !self.filter(id, &event) {
+            return;
+        }
+        match event {
+            Event::Enter => {
+                let action = enter(self.visitor, item);
+                self.on_enter(id, action);
+            }
+            Event::Exit => leave(self.visitor, item),
+        }
+    }
+}
+
+impl<V: VisitorMut + ?Sized> derive_visitor::VisitorMut for Walker<'_, V> {
+    fn visit(&mut self, item: &mut dyn Any, event: Event) {
+        let id = (item as *const dyn Any as *const (), (*item).type_id());
+        if !self.filter(id, &event) {
+            return;
+        }
+        match event {
+            Event::Enter => {
+                let action = enter_mut(self.visitor, item);
+                self.on_enter(id, action);
+            }
+            Event::Exit => leave_mut(self.visitor, item),
+        }
+    }
+}
+
+// DriveRc drives the visitors through the `Option<Rc<_>>` clauses of the
+// statements, see `drive_rc`.
+pub trait DriveRc<V> {
+    fn drive_rc(self, visitor: &mut V);
+}
+
+impl<T: Drive, V: derive_visitor::Visitor> DriveRc<V> for &Option<Rc<T>> {
+    fn drive_rc(self, visitor: &mut V) {
+        if let Some(node) = self {
+            node.as_ref().drive(visitor);
+        }
+    }
+}
+
+// A clause still shared by several nodes is copied before it's changed, so
+// the other owners keep the original. The parser never shares them.
+impl<T: DriveMut + Clone, V: derive_visitor::VisitorMut> DriveRc<V> for &mut Option<Rc<T>> {
+    fn drive_rc(self, visitor: &mut V) {
+        if let Some(node) = self {
+            Rc::make_mut(node).drive_mut(visitor);
+        }
+    }
+}
+
+// DriveRc is used as `#[drive(with = "crate::ast::visitor::drive_rc")]` on
+// the `Option<Rc<_>>` fields, for which `derive_visitor` has no impl.
+pub fn drive_rc<R: DriveRc<V>, V>(node: R, visitor: &mut V) {
+    node.drive_rc(visitor);
+}
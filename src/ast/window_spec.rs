@@ -2,11 +2,18 @@ use crate::ast::ci_str::CIStr;
 use crate::ast::frame_clause::FrameClause;
 use crate::ast::order_by_clause::OrderByClause;
 use crate::ast::partition_by_clause::PartitionByClause;
-use derive_visitor::Drive;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 // WindowSpec is the specification of a window.
-#[derive(Debug, Drive, Default)]
+#[derive(
+    Debug, Drive, DriveMut, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub struct WindowSpec {
+    #[drive(skip)]
+    pub span: Span,
     #[drive(skip)]
     pub name: CIStr,
     // Ref is the reference window of this specification. For example, in `w2 as (w1 order by a)`,
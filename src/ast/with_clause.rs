@@ -1,8 +1,13 @@
 use crate::ast::common_table_expression::CommonTableExpression;
-use derive_visitor::Drive;
+use crate::common::span::Span;
+use derive_visitor::{Drive, DriveMut};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Drive)]
+#[derive(Debug, Drive, DriveMut, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct WithClause {
+    #[drive(skip)]
+    pub span: Span,
     #[drive(skip)]
     pub is_recursive: bool,
     pub ctes: Vec<CommonTableExpression>,
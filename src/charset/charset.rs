@@ -128,7 +128,7 @@ pub fn get_default_collation(charset_str: &str) -> Result<String, CustomError> {
 
 // GetCharsetInfo returns charset and collation for cs as name.
 pub fn get_charset_info(cs: &str) -> Result<Charset, CustomError> {
-    let cs = if cs.to_uppercase() == CHARSET_UTF8MB3 {
+    let cs = if cs.to_lowercase() == CHARSET_UTF8MB3 {
         CHARSET_UTF8
     } else {
         cs
@@ -139,7 +139,7 @@ pub fn get_charset_info(cs: &str) -> Result<Charset, CustomError> {
         return Ok(c.lock().unwrap().clone());
     }
 
-    if let Some(c) = charsets.get(&cs_lower) {
+    if charsets.contains_key(&cs_lower) {
         return Err(CustomError::Normal(format!("Unsupported charset {}", cs)));
     }
 
@@ -151,11 +151,11 @@ fn utf8_alias(csname: &str) -> String {
         "utf8mb3_bin" => "utf8_bin".to_string(),
         "utf8mb3_unicode_ci" => "utf8_unicode_ci".to_string(),
         "utf8mb3_general_ci" => "utf8_general_ci".to_string(),
-        _ => "".to_string(),
+        _ => csname.to_string(),
     }
 }
 pub fn get_collation_by_name(name: &str) -> Result<Collation, CustomError> {
-    let csname: String = utf8_alias(&name.to_uppercase());
+    let csname: String = utf8_alias(&name.to_lowercase());
     let msg = mysql_err_name.get(&errcode::ERR_UNKNOWN_COLLATION).unwrap();
     let msg = formatx!(&msg.raw, name)?;
 
@@ -254,2143 +254,337 @@ lazy_static! {
 
         s
     };
-    pub static ref collations: Vec<Arc<Mutex<Collation>>> = {
-        let mut c = Vec::<Arc<Mutex<Collation>>>::new();
-        c.push(Collation::new_ref(
-            1,
-            "big5",
-            "big5_chinese_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            2,
-            "latin2",
-            "latin2_czech_cs",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            3,
-            "dec8",
-            "dec8_swedish_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            4,
-            "cp850",
-            "cp850_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            5,
-            "latin1",
-            "latin1_german1_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            6,
-            "hp8",
-            "hp8_english_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            7,
-            "koi8r",
-            "koi8r_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            8,
-            "latin1",
-            "latin1_swedish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            9,
-            "latin2",
-            "latin2_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            10,
-            "swe7",
-            "swe7_swedish_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            11,
-            "ascii",
-            "ascii_general_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            12,
-            "ujis",
-            "ujis_japanese_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            13,
-            "sjis",
-            "sjis_japanese_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            14,
-            "cp1251",
-            "cp1251_bulgarian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            15,
-            "latin1",
-            "latin1_danish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            16,
-            "hebrew",
-            "hebrew_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            18,
-            "tis620",
-            "tis620_thai_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            19,
-            "euckr",
-            "euckr_korean_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            20,
-            "latin7",
-            "latin7_estonian_cs",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            21,
-            "latin2",
-            "latin2_hungarian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            22,
-            "koi8u",
-            "koi8u_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            23,
-            "cp1251",
-            "cp1251_ukrainian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            24,
-            "gb2312",
-            "gb2312_chinese_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            25,
-            "greek",
-            "greek_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            26,
-            "cp1250",
-            "cp1250_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            27,
-            "latin2",
-            "latin2_croatian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            28,
-            "gbk",
-            "gbk_chinese_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            29,
-            "cp1257",
-            "cp1257_lithuanian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            30,
-            "latin5",
-            "latin5_turkish_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            31,
-            "latin1",
-            "latin1_german2_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            32,
-            "armscii8",
-            "armscii8_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            33,
-            "utf8",
-            "utf8_general_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            34,
-            "cp1250",
-            "cp1250_czech_cs",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            35,
-            "ucs2",
-            "ucs2_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            36,
-            "cp866",
-            "cp866_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            37,
-            "keybcs2",
-            "keybcs2_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            38,
-            "macce",
-            "macce_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            39,
-            "macroman",
-            "macroman_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            40,
-            "cp852",
-            "cp852_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            41,
-            "latin7",
-            "latin7_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            42,
-            "latin7",
-            "latin7_general_cs",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            43,
-            "macce",
-            "macce_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            44,
-            "cp1250",
-            "cp1250_croatian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            45,
-            "utf8mb4",
-            "utf8mb4_general_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            46,
-            "utf8mb4",
-            "utf8mb4_bin",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            47,
-            "latin1",
-            "latin1_bin",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            48,
-            "latin1",
-            "latin1_general_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            49,
-            "latin1",
-            "latin1_general_cs",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            50,
-            "cp1251",
-            "cp1251_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            51,
-            "cp1251",
-            "cp1251_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            52,
-            "cp1251",
-            "cp1251_general_cs",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            53,
-            "macroman",
-            "macroman_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            54,
-            "utf16",
-            "utf16_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            55,
-            "utf16",
-            "utf16_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            56,
-            "utf16le",
-            "utf16le_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            57,
-            "cp1256",
-            "cp1256_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            58,
-            "cp1257",
-            "cp1257_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            59,
-            "cp1257",
-            "cp1257_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            60,
-            "utf32",
-            "utf32_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            61,
-            "utf32",
-            "utf32_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            62,
-            "utf16le",
-            "utf16le_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            63, "binary", "binary", true, 1, PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            64,
-            "armscii8",
-            "armscii8_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            65,
-            "ascii",
-            "ascii_bin",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            66,
-            "cp1250",
-            "cp1250_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            67,
-            "cp1256",
-            "cp1256_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            68,
-            "cp866",
-            "cp866_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            69, "dec8", "dec8_bin", false, 1, PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            70,
-            "greek",
-            "greek_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            71,
-            "hebrew",
-            "hebrew_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            72, "hp8", "hp8_bin", false, 1, PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            73,
-            "keybcs2",
-            "keybcs2_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            74,
-            "koi8r",
-            "koi8r_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            75,
-            "koi8u",
-            "koi8u_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            76,
-            "utf8",
-            "utf8_tolower_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            77,
-            "latin2",
-            "latin2_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            78,
-            "latin5",
-            "latin5_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            79,
-            "latin7",
-            "latin7_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            80,
-            "cp850",
-            "cp850_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            81,
-            "cp852",
-            "cp852_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            82, "swe7", "swe7_bin", false, 1, PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            83, "utf8", "utf8_bin", true, 1, PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            84, "big5", "big5_bin", false, 1, PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            85,
-            "euckr",
-            "euckr_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            86,
-            "gb2312",
-            "gb2312_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(87, "gbk", "gbk_bin", true, 1, PAD_SPACE));
-        c.push(Collation::new_ref(
-            88, "sjis", "sjis_bin", false, 1, PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            89,
-            "tis620",
-            "tis620_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            90, "ucs2", "ucs2_bin", false, 1, PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            91, "ujis", "ujis_bin", false, 1, PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            92,
-            "geostd8",
-            "geostd8_general_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            93,
-            "geostd8",
-            "geostd8_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            94,
-            "latin1",
-            "latin1_spanish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            95,
-            "cp932",
-            "cp932_japanese_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            96,
-            "cp932",
-            "cp932_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            97,
-            "eucjpms",
-            "eucjpms_japanese_ci",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            98,
-            "eucjpms",
-            "eucjpms_bin",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            99,
-            "cp1250",
-            "cp1250_polish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            101,
-            "utf16",
-            "utf16_unicode_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            102,
-            "utf16",
-            "utf16_icelandic_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            103,
-            "utf16",
-            "utf16_latvian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            104,
-            "utf16",
-            "utf16_romanian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            105,
-            "utf16",
-            "utf16_slovenian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            106,
-            "utf16",
-            "utf16_polish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            107,
-            "utf16",
-            "utf16_estonian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            108,
-            "utf16",
-            "utf16_spanish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            109,
-            "utf16",
-            "utf16_swedish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            110,
-            "utf16",
-            "utf16_turkish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            111,
-            "utf16",
-            "utf16_czech_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            112,
-            "utf16",
-            "utf16_danish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            113,
-            "utf16",
-            "utf16_lithuanian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            114,
-            "utf16",
-            "utf16_slovak_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            115,
-            "utf16",
-            "utf16_spanish2_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            116,
-            "utf16",
-            "utf16_roman_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            117,
-            "utf16",
-            "utf16_persian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            118,
-            "utf16",
-            "utf16_esperanto_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            119,
-            "utf16",
-            "utf16_hungarian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            120,
-            "utf16",
-            "utf16_sinhala_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            121,
-            "utf16",
-            "utf16_german2_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            122,
-            "utf16",
-            "utf16_croatian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            123,
-            "utf16",
-            "utf16_unicode_520_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            124,
-            "utf16",
-            "utf16_vietnamese_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            128,
-            "ucs2",
-            "ucs2_unicode_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            129,
-            "ucs2",
-            "ucs2_icelandic_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            130,
-            "ucs2",
-            "ucs2_latvian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            131,
-            "ucs2",
-            "ucs2_romanian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            132,
-            "ucs2",
-            "ucs2_slovenian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            133,
-            "ucs2",
-            "ucs2_polish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            134,
-            "ucs2",
-            "ucs2_estonian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            135,
-            "ucs2",
-            "ucs2_spanish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            136,
-            "ucs2",
-            "ucs2_swedish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            137,
-            "ucs2",
-            "ucs2_turkish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            138,
-            "ucs2",
-            "ucs2_czech_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            139,
-            "ucs2",
-            "ucs2_danish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            140,
-            "ucs2",
-            "ucs2_lithuanian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            141,
-            "ucs2",
-            "ucs2_slovak_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            142,
-            "ucs2",
-            "ucs2_spanish2_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            143,
-            "ucs2",
-            "ucs2_roman_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            144,
-            "ucs2",
-            "ucs2_persian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            145,
-            "ucs2",
-            "ucs2_esperanto_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            146,
-            "ucs2",
-            "ucs2_hungarian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            147,
-            "ucs2",
-            "ucs2_sinhala_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            148,
-            "ucs2",
-            "ucs2_german2_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            149,
-            "ucs2",
-            "ucs2_croatian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            150,
-            "ucs2",
-            "ucs2_unicode_520_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            151,
-            "ucs2",
-            "ucs2_vietnamese_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            159,
-            "ucs2",
-            "ucs2_general_mysql500_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            160,
-            "utf32",
-            "utf32_unicode_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            161,
-            "utf32",
-            "utf32_icelandic_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            162,
-            "utf32",
-            "utf32_latvian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            163,
-            "utf32",
-            "utf32_romanian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            164,
-            "utf32",
-            "utf32_slovenian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            165,
-            "utf32",
-            "utf32_polish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            166,
-            "utf32",
-            "utf32_estonian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            167,
-            "utf32",
-            "utf32_spanish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            168,
-            "utf32",
-            "utf32_swedish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            169,
-            "utf32",
-            "utf32_turkish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            170,
-            "utf32",
-            "utf32_czech_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            171,
-            "utf32",
-            "utf32_danish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            172,
-            "utf32",
-            "utf32_lithuanian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            173,
-            "utf32",
-            "utf32_slovak_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            174,
-            "utf32",
-            "utf32_spanish2_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            175,
-            "utf32",
-            "utf32_roman_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            176,
-            "utf32",
-            "utf32_persian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            177,
-            "utf32",
-            "utf32_esperanto_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            178,
-            "utf32",
-            "utf32_hungarian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            179,
-            "utf32",
-            "utf32_sinhala_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            180,
-            "utf32",
-            "utf32_german2_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            181,
-            "utf32",
-            "utf32_croatian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            182,
-            "utf32",
-            "utf32_unicode_520_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            183,
-            "utf32",
-            "utf32_vietnamese_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            192,
-            "utf8",
-            "utf8_unicode_ci",
-            false,
-            8,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            193,
-            "utf8",
-            "utf8_icelandic_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            194,
-            "utf8",
-            "utf8_latvian_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            195,
-            "utf8",
-            "utf8_romanian_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            196,
-            "utf8",
-            "utf8_slovenian_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            197,
-            "utf8",
-            "utf8_polish_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            198,
-            "utf8",
-            "utf8_estonian_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            199,
-            "utf8",
-            "utf8_spanish_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            200,
-            "utf8",
-            "utf8_swedish_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            201,
-            "utf8",
-            "utf8_turkish_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            202,
-            "utf8",
-            "utf8_czech_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            203,
-            "utf8",
-            "utf8_danish_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            204,
-            "utf8",
-            "utf8_lithuanian_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            205,
-            "utf8",
-            "utf8_slovak_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            206,
-            "utf8",
-            "utf8_spanish2_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            207,
-            "utf8",
-            "utf8_roman_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            208,
-            "utf8",
-            "utf8_persian_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            209,
-            "utf8",
-            "utf8_esperanto_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            210,
-            "utf8",
-            "utf8_hungarian_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            211,
-            "utf8",
-            "utf8_sinhala_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            212,
-            "utf8",
-            "utf8_german2_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            213,
-            "utf8",
-            "utf8_croatian_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            214,
-            "utf8",
-            "utf8_unicode_520_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            215,
-            "utf8",
-            "utf8_vietnamese_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            223,
-            "utf8",
-            "utf8_general_mysql500_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            224,
-            "utf8mb4",
-            "utf8mb4_unicode_ci",
-            false,
-            8,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            225,
-            "utf8mb4",
-            "utf8mb4_icelandic_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            226,
-            "utf8mb4",
-            "utf8mb4_latvian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            227,
-            "utf8mb4",
-            "utf8mb4_romanian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            228,
-            "utf8mb4",
-            "utf8mb4_slovenian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            229,
-            "utf8mb4",
-            "utf8mb4_polish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            230,
-            "utf8mb4",
-            "utf8mb4_estonian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            231,
-            "utf8mb4",
-            "utf8mb4_spanish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            232,
-            "utf8mb4",
-            "utf8mb4_swedish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            233,
-            "utf8mb4",
-            "utf8mb4_turkish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            234,
-            "utf8mb4",
-            "utf8mb4_czech_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            235,
-            "utf8mb4",
-            "utf8mb4_danish_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            236,
-            "utf8mb4",
-            "utf8mb4_lithuanian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            237,
-            "utf8mb4",
-            "utf8mb4_slovak_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            238,
-            "utf8mb4",
-            "utf8mb4_spanish2_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            239,
-            "utf8mb4",
-            "utf8mb4_roman_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            240,
-            "utf8mb4",
-            "utf8mb4_persian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            241,
-            "utf8mb4",
-            "utf8mb4_esperanto_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            242,
-            "utf8mb4",
-            "utf8mb4_hungarian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            243,
-            "utf8mb4",
-            "utf8mb4_sinhala_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            244,
-            "utf8mb4",
-            "utf8mb4_german2_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            245,
-            "utf8mb4",
-            "utf8mb4_croatian_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
+    pub static ref collations: Vec<Arc<Mutex<Collation>>> = vec![
+        Collation::new_ref(1, "big5", "big5_chinese_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(2, "latin2", "latin2_czech_cs", false, 1, PAD_SPACE),
+        Collation::new_ref(3, "dec8", "dec8_swedish_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(4, "cp850", "cp850_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(5, "latin1", "latin1_german1_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(6, "hp8", "hp8_english_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(7, "koi8r", "koi8r_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(8, "latin1", "latin1_swedish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(9, "latin2", "latin2_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(10, "swe7", "swe7_swedish_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(11, "ascii", "ascii_general_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(12, "ujis", "ujis_japanese_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(13, "sjis", "sjis_japanese_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(14, "cp1251", "cp1251_bulgarian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(15, "latin1", "latin1_danish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(16, "hebrew", "hebrew_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(18, "tis620", "tis620_thai_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(19, "euckr", "euckr_korean_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(20, "latin7", "latin7_estonian_cs", false, 1, PAD_SPACE),
+        Collation::new_ref(21, "latin2", "latin2_hungarian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(22, "koi8u", "koi8u_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(23, "cp1251", "cp1251_ukrainian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(24, "gb2312", "gb2312_chinese_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(25, "greek", "greek_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(26, "cp1250", "cp1250_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(27, "latin2", "latin2_croatian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(28, "gbk", "gbk_chinese_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(29, "cp1257", "cp1257_lithuanian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(30, "latin5", "latin5_turkish_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(31, "latin1", "latin1_german2_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(32, "armscii8", "armscii8_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(33, "utf8", "utf8_general_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(34, "cp1250", "cp1250_czech_cs", false, 1, PAD_SPACE),
+        Collation::new_ref(35, "ucs2", "ucs2_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(36, "cp866", "cp866_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(37, "keybcs2", "keybcs2_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(38, "macce", "macce_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(39, "macroman", "macroman_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(40, "cp852", "cp852_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(41, "latin7", "latin7_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(42, "latin7", "latin7_general_cs", false, 1, PAD_SPACE),
+        Collation::new_ref(43, "macce", "macce_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(44, "cp1250", "cp1250_croatian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(45, "utf8mb4", "utf8mb4_general_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(46, "utf8mb4", "utf8mb4_bin", true, 1, PAD_SPACE),
+        Collation::new_ref(47, "latin1", "latin1_bin", true, 1, PAD_SPACE),
+        Collation::new_ref(48, "latin1", "latin1_general_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(49, "latin1", "latin1_general_cs", false, 1, PAD_SPACE),
+        Collation::new_ref(50, "cp1251", "cp1251_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(51, "cp1251", "cp1251_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(52, "cp1251", "cp1251_general_cs", false, 1, PAD_SPACE),
+        Collation::new_ref(53, "macroman", "macroman_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(54, "utf16", "utf16_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(55, "utf16", "utf16_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(56, "utf16le", "utf16le_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(57, "cp1256", "cp1256_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(58, "cp1257", "cp1257_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(59, "cp1257", "cp1257_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(60, "utf32", "utf32_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(61, "utf32", "utf32_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(62, "utf16le", "utf16le_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(63, "binary", "binary", true, 1, PAD_NONE),
+        Collation::new_ref(64, "armscii8", "armscii8_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(65, "ascii", "ascii_bin", true, 1, PAD_SPACE),
+        Collation::new_ref(66, "cp1250", "cp1250_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(67, "cp1256", "cp1256_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(68, "cp866", "cp866_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(69, "dec8", "dec8_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(70, "greek", "greek_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(71, "hebrew", "hebrew_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(72, "hp8", "hp8_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(73, "keybcs2", "keybcs2_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(74, "koi8r", "koi8r_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(75, "koi8u", "koi8u_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(76, "utf8", "utf8_tolower_ci", false, 1, PAD_NONE),
+        Collation::new_ref(77, "latin2", "latin2_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(78, "latin5", "latin5_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(79, "latin7", "latin7_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(80, "cp850", "cp850_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(81, "cp852", "cp852_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(82, "swe7", "swe7_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(83, "utf8", "utf8_bin", true, 1, PAD_SPACE),
+        Collation::new_ref(84, "big5", "big5_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(85, "euckr", "euckr_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(86, "gb2312", "gb2312_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(87, "gbk", "gbk_bin", true, 1, PAD_SPACE),
+        Collation::new_ref(88, "sjis", "sjis_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(89, "tis620", "tis620_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(90, "ucs2", "ucs2_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(91, "ujis", "ujis_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(92, "geostd8", "geostd8_general_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(93, "geostd8", "geostd8_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(94, "latin1", "latin1_spanish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(95, "cp932", "cp932_japanese_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(96, "cp932", "cp932_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(97, "eucjpms", "eucjpms_japanese_ci", true, 1, PAD_SPACE),
+        Collation::new_ref(98, "eucjpms", "eucjpms_bin", false, 1, PAD_SPACE),
+        Collation::new_ref(99, "cp1250", "cp1250_polish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(101, "utf16", "utf16_unicode_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(102, "utf16", "utf16_icelandic_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(103, "utf16", "utf16_latvian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(104, "utf16", "utf16_romanian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(105, "utf16", "utf16_slovenian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(106, "utf16", "utf16_polish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(107, "utf16", "utf16_estonian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(108, "utf16", "utf16_spanish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(109, "utf16", "utf16_swedish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(110, "utf16", "utf16_turkish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(111, "utf16", "utf16_czech_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(112, "utf16", "utf16_danish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(113, "utf16", "utf16_lithuanian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(114, "utf16", "utf16_slovak_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(115, "utf16", "utf16_spanish2_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(116, "utf16", "utf16_roman_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(117, "utf16", "utf16_persian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(118, "utf16", "utf16_esperanto_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(119, "utf16", "utf16_hungarian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(120, "utf16", "utf16_sinhala_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(121, "utf16", "utf16_german2_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(122, "utf16", "utf16_croatian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(123, "utf16", "utf16_unicode_520_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(124, "utf16", "utf16_vietnamese_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(128, "ucs2", "ucs2_unicode_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(129, "ucs2", "ucs2_icelandic_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(130, "ucs2", "ucs2_latvian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(131, "ucs2", "ucs2_romanian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(132, "ucs2", "ucs2_slovenian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(133, "ucs2", "ucs2_polish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(134, "ucs2", "ucs2_estonian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(135, "ucs2", "ucs2_spanish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(136, "ucs2", "ucs2_swedish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(137, "ucs2", "ucs2_turkish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(138, "ucs2", "ucs2_czech_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(139, "ucs2", "ucs2_danish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(140, "ucs2", "ucs2_lithuanian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(141, "ucs2", "ucs2_slovak_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(142, "ucs2", "ucs2_spanish2_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(143, "ucs2", "ucs2_roman_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(144, "ucs2", "ucs2_persian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(145, "ucs2", "ucs2_esperanto_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(146, "ucs2", "ucs2_hungarian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(147, "ucs2", "ucs2_sinhala_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(148, "ucs2", "ucs2_german2_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(149, "ucs2", "ucs2_croatian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(150, "ucs2", "ucs2_unicode_520_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(151, "ucs2", "ucs2_vietnamese_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(159, "ucs2", "ucs2_general_mysql500_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(160, "utf32", "utf32_unicode_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(161, "utf32", "utf32_icelandic_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(162, "utf32", "utf32_latvian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(163, "utf32", "utf32_romanian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(164, "utf32", "utf32_slovenian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(165, "utf32", "utf32_polish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(166, "utf32", "utf32_estonian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(167, "utf32", "utf32_spanish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(168, "utf32", "utf32_swedish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(169, "utf32", "utf32_turkish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(170, "utf32", "utf32_czech_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(171, "utf32", "utf32_danish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(172, "utf32", "utf32_lithuanian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(173, "utf32", "utf32_slovak_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(174, "utf32", "utf32_spanish2_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(175, "utf32", "utf32_roman_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(176, "utf32", "utf32_persian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(177, "utf32", "utf32_esperanto_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(178, "utf32", "utf32_hungarian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(179, "utf32", "utf32_sinhala_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(180, "utf32", "utf32_german2_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(181, "utf32", "utf32_croatian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(182, "utf32", "utf32_unicode_520_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(183, "utf32", "utf32_vietnamese_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(192, "utf8", "utf8_unicode_ci", false, 8, PAD_SPACE),
+        Collation::new_ref(193, "utf8", "utf8_icelandic_ci", false, 1, PAD_NONE),
+        Collation::new_ref(194, "utf8", "utf8_latvian_ci", false, 1, PAD_NONE),
+        Collation::new_ref(195, "utf8", "utf8_romanian_ci", false, 1, PAD_NONE),
+        Collation::new_ref(196, "utf8", "utf8_slovenian_ci", false, 1, PAD_NONE),
+        Collation::new_ref(197, "utf8", "utf8_polish_ci", false, 1, PAD_NONE),
+        Collation::new_ref(198, "utf8", "utf8_estonian_ci", false, 1, PAD_NONE),
+        Collation::new_ref(199, "utf8", "utf8_spanish_ci", false, 1, PAD_NONE),
+        Collation::new_ref(200, "utf8", "utf8_swedish_ci", false, 1, PAD_NONE),
+        Collation::new_ref(201, "utf8", "utf8_turkish_ci", false, 1, PAD_NONE),
+        Collation::new_ref(202, "utf8", "utf8_czech_ci", false, 1, PAD_NONE),
+        Collation::new_ref(203, "utf8", "utf8_danish_ci", false, 1, PAD_NONE),
+        Collation::new_ref(204, "utf8", "utf8_lithuanian_ci", false, 1, PAD_NONE),
+        Collation::new_ref(205, "utf8", "utf8_slovak_ci", false, 1, PAD_NONE),
+        Collation::new_ref(206, "utf8", "utf8_spanish2_ci", false, 1, PAD_NONE),
+        Collation::new_ref(207, "utf8", "utf8_roman_ci", false, 1, PAD_NONE),
+        Collation::new_ref(208, "utf8", "utf8_persian_ci", false, 1, PAD_NONE),
+        Collation::new_ref(209, "utf8", "utf8_esperanto_ci", false, 1, PAD_NONE),
+        Collation::new_ref(210, "utf8", "utf8_hungarian_ci", false, 1, PAD_NONE),
+        Collation::new_ref(211, "utf8", "utf8_sinhala_ci", false, 1, PAD_NONE),
+        Collation::new_ref(212, "utf8", "utf8_german2_ci", false, 1, PAD_NONE),
+        Collation::new_ref(213, "utf8", "utf8_croatian_ci", false, 1, PAD_NONE),
+        Collation::new_ref(214, "utf8", "utf8_unicode_520_ci", false, 1, PAD_NONE),
+        Collation::new_ref(215, "utf8", "utf8_vietnamese_ci", false, 1, PAD_NONE),
+        Collation::new_ref(223, "utf8", "utf8_general_mysql500_ci", false, 1, PAD_NONE),
+        Collation::new_ref(224, "utf8mb4", "utf8mb4_unicode_ci", false, 8, PAD_SPACE),
+        Collation::new_ref(225, "utf8mb4", "utf8mb4_icelandic_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(226, "utf8mb4", "utf8mb4_latvian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(227, "utf8mb4", "utf8mb4_romanian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(228, "utf8mb4", "utf8mb4_slovenian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(229, "utf8mb4", "utf8mb4_polish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(230, "utf8mb4", "utf8mb4_estonian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(231, "utf8mb4", "utf8mb4_spanish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(232, "utf8mb4", "utf8mb4_swedish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(233, "utf8mb4", "utf8mb4_turkish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(234, "utf8mb4", "utf8mb4_czech_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(235, "utf8mb4", "utf8mb4_danish_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(236, "utf8mb4", "utf8mb4_lithuanian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(237, "utf8mb4", "utf8mb4_slovak_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(238, "utf8mb4", "utf8mb4_spanish2_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(239, "utf8mb4", "utf8mb4_roman_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(240, "utf8mb4", "utf8mb4_persian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(241, "utf8mb4", "utf8mb4_esperanto_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(242, "utf8mb4", "utf8mb4_hungarian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(243, "utf8mb4", "utf8mb4_sinhala_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(244, "utf8mb4", "utf8mb4_german2_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(245, "utf8mb4", "utf8mb4_croatian_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(
             246,
             "utf8mb4",
             "utf8mb4_unicode_520_ci",
             false,
             1,
             PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            247,
-            "utf8mb4",
-            "utf8mb4_vietnamese_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            248,
-            "gb18030",
-            "gb18030_chinese_ci",
-            false,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            249,
-            "gb18030",
-            "gb18030_bin",
-            true,
-            1,
-            PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
+        ),
+        Collation::new_ref(247, "utf8mb4", "utf8mb4_vietnamese_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(248, "gb18030", "gb18030_chinese_ci", false, 1, PAD_SPACE),
+        Collation::new_ref(249, "gb18030", "gb18030_bin", true, 1, PAD_SPACE),
+        Collation::new_ref(
             250,
             "gb18030",
             "gb18030_unicode_520_ci",
             false,
             1,
             PAD_SPACE,
-        ));
-        c.push(Collation::new_ref(
-            255,
-            "utf8mb4",
-            "utf8mb4_0900_ai_ci",
-            false,
-            0,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
+        ),
+        Collation::new_ref(255, "utf8mb4", "utf8mb4_0900_ai_ci", false, 0, PAD_NONE),
+        Collation::new_ref(
             256,
             "utf8mb4",
             "utf8mb4_de_pb_0900_ai_ci",
             false,
             1,
             PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            257,
-            "utf8mb4",
-            "utf8mb4_is_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            258,
-            "utf8mb4",
-            "utf8mb4_lv_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            259,
-            "utf8mb4",
-            "utf8mb4_ro_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            260,
-            "utf8mb4",
-            "utf8mb4_sl_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            261,
-            "utf8mb4",
-            "utf8mb4_pl_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            262,
-            "utf8mb4",
-            "utf8mb4_et_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            263,
-            "utf8mb4",
-            "utf8mb4_es_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            264,
-            "utf8mb4",
-            "utf8mb4_sv_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            265,
-            "utf8mb4",
-            "utf8mb4_tr_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            266,
-            "utf8mb4",
-            "utf8mb4_cs_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            267,
-            "utf8mb4",
-            "utf8mb4_da_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            268,
-            "utf8mb4",
-            "utf8mb4_lt_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            269,
-            "utf8mb4",
-            "utf8mb4_sk_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
+        ),
+        Collation::new_ref(257, "utf8mb4", "utf8mb4_is_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(258, "utf8mb4", "utf8mb4_lv_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(259, "utf8mb4", "utf8mb4_ro_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(260, "utf8mb4", "utf8mb4_sl_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(261, "utf8mb4", "utf8mb4_pl_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(262, "utf8mb4", "utf8mb4_et_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(263, "utf8mb4", "utf8mb4_es_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(264, "utf8mb4", "utf8mb4_sv_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(265, "utf8mb4", "utf8mb4_tr_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(266, "utf8mb4", "utf8mb4_cs_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(267, "utf8mb4", "utf8mb4_da_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(268, "utf8mb4", "utf8mb4_lt_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(269, "utf8mb4", "utf8mb4_sk_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(
             270,
             "utf8mb4",
             "utf8mb4_es_trad_0900_ai_ci",
             false,
             1,
             PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            271,
-            "utf8mb4",
-            "utf8mb4_la_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            273,
-            "utf8mb4",
-            "utf8mb4_eo_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            274,
-            "utf8mb4",
-            "utf8mb4_hu_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            275,
-            "utf8mb4",
-            "utf8mb4_hr_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            277,
-            "utf8mb4",
-            "utf8mb4_vi_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            278,
-            "utf8mb4",
-            "utf8mb4_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
+        ),
+        Collation::new_ref(271, "utf8mb4", "utf8mb4_la_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(273, "utf8mb4", "utf8mb4_eo_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(274, "utf8mb4", "utf8mb4_hu_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(275, "utf8mb4", "utf8mb4_hr_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(277, "utf8mb4", "utf8mb4_vi_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(278, "utf8mb4", "utf8mb4_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(
             279,
             "utf8mb4",
             "utf8mb4_de_pb_0900_as_cs",
             false,
             1,
             PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            280,
-            "utf8mb4",
-            "utf8mb4_is_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            281,
-            "utf8mb4",
-            "utf8mb4_lv_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            282,
-            "utf8mb4",
-            "utf8mb4_ro_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            283,
-            "utf8mb4",
-            "utf8mb4_sl_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            284,
-            "utf8mb4",
-            "utf8mb4_pl_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            285,
-            "utf8mb4",
-            "utf8mb4_et_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            286,
-            "utf8mb4",
-            "utf8mb4_es_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            287,
-            "utf8mb4",
-            "utf8mb4_sv_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            288,
-            "utf8mb4",
-            "utf8mb4_tr_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            289,
-            "utf8mb4",
-            "utf8mb4_cs_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            290,
-            "utf8mb4",
-            "utf8mb4_da_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            291,
-            "utf8mb4",
-            "utf8mb4_lt_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            292,
-            "utf8mb4",
-            "utf8mb4_sk_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
+        ),
+        Collation::new_ref(280, "utf8mb4", "utf8mb4_is_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(281, "utf8mb4", "utf8mb4_lv_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(282, "utf8mb4", "utf8mb4_ro_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(283, "utf8mb4", "utf8mb4_sl_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(284, "utf8mb4", "utf8mb4_pl_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(285, "utf8mb4", "utf8mb4_et_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(286, "utf8mb4", "utf8mb4_es_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(287, "utf8mb4", "utf8mb4_sv_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(288, "utf8mb4", "utf8mb4_tr_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(289, "utf8mb4", "utf8mb4_cs_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(290, "utf8mb4", "utf8mb4_da_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(291, "utf8mb4", "utf8mb4_lt_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(292, "utf8mb4", "utf8mb4_sk_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(
             293,
             "utf8mb4",
             "utf8mb4_es_trad_0900_as_cs",
             false,
             1,
             PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            294,
-            "utf8mb4",
-            "utf8mb4_la_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            296,
-            "utf8mb4",
-            "utf8mb4_eo_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            297,
-            "utf8mb4",
-            "utf8mb4_hu_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            298,
-            "utf8mb4",
-            "utf8mb4_hr_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            300,
-            "utf8mb4",
-            "utf8mb4_vi_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            303,
-            "utf8mb4",
-            "utf8mb4_ja_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
+        ),
+        Collation::new_ref(294, "utf8mb4", "utf8mb4_la_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(296, "utf8mb4", "utf8mb4_eo_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(297, "utf8mb4", "utf8mb4_hu_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(298, "utf8mb4", "utf8mb4_hr_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(300, "utf8mb4", "utf8mb4_vi_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(303, "utf8mb4", "utf8mb4_ja_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(
             304,
             "utf8mb4",
             "utf8mb4_ja_0900_as_cs_ks",
             false,
             1,
             PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            305,
-            "utf8mb4",
-            "utf8mb4_0900_as_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            306,
-            "utf8mb4",
-            "utf8mb4_ru_0900_ai_ci",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            307,
-            "utf8mb4",
-            "utf8mb4_ru_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            308,
-            "utf8mb4",
-            "utf8mb4_zh_0900_as_cs",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
-            309,
-            "utf8mb4",
-            "utf8mb4_0900_bin",
-            false,
-            1,
-            PAD_NONE,
-        ));
-        c.push(Collation::new_ref(
+        ),
+        Collation::new_ref(305, "utf8mb4", "utf8mb4_0900_as_ci", false, 1, PAD_NONE),
+        Collation::new_ref(306, "utf8mb4", "utf8mb4_ru_0900_ai_ci", false, 1, PAD_NONE),
+        Collation::new_ref(307, "utf8mb4", "utf8mb4_ru_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(308, "utf8mb4", "utf8mb4_zh_0900_as_cs", false, 1, PAD_NONE),
+        Collation::new_ref(309, "utf8mb4", "utf8mb4_0900_bin", false, 1, PAD_NONE),
+        Collation::new_ref(
             2048,
             "utf8mb4",
             "utf8mb4_zh_pinyin_tidb_as_cs",
             false,
             1,
             PAD_NONE,
-        ));
-
-        c
-    };
+        ),
+    ];
     pub static ref character_set_infos: HashMap<String, Arc<Mutex<Charset>>> = {
         let mut m = HashMap::<String, Arc<Mutex<Charset>>>::new();
         m.insert(
@@ -2449,12 +643,14 @@ lazy_static! {
         );
 
         for c in collations.iter() {
-            if let Some(charset) = m.get(&c.lock().unwrap().charset_name) {
-                charset
-                    .lock()
-                    .unwrap()
-                    .collations
-                    .insert(c.lock().unwrap().name.clone(), c.clone());
+            // The collation is locked once, the guard of the `if let`
+            // scrutinee would live until the end of the block otherwise.
+            let (charset_name, name) = {
+                let collation = c.lock().unwrap();
+                (collation.charset_name.clone(), collation.name.clone())
+            };
+            if let Some(charset) = m.get(&charset_name) {
+                charset.lock().unwrap().collations.insert(name, c.clone());
             }
         }
 
@@ -2874,12 +1070,14 @@ lazy_static! {
         );
 
         for c in collations.iter() {
-            if let Some(charset) = m.get(&c.lock().unwrap().charset_name) {
-                charset
-                    .lock()
-                    .unwrap()
-                    .collations
-                    .insert(c.lock().unwrap().name.clone(), c.clone());
+            // The collation is locked once, the guard of the `if let`
+            // scrutinee would live until the end of the block otherwise.
+            let (charset_name, name) = {
+                let collation = c.lock().unwrap();
+                (collation.charset_name.clone(), collation.name.clone())
+            };
+            if let Some(charset) = m.get(&charset_name) {
+                charset.lock().unwrap().collations.insert(name, c.clone());
             }
         }
 
@@ -2,6 +2,7 @@ use crate::charset::encoding_ascii::EncodingASCII;
 use crate::charset::encoding_bin::EncodingBin;
 use crate::charset::encoding_gbk::EncodingGBK;
 use crate::charset::encoding_latin1::EncodingLatin1;
+use crate::charset::encoding_lazy_static::{encoding_bin_impl, encoding_map};
 use crate::charset::encoding_trait::EncodingTrait;
 use crate::charset::encoding_utf8::EncodingUTF8;
 use crate::charset::encoding_utf8mb3_strict::EncodingUtf8Mb3Strict;
@@ -135,6 +136,15 @@ impl EncodingTrait for Encoding {
     }
 }
 
+// FindEncoding finds the encoding according to charset, the binary encoding
+// is returned for an unknown charset.
+pub fn find_encoding(charset: &str) -> &'static Encoding {
+    match encoding_map.get(charset.to_lowercase().as_str()) {
+        Some(enc) => enc,
+        None => &encoding_bin_impl,
+    }
+}
+
 #[allow(dead_code)]
 pub enum EncodingTp {
     None,
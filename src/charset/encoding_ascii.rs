@@ -6,7 +6,6 @@ use crate::charset::encoding::{
 use crate::charset::encoding_lazy_static::encoding_utf8_impl;
 use crate::charset::encoding_trait::{generate_encoding_err, EncodingTrait};
 use crate::common::error::CustomError;
-use logos::Source;
 
 #[allow(dead_code)]
 pub struct EncodingASCII {
@@ -23,7 +22,7 @@ impl EncodingTrait for EncodingASCII {
     }
 
     fn peek(&self, src: &[u8]) -> Vec<u8> {
-        if src.len() == 0 {
+        if src.is_empty() {
             return src.to_vec();
         }
 
@@ -43,7 +42,7 @@ impl EncodingTrait for EncodingASCII {
         src.iter().all(|&b| b <= 127)
     }
 
-    fn foreach<F>(&self, src: &[u8], _: i16, f: F)
+    fn foreach<F>(&self, src: &[u8], _: i16, mut f: F)
     where
         F: FnMut(&[u8], &[u8], bool) -> bool,
     {
@@ -87,7 +86,7 @@ impl EncodingTrait for EncodingASCII {
                     return false;
                 }
                 if op & OP_TRUNCATE_REPLACE != 0 {
-                    dest.push('?' as u8);
+                    dest.push(b'?');
                     return true;
                 }
             }
@@ -98,10 +97,10 @@ impl EncodingTrait for EncodingASCII {
                 dest.extend_from_slice(to);
             }
 
-            return true;
+            true
         };
 
-        self.foreach(&src, op, callback);
+        self.foreach(src, op, callback);
 
         Ok(dest.to_vec())
     }
@@ -18,7 +18,7 @@ impl EncodingTrait for EncodingBin {
     }
 
     fn peek(&self, src: &[u8]) -> Vec<u8> {
-        if src.len() == 0 {
+        if src.is_empty() {
             return src.to_vec();
         }
 
@@ -38,7 +38,7 @@ impl EncodingTrait for EncodingBin {
         true
     }
 
-    fn foreach<F>(&self, src: &[u8], _: i16, f: F)
+    fn foreach<F>(&self, src: &[u8], _: i16, mut f: F)
     where
         F: FnMut(&[u8], &[u8], bool) -> bool,
     {
@@ -26,7 +26,7 @@ impl EncodingTrait for EncodingGBK {
 
     fn peek(&self, src: &[u8]) -> Vec<u8> {
         let mut char_len = 2;
-        if src.len() == 0 || src[0] < 0x80 {
+        if src.is_empty() || src[0] < 0x80 {
             char_len = 1
         }
         if char_len < src.len() {
@@ -43,11 +43,10 @@ impl EncodingTrait for EncodingGBK {
             return 0;
         }
 
-        if 0x81 <= bs[0] && bs[0] <= 0xfe {
-            if (0x40 <= bs[1] && bs[1] <= 0x7e) || (0x80 <= bs[1] && bs[1] <= 0xfe) {
+        if 0x81 <= bs[0] && bs[0] <= 0xfe
+            && ((0x40 <= bs[1] && bs[1] <= 0x7e) || (0x80 <= bs[1] && bs[1] <= 0xfe)) {
                 return 2;
             }
-        }
 
         0
     }
@@ -56,7 +55,7 @@ impl EncodingTrait for EncodingGBK {
         std::str::from_utf8(src).is_ok()
     }
 
-    fn foreach<F>(&self, src: &[u8], op: i16, f: F)
+    fn foreach<F>(&self, src: &[u8], op: i16, mut f: F)
     where
         F: FnMut(&[u8], &[u8], bool) -> bool,
     {
@@ -102,7 +101,7 @@ impl EncodingTrait for EncodingGBK {
                     return false;
                 }
                 if op & OP_TRUNCATE_REPLACE != 0 {
-                    dest.push('?' as u8);
+                    dest.push(b'?');
                     return true;
                 }
             }
@@ -113,12 +112,12 @@ impl EncodingTrait for EncodingGBK {
                 dest.extend_from_slice(to);
             }
 
-            return true;
+            true
         };
 
-        self.foreach(&src, op, callback);
+        self.foreach(src, op, callback);
 
-        let _ = err?;
+        err?;
 
         Ok(dest.to_vec())
     }
@@ -139,7 +138,7 @@ impl EncodingGBK {
         src: &[u8],
         at_eof: bool,
     ) -> Result<(usize, usize), CustomError> {
-        if src.len() == 0 {
+        if src.is_empty() {
             return Ok((0, 0));
         }
 
@@ -1579,7 +1579,7 @@ lazy_static! {
 #[allow(dead_code)]
 pub fn lookup(label: &str) -> Option<&'static EncodingName> {
     let key = label
-        .trim_matches(&['\t', '\n', '\t', char::from(12), ' '])
+        .trim_matches(['\t', '\n', '\t', char::from(12), ' '])
         .to_string();
     encodings.get(&key)
 }
@@ -42,6 +42,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::vec_init_then_push)]
     fn gbk_coding_test() -> Result<(), CustomError> {
         let enc = &encoding_gbk_impl;
         let mut gbk_cases = Vec::new();
@@ -131,8 +132,8 @@ mod tests {
             println!("gbk case [{}]: {:?}", i, &tc);
             let result2 = enc.transform(&mut vec![], tc.utf8_str.as_bytes(), OP_DECODE_REPLACE);
             if tc.is_valid {
-                if result2.is_err() {
-                    return Err(result2.unwrap_err());
+                if let Err(err) = result2 {
+                    return Err(err);
                 }
             } else {
                 match result2 {
@@ -155,6 +156,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::vec_init_then_push)]
     fn utf8_encoding_test() -> Result<(), CustomError> {
         let enc = &encoding_gbk_impl;
 
@@ -199,8 +201,8 @@ mod tests {
             println!("utf8 case [{}]: {:?}", i, &tc);
             let result2 = enc.transform(&mut vec![], tc.utf8_str.as_bytes(), OP_ENCODE_REPLACE);
             if tc.is_valid {
-                if result2.is_err() {
-                    return Err(result2.unwrap_err());
+                if let Err(err) = result2 {
+                    return Err(err);
                 }
             } else {
                 match result2 {
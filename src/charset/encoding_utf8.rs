@@ -22,7 +22,7 @@ impl EncodingTrait for EncodingUTF8 {
 
     fn peek(&self, src: &[u8]) -> Vec<u8> {
         let mut next_len = 4;
-        if src.len() == 0 || src[0] < 0x80 {
+        if src.is_empty() || src[0] < 0x80 {
             next_len = 1
         } else if src[0] < 0xe0 {
             next_len = 2;
@@ -50,7 +50,7 @@ impl EncodingTrait for EncodingUTF8 {
         std::str::from_utf8(src).is_ok()
     }
 
-    fn foreach<F>(&self, src: &[u8], _: i16, f: F)
+    fn foreach<F>(&self, src: &[u8], _: i16, mut f: F)
     where
         F: FnMut(&[u8], &[u8], bool) -> bool,
     {
@@ -101,7 +101,7 @@ impl EncodingTrait for EncodingUTF8 {
                     return false;
                 }
                 if op & OP_TRUNCATE_REPLACE != 0 {
-                    dest.push('?' as u8);
+                    dest.push(b'?');
                     return true;
                 }
             }
@@ -112,10 +112,10 @@ impl EncodingTrait for EncodingUTF8 {
                 dest.extend_from_slice(to);
             }
 
-            return true;
+            true
         };
 
-        self.foreach(&src, op, callback);
+        self.foreach(src, op, callback);
 
         Ok(dest.to_vec())
     }
@@ -24,7 +24,7 @@ impl EncodingTrait for EncodingUtf8Mb3Strict {
 
     fn peek(&self, src: &[u8]) -> Vec<u8> {
         let mut next_len = 4;
-        if src.len() == 0 || src[0] < 0x80 {
+        if src.is_empty() || src[0] < 0x80 {
             next_len = 1
         } else if src[0] < 0xe0 {
             next_len = 2;
@@ -52,7 +52,7 @@ impl EncodingTrait for EncodingUtf8Mb3Strict {
         std::str::from_utf8(src).is_ok()
     }
 
-    fn foreach<F>(&self, src: &[u8], _: i16, f: F)
+    fn foreach<F>(&self, src: &[u8], _: i16, mut f: F)
     where
         F: FnMut(&[u8], &[u8], bool) -> bool,
     {
@@ -103,7 +103,7 @@ impl EncodingTrait for EncodingUtf8Mb3Strict {
                     return false;
                 }
                 if op & OP_TRUNCATE_REPLACE != 0 {
-                    dest.push('?' as u8);
+                    dest.push(b'?');
                     return true;
                 }
             }
@@ -114,10 +114,10 @@ impl EncodingTrait for EncodingUtf8Mb3Strict {
                 dest.extend_from_slice(to);
             }
 
-            return true;
+            true
         };
 
-        self.foreach(&src, op, callback);
+        self.foreach(src, op, callback);
 
         Ok(dest.to_vec())
     }
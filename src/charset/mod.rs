@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 pub mod charset;
 pub mod encoding;
 pub mod encoding_ascii;
@@ -5,7 +6,7 @@ pub mod encoding_bin;
 pub mod encoding_gbk;
 pub mod encoding_latin1;
 mod encoding_lazy_static;
-mod encoding_table;
+pub mod encoding_table;
 mod encoding_test;
 pub mod encoding_trait;
 pub mod encoding_utf8;
@@ -20,7 +20,7 @@ use std::num::ParseIntError;
 
 pub type Result<T> = std::result::Result<T, ParseError>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParseError(pub Span, pub String);
 
 impl ParseError {
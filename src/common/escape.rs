@@ -0,0 +1,81 @@
+// Escape sequences of string literals.
+// See https://dev.mysql.com/doc/refman/8.0/en/string-literals.html
+
+/// Returns the value of a quoted string literal, the escape sequences and the
+/// doubled quotes are replaced by the characters they stand for. `\%` and
+/// `\_` are kept as they are, so they still escape the LIKE wildcards, and
+/// `\` is an ordinary character if `no_backslash_escapes` is true.
+pub fn unescape_string(quoted: &str, no_backslash_escapes: bool) -> String {
+    let mut chars = quoted.chars();
+    let quote = match chars.next() {
+        Some(quote) => quote,
+        None => return String::new(),
+    };
+    let content = &quoted[quote.len_utf8()..quoted.len() - quote.len_utf8()];
+
+    let mut s = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if !no_backslash_escapes => match chars.next() {
+                Some('0') => s.push('\0'),
+                Some('b') => s.push('\x08'),
+                Some('n') => s.push('\n'),
+                Some('r') => s.push('\r'),
+                Some('t') => s.push('\t'),
+                Some('Z') => s.push('\x1a'),
+                Some(c @ ('%' | '_')) => {
+                    s.push('\\');
+                    s.push(c);
+                }
+                Some(c) => s.push(c),
+                None => s.push('\\'),
+            },
+            c if c == quote && chars.peek() == Some(&quote) => {
+                chars.next();
+                s.push(quote);
+            }
+            c => s.push(c),
+        }
+    }
+    s
+}
+
+/// Returns the length of the quoted string literal at the start of `s`, or
+/// None if it isn't terminated.
+pub fn quoted_string_len(s: &str, no_backslash_escapes: bool) -> Option<usize> {
+    let mut chars = s.char_indices();
+    let quote = chars.next()?.1;
+    while let Some((_, c)) = chars.next() {
+        if c == '\\' && !no_backslash_escapes {
+            chars.next();
+        } else if c == quote {
+            match chars.clone().next() {
+                Some((_, next)) if next == quote => {
+                    chars.next();
+                }
+                Some((n, _)) => return Some(n),
+                None => return Some(s.len()),
+            }
+        }
+    }
+    None
+}
+
+/// Returns the string with the backslash, NUL, `\n`, `\r` and `\Z` written as
+/// escape sequences, so that it reads back as the same value when quoted.
+/// The quotes are left to the caller.
+pub fn escape_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\0' => escaped.push_str("\\0"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\x1a' => escaped.push_str("\\Z"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,155 @@
+// JSON path expressions select values of a JSON document, e.g. `$.a[0].b`.
+// See https://dev.mysql.com/doc/refman/8.0/en/json.html#json-path-syntax
+
+/// Checks the syntax of a JSON path expression.
+pub fn is_valid_json_path(path: &str) -> bool {
+    let mut cursor = Cursor {
+        bytes: path.as_bytes(),
+        pos: 0,
+    };
+    cursor.skip_whitespace();
+    if !cursor.eat(b'$') {
+        return false;
+    }
+
+    // `**` must be followed by a member or an array location.
+    let mut after_double_asterisk = false;
+    loop {
+        cursor.skip_whitespace();
+        let valid = match cursor.next() {
+            None => return !after_double_asterisk,
+            Some(b'.') => {
+                after_double_asterisk = false;
+                cursor.member()
+            }
+            Some(b'[') => {
+                after_double_asterisk = false;
+                cursor.array_location()
+            }
+            Some(b'*') if !after_double_asterisk => {
+                after_double_asterisk = true;
+                cursor.eat(b'*')
+            }
+            _ => false,
+        };
+        if !valid {
+            return false;
+        }
+    }
+}
+
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let b = self.peek();
+        if b.is_some() {
+            self.pos += 1;
+        }
+        b
+    }
+
+    fn eat(&mut self, b: u8) -> bool {
+        if self.peek() == Some(b) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let end = self.pos + keyword.len();
+        match self.bytes.get(self.pos..end) {
+            Some(s) if s.eq_ignore_ascii_case(keyword.as_bytes()) => {
+                self.pos = end;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b) if b.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    // Member is `.*`, `.key` or `."key"`, the key name is an ECMAScript
+    // identifier unless quoted.
+    fn member(&mut self) -> bool {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'*') => {
+                self.pos += 1;
+                true
+            }
+            Some(b'"') => {
+                self.pos += 1;
+                while let Some(b) = self.next() {
+                    match b {
+                        b'\\' => {
+                            self.pos += 1;
+                        }
+                        b'"' => return true,
+                        _ => {}
+                    }
+                }
+                false
+            }
+            _ => {
+                let start = self.pos;
+                while matches!(
+                    self.peek(),
+                    Some(b) if b.is_ascii_alphanumeric() || b == b'_' || b == b'$' || b >= 0x80
+                ) {
+                    self.pos += 1;
+                }
+                self.pos > start && !self.bytes[start].is_ascii_digit()
+            }
+        }
+    }
+
+    // ArrayLocation is `[*]`, `[n]`, `[last]`, `[last-n]` or `[m to n]`.
+    fn array_location(&mut self) -> bool {
+        self.skip_whitespace();
+        if self.eat(b'*') {
+            self.skip_whitespace();
+            return self.eat(b']');
+        }
+        if !self.array_index() {
+            return false;
+        }
+        self.skip_whitespace();
+        if self.eat_keyword("to") {
+            self.skip_whitespace();
+            if !self.array_index() {
+                return false;
+            }
+            self.skip_whitespace();
+        }
+        self.eat(b']')
+    }
+
+    fn array_index(&mut self) -> bool {
+        if self.eat_keyword("last") {
+            self.skip_whitespace();
+            if !self.eat(b'-') {
+                return true;
+            }
+            self.skip_whitespace();
+        }
+        let start = self.pos;
+        while matches!(self.peek(), Some(b) if b.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        self.pos > start
+    }
+}
@@ -264,6 +264,7 @@ lazy_static! {
         s.insert("DUPLICATE".to_string(), TokenKind::DUPLICATE);
         s.insert("DURATION".to_string(), TokenKind::DURATION);
         s.insert("DYNAMIC".to_string(), TokenKind::DYNAMIC);
+        s.insert("EMPTY".to_string(), TokenKind::EMPTY);
         s.insert("ELSE".to_string(), TokenKind::ELSE);
         s.insert("ELSEIF".to_string(), TokenKind::ELSEIF);
         s.insert("ENABLE".to_string(), TokenKind::ENABLE);
@@ -334,6 +335,8 @@ lazy_static! {
         s.insert("FUNCTION".to_string(), TokenKind::FUNCTION);
         s.insert("GC_TTL".to_string(), TokenKind::GC_TTL);
         s.insert("GENERAL".to_string(), TokenKind::GENERAL);
+        s.insert("GEOMETRY".to_string(), TokenKind::GEOMETRY);
+        s.insert("GEOMETRYCOLLECTION".to_string(), TokenKind::GEOMETRYCOLLECTION);
         s.insert("GENERATED".to_string(), TokenKind::GENERATED);
         s.insert("GET_FORMAT".to_string(), TokenKind::GET_FORMAT);
         s.insert("GLOBAL".to_string(), TokenKind::GLOBAL);
@@ -411,6 +414,7 @@ lazy_static! {
         s.insert("JSON_ARRAYAGG".to_string(), TokenKind::JSON_ARRAYAGG);
         s.insert("JSON_OBJECTAGG".to_string(), TokenKind::JSON_OBJECTAGG);
         s.insert("JSON".to_string(), TokenKind::JSON);
+        s.insert("JSON_TABLE".to_string(), TokenKind::JSON_TABLE);
         s.insert("KEY_BLOCK_SIZE".to_string(), TokenKind::KEY_BLOCK_SIZE);
         s.insert("KEY".to_string(), TokenKind::KEY);
         s.insert("KEYS".to_string(), TokenKind::KEYS);
@@ -420,6 +424,7 @@ lazy_static! {
         s.insert("LAST_BACKUP".to_string(), TokenKind::LAST_BACKUP);
         s.insert("LAST".to_string(), TokenKind::LAST);
         s.insert("LASTVAL".to_string(), TokenKind::LASTVAL);
+        s.insert("LATERAL".to_string(), TokenKind::LATERAL);
         s.insert("LEADER".to_string(), TokenKind::LEADER);
         s.insert(
             "LEADER_CONSTRAINTS".to_string(),
@@ -435,6 +440,7 @@ lazy_static! {
         s.insert("LEAVE".to_string(), TokenKind::LEAVE);
         s.insert("LEFT".to_string(), TokenKind::LEFT);
         s.insert("LESS".to_string(), TokenKind::LESS);
+        s.insert("LINESTRING".to_string(), TokenKind::LINESTRING);
         s.insert("LEVEL".to_string(), TokenKind::LEVEL);
         s.insert("LIKE".to_string(), TokenKind::LIKE);
         s.insert("LIMIT".to_string(), TokenKind::LIMIT);
@@ -500,10 +506,14 @@ lazy_static! {
         s.insert("MODE".to_string(), TokenKind::MODE);
         s.insert("MODIFY".to_string(), TokenKind::MODIFY);
         s.insert("MONTH".to_string(), TokenKind::MONTH);
+        s.insert("MULTILINESTRING".to_string(), TokenKind::MULTILINESTRING);
+        s.insert("MULTIPOINT".to_string(), TokenKind::MULTIPOINT);
+        s.insert("MULTIPOLYGON".to_string(), TokenKind::MULTIPOLYGON);
         s.insert("NAMES".to_string(), TokenKind::NAMES);
         s.insert("NATIONAL".to_string(), TokenKind::NATIONAL);
         s.insert("NATURAL".to_string(), TokenKind::NATURAL);
         s.insert("NCHAR".to_string(), TokenKind::NCHAR);
+        s.insert("NESTED".to_string(), TokenKind::NESTED);
         s.insert("NEVER".to_string(), TokenKind::NEVER);
         s.insert("NEXT_ROW_ID".to_string(), TokenKind::NEXT_ROW_ID);
         s.insert("NEXT".to_string(), TokenKind::NEXT);
@@ -549,6 +559,7 @@ lazy_static! {
         s.insert("OPTIMIZE".to_string(), TokenKind::OPTIMIZE);
         s.insert("OPTION".to_string(), TokenKind::OPTION);
         s.insert("OPTIONAL".to_string(), TokenKind::OPTIONAL);
+        s.insert("ORDINALITY".to_string(), TokenKind::ORDINALITY);
         s.insert("OPTIONALLY".to_string(), TokenKind::OPTIONALLY);
         s.insert("OR".to_string(), TokenKind::OR);
         s.insert("ORDER".to_string(), TokenKind::ORDER);
@@ -574,6 +585,7 @@ lazy_static! {
         s.insert("PLUGINS".to_string(), TokenKind::PLUGINS);
         s.insert("POINT".to_string(), TokenKind::POINT);
         s.insert("POLICY".to_string(), TokenKind::POLICY);
+        s.insert("POLYGON".to_string(), TokenKind::POLYGON);
         s.insert("POSITION".to_string(), TokenKind::POSITION);
         s.insert(
             "PRE_SPLIT_REGIONS".to_string(),
@@ -584,6 +596,8 @@ lazy_static! {
         s.insert("PRECISION".to_string(), TokenKind::PRECISION);
         s.insert("PREPARE".to_string(), TokenKind::PREPARE);
         s.insert("PRESERVE".to_string(), TokenKind::PRESERVE);
+        s.insert("PREV".to_string(), TokenKind::PREV);
+        s.insert("PREVIOUS".to_string(), TokenKind::PREVIOUS);
         s.insert("PRIMARY".to_string(), TokenKind::PRIMARY);
         s.insert("PRIMARY_REGION".to_string(), TokenKind::PRIMARY_REGION);
         s.insert("PRIVILEGES".to_string(), TokenKind::PRIVILEGES);
@@ -707,6 +721,7 @@ lazy_static! {
         s.insert("SMALLINT".to_string(), TokenKind::SMALLINT);
         s.insert("SNAPSHOT".to_string(), TokenKind::SNAPSHOT);
         s.insert("SOME".to_string(), TokenKind::SOME);
+        s.insert("SOUNDS".to_string(), TokenKind::SOUNDS);
         s.insert("SOURCE".to_string(), TokenKind::SOURCE);
         s.insert("SPATIAL".to_string(), TokenKind::SPATIAL);
         s.insert("SPLIT".to_string(), TokenKind::SPLIT);
@@ -922,6 +937,7 @@ lazy_static! {
             "PASSWORD_LOCK_TIME".to_string(),
             TokenKind::PASSWORD_LOCK_TIME,
         );
+        s.insert("PATH".to_string(), TokenKind::PATH);
         s.insert("REUSE".to_string(), TokenKind::REUSE);
 
         s
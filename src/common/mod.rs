@@ -1,4 +1,6 @@
 pub mod error;
+pub mod escape;
+pub mod json_path;
 pub mod misc;
 pub mod span;
 pub mod terror;
@@ -0,0 +1,7 @@
+pub mod ast;
+pub mod charset;
+pub mod common;
+pub mod mysql;
+pub mod parser;
+mod parser_test;
+pub mod types;
@@ -1,11 +1,3 @@
-mod ast;
-mod charset;
-mod common;
-mod mysql;
-mod parser;
-mod parser_test;
-mod types;
-
 fn main() {
     println!("Hello, world!");
 }
@@ -1,6 +1,9 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
 // PriorityEnum is defined for Priority const values.
 #[allow(dead_code)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum PriorityEnum {
     // Priority const values.
     // See https://dev.mysql.com/doc/refman/5.7/en/insert.html
@@ -10,3 +13,192 @@ pub enum PriorityEnum {
     HighPriority,
     DelayedPriority,
 }
+
+// SqlMode is the set of sql_mode flags that change how a statement is read.
+// See https://dev.mysql.com/doc/refman/8.0/en/sql-mode.html
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SqlMode(pub u64);
+
+// The sql_mode flags, the values are the ones used by TiDB.
+pub const MODE_NONE: SqlMode = SqlMode(0);
+pub const MODE_REAL_AS_FLOAT: SqlMode = SqlMode(1 << 0);
+pub const MODE_PIPES_AS_CONCAT: SqlMode = SqlMode(1 << 1);
+pub const MODE_ANSI_QUOTES: SqlMode = SqlMode(1 << 2);
+pub const MODE_IGNORE_SPACE: SqlMode = SqlMode(1 << 3);
+pub const MODE_NOT_USED: SqlMode = SqlMode(1 << 4);
+pub const MODE_ONLY_FULL_GROUP_BY: SqlMode = SqlMode(1 << 5);
+pub const MODE_NO_UNSIGNED_SUBTRACTION: SqlMode = SqlMode(1 << 6);
+pub const MODE_NO_DIR_IN_CREATE: SqlMode = SqlMode(1 << 7);
+pub const MODE_POSTGRESQL: SqlMode = SqlMode(1 << 8);
+pub const MODE_ORACLE: SqlMode = SqlMode(1 << 9);
+pub const MODE_MSSQL: SqlMode = SqlMode(1 << 10);
+pub const MODE_DB2: SqlMode = SqlMode(1 << 11);
+pub const MODE_MAXDB: SqlMode = SqlMode(1 << 12);
+pub const MODE_NO_KEY_OPTIONS: SqlMode = SqlMode(1 << 13);
+pub const MODE_NO_TABLE_OPTIONS: SqlMode = SqlMode(1 << 14);
+pub const MODE_NO_FIELD_OPTIONS: SqlMode = SqlMode(1 << 15);
+pub const MODE_MYSQL323: SqlMode = SqlMode(1 << 16);
+pub const MODE_MYSQL40: SqlMode = SqlMode(1 << 17);
+pub const MODE_ANSI: SqlMode = SqlMode(1 << 18);
+pub const MODE_NO_AUTO_VALUE_ON_ZERO: SqlMode = SqlMode(1 << 19);
+pub const MODE_NO_BACKSLASH_ESCAPES: SqlMode = SqlMode(1 << 20);
+pub const MODE_STRICT_TRANS_TABLES: SqlMode = SqlMode(1 << 21);
+pub const MODE_STRICT_ALL_TABLES: SqlMode = SqlMode(1 << 22);
+pub const MODE_NO_ZERO_IN_DATE: SqlMode = SqlMode(1 << 23);
+pub const MODE_NO_ZERO_DATE: SqlMode = SqlMode(1 << 24);
+pub const MODE_INVALID_DATES: SqlMode = SqlMode(1 << 25);
+pub const MODE_ERROR_FOR_DIVISION_BY_ZERO: SqlMode = SqlMode(1 << 26);
+pub const MODE_TRADITIONAL: SqlMode = SqlMode(1 << 27);
+pub const MODE_NO_AUTO_CREATE_USER: SqlMode = SqlMode(1 << 28);
+pub const MODE_HIGH_NOT_PRECEDENCE: SqlMode = SqlMode(1 << 29);
+pub const MODE_NO_ENGINE_SUBSTITUTION: SqlMode = SqlMode(1 << 30);
+pub const MODE_PAD_CHAR_TO_FULL_LENGTH: SqlMode = SqlMode(1 << 31);
+pub const MODE_ALLOW_INVALID_DATES: SqlMode = SqlMode(1 << 32);
+
+// The names of the sql_mode flags.
+const SQL_MODE_NAMES: [(&str, SqlMode); 33] = [
+    ("REAL_AS_FLOAT", MODE_REAL_AS_FLOAT),
+    ("PIPES_AS_CONCAT", MODE_PIPES_AS_CONCAT),
+    ("ANSI_QUOTES", MODE_ANSI_QUOTES),
+    ("IGNORE_SPACE", MODE_IGNORE_SPACE),
+    ("NOT_USED", MODE_NOT_USED),
+    ("ONLY_FULL_GROUP_BY", MODE_ONLY_FULL_GROUP_BY),
+    ("NO_UNSIGNED_SUBTRACTION", MODE_NO_UNSIGNED_SUBTRACTION),
+    ("NO_DIR_IN_CREATE", MODE_NO_DIR_IN_CREATE),
+    ("POSTGRESQL", MODE_POSTGRESQL),
+    ("ORACLE", MODE_ORACLE),
+    ("MSSQL", MODE_MSSQL),
+    ("DB2", MODE_DB2),
+    ("MAXDB", MODE_MAXDB),
+    ("NO_KEY_OPTIONS", MODE_NO_KEY_OPTIONS),
+    ("NO_TABLE_OPTIONS", MODE_NO_TABLE_OPTIONS),
+    ("NO_FIELD_OPTIONS", MODE_NO_FIELD_OPTIONS),
+    ("MYSQL323", MODE_MYSQL323),
+    ("MYSQL40", MODE_MYSQL40),
+    ("ANSI", MODE_ANSI),
+    ("NO_AUTO_VALUE_ON_ZERO", MODE_NO_AUTO_VALUE_ON_ZERO),
+    ("NO_BACKSLASH_ESCAPES", MODE_NO_BACKSLASH_ESCAPES),
+    ("STRICT_TRANS_TABLES", MODE_STRICT_TRANS_TABLES),
+    ("STRICT_ALL_TABLES", MODE_STRICT_ALL_TABLES),
+    ("NO_ZERO_IN_DATE", MODE_NO_ZERO_IN_DATE),
+    ("NO_ZERO_DATE", MODE_NO_ZERO_DATE),
+    ("INVALID_DATES", MODE_INVALID_DATES),
+    (
+        "ERROR_FOR_DIVISION_BY_ZERO",
+        MODE_ERROR_FOR_DIVISION_BY_ZERO,
+    ),
+    ("TRADITIONAL", MODE_TRADITIONAL),
+    ("NO_AUTO_CREATE_USER", MODE_NO_AUTO_CREATE_USER),
+    ("HIGH_NOT_PRECEDENCE", MODE_HIGH_NOT_PRECEDENCE),
+    ("NO_ENGINE_SUBSTITUTION", MODE_NO_ENGINE_SUBSTITUTION),
+    ("PAD_CHAR_TO_FULL_LENGTH", MODE_PAD_CHAR_TO_FULL_LENGTH),
+    ("ALLOW_INVALID_DATES", MODE_ALLOW_INVALID_DATES),
+];
+
+// CombinationSqlMode is the modes that a combination mode stands for, e.g.
+// ANSI is REAL_AS_FLOAT, PIPES_AS_CONCAT, ANSI_QUOTES, IGNORE_SPACE and
+// ONLY_FULL_GROUP_BY.
+fn combination_sql_mode(name: &str) -> SqlMode {
+    let modes: &[SqlMode] = match name {
+        "ANSI" => &[
+            MODE_REAL_AS_FLOAT,
+            MODE_PIPES_AS_CONCAT,
+            MODE_ANSI_QUOTES,
+            MODE_IGNORE_SPACE,
+            MODE_ONLY_FULL_GROUP_BY,
+        ],
+        "DB2" | "MSSQL" | "POSTGRESQL" => &[
+            MODE_PIPES_AS_CONCAT,
+            MODE_ANSI_QUOTES,
+            MODE_IGNORE_SPACE,
+            MODE_NO_KEY_OPTIONS,
+            MODE_NO_TABLE_OPTIONS,
+            MODE_NO_FIELD_OPTIONS,
+        ],
+        "MAXDB" | "ORACLE" => &[
+            MODE_PIPES_AS_CONCAT,
+            MODE_ANSI_QUOTES,
+            MODE_IGNORE_SPACE,
+            MODE_NO_KEY_OPTIONS,
+            MODE_NO_TABLE_OPTIONS,
+            MODE_NO_FIELD_OPTIONS,
+            MODE_NO_AUTO_CREATE_USER,
+        ],
+        "MYSQL323" | "MYSQL40" => &[MODE_HIGH_NOT_PRECEDENCE],
+        "TRADITIONAL" => &[
+            MODE_STRICT_TRANS_TABLES,
+            MODE_STRICT_ALL_TABLES,
+            MODE_NO_ZERO_IN_DATE,
+            MODE_NO_ZERO_DATE,
+            MODE_ERROR_FOR_DIVISION_BY_ZERO,
+            MODE_NO_AUTO_CREATE_USER,
+            MODE_NO_ENGINE_SUBSTITUTION,
+        ],
+        _ => &[],
+    };
+    modes.iter().fold(MODE_NONE, |mode, m| mode | *m)
+}
+
+impl SqlMode {
+    // FromModeStr parses a sql_mode value like `ANSI,NO_BACKSLASH_ESCAPES`, the
+    // combination modes are expanded. It returns the unknown mode name on
+    // error.
+    pub fn from_mode_str(s: &str) -> Result<SqlMode, String> {
+        let mut sql_mode = MODE_NONE;
+        for name in s.split(',') {
+            let name = name.trim().to_uppercase();
+            if name.is_empty() {
+                continue;
+            }
+            match SQL_MODE_NAMES.iter().find(|(n, _)| *n == name) {
+                Some((_, mode)) => sql_mode = sql_mode | *mode | combination_sql_mode(&name),
+                None => return Err(name),
+            }
+        }
+        Ok(sql_mode)
+    }
+
+    // HasRealAsFloatMode detects if 'REAL_AS_FLOAT' mode is set, REAL is a
+    // synonym of FLOAT instead of DOUBLE then.
+    pub fn has_real_as_float_mode(&self) -> bool {
+        self.0 & MODE_REAL_AS_FLOAT.0 != 0
+    }
+
+    // HasPipesAsConcatMode detects if 'PIPES_AS_CONCAT' mode is set, `||` is
+    // the concatenation operator instead of OR then.
+    pub fn has_pipes_as_concat_mode(&self) -> bool {
+        self.0 & MODE_PIPES_AS_CONCAT.0 != 0
+    }
+
+    // HasAnsiQuotesMode detects if 'ANSI_QUOTES' mode is set, `"` quotes
+    // identifiers instead of strings then.
+    pub fn has_ansi_quotes_mode(&self) -> bool {
+        self.0 & MODE_ANSI_QUOTES.0 != 0
+    }
+
+    // HasIgnoreSpaceMode detects if 'IGNORE_SPACE' mode is set, the names of
+    // builtin functions may be followed by spaces then.
+    pub fn has_ignore_space_mode(&self) -> bool {
+        self.0 & MODE_IGNORE_SPACE.0 != 0
+    }
+
+    // HasNoBackslashEscapesMode detects if 'NO_BACKSLASH_ESCAPES' mode is set,
+    // `\` is then an ordinary character in string literals.
+    pub fn has_no_backslash_escapes_mode(&self) -> bool {
+        self.0 & MODE_NO_BACKSLASH_ESCAPES.0 != 0
+    }
+
+    // HasHighNotPrecedenceMode detects if 'HIGH_NOT_PRECEDENCE' mode is set,
+    // NOT has the precedence of `!` then.
+    pub fn has_high_not_precedence_mode(&self) -> bool {
+        self.0 & MODE_HIGH_NOT_PRECEDENCE.0 != 0
+    }
+}
+
+impl std::ops::BitOr for SqlMode {
+    type Output = SqlMode;
+
+    fn bitor(self, rhs: SqlMode) -> SqlMode {
+        SqlMode(self.0 | rhs.0)
+    }
+}
@@ -18,7 +18,7 @@ impl ErrMessage {
         }
     }
     pub fn get_message(&self) -> String {
-        if self.redact_arg_pos.len() == 0 {
+        if self.redact_arg_pos.is_empty() {
             self.raw.clone()
         } else {
             self.raw.format(&self.redact_arg_pos)
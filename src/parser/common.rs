@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::ast::spanned::Spanned;
 use crate::common::span::{Range, Span};
 use crate::parser::error::{Error, ErrorKind};
 use crate::parser::input::Input;
@@ -27,8 +28,13 @@ use std::num::{ParseFloatError, ParseIntError};
 
 pub type IResult<'a, Output> = nom::IResult<Input<'a>, Output, Error<'a>>;
 
+// MatchText matches a token by its text, keywords are case insensitive.
 pub fn match_text(text: &'static str) -> impl FnMut(Input) -> IResult<&Token> {
-    move |i| match i.tokens.first().filter(|token| token.text() == text) {
+    move |i| match i
+        .tokens
+        .first()
+        .filter(|token| token.text().eq_ignore_ascii_case(text))
+    {
         Some(token) => Ok((i.slice(1..), token)),
         _ => Err(nom::Err::Error(Error::from_error_kind(
             i,
@@ -47,7 +53,7 @@ pub fn match_token(kind: TokenKind) -> impl FnMut(Input) -> IResult<&Token> {
     }
 }
 
-pub fn any_token(i: Input) -> IResult<&Token> {
+pub fn any_token(i: Input<'_>) -> IResult<'_, &Token<'_>> {
     match i
         .tokens
         .first()
@@ -245,6 +251,38 @@ pub fn transform_span(tokens: &[Token]) -> Span {
     })
 }
 
+// WithSpan returns the output of the parser together with the range of the
+// tokens it consumed, the span is None if nothing is consumed.
+pub fn with_span<'a, T>(
+    mut parser: impl FnMut(Input<'a>) -> IResult<'a, T>,
+) -> impl FnMut(Input<'a>) -> IResult<'a, (T, Span)> {
+    move |input: Input<'a>| {
+        let (rest, output) = parser(input)?;
+        let consumed = input.offset(&rest);
+        let span = if consumed > 0 {
+            transform_span(&input.tokens[..consumed])
+        } else {
+            None
+        };
+        Ok((rest, (output, span)))
+    }
+}
+
+// Spanned records the range of the tokens consumed by the parser as the span
+// of the node it returns.
+pub fn spanned<'a, T: Spanned>(
+    parser: impl FnMut(Input<'a>) -> IResult<'a, T>,
+) -> impl FnMut(Input<'a>) -> IResult<'a, T> {
+    let mut parser = with_span(parser);
+    move |input: Input<'a>| {
+        let (rest, (mut node, span)) = parser(input)?;
+        if span.is_some() {
+            node.set_span(span);
+        }
+        Ok((rest, node))
+    }
+}
+
 pub fn run_pratt_parser<'a, I, P, E>(
     mut parser: P,
     iter: &I,
@@ -317,8 +355,10 @@ where
     }
 }
 
-macro_rules! declare_experimental_feature {
-    ($check_fn_name: ident, $feature_name: literal) => {
+// Declare a parser wrapper that rejects the syntax which the dialect doesn't
+// support, `$supported` is the `Dialect` method that tells whether it does.
+macro_rules! declare_dialect_feature {
+    ($check_fn_name: ident, $supported: ident, $feature_name: literal) => {
         pub fn $check_fn_name<'a, O, F>(
             is_exclusive: bool,
             mut parser: F,
@@ -328,18 +368,16 @@ macro_rules! declare_experimental_feature {
         {
             move |input: Input| {
                 parser.parse(input).and_then(|(i, res)| {
-                    if input.dialect.is_experimental() {
+                    if input.dialect.$supported() {
                         Ok((i, res))
                     } else {
                         i.backtrace.clear();
                         let error = Error::from_error_kind(
                             input,
-                            ErrorKind::Other(
-                                concat!(
-                                    $feature_name,
-                                    " only works in experimental dialect, try `set sql_dialect = 'experimental'`"
-                                )
-                            ),
+                            ErrorKind::Other(concat!(
+                                $feature_name,
+                                " is not supported by the dialect"
+                            )),
                         );
                         if is_exclusive {
                             Err(nom::Err::Failure(error))
@@ -353,11 +391,58 @@ macro_rules! declare_experimental_feature {
     };
 }
 
-declare_experimental_feature!(check_experimental_chain_function, "chain function");
-declare_experimental_feature!(check_experimental_list_comprehension, "list comprehension");
+declare_dialect_feature!(check_tidb_table_sample, is_tidb, "TABLESAMPLE");
+declare_dialect_feature!(check_tidb_as_of_timestamp, is_tidb, "AS OF TIMESTAMP");
+declare_dialect_feature!(check_tidb_admin, is_tidb, "ADMIN");
+declare_dialect_feature!(check_tidb_binding, is_tidb, "BINDING");
+declare_dialect_feature!(check_tidb_split_region, is_tidb, "SPLIT TABLE");
+declare_dialect_feature!(check_tidb_flashback, is_tidb, "FLASHBACK");
+declare_dialect_feature!(check_tidb_placement_policy, is_tidb, "PLACEMENT POLICY");
+declare_dialect_feature!(check_tidb_show_table_regions, is_tidb, "SHOW TABLE REGIONS");
+declare_dialect_feature!(check_tidb_set_config, is_tidb, "SET CONFIG");
+declare_dialect_feature!(
+    check_window_function,
+    supports_window_function,
+    "window function"
+);
+declare_dialect_feature!(check_cte, supports_cte, "WITH");
+declare_dialect_feature!(check_for_share, supports_for_share, "FOR SHARE");
+declare_dialect_feature!(
+    check_lock_wait,
+    supports_lock_wait,
+    "NOWAIT and SKIP LOCKED"
+);
+declare_dialect_feature!(check_table_stmt, supports_table_values_stmt, "TABLE");
+declare_dialect_feature!(check_values_stmt, supports_table_values_stmt, "VALUES");
+declare_dialect_feature!(
+    check_intersect_except,
+    supports_intersect_except,
+    "INTERSECT and EXCEPT"
+);
+declare_dialect_feature!(check_lateral, supports_lateral, "LATERAL");
+declare_dialect_feature!(check_json_table, supports_json_table, "JSON_TABLE");
+declare_dialect_feature!(check_json_arrow, supports_json_arrow, "`->` and `->>`");
+declare_dialect_feature!(check_sequence, supports_sequence, "SEQUENCE");
+declare_dialect_feature!(check_mariadb_or_replace, is_mariadb, "OR REPLACE");
+declare_dialect_feature!(
+    check_mariadb_temporary_sequence,
+    is_mariadb,
+    "TEMPORARY SEQUENCE"
+);
+declare_dialect_feature!(
+    check_mariadb_previous_value,
+    is_mariadb,
+    "PREVIOUS VALUE FOR"
+);
+declare_dialect_feature!(check_mariadb_system_time, is_mariadb, "FOR SYSTEM_TIME");
+declare_dialect_feature!(
+    check_multi_valued_index,
+    supports_multi_valued_index,
+    "ARRAY cast and MEMBER OF"
+);
 
 pub fn get_u64_form_num(num: &str) -> u64 {
-    num.parse::<u64>().unwrap_or_else(|_| 0)
+    num.parse::<u64>().unwrap_or(0)
 }
 
 pub fn get_i64_form_num(num: &str) -> Result<i64, ParseIntError> {
@@ -365,7 +450,7 @@ pub fn get_i64_form_num(num: &str) -> Result<i64, ParseIntError> {
 }
 
 pub fn get_isize_form_num(num: &str) -> isize {
-    num.parse::<isize>().unwrap_or_else(|_| 0)
+    num.parse::<isize>().unwrap_or(0)
 }
 
 pub fn get_f64_form_num(num: &str) -> Result<f64, ParseFloatError> {
@@ -18,7 +18,8 @@ use std::fmt::Write;
 use std::num::IntErrorKind;
 use std::num::ParseIntError;
 
-use crate::common::span::{pretty_print_error, Range};
+use crate::common::error::ParseError;
+use crate::common::span::{pretty_print_error, Range, Span};
 use crate::parser::common::transform_span;
 use crate::parser::input::Input;
 use crate::parser::token_kind::TokenKind;
@@ -80,6 +81,33 @@ impl Backtrace {
     }
 }
 
+/// Collect the warnings reported during parsing, e.g. the optimizer hints
+/// which are unknown or malformed are ignored with a warning like MySQL does.
+#[derive(Debug, Default)]
+pub struct Warnings {
+    inner: RefCell<Vec<ParseError>>,
+}
+
+impl Warnings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a warning. The same warning may be reported more than once when
+    /// the parser backtracks, only the first one is kept.
+    pub fn push(&self, span: Span, msg: String) {
+        let warning = ParseError(span, msg);
+        let mut inner = self.inner.borrow_mut();
+        if !inner.contains(&warning) {
+            inner.push(warning);
+        }
+    }
+
+    pub fn take(&self) -> Vec<ParseError> {
+        self.inner.take()
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct BacktraceInner {
     /// The span of the next token of the last valid one when encountering an error.
@@ -211,7 +239,7 @@ pub fn display_parser_error(error: Error, source: &str) -> String {
             .iter()
             .chain(&inner.errors)
             .filter_map(|kind| match kind {
-                ErrorKind::ExpectToken(EOI) => None,
+                ErrorKind::ExpectToken(TokenKind::EOI) => None,
                 ErrorKind::ExpectToken(token) if token.is_keyword() => {
                     Some(format!("`{:?}`", token))
                 }
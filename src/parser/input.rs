@@ -19,7 +19,8 @@ use std::ops::RangeTo;
 
 use enum_as_inner::EnumAsInner;
 
-use crate::parser::error::Backtrace;
+use crate::mysql::consts::SqlMode;
+use crate::parser::error::{Backtrace, Warnings};
 use crate::parser::token::Token;
 
 /// Input tokens slice with a backtrace that records all errors including
@@ -29,7 +30,9 @@ pub struct Input<'a> {
     pub tokens: &'a [Token<'a>],
     pub dialect: Dialect,
     pub mode: ParseMode,
+    pub sql_mode: SqlMode,
     pub backtrace: &'a Backtrace,
+    pub warnings: &'a Warnings,
     pub charset: &'a str,
     pub collation: &'a str,
 }
@@ -92,8 +95,8 @@ impl<'a> nom::Slice<RangeFull> for Input<'a> {
 
 #[derive(Clone, Debug)]
 pub struct WithSpan<'a, T> {
-    pub(crate) span: Input<'a>,
-    pub(crate) elem: T,
+    pub span: Input<'a>,
+    pub elem: T,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, EnumAsInner)]
@@ -103,46 +106,132 @@ pub enum ParseMode {
     Template,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, EnumAsInner)]
+// Dialect is the server that the statements are written for, the reserved
+// words and the accepted syntax differ between the servers and their versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Dialect {
-    PostgreSQL,
+    MySQL57,
     #[default]
-    MySQL,
-    Hive,
-    PRQL,
-    Experimental,
+    MySQL80,
+    MySQL84,
+    MariaDB10,
+    MariaDB11,
+    TiDB,
 }
 
 impl Dialect {
-    pub fn is_ident_quote(&self, c: char) -> bool {
-        match self {
-            Dialect::MySQL => c == '`',
-            Dialect::Hive => c == '`',
-            // TODO: remove '`' quote support once mysql handler correctly set mysql dialect.
-            Dialect::Experimental | Dialect::PostgreSQL | Dialect::PRQL => c == '"' || c == '`',
-        }
+    pub fn is_mysql(&self) -> bool {
+        matches!(self, Dialect::MySQL57 | Dialect::MySQL80 | Dialect::MySQL84)
     }
 
-    pub fn is_string_quote(&self, c: char) -> bool {
-        match self {
-            Dialect::MySQL => c == '\'' || c == '"',
-            Dialect::Hive => c == '\'' || c == '"',
-            Dialect::Experimental | Dialect::PostgreSQL | Dialect::PRQL => c == '\'',
-        }
+    pub fn is_mariadb(&self) -> bool {
+        matches!(self, Dialect::MariaDB10 | Dialect::MariaDB11)
+    }
+
+    pub fn is_tidb(&self) -> bool {
+        *self == Dialect::TiDB
     }
 
-    pub fn substr_index_zero_literal_as_one(&self) -> bool {
+    // ServerVersion is the version that the executable comments are checked
+    // against, in the `MMmmpp` form, see `tokenize_sql_with_server_version`.
+    pub fn server_version(&self) -> u32 {
         match self {
-            Dialect::MySQL => false,
-            Dialect::Hive => true,
-            Dialect::Experimental | Dialect::PostgreSQL | Dialect::PRQL => false,
+            Dialect::MySQL57 => 50744,
+            Dialect::MySQL80 => 80040,
+            Dialect::MySQL84 => 80403,
+            Dialect::MariaDB10 => 101111,
+            Dialect::MariaDB11 => 110405,
+            // TiDB reports itself as MySQL 8.0.11.
+            Dialect::TiDB => 80011,
         }
     }
 
+    pub fn is_ident_quote(&self, c: char) -> bool {
+        c == '`'
+    }
+
+    pub fn is_string_quote(&self, c: char) -> bool {
+        c == '\'' || c == '"'
+    }
+
     pub fn default_ident_quote(&self) -> char {
+        '`'
+    }
+
+    // IsReservedIdent returns true for the words that are reserved by the
+    // dialect although they aren't keywords of the parser, they can't be
+    // unquoted identifiers then.
+    pub fn is_reserved_ident(&self, ident: &str) -> bool {
         match self {
-            Dialect::MySQL | Dialect::Hive => '`',
-            Dialect::Experimental | Dialect::PostgreSQL | Dialect::PRQL => '"',
+            // See https://dev.mysql.com/doc/refman/8.4/en/keywords.html
+            Dialect::MySQL84 => ["MANUAL", "PARALLEL", "QUALIFY"]
+                .iter()
+                .any(|word| word.eq_ignore_ascii_case(ident)),
+            _ => false,
         }
     }
+
+    // Window functions and the OVER clause came with MySQL 8.0 and MariaDB 10.2.
+    pub fn supports_window_function(&self) -> bool {
+        *self != Dialect::MySQL57
+    }
+
+    // WITH and the common table expressions came with MySQL 8.0 and MariaDB 10.2.
+    pub fn supports_cte(&self) -> bool {
+        *self != Dialect::MySQL57
+    }
+
+    // FOR SHARE came with MySQL 8.0, MariaDB only has LOCK IN SHARE MODE.
+    pub fn supports_for_share(&self) -> bool {
+        matches!(self, Dialect::MySQL80 | Dialect::MySQL84 | Dialect::TiDB)
+    }
+
+    // NOWAIT and SKIP LOCKED came with MySQL 8.0 and MariaDB 10.3 and 10.6.
+    pub fn supports_lock_wait(&self) -> bool {
+        *self != Dialect::MySQL57
+    }
+
+    // The TABLE and VALUES statements are MySQL 8.0.19+ and TiDB only.
+    pub fn supports_table_values_stmt(&self) -> bool {
+        matches!(self, Dialect::MySQL80 | Dialect::MySQL84 | Dialect::TiDB)
+    }
+
+    // INTERSECT and EXCEPT came with MySQL 8.0.31 and MariaDB 10.3.
+    pub fn supports_intersect_except(&self) -> bool {
+        *self != Dialect::MySQL57
+    }
+
+    // INTERSECT ALL and EXCEPT ALL aren't supported by TiDB.
+    pub fn supports_intersect_except_all(&self) -> bool {
+        self.supports_intersect_except() && !self.is_tidb()
+    }
+
+    // LATERAL derived tables are MySQL 8.0.14+ only.
+    pub fn supports_lateral(&self) -> bool {
+        matches!(self, Dialect::MySQL80 | Dialect::MySQL84)
+    }
+
+    // JSON_TABLE came with MySQL 8.0.4 and MariaDB 10.6.
+    pub fn supports_json_table(&self) -> bool {
+        matches!(
+            self,
+            Dialect::MySQL80 | Dialect::MySQL84 | Dialect::MariaDB10 | Dialect::MariaDB11
+        )
+    }
+
+    // The `->` and `->>` operators aren't supported by MariaDB.
+    pub fn supports_json_arrow(&self) -> bool {
+        !self.is_mariadb()
+    }
+
+    // Sequences came with MariaDB 10.3 and TiDB 4.0.
+    pub fn supports_sequence(&self) -> bool {
+        self.is_mariadb() || self.is_tidb()
+    }
+
+    // CAST(... AS type ARRAY) and MEMBER OF are used by multi-valued indexes
+    // of MySQL 8.0.17+ and TiDB.
+    pub fn supports_multi_valued_index(&self) -> bool {
+        matches!(self, Dialect::MySQL80 | Dialect::MySQL84 | Dialect::TiDB)
+    }
 }
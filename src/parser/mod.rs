@@ -2,6 +2,8 @@ pub mod token_kind;
 pub mod token;
 pub mod input;
 pub mod error;
+#[allow(clippy::module_inception)]
 pub mod parser;
+pub mod script;
 pub mod common;
-mod statements;
\ No newline at end of file
+pub mod statements;
\ No newline at end of file
@@ -1,15 +1,48 @@
 use crate::ast::statement::Statement;
 use crate::common::error::{ParseError, Result};
+use crate::mysql::consts::SqlMode;
 use crate::parser::common::{transform_span, IResult};
-use crate::parser::error::{display_parser_error, Backtrace};
+use crate::parser::error::{display_parser_error, Backtrace, Warnings};
 use crate::parser::input::{Dialect, Input, ParseMode};
 use crate::parser::statements::statement::statement;
-use crate::parser::token::{Token, Tokenizer};
+use crate::parser::token::{Token, Tokenizer, DEFAULT_SERVER_VERSION};
 use crate::parser::token_kind::TokenKind;
 use std::string::ToString;
 
-pub fn tokenize_sql(sql: &str) -> Result<Vec<Token>> {
-    let mut tokens = Tokenizer::new(sql).collect::<Result<Vec<_>>>()?;
+pub fn tokenize_sql(sql: &str) -> Result<Vec<Token<'_>>> {
+    tokenize_sql_with_server_version(sql, DEFAULT_SERVER_VERSION)
+}
+
+/// Tokenize a SQL string, executable comments `/*!NNNNN ... */` are read as
+/// the server of `server_version` does.
+pub fn tokenize_sql_with_server_version(sql: &str, server_version: u32) -> Result<Vec<Token<'_>>> {
+    tokenize_sql_with_sql_mode(sql, server_version, SqlMode::default())
+}
+
+/// Tokenize a SQL string as the server of `server_version` running with
+/// `sql_mode` does.
+pub fn tokenize_sql_with_sql_mode(
+    sql: &str,
+    server_version: u32,
+    sql_mode: SqlMode,
+) -> Result<Vec<Token<'_>>> {
+    let mut tokens =
+        Tokenizer::with_sql_mode(sql, server_version, sql_mode).collect::<Result<Vec<_>>>()?;
+    for (pos, token) in tokens.iter_mut().enumerate() {
+        token.pos = pos
+    }
+
+    Ok(tokens)
+}
+
+/// Tokenize a SQL string as the server of `dialect` running with `sql_mode`
+/// does, executable comments are checked against `dialect.server_version()`.
+pub fn tokenize_sql_with_dialect(
+    sql: &str,
+    dialect: Dialect,
+    sql_mode: SqlMode,
+) -> Result<Vec<Token<'_>>> {
+    let mut tokens = Tokenizer::with_dialect(sql, dialect, sql_mode).collect::<Result<Vec<_>>>()?;
     for (pos, token) in tokens.iter_mut().enumerate() {
         token.pos = pos
     }
@@ -20,24 +53,53 @@ pub fn tokenize_sql(sql: &str) -> Result<Vec<Token>> {
 /// Parse a SQL string into `Statement`s.
 #[fastrace::trace]
 pub fn parse_sql(tokens: &[Token], dialect: Dialect) -> Result<Statement> {
-    run_parser(
+    parse_sql_with_warnings(tokens, dialect).map(|(stmt, _)| stmt)
+}
+
+/// Parse a SQL string into `Statement`s, and return the warnings reported
+/// during parsing, e.g. the ignored optimizer hints.
+#[fastrace::trace]
+pub fn parse_sql_with_warnings(
+    tokens: &[Token],
+    dialect: Dialect,
+) -> Result<(Statement, Vec<ParseError>)> {
+    parse_sql_with_sql_mode(tokens, dialect, SqlMode::default())
+}
+
+/// Parse a SQL string into `Statement`s as the server running with `sql_mode`
+/// does, and return the warnings reported during parsing.
+#[fastrace::trace]
+pub fn parse_sql_with_sql_mode(
+    tokens: &[Token],
+    dialect: Dialect,
+    sql_mode: SqlMode,
+) -> Result<(Statement, Vec<ParseError>)> {
+    let warnings = Warnings::new();
+    let stmt = run_parser(
         tokens,
         dialect,
         ParseMode::Default,
+        sql_mode,
         false,
         "".to_string(),
         "".to_string(),
+        &warnings,
         statement,
-    )
+    )?;
+
+    Ok((stmt, warnings.take()))
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn run_parser<O>(
     tokens: &[Token],
     dialect: Dialect,
     mode: ParseMode,
+    sql_mode: SqlMode,
     allow_partial: bool,
     charset: String,
     collation: String,
+    warnings: &Warnings,
     mut parser: impl FnMut(Input) -> IResult<O>,
 ) -> Result<O> {
     let backtrace = Backtrace::new();
@@ -45,7 +107,9 @@ pub fn run_parser<O>(
         tokens,
         dialect,
         mode,
+        sql_mode,
         backtrace: &backtrace,
+        warnings,
         charset: &charset,
         collation: &collation,
     };
@@ -0,0 +1,274 @@
+use crate::ast::statement::Statement;
+use crate::common::error::{ParseError, Result};
+use crate::common::escape::quoted_string_len;
+use crate::common::span::{offset_span, Range};
+use crate::mysql::consts::SqlMode;
+use crate::parser::input::Dialect;
+use crate::parser::parser::{parse_sql_with_sql_mode, tokenize_sql_with_dialect};
+use crate::parser::token_kind::TokenKind;
+
+pub const DEFAULT_DELIMITER: &str = ";";
+
+/// A statement of a SQL script, such as a `.sql` file or a mysqldump output.
+#[derive(Debug)]
+pub struct ScriptStatement {
+    /// The byte range of the statement in the script, excluding the delimiter.
+    pub span: Range,
+    /// The delimiter which terminated the statement, empty if the statement
+    /// reached the end of the script.
+    pub delimiter: String,
+    /// The parsed statement, or the error which made it unparsable.
+    pub stmt: Result<Statement>,
+    /// The warnings reported while parsing the statement.
+    pub warnings: Vec<ParseError>,
+}
+
+/// A raw statement text cut out of a script by `ScriptSplitter`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptSegment {
+    pub span: Range,
+    pub delimiter: String,
+}
+
+/// Parse every statement of a script.
+///
+/// The `DELIMITER` directive of the mysql client is honoured, so the body of
+/// `CREATE PROCEDURE` which contains `;` stays in one statement. A statement
+/// which fails to parse doesn't abort the rest of the script, its error is
+/// carried by the returned `ScriptStatement` instead.
+pub fn parse_script(script: &str, dialect: Dialect) -> impl Iterator<Item = ScriptStatement> + '_ {
+    parse_script_with_sql_mode(script, dialect, SqlMode::default())
+}
+
+/// Parse every statement of a script written for a server running with
+/// `sql_mode`, e.g. `\` doesn't escape the quotes of string literals with
+/// NO_BACKSLASH_ESCAPES.
+pub fn parse_script_with_sql_mode(
+    script: &str,
+    dialect: Dialect,
+    sql_mode: SqlMode,
+) -> impl Iterator<Item = ScriptStatement> + '_ {
+    ScriptSplitter::with_sql_mode(script, sql_mode)
+        .filter_map(move |segment| parse_segment(script, segment, dialect, sql_mode))
+}
+
+fn parse_segment(
+    script: &str,
+    segment: ScriptSegment,
+    dialect: Dialect,
+    sql_mode: SqlMode,
+) -> Option<ScriptStatement> {
+    let sql = &script[std::ops::Range::from(segment.span)];
+    let result = match tokenize_sql_with_dialect(sql, dialect, sql_mode) {
+        // Only comments left, e.g. a `/* ... */` between two delimiters.
+        Ok(tokens) if tokens.len() == 1 && tokens[0].kind == TokenKind::EOI => return None,
+        Ok(tokens) => parse_sql_with_sql_mode(&tokens, dialect, sql_mode),
+        Err(err) => Err(err),
+    };
+    // Make the spans relative to the whole script.
+    let offset = |ParseError(span, msg)| ParseError(offset_span(span, segment.span.start()), msg);
+    let (stmt, warnings) = match result {
+        Ok((stmt, warnings)) => (Ok(stmt), warnings.into_iter().map(offset).collect()),
+        Err(err) => (Err(offset(err)), vec![]),
+    };
+
+    Some(ScriptStatement {
+        span: segment.span,
+        delimiter: segment.delimiter,
+        stmt,
+        warnings,
+    })
+}
+
+/// Split a script into statements the way the mysql client does.
+///
+/// Delimiters inside of quoted strings, quoted identifiers and comments are
+/// ignored. `DELIMITER <str>` (or `\d <str>`) at the beginning of a statement
+/// changes the delimiter used for the following statements.
+pub struct ScriptSplitter<'a> {
+    source: &'a str,
+    pos: usize,
+    delimiter: String,
+    // The sql_mode that changes how quoted strings end.
+    sql_mode: SqlMode,
+}
+
+impl<'a> ScriptSplitter<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self::with_sql_mode(source, SqlMode::default())
+    }
+
+    /// Create a splitter which reads quoted strings as the server running with
+    /// `sql_mode` does, `\` is an ordinary character with NO_BACKSLASH_ESCAPES
+    /// and in the `"` quoted identifiers of ANSI_QUOTES.
+    pub fn with_sql_mode(source: &'a str, sql_mode: SqlMode) -> Self {
+        ScriptSplitter {
+            source,
+            pos: 0,
+            delimiter: DEFAULT_DELIMITER.to_string(),
+            sql_mode,
+        }
+    }
+
+    // Returns the position after the quoted string or identifier which starts
+    // at `i`, or the end of the script if it isn't terminated.
+    fn skip_quoted(&self, i: usize, quote: u8) -> usize {
+        let no_backslash_escapes = quote == b'`'
+            || (quote == b'"' && self.sql_mode.has_ansi_quotes_mode())
+            || self.sql_mode.has_no_backslash_escapes_mode();
+        match quoted_string_len(&self.source[i..], no_backslash_escapes) {
+            Some(len) => i + len,
+            None => self.source.len(),
+        }
+    }
+
+    /// The script being split.
+    pub fn source(&self) -> &'a str {
+        self.source
+    }
+
+    /// The delimiter in effect at the current position.
+    pub fn delimiter(&self) -> &str {
+        &self.delimiter
+    }
+
+    // Skip the whitespaces and comments before a statement. Executable
+    // comments like `/*!40101 SET NAMES utf8 */` are statements themselves.
+    fn skip_blank(&mut self) {
+        let bytes = self.source.as_bytes();
+        loop {
+            while self.pos < bytes.len() && bytes[self.pos].is_ascii_whitespace() {
+                self.pos += 1;
+            }
+            let rest = &bytes[self.pos..];
+            if rest.starts_with(b"/*!") || rest.starts_with(b"/*M!") {
+                return;
+            }
+            match skip_comment(bytes, self.pos) {
+                Some(end) => self.pos = end,
+                None => return,
+            }
+        }
+    }
+
+    // Try to consume a `DELIMITER <str>` directive at the current position.
+    fn delimiter_directive(&mut self) -> bool {
+        let rest = &self.source[self.pos..];
+        let keyword_len = if starts_with_ignore_case(rest, "DELIMITER") {
+            "DELIMITER".len()
+        } else if rest.starts_with("\\d") {
+            "\\d".len()
+        } else {
+            return false;
+        };
+        match rest[keyword_len..].chars().next() {
+            Some(' ' | '\t') => {}
+            _ => return false,
+        }
+
+        let line_end = rest.find('\n').unwrap_or(rest.len());
+        let arg = rest[keyword_len..line_end].trim();
+        let delimiter = match arg.chars().next() {
+            Some(quote @ ('\'' | '"' | '`')) => match arg[1..].find(quote) {
+                Some(end) => &arg[1..end + 1],
+                None => &arg[1..],
+            },
+            _ => arg.split_whitespace().next().unwrap_or(""),
+        };
+        // The mysql client refuses an empty delimiter and keeps the old one.
+        if !delimiter.is_empty() {
+            self.delimiter = delimiter.to_string();
+        }
+        self.pos += line_end;
+
+        true
+    }
+}
+
+impl<'a> Iterator for ScriptSplitter<'a> {
+    type Item = ScriptSegment;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.source.as_bytes();
+        loop {
+            self.skip_blank();
+            if self.pos >= bytes.len() {
+                return None;
+            }
+            if self.delimiter_directive() {
+                continue;
+            }
+            // Empty statement, e.g. `;;`.
+            if bytes[self.pos..].starts_with(self.delimiter.as_bytes()) {
+                self.pos += self.delimiter.len();
+                continue;
+            }
+            break;
+        }
+
+        let start = self.pos;
+        let mut i = start;
+        while i < bytes.len() {
+            if let Some(end) = skip_comment(bytes, i) {
+                i = end;
+                continue;
+            }
+            if let quote @ (b'\'' | b'"' | b'`') = bytes[i] {
+                i = self.skip_quoted(i, quote);
+                continue;
+            }
+            if bytes[i..].starts_with(self.delimiter.as_bytes()) {
+                self.pos = i + self.delimiter.len();
+                return Some(ScriptSegment {
+                    span: trim_end_span(bytes, start, i),
+                    delimiter: self.delimiter.clone(),
+                });
+            }
+            i += 1;
+        }
+
+        self.pos = bytes.len();
+        Some(ScriptSegment {
+            span: trim_end_span(bytes, start, bytes.len()),
+            delimiter: String::new(),
+        })
+    }
+}
+
+fn trim_end_span(bytes: &[u8], start: usize, mut end: usize) -> Range {
+    while end > start && bytes[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    (start..end).into()
+}
+
+fn starts_with_ignore_case(s: &str, prefix: &str) -> bool {
+    s.len() >= prefix.len() && s.as_bytes()[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+}
+
+// Returns the position after the comment which starts at `i`, if any.
+// `-- ` comments require a whitespace after the dashes, the same as MySQL.
+fn skip_comment(bytes: &[u8], i: usize) -> Option<usize> {
+    let line_end = |from: usize| {
+        bytes[from..]
+            .iter()
+            .position(|b| *b == b'\n')
+            .map(|p| from + p + 1)
+            .unwrap_or(bytes.len())
+    };
+    match bytes.get(i..i + 2) {
+        Some(b"--") if bytes.get(i + 2).is_none_or(|b| b.is_ascii_whitespace()) => {
+            Some(line_end(i))
+        }
+        Some(b"/*") => {
+            let end = bytes[i + 2..]
+                .windows(2)
+                .position(|w| w == b"*/")
+                .map(|p| i + 2 + p + 2)
+                .unwrap_or(bytes.len());
+            Some(end)
+        }
+        _ if bytes.get(i) == Some(&b'#') => Some(line_end(i)),
+        _ => None,
+    }
+}
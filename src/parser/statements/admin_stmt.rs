@@ -0,0 +1,57 @@
+use crate::ast::admin_stmt::{AdminStmt, AdminStmtType};
+use crate::ast::statement::Statement;
+use crate::parser::common::*;
+use crate::parser::input::Input;
+use crate::parser::statements::expression::expression;
+use crate::parser::statements::num_literal::i64_num;
+use crate::parser::statements::table_name::table_name;
+use crate::parser::token_kind::TokenKind::*;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom_rule::rule;
+
+// See https://docs.pingcap.com/tidb/stable/sql-statement-admin
+pub fn admin_statement(i: Input) -> IResult<Statement> {
+    check_tidb_admin(
+        true,
+        alt((
+            map(
+                rule!(ADMIN ~ SHOW ~ DDL ~ JOBS ~ #i64_num? ~ (WHERE ~ #expression)?),
+                |(_, _, _, _, job_number, where_clause)| {
+                    let mut stmt = AdminStmt::default();
+                    stmt.tp = AdminStmtType::ShowDdlJobs;
+                    stmt.job_number = job_number.unwrap_or(0);
+                    stmt.where_clause = where_clause.map(|(_, expr)| expr);
+
+                    Statement::AdminStmt(Box::new(stmt))
+                },
+            ),
+            map(rule!(ADMIN ~ SHOW ~ DDL), |_| {
+                let mut stmt = AdminStmt::default();
+                stmt.tp = AdminStmtType::ShowDdl;
+
+                Statement::AdminStmt(Box::new(stmt))
+            }),
+            map(
+                rule!(ADMIN ~ CHECK ~ TABLE ~ #comma_separated_list1(table_name)),
+                |(_, _, _, tables)| {
+                    let mut stmt = AdminStmt::default();
+                    stmt.tp = AdminStmtType::CheckTable;
+                    stmt.tables = tables;
+
+                    Statement::AdminStmt(Box::new(stmt))
+                },
+            ),
+            map(
+                rule!(ADMIN ~ CANCEL ~ DDL ~ JOBS ~ #comma_separated_list1(i64_num)),
+                |(_, _, _, _, job_ids)| {
+                    let mut stmt = AdminStmt::default();
+                    stmt.tp = AdminStmtType::CancelDdlJobs;
+                    stmt.job_ids = job_ids;
+
+                    Statement::AdminStmt(Box::new(stmt))
+                },
+            ),
+        )),
+    )(i)
+}
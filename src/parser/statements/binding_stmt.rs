@@ -0,0 +1,70 @@
+use crate::ast::binding_stmt::{CreateBindingStmt, DropBindingStmt};
+use crate::ast::statement::Statement;
+use crate::parser::common::*;
+use crate::parser::input::Input;
+use crate::parser::statements::common::global_scope;
+use crate::parser::statements::select_statement::select_statement;
+use crate::parser::token_kind::TokenKind::*;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom_rule::rule;
+
+// See https://docs.pingcap.com/tidb/stable/sql-statement-create-binding
+pub fn create_binding_statement(i: Input) -> IResult<Statement> {
+    check_tidb_binding(
+        true,
+        alt((
+            map(
+                rule!(
+                    CREATE ~ #global_scope ~ BINDING ~ FOR ~ #bindable_statement
+                    ~ USING ~ #bindable_statement
+                ),
+                |(_, global_scope, _, _, origin_node, _, hinted_node)| {
+                    Statement::CreateBindingStmt(Box::new(CreateBindingStmt {
+                        span: None,
+                        global_scope,
+                        origin_node: Some(Box::new(origin_node)),
+                        hinted_node: Box::new(hinted_node),
+                    }))
+                },
+            ),
+            map(
+                rule!(CREATE ~ #global_scope ~ BINDING ~ USING ~ #bindable_statement),
+                |(_, global_scope, _, _, hinted_node)| {
+                    Statement::CreateBindingStmt(Box::new(CreateBindingStmt {
+                        span: None,
+                        global_scope,
+                        origin_node: None,
+                        hinted_node: Box::new(hinted_node),
+                    }))
+                },
+            ),
+        )),
+    )(i)
+}
+
+// See https://docs.pingcap.com/tidb/stable/sql-statement-drop-binding
+pub fn drop_binding_statement(i: Input) -> IResult<Statement> {
+    check_tidb_binding(
+        true,
+        map(
+            rule!(
+                DROP ~ #global_scope ~ BINDING ~ FOR ~ #bindable_statement
+                ~ (USING ~ #bindable_statement)?
+            ),
+            |(_, global_scope, _, _, origin_node, hinted_node)| {
+                Statement::DropBindingStmt(Box::new(DropBindingStmt {
+                    span: None,
+                    global_scope,
+                    origin_node: Box::new(origin_node),
+                    hinted_node: hinted_node.map(|(_, stmt)| Box::new(stmt)),
+                }))
+            },
+        ),
+    )(i)
+}
+
+// BindableStmt is the statement a binding is created for.
+fn bindable_statement(i: Input) -> IResult<Statement> {
+    select_statement(i)
+}
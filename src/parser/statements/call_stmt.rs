@@ -0,0 +1,50 @@
+use crate::ast::call_stmt::CallStmt;
+use crate::ast::ci_str::CIStr;
+use crate::ast::expr_node::{FuncCallExpr, FuncCallExprType};
+use crate::ast::statement::Statement;
+use crate::parser::common::*;
+use crate::parser::input::Input;
+use crate::parser::statements::common::identifier;
+use crate::parser::statements::expression::expression_list_opt;
+use crate::parser::token_kind::TokenKind::*;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom_rule::rule;
+
+// See https://dev.mysql.com/doc/refman/5.7/en/call.html
+pub fn call_statement(i: Input) -> IResult<Statement> {
+    map(rule!(CALL ~ #procedure_call), |(_, procedure)| {
+        Statement::CallStmt(Box::new(CallStmt {
+            span: None,
+            procedure: Some(Box::new(procedure)),
+        }))
+    })(i)
+}
+
+pub fn procedure_call(i: Input) -> IResult<FuncCallExpr> {
+    spanned(alt((
+        map(
+            rule!(#identifier ~ "." ~ #identifier ~ ("(" ~ #expression_list_opt ~ ")")?),
+            |(schema, _, name, args)| {
+                let mut fn_expr = FuncCallExpr::default();
+                fn_expr.tp = FuncCallExprType::Generic;
+                fn_expr.schema = CIStr::new(&schema);
+                fn_expr.fn_name = CIStr::new(&name);
+                fn_expr.args = args.map(|(_, exprs, _)| exprs).unwrap_or_default();
+
+                fn_expr
+            },
+        ),
+        map(
+            rule!(#identifier ~ ("(" ~ #expression_list_opt ~ ")")?),
+            |(name, args)| {
+                let mut fn_expr = FuncCallExpr::default();
+                fn_expr.tp = FuncCallExprType::Generic;
+                fn_expr.fn_name = CIStr::new(&name);
+                fn_expr.args = args.map(|(_, exprs, _)| exprs).unwrap_or_default();
+
+                fn_expr
+            },
+        ),
+    )))(i)
+}
@@ -3,44 +3,40 @@ use crate::ast::column_name::ColumnName;
 use crate::ast::expr_node::ColumnNameExpr;
 use crate::parser::common::*;
 use crate::parser::input::Input;
-use crate::parser::token_kind::TokenKind::*;
+use crate::parser::statements::common::identifier;
 use nom::branch::alt;
 use nom::combinator::map;
 use nom_rule::rule;
 
+// ColumnName is `[[schema.]table.]column`, the longest form is tried first.
+// The keywords which aren't reserved by the dialect are names too.
 pub fn column_name(i: Input) -> IResult<ColumnName> {
-    alt((
-        map(rule!(Ident), |(col_name)| {
-            let name = col_name.get_trim_start_end_text('`');
-
-            let mut cn = ColumnName::default();
-            cn.name = CIStr::new(name);
-            cn
-        }),
-        map(rule!(Ident ~ "." ~ Ident), |(tbl_name, _, col_name)| {
-            let table = tbl_name.get_trim_start_end_text('`');
-            let name = col_name.get_trim_start_end_text('`');
-
-            let mut cn = ColumnName::default();
-            cn.table = CIStr::new(table);
-            cn.name = CIStr::new(name);
-            cn
-        }),
+    spanned(alt((
         map(
-            rule!(Ident ~ "." ~ Ident ~ "." ~ Ident),
-            |(schema_name, _, tbl_name, _, col_name)| {
-                let schema = schema_name.get_trim_start_end_text('`');
-                let table = tbl_name.get_trim_start_end_text('`');
-                let name = col_name.get_trim_start_end_text('`');
-
+            rule!(#identifier ~ "." ~ #identifier ~ "." ~ #identifier),
+            |(schema, _, table, _, name)| {
                 let mut cn = ColumnName::default();
-                cn.schema = CIStr::new(schema);
-                cn.table = CIStr::new(table);
-                cn.name = CIStr::new(name);
+                cn.schema = CIStr::new(&schema);
+                cn.table = CIStr::new(&table);
+                cn.name = CIStr::new(&name);
                 cn
             },
         ),
-    ))(i)
+        map(
+            rule!(#identifier ~ "." ~ #identifier),
+            |(table, _, name)| {
+                let mut cn = ColumnName::default();
+                cn.table = CIStr::new(&table);
+                cn.name = CIStr::new(&name);
+                cn
+            },
+        ),
+        map(rule!(#identifier), |name| {
+            let mut cn = ColumnName::default();
+            cn.name = CIStr::new(&name);
+            cn
+        }),
+    )))(i)
 }
 
 pub fn column_name_list(i: Input) -> IResult<Vec<ColumnName>> {
@@ -48,5 +44,8 @@ pub fn column_name_list(i: Input) -> IResult<Vec<ColumnName>> {
 }
 
 pub fn simple_ident(i: Input) -> IResult<ColumnNameExpr> {
-    map(rule!(#column_name), |name| ColumnNameExpr { name: name })(i)
+    spanned(map(rule!(#column_name), |name| ColumnNameExpr {
+        span: None,
+        name,
+    }))(i)
 }
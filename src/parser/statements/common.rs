@@ -3,18 +3,19 @@ use crate::ast::common::{
     FULLTEXT_SEARCH_MODIFIER_BOOLEAN_MODE, FULLTEXT_SEARCH_MODIFIER_NATURAL_LANGUAGE_MODE,
     FULLTEXT_SEARCH_MODIFIER_WITH_QUERY_EXPANSION,
 };
-use crate::ast::expr_node::{ExprNode, ValueExpr, ValueExprKind};
+use crate::ast::expr_node::{ExprNode, TimeUnitExpr, ValueExpr, ValueExprKind};
 use crate::ast::functions::TimeUnitType;
 use crate::ast::table_name::TableName;
 use crate::charset::charset;
+use crate::common::escape::unescape_string;
 use crate::common::misc::is_in_correct_identifier_name;
 use crate::mysql::consts::PriorityEnum;
 use crate::parser::common::*;
-use crate::parser::error::ErrorKind;
+use crate::parser::error::{Error, ErrorKind};
 use crate::parser::input::Input;
 use crate::parser::statements::keywords::{
-    not_keyword_token, tidb_keyword, time_unit_1, timestamp_unit, timestamp_unit_sql_tsi,
-    un_reserved_keyword,
+    dialect_un_reserved_keyword, not_keyword_token, tidb_keyword, time_unit_1, timestamp_unit,
+    timestamp_unit_sql_tsi, un_reserved_keyword,
 };
 use crate::parser::token_kind::TokenKind::*;
 use nom::branch::alt;
@@ -29,8 +30,54 @@ pub fn priority(i: Input) -> IResult<PriorityEnum> {
     ))(i)
 }
 
+// LogOr is OR, `||` is also OR unless PIPES_AS_CONCAT is set.
 pub fn log_or(i: Input) -> IResult<()> {
-    map(rule!(OR | PipesAsOr), |_| {})(i)
+    if i.sql_mode.has_pipes_as_concat_mode() {
+        map(rule!(OR), |_| {})(i)
+    } else {
+        map(rule!(OR | PipesAsOr), |_| {})(i)
+    }
+}
+
+// Pipes is the `||` concatenation operator of PIPES_AS_CONCAT.
+pub fn pipes(i: Input) -> IResult<()> {
+    if i.sql_mode.has_pipes_as_concat_mode() {
+        map(rule!(PipesAsOr), |_| {})(i)
+    } else {
+        Err(nom::Err::Error(not_an_operator(i)))
+    }
+}
+
+// LowNot is the NOT operator of expression, NOT has the precedence of `!`
+// instead with HIGH_NOT_PRECEDENCE.
+pub fn low_not(i: Input) -> IResult<()> {
+    if i.sql_mode.has_high_not_precedence_mode() {
+        Err(nom::Err::Error(not_an_operator(i)))
+    } else {
+        map(rule!(NOT), |_| {})(i)
+    }
+}
+
+// NotAnOperator is the error of an operator which the sql_mode turns off, it
+// isn't recorded as an expected token since the token means something else.
+fn not_an_operator(i: Input) -> Error {
+    nom::error::ParseError::from_error_kind(i, nom::error::ErrorKind::Tag)
+}
+
+// HighNot is the NOT operator of simple_expr with HIGH_NOT_PRECEDENCE.
+pub fn high_not(i: Input) -> IResult<()> {
+    if i.sql_mode.has_high_not_precedence_mode() {
+        map(rule!(NOT | NOT2), |_| {})(i)
+    } else {
+        map(rule!(NOT2), |_| {})(i)
+    }
+}
+
+// GlobalScope is the optional `GLOBAL` or `SESSION`, it's true for GLOBAL.
+pub fn global_scope(i: Input) -> IResult<bool> {
+    map(rule!((GLOBAL | SESSION)?), |t| {
+        t.is_some_and(|t| t.kind == GLOBAL)
+    })(i)
 }
 
 pub fn log_and(i: Input) -> IResult<()> {
@@ -39,9 +86,26 @@ pub fn log_and(i: Input) -> IResult<()> {
 
 pub fn time_unit(i: Input) -> IResult<TimeUnitType> {
     alt((
-        map(rule!(#timestamp_unit), |(t)| t),
-        map(rule!(#timestamp_unit_sql_tsi), |(t)| t),
-        map(rule!(#time_unit_1), |(t)| t),
+        map(rule!(#timestamp_unit), |t| t),
+        map(rule!(#timestamp_unit_sql_tsi), |t| t),
+        map(rule!(#time_unit_1), |t| t),
+    ))(i)
+}
+
+// TimeUnitExpr is a time unit used as a function argument, e.g. the `DAY` in
+// `DATE_ADD(d, INTERVAL 1 DAY)`.
+pub fn time_unit_expr(i: Input) -> IResult<ExprNode> {
+    spanned(map(rule!(#time_unit), |unit| {
+        ExprNode::TimeUnitExpr(TimeUnitExpr { span: None, unit })
+    }))(i)
+}
+
+// TimestampUnitExpr is the time unit argument of TIMESTAMPADD and
+// TIMESTAMPDIFF.
+pub fn timestamp_unit_expr(i: Input) -> IResult<ExprNode> {
+    spanned(map(
+        rule!(#timestamp_unit | #timestamp_unit_sql_tsi),
+        |unit| ExprNode::TimeUnitExpr(TimeUnitExpr { span: None, unit }),
     ))(i)
 }
 
@@ -67,22 +131,37 @@ pub fn fulltext_search_modifier_opt(i: Input) -> IResult<isize> {
 }
 
 pub fn ident(i: Input) -> IResult<String> {
-    map(rule!(Ident), |t| t.text().to_string())(i)
+    map_res(rule!(Ident), |t| {
+        if i.dialect.is_reserved_ident(t.text()) {
+            return Err(nom::Err::Error(ErrorKind::Other("reserved keyword")));
+        }
+        Ok(t.ident_text())
+    })(i)
 }
 
-pub fn string_lit(i: Input) -> IResult<String> {
-    map(rule!(LiteralString), |t| t.text().to_string())(i)
+// StringValue is the value of a string literal, the quotes are removed and
+// the escape sequences are replaced.
+pub fn string_value(i: Input) -> IResult<String> {
+    map(rule!(LiteralString), |t| {
+        unescape_string(t.text(), i.sql_mode.has_no_backslash_escapes_mode())
+    })(i)
 }
 
 pub fn identifier(i: Input) -> IResult<String> {
     map(
-        rule!(#ident | #un_reserved_keyword | #not_keyword_token | #tidb_keyword),
-        |(s)| s,
+        rule!(
+            #ident
+                | #un_reserved_keyword
+                | #not_keyword_token
+                | #tidb_keyword
+                | #dialect_un_reserved_keyword
+        ),
+        |s| s,
     )(i)
 }
 
 pub fn ident_list(i: Input) -> IResult<Vec<CIStr>> {
-    map(rule!(#ident_string_list), |(idents)| {
+    map(rule!(#ident_string_list), |idents| {
         idents.iter().map(|ident| CIStr::new(ident)).collect()
     })(i)
 }
@@ -91,12 +170,12 @@ pub fn ident_string_list(i: Input) -> IResult<Vec<String>> {
 }
 
 pub fn string_name(i: Input) -> IResult<String> {
-    map(rule!(#string_lit | #identifier), |(s)| s)(i)
+    map(rule!(#string_value | #identifier), |s| s)(i)
 }
 
 pub fn charset_name(i: Input) -> IResult<String> {
     alt((
-        map(rule!(#string_name), |(s)| {
+        map(rule!(#string_name), |s| {
             /*
             // Validate input charset name to keep the same behavior as parser of MySQL.
             cs, err := charset.GetCharsetInfo($1)
@@ -111,7 +190,7 @@ pub fn charset_name(i: Input) -> IResult<String> {
              */
             s
         }),
-        map(rule!(BINARY), |(t)| t.text().to_string()),
+        map(rule!(BINARY), |t| t.text().to_string()),
     ))(i)
 }
 
@@ -152,12 +231,12 @@ pub fn field_len(i: Input) -> IResult<isize> {
 }
 
 pub fn length_num(i: Input) -> IResult<u64> {
-    map(rule!(LiteralInteger), |(val)| get_u64_form_num(val.text()))(i)
+    map(rule!(LiteralInteger), |val| get_u64_form_num(val.text()))(i)
 }
 
 pub fn table_name(i: Input) -> IResult<TableName> {
-    alt((
-        map(rule!(#identifier), |(table_name)| {
+    spanned(alt((
+        map(rule!(#identifier), |table_name| {
             let mut tbl_name = TableName::default();
             tbl_name.name = CIStr::new(&table_name);
             tbl_name
@@ -185,29 +264,24 @@ pub fn table_name(i: Input) -> IResult<TableName> {
             tbl_name.name = CIStr::new(&table_name);
             tbl_name
         }),
-    ))(i)
+    )))(i)
 }
 
 pub fn collation_name(i: Input) -> IResult<String> {
     alt((
         map_res(
             rule!(#string_name),
-            |(name)| match charset::get_collation_by_name(&name) {
+            |name| match charset::get_collation_by_name(&name) {
                 Ok(v) => Ok(v.name),
-                Err(e) => Err(nom::Err::Error(ErrorKind::ExpectText(&format!(
-                    "{}",
-                    e.to_string()
-                )))),
+                Err(_) => Err(nom::Err::Error(ErrorKind::Other("Unknown collation"))),
             },
         ),
-        map(rule!(BINARY), |(_)| Ok(charset::COLLATION_BIN.to_string())),
+        map(rule!(BINARY), |_| charset::COLLATION_BIN.to_string()),
     ))(i)
 }
 
 pub fn opt_null_treatment(i: Input) -> IResult<bool> {
-    map(rule!(#opt_null_treatment_sub?), |(b)| {
-        b.unwrap_or_else(|| false)
-    })(i)
+    map(rule!(#opt_null_treatment_sub?), |b| b.unwrap_or(false))(i)
 }
 
 pub fn opt_null_treatment_sub(i: Input) -> IResult<bool> {
@@ -218,9 +292,7 @@ pub fn opt_null_treatment_sub(i: Input) -> IResult<bool> {
 }
 
 pub fn opt_from_first_last(i: Input) -> IResult<bool> {
-    map(rule!(#opt_from_first_last_sub?), |(b)| {
-        b.unwrap_or_else(|| false)
-    })(i)
+    map(rule!(#opt_from_first_last_sub?), |b| b.unwrap_or(false))(i)
 }
 
 pub fn opt_from_first_last_sub(i: Input) -> IResult<bool> {
@@ -238,26 +310,23 @@ pub fn buggy_default_false_distinct_opt(i: Input) -> IResult<bool> {
 }
 
 pub fn default_false_distinct_opt(i: Input) -> IResult<bool> {
-    map(rule!(#distinct_opt?), |(b)| b.unwrap_or_else(|| false))(i)
+    map(rule!(#distinct_opt?), |b| b.unwrap_or(false))(i)
 }
 
 pub fn default_true_distinct_opt(i: Input) -> IResult<bool> {
-    map(rule!(#distinct_opt?), |(b)| b.unwrap_or_else(|| true))(i)
+    map(rule!(#distinct_opt?), |b| b.unwrap_or(true))(i)
 }
 
 pub fn distinct_opt(i: Input) -> IResult<bool> {
-    alt((
-        map(rule!(ALL), |(_)| false),
-        map(rule!(#distinct_kwd), |(b)| b),
-    ))(i)
+    alt((map(rule!(ALL), |_| false), map(rule!(#distinct_kwd), |b| b)))(i)
 }
 
 pub fn distinct_kwd(i: Input) -> IResult<bool> {
-    map(rule!(DISTINCT | DISTINCTROW), |(_)| true)
+    map(rule!(DISTINCT | DISTINCTROW), |_| true)(i)
 }
 
 pub fn opt_gconcat_separator(i: Input) -> IResult<ValueExpr> {
-    map(rule!(SEPARATOR ~ #string_lit), |(_, s)| {
+    map(rule!(SEPARATOR ~ #string_value), |(_, s)| {
         ValueExpr::new(&s, ValueExprKind::String, "", "")
-    })
+    })(i)
 }
@@ -0,0 +1,15 @@
+use crate::ast::do_stmt::DoStmt;
+use crate::ast::statement::Statement;
+use crate::parser::common::*;
+use crate::parser::input::Input;
+use crate::parser::statements::expression::expression_list;
+use crate::parser::token_kind::TokenKind::*;
+use nom::combinator::map;
+use nom_rule::rule;
+
+// See https://dev.mysql.com/doc/refman/5.7/en/do.html
+pub fn do_statement(i: Input) -> IResult<Statement> {
+    map(rule!(DO ~ #expression_list), |(_, exprs)| {
+        Statement::DoStmt(Box::new(DoStmt { span: None, exprs }))
+    })(i)
+}
@@ -1,11 +1,13 @@
 use crate::ast::ci_str::CIStr;
 use crate::ast::common::{FulltextSearchModifier, FULLTEXT_SEARCH_MODIFIER_NATURAL_LANGUAGE_MODE};
 use crate::ast::expr_node::{
-    AggregateFuncExpr, BinaryOperationExpr, ExprNode, FuncCallExpr, FuncCallExprType,
-    GetFormatSelectorExpr, MatchAgainst, ParamMarkerExpr, PositionExpr, SetCollationExpr,
-    TableNameExpr, TimeUnitExpr, TrimDirectionExpr, UnaryOperationExpr, ValueExpr, ValueExprKind,
-    VariableExpr, WindowFuncExpr,
+    AggregateFuncExpr, BinaryOperationExpr, CaseExpr, CastFunctionType, ExistsSubqueryExpr,
+    ExprNode, FuncCallExpr, FuncCallExprType, FuncCastExpr, GetFormatSelectorExpr, MatchAgainst,
+    ParamMarkerExpr, ParenthesesExpr, PositionExpr, RowExpr, SetCollationExpr, TableNameExpr,
+    TimeUnitExpr, TrimDirectionExpr, UnaryOperationExpr, ValueExpr, ValueExprKind, VariableExpr,
+    WhenClause, WindowFuncExpr,
 };
+use crate::ast::field_type::{DataType, FieldType};
 use crate::ast::frame_clause::{BoundType, FrameBound, FrameClause, FrameExtent, FrameType};
 use crate::ast::functions;
 use crate::ast::functions::TimeUnitType;
@@ -13,79 +15,95 @@ use crate::ast::group_by_clause::ByItem;
 use crate::ast::op_code::OpCode;
 use crate::ast::order_by_clause::OrderByClause;
 use crate::ast::partition_by_clause::PartitionByClause;
+use crate::ast::spanned::Spanned;
 use crate::ast::window_spec::WindowSpec;
+use crate::charset::charset;
+use crate::common::json_path::is_valid_json_path;
 use crate::common::misc::is_in_token_map;
+use crate::common::span::{merge_span, Span};
 use crate::parser::common::*;
+use crate::parser::error::{Error, ErrorKind};
 use crate::parser::input::Input;
 use crate::parser::statements::column_name::{column_name_list, simple_ident};
 use crate::parser::statements::common::{
-    buggy_default_false_distinct_opt, collation_name, distinct_kwd, field_len,
-    fulltext_search_modifier_opt, func_datetime_prec, func_datetime_prec_list_opt, log_and, log_or,
-    opt_from_first_last, opt_gconcat_separator, opt_null_treatment, optional_braces, string_lit,
-    table_name, time_unit,
+    buggy_default_false_distinct_opt, charset_name, collation_name, distinct_kwd, field_len,
+    fulltext_search_modifier_opt, func_datetime_prec, func_datetime_prec_list_opt, high_not,
+    log_and, log_or, low_not, opt_from_first_last, opt_gconcat_separator, opt_null_treatment,
+    optional_braces, pipes, string_value, table_name, time_unit, time_unit_expr,
+    timestamp_unit_expr,
 };
+use crate::parser::statements::field_type::cast_type;
 use crate::parser::statements::keywords::{
     function_name_conflict, function_name_date_arith, function_name_date_arith_multi_forms,
     function_name_datetime_precision, function_name_optional_braces, get_format_selector,
-    timestamp_unit, timestamp_unit_sql_tsi, trim_direction,
+    trim_direction,
 };
 use crate::parser::statements::literal::literal;
 use crate::parser::statements::num_literal::{num_literal, signed_num};
-use crate::parser::statements::vairable::vairable;
+use crate::parser::statements::predicate::bool_pri;
+use crate::parser::statements::select_statement::sub_select;
+use crate::parser::statements::vairable::{at_ident_name, vairable};
 use crate::parser::token_kind::TokenKind::*;
 use nom::branch::alt;
 use nom::combinator::map;
 use nom_rule::rule;
 
+// Expression is parsed by precedence climbing, from the lowest precedence:
+// assignment, OR, XOR, AND, NOT and then bool_pri. The binary operators of a
+// level are left associative, e.g. `a OR b OR c` is `(a OR b) OR c`.
+// See https://dev.mysql.com/doc/refman/8.0/en/operator-precedence.html
 pub fn expression(i: Input) -> IResult<ExprNode> {
-    alt((
+    spanned(alt((
         map(
             rule!(SingleAtIdent ~ AssignmentEq ~ #expression),
             |(s_ident, _, expr_node)| {
-                let s_ident = s_ident.text().trim_start_matches(|c| c == '@');
                 let mut expr = VariableExpr::default();
-                expr.name = s_ident.to_string();
+                expr.name = at_ident_name(i, &s_ident.text()[1..]);
                 expr.value = Some(Box::new(expr_node));
                 ExprNode::VariableExpr(expr)
             },
         ),
-        map(
-            rule!(#expression ~ #log_or ~ #expression),
-            |(expr1, _, expr2)| {
-                ExprNode::BinaryOperationExpr(BinaryOperationExpr {
-                    op: OpCode::LogicOr,
-                    l: Some(Box::new(expr1)),
-                    r: Some(Box::new(expr2)),
-                })
-            },
-        ),
-        map(
-            rule!(#expression ~ "XOR" ~ #expression),
-            |(expr1, _, expr2)| {
-                ExprNode::BinaryOperationExpr(BinaryOperationExpr {
-                    op: OpCode::LogicXor,
-                    l: Some(Box::new(expr1)),
-                    r: Some(Box::new(expr2)),
-                })
-            },
-        ),
-        map(
-            rule!(#expression ~ #log_and ~ #expression),
-            |(expr1, _, expr2)| {
-                ExprNode::BinaryOperationExpr(BinaryOperationExpr {
-                    op: OpCode::LogicAnd,
-                    l: Some(Box::new(expr1)),
-                    r: Some(Box::new(expr2)),
-                })
-            },
-        ),
-        map(rule!(NOT ~ #expression), |(_, mut expr)| match expr {
+        or_expr,
+    )))(i)
+}
+
+fn or_expr(i: Input) -> IResult<ExprNode> {
+    let tail = with_span(map(rule!(#log_or ~ #xor_expr), |(_, r)| {
+        BinaryTail::Op(OpCode::LogicOr, r)
+    }));
+    map(rule!(#xor_expr ~ #tail*), |(expr, tails)| {
+        fold_tails(expr, tails)
+    })(i)
+}
+
+fn xor_expr(i: Input) -> IResult<ExprNode> {
+    let tail = with_span(map(rule!(XOR ~ #and_expr), |(_, r)| {
+        BinaryTail::Op(OpCode::LogicXor, r)
+    }));
+    map(rule!(#and_expr ~ #tail*), |(expr, tails)| {
+        fold_tails(expr, tails)
+    })(i)
+}
+
+fn and_expr(i: Input) -> IResult<ExprNode> {
+    let tail = with_span(map(rule!(#log_and ~ #not_expr), |(_, r)| {
+        BinaryTail::Op(OpCode::LogicAnd, r)
+    }));
+    map(rule!(#not_expr ~ #tail*), |(expr, tails)| {
+        fold_tails(expr, tails)
+    })(i)
+}
+
+fn not_expr(i: Input) -> IResult<ExprNode> {
+    spanned(alt((
+        map(rule!(#low_not ~ #not_expr), |(_, expr)| match expr {
             ExprNode::ExistsSubqueryExpr(mut v) => {
                 v.not = !v.not;
                 ExprNode::ExistsSubqueryExpr(v)
             }
             _ => {
                 let e = UnaryOperationExpr {
+                    span: None,
                     op: OpCode::Not,
                     v: Some(Box::new(expr)),
                 };
@@ -95,172 +113,216 @@ pub fn expression(i: Input) -> IResult<ExprNode> {
         map(
             rule!(MATCH ~ "(" ~ #column_name_list ~ ")" ~ AGAINST ~ "(" ~ #bit_expr ~ #fulltext_search_modifier_opt? ~ ")"),
             |(_, _, columns, _, _, _, expr, opt_value, _)| {
-                let modifier =
-                    opt_value.unwrap_or_else(|| FULLTEXT_SEARCH_MODIFIER_NATURAL_LANGUAGE_MODE);
+                let modifier = opt_value.unwrap_or(FULLTEXT_SEARCH_MODIFIER_NATURAL_LANGUAGE_MODE);
 
                 ExprNode::MatchAgainst(MatchAgainst {
+                    span: None,
                     column_names: columns,
                     against: Some(Box::new(expr)),
                     modifier: FulltextSearchModifier { v: modifier },
                 })
             },
         ),
-    ))(i)
+        bool_pri,
+    )))(i)
 }
 
-pub fn bit_expr(i: Input) -> IResult<ExprNode> {
-    alt((
-        map(rule!(#bit_expr ~ "|" ~ #bit_expr), |(l, _, r)| {
-            ExprNode::BinaryOperationExpr(BinaryOperationExpr {
-                op: OpCode::Or,
-                l: Some(Box::new(l)),
-                r: Some(Box::new(r)),
-            })
-        }),
-        map(rule!(#bit_expr ~ "&" ~ #bit_expr), |(l, _, r)| {
-            ExprNode::BinaryOperationExpr(BinaryOperationExpr {
-                op: OpCode::And,
-                l: Some(Box::new(l)),
-                r: Some(Box::new(r)),
-            })
-        }),
-        map(rule!(#bit_expr ~ "<<" ~ #bit_expr), |(l, _, r)| {
-            ExprNode::BinaryOperationExpr(BinaryOperationExpr {
-                op: OpCode::LeftShift,
-                l: Some(Box::new(l)),
-                r: Some(Box::new(r)),
-            })
-        }),
-        map(rule!(#bit_expr ~ ">>" ~ #bit_expr), |(l, _, r)| {
-            ExprNode::BinaryOperationExpr(BinaryOperationExpr {
-                op: OpCode::RightShift,
-                l: Some(Box::new(l)),
-                r: Some(Box::new(r)),
-            })
-        }),
-        map(rule!(#bit_expr ~ "+" ~ #bit_expr), |(l, _, r)| {
-            ExprNode::BinaryOperationExpr(BinaryOperationExpr {
-                op: OpCode::Plus,
-                l: Some(Box::new(l)),
-                r: Some(Box::new(r)),
-            })
-        }),
-        map(rule!(#bit_expr ~ "-" ~ #bit_expr), |(l, _, r)| {
-            ExprNode::BinaryOperationExpr(BinaryOperationExpr {
-                op: OpCode::Minus,
+// BinaryTail is an operator and its right operand, applied to the expression
+// on its left, e.g. the `+ 1` of `a + 1`.
+#[allow(clippy::large_enum_variant)]
+enum BinaryTail {
+    Op(OpCode, ExprNode),
+    // `+ INTERVAL expr unit` and `- INTERVAL expr unit` are DATE_ADD and
+    // DATE_SUB calls.
+    DateArith(&'static str, ExprNode, ExprNode),
+    // `||` is a CONCAT call with PIPES_AS_CONCAT.
+    Concat(ExprNode),
+}
+
+impl BinaryTail {
+    fn apply(self, l: ExprNode) -> ExprNode {
+        match self {
+            BinaryTail::Op(op, r) => ExprNode::BinaryOperationExpr(BinaryOperationExpr {
+                span: None,
+                op,
                 l: Some(Box::new(l)),
                 r: Some(Box::new(r)),
-            })
-        }),
-        map(
-            rule!(#bit_expr ~ "+" ~ INTERVAL ~ #expression ~ #time_unit),
-            |(be, _, _, expr, tu)| {
-                let time_expr = ExprNode::TimeUnitExpr(TimeUnitExpr { unit: tu });
-
+            }),
+            BinaryTail::DateArith(fn_name, expr, unit) => {
                 let mut fn_expr = FuncCallExpr::default();
-                fn_expr.fn_name = CIStr::new("DATE_ADD");
-                fn_expr.args = vec![be, expr, time_expr];
-
+                fn_expr.fn_name = CIStr::new(fn_name);
+                fn_expr.args = vec![l, expr, unit];
                 ExprNode::FuncCallExpr(fn_expr)
-            },
-        ),
-        map(
-            rule!(#bit_expr ~ "-" ~ INTERVAL ~ #expression ~ #time_unit),
-            |(be, _, _, expr, tu)| {
-                let time_expr = ExprNode::TimeUnitExpr(TimeUnitExpr { unit: tu });
-
+            }
+            BinaryTail::Concat(r) => {
                 let mut fn_expr = FuncCallExpr::default();
-                fn_expr.fn_name = CIStr::new("DATE_SUB");
-                fn_expr.args = vec![be, expr, time_expr];
-
+                fn_expr.fn_name = CIStr::new(functions::CONCAT);
+                fn_expr.args = vec![l, r];
                 ExprNode::FuncCallExpr(fn_expr)
-            },
-        ),
-        map(
-            rule!(INTERVAL ~ #expression ~ #time_unit ~ "+" ~ #bit_expr),
-            |(_, expr, tu, _, be)| {
-                let time_expr = ExprNode::TimeUnitExpr(TimeUnitExpr { unit: tu });
+            }
+        }
+    }
+}
 
-                let mut fn_expr = FuncCallExpr::default();
-                fn_expr.fn_name = CIStr::new("DATE_ADD");
-                fn_expr.args = vec![be, expr, time_expr];
+// FoldTails applies the tails from left to right, the span of each node runs
+// from its left operand to the end of its tail.
+fn fold_tails(expr: ExprNode, tails: Vec<(BinaryTail, Span)>) -> ExprNode {
+    tails.into_iter().fold(expr, |l, (tail, span)| {
+        let span = merge_span(l.span(), span);
+        let mut expr = tail.apply(l);
+        expr.set_span(span);
+        expr
+    })
+}
 
-                ExprNode::FuncCallExpr(fn_expr)
-            },
+// BitExpr is parsed by precedence climbing too, from the lowest precedence:
+// `|`, `&`, `<<` and `>>`, `+` and `-`, `*`, `/`, `%`, DIV and MOD, `^` and
+// then simple_expr.
+pub fn bit_expr(i: Input) -> IResult<ExprNode> {
+    let tail = with_span(map(rule!("|" ~ #bit_and_expr), |(_, r)| {
+        BinaryTail::Op(OpCode::Or, r)
+    }));
+    map(rule!(#bit_and_expr ~ #tail*), |(expr, tails)| {
+        fold_tails(expr, tails)
+    })(i)
+}
+
+fn bit_and_expr(i: Input) -> IResult<ExprNode> {
+    let tail = with_span(map(rule!("&" ~ #shift_expr), |(_, r)| {
+        BinaryTail::Op(OpCode::And, r)
+    }));
+    map(rule!(#shift_expr ~ #tail*), |(expr, tails)| {
+        fold_tails(expr, tails)
+    })(i)
+}
+
+fn shift_expr(i: Input) -> IResult<ExprNode> {
+    let tail = with_span(alt((
+        map(rule!("<<" ~ #additive_expr), |(_, r)| {
+            BinaryTail::Op(OpCode::LeftShift, r)
+        }),
+        map(rule!(">>" ~ #additive_expr), |(_, r)| {
+            BinaryTail::Op(OpCode::RightShift, r)
+        }),
+    )));
+    map(rule!(#additive_expr ~ #tail*), |(expr, tails)| {
+        fold_tails(expr, tails)
+    })(i)
+}
+
+fn additive_expr(i: Input) -> IResult<ExprNode> {
+    let tail = with_span(alt((
+        map(
+            rule!("+" ~ INTERVAL ~ #expression ~ #time_unit_expr),
+            |(_, _, expr, unit)| BinaryTail::DateArith("DATE_ADD", expr, unit),
         ),
-        map(rule!(#bit_expr ~ "*" ~ #bit_expr), |(l, _, r)| {
-            ExprNode::BinaryOperationExpr(BinaryOperationExpr {
-                op: OpCode::Mul,
-                l: Some(Box::new(l)),
-                r: Some(Box::new(r)),
-            })
+        map(
+            rule!("-" ~ INTERVAL ~ #expression ~ #time_unit_expr),
+            |(_, _, expr, unit)| BinaryTail::DateArith("DATE_SUB", expr, unit),
+        ),
+        map(rule!("+" ~ #multiplicative_expr), |(_, r)| {
+            BinaryTail::Op(OpCode::Plus, r)
         }),
-        map(rule!(#bit_expr ~ "/" ~ #bit_expr), |(l, _, r)| {
-            ExprNode::BinaryOperationExpr(BinaryOperationExpr {
-                op: OpCode::Div,
-                l: Some(Box::new(l)),
-                r: Some(Box::new(r)),
-            })
+        map(rule!("-" ~ #multiplicative_expr), |(_, r)| {
+            BinaryTail::Op(OpCode::Minus, r)
         }),
-        map(rule!(#bit_expr ~ "%" ~ #bit_expr), |(l, _, r)| {
-            ExprNode::BinaryOperationExpr(BinaryOperationExpr {
-                op: OpCode::Mod,
-                l: Some(Box::new(l)),
-                r: Some(Box::new(r)),
-            })
+    )));
+    // `INTERVAL expr unit + expr` is DATE_ADD with the operands swapped.
+    let interval = spanned(map(
+        rule!(INTERVAL ~ #expression ~ #time_unit_expr ~ "+" ~ #multiplicative_expr),
+        |(_, expr, unit, _, be)| BinaryTail::DateArith("DATE_ADD", expr, unit).apply(be),
+    ));
+    map(
+        rule!((#interval | #multiplicative_expr) ~ #tail*),
+        |(expr, tails)| fold_tails(expr, tails),
+    )(i)
+}
+
+fn multiplicative_expr(i: Input) -> IResult<ExprNode> {
+    let tail = with_span(alt((
+        map(rule!("*" ~ #bit_xor_expr), |(_, r)| {
+            BinaryTail::Op(OpCode::Mul, r)
         }),
-        map(rule!(#bit_expr ~ DIV ~ #bit_expr), |(l, _, r)| {
-            ExprNode::BinaryOperationExpr(BinaryOperationExpr {
-                op: OpCode::IntDiv,
-                l: Some(Box::new(l)),
-                r: Some(Box::new(r)),
-            })
+        map(rule!("/" ~ #bit_xor_expr), |(_, r)| {
+            BinaryTail::Op(OpCode::Div, r)
         }),
-        map(rule!(#bit_expr ~ MOD ~ #bit_expr), |(l, _, r)| {
-            ExprNode::BinaryOperationExpr(BinaryOperationExpr {
-                op: OpCode::Mod,
-                l: Some(Box::new(l)),
-                r: Some(Box::new(r)),
-            })
+        map(rule!("%" ~ #bit_xor_expr), |(_, r)| {
+            BinaryTail::Op(OpCode::Mod, r)
         }),
-        map(rule!(#bit_expr ~ "^" ~ #bit_expr), |(l, _, r)| {
-            ExprNode::BinaryOperationExpr(BinaryOperationExpr {
-                op: OpCode::Xor,
-                l: Some(Box::new(l)),
-                r: Some(Box::new(r)),
-            })
+        map(rule!(DIV ~ #bit_xor_expr), |(_, r)| {
+            BinaryTail::Op(OpCode::IntDiv, r)
         }),
-        map(rule!(#simple_expr), |(expr)| expr),
-    ))(i)
+        map(rule!(MOD ~ #bit_xor_expr), |(_, r)| {
+            BinaryTail::Op(OpCode::Mod, r)
+        }),
+    )));
+    map(rule!(#bit_xor_expr ~ #tail*), |(expr, tails)| {
+        fold_tails(expr, tails)
+    })(i)
+}
+
+fn bit_xor_expr(i: Input) -> IResult<ExprNode> {
+    let tail = with_span(map(rule!("^" ~ #simple_expr), |(_, r)| {
+        BinaryTail::Op(OpCode::Xor, r)
+    }));
+    map(rule!(#simple_expr ~ #tail*), |(expr, tails)| {
+        fold_tails(expr, tails)
+    })(i)
 }
 
+// SimpleExpr is an operand concatenated by `||` with PIPES_AS_CONCAT, the
+// unary operators and COLLATE bind tighter than `||`.
 pub fn simple_expr(i: Input) -> IResult<ExprNode> {
-    map(rule!(#simple_expr_sub_1 | #simple_expr_sub_2), |expr| expr)(i)
+    let tail = with_span(map(rule!(#pipes ~ #collate_expr), |(_, r)| {
+        BinaryTail::Concat(r)
+    }));
+    map(rule!(#collate_expr ~ #tail*), |(expr, tails)| {
+        fold_tails(expr, tails)
+    })(i)
+}
+
+// CollateExpr is an operand followed by any number of COLLATE clauses.
+fn collate_expr(i: Input) -> IResult<ExprNode> {
+    let tail = with_span(map(rule!(COLLATE ~ #collation_name), |(_, collate)| {
+        collate
+    }));
+    map_res(
+        rule!((#simple_expr_sub_1 | #simple_expr_sub_2) ~ #tail*),
+        |(expr, tails)| {
+            tails.into_iter().try_fold(expr, |expr, (collate, span)| {
+                if !is_collation_of_literal(&expr, &collate) {
+                    return Err(nom::Err::Error(ErrorKind::Other(
+                        "COLLATION is not valid for the CHARACTER SET of the string",
+                    )));
+                }
+                Ok(ExprNode::SetCollationExpr(SetCollationExpr {
+                    span: merge_span(expr.span(), span),
+                    expr: Some(Box::new(expr)),
+                    collate,
+                }))
+            })
+        },
+    )(i)
 }
 
 pub fn simple_expr_sub_1(i: Input) -> IResult<ExprNode> {
-    alt((
-        map(rule!(#simple_ident), |expr| ExprNode::ColumnNameExpr(expr)),
+    spanned(alt((
+        map(rule!(#paren_expr), |expr| expr),
+        map(rule!(#literal), |expr| expr),
+        map(rule!(#json_column_path), |expr| expr),
         map(rule!(#function_call_keyword), |expr| expr),
+        map(rule!(#case_expr), ExprNode::CaseExpr),
+        map(rule!(#cast_expr), |expr| expr),
         map(rule!(#function_call_non_keyword), |expr| expr),
         map(rule!(#function_call_generic), |expr| {
             ExprNode::FuncCallExpr(expr)
         }),
-        map(
-            rule!(#simple_expr ~ COLLATE ~ #collation_name),
-            |(expr, _, collate)| {
-                ExprNode::SetCollationExpr(SetCollationExpr {
-                    expr: Some(Box::new(expr)),
-                    collate,
-                })
-            },
-        ),
-        map(rule!(#window_func_call), |(expr)| {
+        map(rule!(#window_func_call), |expr| {
             ExprNode::WindowFuncExpr(expr)
         }),
-        map(rule!(#literal), |(expr)| expr),
-        map(rule!("?"), |(t)| {
+        map(rule!("?"), |t| {
             ExprNode::ParamMarkerExpr(ParamMarkerExpr {
+                span: None,
                 offset: 0,
                 order: 0,
                 in_execute: false,
@@ -270,59 +332,222 @@ pub fn simple_expr_sub_1(i: Input) -> IResult<ExprNode> {
                 end_pos: t.span.end as usize,
             })
         }),
-        map(rule!(#vairable), |(expr)| expr),
-        map(rule!(#sum_expr), |(expr)| expr),
-        map(rule!("!" ~ #simple_expr), |(_, expr)| {
+        map(rule!(#vairable), |expr| expr),
+        map(rule!(#sum_expr), |expr| expr),
+        // A column name is tried after the function calls which start with
+        // an identifier too.
+        map(rule!(#simple_ident), ExprNode::ColumnNameExpr),
+        map(rule!("!" ~ #collate_expr), |(_, expr)| {
             ExprNode::UnaryOperationExpr(UnaryOperationExpr {
+                span: None,
                 op: OpCode::Not2,
                 v: Some(Box::new(expr)),
             })
         }),
-        map(rule!("~" ~ #simple_expr), |(_, expr)| {
+        map(rule!("~" ~ #collate_expr), |(_, expr)| {
             ExprNode::UnaryOperationExpr(UnaryOperationExpr {
+                span: None,
                 op: OpCode::BitNeg,
                 v: Some(Box::new(expr)),
             })
         }),
-        map(rule!("-" ~ #simple_expr), |(_, expr)| {
+        map(rule!("-" ~ #collate_expr), |(_, expr)| {
             ExprNode::UnaryOperationExpr(UnaryOperationExpr {
+                span: None,
                 op: OpCode::Minus,
                 v: Some(Box::new(expr)),
             })
         }),
-        map(rule!("+" ~ #simple_expr), |(_, expr)| {
+        map(rule!("+" ~ #collate_expr), |(_, expr)| {
             ExprNode::UnaryOperationExpr(UnaryOperationExpr {
+                span: None,
                 op: OpCode::Plus,
                 v: Some(Box::new(expr)),
             })
         }),
-        map(
-            rule!(#simple_expr ~ PipesAsOr ~ #simple_expr),
-            |(expr1, _, expr2)| {
-                let mut fn_expr = FuncCallExpr::default();
-                fn_expr.fn_name = CIStr::new(functions::CONCAT);
-                fn_expr.args = vec![expr1, expr2];
-                ExprNode::FuncCallExpr(fn_expr)
-            },
-        ),
-        map(rule!(NOT2 ~ #simple_expr), |(_, expr)| {
+        map(rule!(#high_not ~ #collate_expr), |(_, expr)| {
             ExprNode::UnaryOperationExpr(UnaryOperationExpr {
+                span: None,
                 op: OpCode::Not2,
                 v: Some(Box::new(expr)),
             })
         }),
+    )))(i)
+}
+
+// JsonColumnPath is `col->'$.path'` or `col->>'$.path'`, they are parsed as
+// JSON_EXTRACT and JSON_UNQUOTE(JSON_EXTRACT()) calls.
+pub fn json_column_path(i: Input) -> IResult<ExprNode> {
+    let arrow = check_json_arrow(true, rule!(RArrow | LongRArrow));
+    spanned(map(
+        rule!(#simple_ident ~ #arrow ~ #json_path_lit),
+        |(column, arrow, path)| {
+            let mut extract = FuncCallExpr::default();
+            extract.span = merge_span(column.span, path.span);
+            extract.tp = FuncCallExprType::JsonExtractArrow;
+            extract.fn_name = CIStr::new(functions::JSON_EXTRACT);
+            extract.args = vec![ExprNode::ColumnNameExpr(column), ExprNode::ValueExpr(path)];
+            if arrow.kind == RArrow {
+                return ExprNode::FuncCallExpr(extract);
+            }
+
+            let mut unquote = FuncCallExpr::default();
+            unquote.tp = FuncCallExprType::JsonUnquoteArrow;
+            unquote.fn_name = CIStr::new(functions::JSON_UNQUOTE);
+            unquote.args = vec![ExprNode::FuncCallExpr(extract)];
+            ExprNode::FuncCallExpr(unquote)
+        },
+    ))(i)
+}
+
+// JsonPathLit is a string literal holding a JSON path expression, an invalid
+// path is reported at the literal without trying other branches.
+fn json_path_lit(i: Input) -> IResult<ValueExpr> {
+    let (rest, path) = string_value(i)?;
+    if !is_valid_json_path(&path) {
+        return Err(nom::Err::Failure(Error::from_error_kind(
+            i,
+            ErrorKind::Other("Invalid JSON path expression"),
+        )));
+    }
+    let mut path = ValueExpr::new(&path, ValueExprKind::String, "", "");
+    path.span = transform_span(&i.tokens[..1]);
+    Ok((rest, path))
+}
+
+// CaseExpr is `CASE [value] WHEN ... THEN ... [ELSE ...] END`.
+pub fn case_expr(i: Input) -> IResult<CaseExpr> {
+    spanned(map(
+        rule!(CASE ~ #expression? ~ #when_clause+ ~ (ELSE ~ #expression)? ~ END),
+        |(_, value, when_clauses, else_clause, _)| CaseExpr {
+            span: None,
+            value: value.map(Box::new),
+            when_clauses,
+            else_clause: else_clause.map(|(_, expr)| Box::new(expr)),
+        },
     ))(i)
 }
 
+pub fn when_clause(i: Input) -> IResult<WhenClause> {
+    spanned(map(
+        rule!(WHEN ~ #expression ~ THEN ~ #expression),
+        |(_, expr, _, result)| WhenClause {
+            span: None,
+            expr: Box::new(expr),
+            result: Box::new(result),
+        },
+    ))(i)
+}
+
+// CastExpr is CAST(expr AS type), CONVERT(expr, type), CONVERT(expr USING
+// charset) or BINARY expr.
+pub fn cast_expr(i: Input) -> IResult<ExprNode> {
+    let new_cast = |expr, tp: FieldType, function_type| {
+        ExprNode::FuncCastExpr(FuncCastExpr {
+            span: None,
+            expr: Some(Box::new(expr)),
+            explicit_charset: !tp.charset.is_empty(),
+            tp,
+            function_type,
+        })
+    };
+
+    spanned(alt((
+        map(
+            rule!(CAST ~ "(" ~ #expression ~ AS ~ #cast_type ~ ")"),
+            move |(_, _, expr, _, tp, _)| new_cast(expr, tp, CastFunctionType::CastFunction),
+        ),
+        map(
+            rule!(CONVERT ~ "(" ~ #expression ~ "," ~ #cast_type ~ ")"),
+            move |(_, _, expr, _, tp, _)| new_cast(expr, tp, CastFunctionType::CastConvertFunction),
+        ),
+        map(
+            rule!(CONVERT ~ "(" ~ #expression ~ USING ~ #charset_name ~ ")"),
+            |(_, _, expr, _, charset, _)| {
+                let charset = ValueExpr::new(&charset, ValueExprKind::String, "", "");
+                let mut fn_expr = FuncCallExpr::default();
+                fn_expr.fn_name = CIStr::new(functions::CONVERT);
+                fn_expr.args = vec![expr, ExprNode::ValueExpr(charset)];
+                ExprNode::FuncCallExpr(fn_expr)
+            },
+        ),
+        map(rule!(BINARY ~ #simple_expr), move |(_, expr)| {
+            let mut tp = FieldType::new(DataType::VarBinary);
+            tp.charset = "binary".to_string();
+            new_cast(expr, tp, CastFunctionType::CastBinaryOperator)
+        }),
+    )))(i)
+}
+
+// The COLLATE of a string literal with a known charset must be one of the
+// charset's collations, e.g. `_latin1 'a' COLLATE utf8mb4_bin` is invalid.
+fn is_collation_of_literal(expr: &ExprNode, collate: &str) -> bool {
+    let value = match expr {
+        ExprNode::ValueExpr(value)
+            if matches!(value.kind, ValueExprKind::String) && !value.charset.is_empty() =>
+        {
+            value
+        }
+        _ => return true,
+    };
+    // utf8mb3 is an alias of utf8.
+    let normalize = |cs: &str| {
+        let cs = cs.to_lowercase();
+        if cs == charset::CHARSET_UTF8MB3 {
+            charset::CHARSET_UTF8.to_string()
+        } else {
+            cs
+        }
+    };
+    match charset::get_collation_by_name(collate) {
+        Ok(co) => normalize(&co.charset_name) == normalize(&value.charset),
+        Err(_) => false,
+    }
+}
+
 pub fn simple_expr_sub_2(i: Input) -> IResult<ExprNode> {
-    alt((
-        map(rule!(#simple_ident), |expr| ExprNode::ColumnNameExpr(expr)),
+    spanned(alt((
+        map(rule!(#simple_ident), ExprNode::ColumnNameExpr),
         map(rule!(#function_call_keyword), |expr| expr),
-    ))(i)
+    )))(i)
+}
+
+// ParenExpr is an operand in parentheses: a subquery, `EXISTS (subquery)`,
+// an expression in parentheses or a row constructor.
+fn paren_expr(i: Input) -> IResult<ExprNode> {
+    spanned(alt((
+        map(rule!(#sub_select), ExprNode::SubQueryExpr),
+        map(
+            rule!(EXISTS ~ #with_span(sub_select)),
+            |(_, (mut sub_query, span))| {
+                sub_query.exists = true;
+                sub_query.span = span;
+                ExprNode::ExistsSubqueryExpr(ExistsSubqueryExpr {
+                    span: None,
+                    sel: Some(Box::new(ExprNode::SubQueryExpr(sub_query))),
+                    not: false,
+                })
+            },
+        ),
+        map(rule!("(" ~ #expression ~ ")"), |(_, expr, _)| {
+            ExprNode::ParenthesesExpr(ParenthesesExpr {
+                span: None,
+                expr: Some(Box::new(expr)),
+            })
+        }),
+        map(
+            rule!(ROW? ~ "(" ~ #expression ~ "," ~ #expression_list ~ ")"),
+            |(_, _, first, _, rest, _)| {
+                let mut values = vec![first];
+                values.extend(rest);
+                ExprNode::RowExpr(RowExpr { span: None, values })
+            },
+        ),
+    )))(i)
 }
 
 pub fn function_call_keyword(i: Input) -> IResult<ExprNode> {
-    alt((
+    spanned(alt((
         map(
             rule!(#function_name_conflict ~ "(" ~ #expression_list_opt ~ ")"),
             |(fn_name, _, exprs, _)| {
@@ -409,7 +634,7 @@ pub fn function_call_keyword(i: Input) -> IResult<ExprNode> {
                 ExprNode::FuncCallExpr(fn_expr)
             },
         ),
-        map(rule!(DATE ~ #string_lit), |(_, s)| {
+        map(rule!(DATE ~ #string_value), |(_, s)| {
             let value_expr = ValueExpr::new(&s, ValueExprKind::String, "", "");
             let exprs = vec![ExprNode::ValueExpr(value_expr)];
 
@@ -418,7 +643,7 @@ pub fn function_call_keyword(i: Input) -> IResult<ExprNode> {
             fn_expr.args = exprs;
             ExprNode::FuncCallExpr(fn_expr)
         }),
-        map(rule!(TIME ~ #string_lit), |(_, s)| {
+        map(rule!(TIME ~ #string_value), |(_, s)| {
             let value_expr = ValueExpr::new(&s, ValueExprKind::String, "", "");
             let exprs = vec![ExprNode::ValueExpr(value_expr)];
 
@@ -427,7 +652,7 @@ pub fn function_call_keyword(i: Input) -> IResult<ExprNode> {
             fn_expr.args = exprs;
             ExprNode::FuncCallExpr(fn_expr)
         }),
-        map(rule!(TIMESTAMP ~ #string_lit), |(_, val)| {
+        map(rule!(TIMESTAMP ~ #string_value), |(_, val)| {
             let value_expr = ValueExpr::new(&val, ValueExprKind::String, "", "");
             let exprs = vec![ExprNode::ValueExpr(value_expr)];
 
@@ -446,6 +671,7 @@ pub fn function_call_keyword(i: Input) -> IResult<ExprNode> {
             rule!(MOD ~ "(" ~ #bit_expr ~ "," ~ #bit_expr ~ ")"),
             |(_, _, l, _, r, _)| {
                 ExprNode::BinaryOperationExpr(BinaryOperationExpr {
+                    span: None,
                     op: OpCode::Mod,
                     l: Some(Box::new(l)),
                     r: Some(Box::new(r)),
@@ -461,18 +687,18 @@ pub fn function_call_keyword(i: Input) -> IResult<ExprNode> {
                 ExprNode::FuncCallExpr(fn_expr)
             },
         ),
-    ))(i)
+    )))(i)
 }
 
 pub fn function_call_non_keyword(i: Input) -> IResult<ExprNode> {
-    map(
+    spanned(map(
         rule!(#function_call_non_keyword_1 | #function_call_non_keyword_2),
-        |(e)| e,
-    )(i)
+        |e| e,
+    ))(i)
 }
 
 pub fn function_call_non_keyword_1(i: Input) -> IResult<ExprNode> {
-    alt((
+    spanned(alt((
         map(
             rule!(CURTIME ~ "(" ~ #func_datetime_prec_list_opt ~ ")"),
             |(fn_name, _, exprs, _)| {
@@ -500,6 +726,7 @@ pub fn function_call_non_keyword_1(i: Input) -> IResult<ExprNode> {
                     expr1,
                     expr2,
                     ExprNode::TimeUnitExpr(TimeUnitExpr {
+                        span: None,
                         unit: TimeUnitType::TimeUnitDay,
                     }),
                 ];
@@ -507,29 +734,29 @@ pub fn function_call_non_keyword_1(i: Input) -> IResult<ExprNode> {
             },
         ),
         map(
-            rule!(#function_name_date_arith_multi_forms ~ "(" ~ #expression ~ "," ~ INTERVAL ~ #expression ~ #time_unit ~ ")"),
+            rule!(#function_name_date_arith_multi_forms ~ "(" ~ #expression ~ "," ~ INTERVAL ~ #expression ~ #time_unit_expr ~ ")"),
             |(fn_name, _, expr1, _, _, expr2, unit, _)| {
                 let mut fn_expr = FuncCallExpr::default();
                 fn_expr.fn_name = CIStr::new(&fn_name);
-                fn_expr.args = vec![expr1, expr2, ExprNode::TimeUnitExpr(TimeUnitExpr { unit })];
+                fn_expr.args = vec![expr1, expr2, unit];
                 ExprNode::FuncCallExpr(fn_expr)
             },
         ),
         map(
-            rule!(#function_name_date_arith ~ "(" ~ #expression ~ "," ~ INTERVAL ~ #expression ~ #time_unit ~ ")"),
+            rule!(#function_name_date_arith ~ "(" ~ #expression ~ "," ~ INTERVAL ~ #expression ~ #time_unit_expr ~ ")"),
             |(fn_name, _, expr1, _, _, expr2, unit, _)| {
                 let mut fn_expr = FuncCallExpr::default();
                 fn_expr.fn_name = CIStr::new(&fn_name);
-                fn_expr.args = vec![expr1, expr2, ExprNode::TimeUnitExpr(TimeUnitExpr { unit })];
+                fn_expr.args = vec![expr1, expr2, unit];
                 ExprNode::FuncCallExpr(fn_expr)
             },
         ),
         map(
-            rule!(EXTRACT ~ "(" ~ #time_unit ~ FROM ~ #expression ~ ")"),
+            rule!(EXTRACT ~ "(" ~ #time_unit_expr ~ FROM ~ #expression ~ ")"),
             |(fn_name, _, unit, _, expr, _)| {
                 let mut fn_expr = FuncCallExpr::default();
                 fn_expr.fn_name = CIStr::new(fn_name.text());
-                fn_expr.args = vec![ExprNode::TimeUnitExpr(TimeUnitExpr { unit })];
+                fn_expr.args = vec![unit, expr];
                 ExprNode::FuncCallExpr(fn_expr)
             },
         ),
@@ -539,7 +766,10 @@ pub fn function_call_non_keyword_1(i: Input) -> IResult<ExprNode> {
                 let mut fn_expr = FuncCallExpr::default();
                 fn_expr.fn_name = CIStr::new(fn_name.text());
                 fn_expr.args = vec![
-                    ExprNode::GetFormatSelectorExpr(GetFormatSelectorExpr { selector: typ }),
+                    ExprNode::GetFormatSelectorExpr(GetFormatSelectorExpr {
+                        span: None,
+                        selector: typ,
+                    }),
                     expr,
                 ];
                 ExprNode::FuncCallExpr(fn_expr)
@@ -591,20 +821,20 @@ pub fn function_call_non_keyword_1(i: Input) -> IResult<ExprNode> {
             },
         ),
         map(
-            rule!(TIMESTAMPADD ~ "(" ~ (#timestamp_unit|#timestamp_unit_sql_tsi) ~ "," ~ #expression ~ "," ~ #expression ~ ")"),
+            rule!(TIMESTAMPADD ~ "(" ~ #timestamp_unit_expr ~ "," ~ #expression ~ "," ~ #expression ~ ")"),
             |(fn_name, _, unit, _, expr1, _, expr2, _)| {
                 let mut fn_expr = FuncCallExpr::default();
                 fn_expr.fn_name = CIStr::new(fn_name.text());
-                fn_expr.args = vec![ExprNode::TimeUnitExpr(TimeUnitExpr { unit }), expr1, expr2];
+                fn_expr.args = vec![unit, expr1, expr2];
                 ExprNode::FuncCallExpr(fn_expr)
             },
         ),
         map(
-            rule!(TIMESTAMPDIFF ~ "(" ~ (#timestamp_unit|#timestamp_unit_sql_tsi) ~ "," ~ #expression ~ "," ~ #expression ~ ")"),
+            rule!(TIMESTAMPDIFF ~ "(" ~ #timestamp_unit_expr ~ "," ~ #expression ~ "," ~ #expression ~ ")"),
             |(fn_name, _, unit, _, expr1, _, expr2, _)| {
                 let mut fn_expr = FuncCallExpr::default();
                 fn_expr.fn_name = CIStr::new(fn_name.text());
-                fn_expr.args = vec![ExprNode::TimeUnitExpr(TimeUnitExpr { unit }), expr1, expr2];
+                fn_expr.args = vec![unit, expr1, expr2];
                 ExprNode::FuncCallExpr(fn_expr)
             },
         ),
@@ -626,11 +856,11 @@ pub fn function_call_non_keyword_1(i: Input) -> IResult<ExprNode> {
                 ExprNode::FuncCallExpr(fn_expr)
             },
         ),
-    ))(i)
+    )))(i)
 }
 
 pub fn function_call_non_keyword_2(i: Input) -> IResult<ExprNode> {
-    alt((
+    spanned(alt((
         map(
             rule!(TRIM ~ "(" ~ #expression ~ ")"),
             |(fn_name, _, expr, _)| {
@@ -660,6 +890,7 @@ pub fn function_call_non_keyword_2(i: Input) -> IResult<ExprNode> {
                 ));
 
                 let dirction = ExprNode::TrimDirectionExpr(TrimDirectionExpr {
+                    span: None,
                     direction: trim_type,
                 });
                 let mut fn_expr = FuncCallExpr::default();
@@ -672,6 +903,7 @@ pub fn function_call_non_keyword_2(i: Input) -> IResult<ExprNode> {
             rule!(TRIM ~ "(" ~ #trim_direction ~ #expression ~ FROM ~ #expression ~ ")"),
             |(fn_name, _, trim_type, expr1, _, expr2, _)| {
                 let dirction = ExprNode::TrimDirectionExpr(TrimDirectionExpr {
+                    span: None,
                     direction: trim_type,
                 });
                 let mut fn_expr = FuncCallExpr::default();
@@ -725,7 +957,7 @@ pub fn function_call_non_keyword_2(i: Input) -> IResult<ExprNode> {
                 ExprNode::FuncCallExpr(fn_expr)
             },
         ),
-    ))(i)
+    )))(i)
 }
 
 pub fn expression_list_opt(i: Input) -> IResult<Vec<ExprNode>> {
@@ -736,78 +968,116 @@ pub fn expression_list(i: Input) -> IResult<Vec<ExprNode>> {
     separated_list1(map(rule!(","), |_| ()), expression)(i)
 }
 
+// The sequence functions are MariaDB and TiDB only.
 pub fn function_name_sequence(i: Input) -> IResult<FuncCallExpr> {
-    alt((
-        map(
-            rule!(LASTVAL ~ "(" ~ #table_name ~ ")"),
-            |(_, _, table_name, _)| {
-                let obj_name_expr = ExprNode::TableNameExpr(TableNameExpr { name: table_name });
-
-                let mut fn_expr = FuncCallExpr::default();
-                fn_expr.fn_name = CIStr::new(functions::LAST_VAL);
-                fn_expr.args = vec![obj_name_expr];
-
-                fn_expr
-            },
-        ),
-        map(
-            rule!(SETVAL ~ "(" ~ #table_name ~ "," ~ #signed_num ~ ")"),
-            |(_, _, table_name, _, num, _)| {
-                let obj_name_expr = ExprNode::TableNameExpr(TableNameExpr { name: table_name });
-                let value_expr = ExprNode::ValueExpr(ValueExpr::new(
-                    &num.to_string(),
-                    ValueExprKind::I64(num),
-                    i.charset,
-                    i.collation,
-                ));
-
-                let mut fn_expr = FuncCallExpr::default();
-                fn_expr.fn_name = CIStr::new(functions::SET_VAL);
-                fn_expr.args = vec![obj_name_expr, value_expr];
-
-                fn_expr
-            },
-        ),
-        map(rule!(#next_value_for_sequence), |(expr)| expr),
+    spanned(check_sequence(
+        true,
+        alt((
+            map(
+                rule!(LASTVAL ~ "(" ~ #table_name ~ ")"),
+                |(_, _, table_name, _)| {
+                    let obj_name_expr = ExprNode::TableNameExpr(TableNameExpr {
+                        span: None,
+                        name: table_name,
+                    });
+
+                    let mut fn_expr = FuncCallExpr::default();
+                    fn_expr.fn_name = CIStr::new(functions::LAST_VAL);
+                    fn_expr.args = vec![obj_name_expr];
+
+                    fn_expr
+                },
+            ),
+            map(
+                rule!(SETVAL ~ "(" ~ #table_name ~ "," ~ #signed_num ~ ")"),
+                |(_, _, table_name, _, num, _)| {
+                    let obj_name_expr = ExprNode::TableNameExpr(TableNameExpr {
+                        span: None,
+                        name: table_name,
+                    });
+                    let value_expr = ExprNode::ValueExpr(ValueExpr::new(
+                        &num.to_string(),
+                        ValueExprKind::I64(num),
+                        i.charset,
+                        i.collation,
+                    ));
+
+                    let mut fn_expr = FuncCallExpr::default();
+                    fn_expr.fn_name = CIStr::new(functions::SET_VAL);
+                    fn_expr.args = vec![obj_name_expr, value_expr];
+
+                    fn_expr
+                },
+            ),
+            // `PREVIOUS VALUE FOR seq` is the same as `LASTVAL(seq)` in MariaDB.
+            check_mariadb_previous_value(
+                true,
+                map(
+                    rule!(PREVIOUS ~ VALUE ~ FOR ~ #table_name),
+                    |(_, _, _, table_name)| {
+                        let obj_name_expr = ExprNode::TableNameExpr(TableNameExpr {
+                            span: None,
+                            name: table_name,
+                        });
+
+                        let mut fn_expr = FuncCallExpr::default();
+                        fn_expr.fn_name = CIStr::new(functions::LAST_VAL);
+                        fn_expr.args = vec![obj_name_expr];
+
+                        fn_expr
+                    },
+                ),
+            ),
+            map(rule!(#next_value_for_sequence), |expr| expr),
+        )),
     ))(i)
 }
 
 pub fn next_value_for_sequence(i: Input) -> IResult<FuncCallExpr> {
-    alt((
-        map(
-            rule!(NEXT ~ VALUE ~ FOR ~ #table_name),
-            |(_, _, _, table_name)| {
-                let obj_name_expr = ExprNode::TableNameExpr(TableNameExpr { name: table_name });
-
-                let mut fn_expr = FuncCallExpr::default();
-                fn_expr.fn_name = CIStr::new(functions::NEXT_VAL);
-                fn_expr.args = vec![obj_name_expr];
-
-                fn_expr
-            },
-        ),
-        map(
-            rule!(NEXTVAL ~ "(" ~ #table_name ~ ")"),
-            |(_, _, table_name, _)| {
-                let obj_name_expr = ExprNode::TableNameExpr(TableNameExpr { name: table_name });
-
-                let mut fn_expr = FuncCallExpr::default();
-                fn_expr.fn_name = CIStr::new(functions::NEXT_VAL);
-                fn_expr.args = vec![obj_name_expr];
-
-                fn_expr
-            },
-        ),
+    spanned(check_sequence(
+        true,
+        alt((
+            map(
+                rule!(NEXT ~ VALUE ~ FOR ~ #table_name),
+                |(_, _, _, table_name)| {
+                    let obj_name_expr = ExprNode::TableNameExpr(TableNameExpr {
+                        span: None,
+                        name: table_name,
+                    });
+
+                    let mut fn_expr = FuncCallExpr::default();
+                    fn_expr.fn_name = CIStr::new(functions::NEXT_VAL);
+                    fn_expr.args = vec![obj_name_expr];
+
+                    fn_expr
+                },
+            ),
+            map(
+                rule!(NEXTVAL ~ "(" ~ #table_name ~ ")"),
+                |(_, _, table_name, _)| {
+                    let obj_name_expr = ExprNode::TableNameExpr(TableNameExpr {
+                        span: None,
+                        name: table_name,
+                    });
+
+                    let mut fn_expr = FuncCallExpr::default();
+                    fn_expr.fn_name = CIStr::new(functions::NEXT_VAL);
+                    fn_expr.args = vec![obj_name_expr];
+
+                    fn_expr
+                },
+            ),
+        )),
     ))(i)
 }
 
 pub fn function_call_generic(i: Input) -> IResult<FuncCallExpr> {
-    alt((
+    spanned(alt((
         map(
             rule!(Ident ~ "(" ~ #expression_list_opt ~ ")"),
             |(t, _, exprs, _)| {
                 let mut fn_expr = FuncCallExpr::default();
-                fn_expr.fn_name = CIStr::new(t.get_trim_start_end_text('`'));
+                fn_expr.fn_name = CIStr::new(&t.ident_text());
                 fn_expr.args = exprs;
 
                 fn_expr
@@ -823,18 +1093,19 @@ pub fn function_call_generic(i: Input) -> IResult<FuncCallExpr> {
                 };
 
                 let mut fn_expr = FuncCallExpr::default();
-                fn_expr.schema = CIStr::new(t1.get_trim_start_end_text('`'));
-                fn_expr.fn_name = CIStr::new(t2.text());
+                fn_expr.tp = tp;
+                fn_expr.schema = CIStr::new(&t1.ident_text());
+                fn_expr.fn_name = CIStr::new(&t2.ident_text());
                 fn_expr.args = exprs;
 
                 fn_expr
             },
         ),
-    ))(i)
+    )))(i)
 }
 
 pub fn window_func_call(i: Input) -> IResult<WindowFuncExpr> {
-    alt((
+    spanned(alt((
         map(
             rule!(ROW_NUMBER ~ "(" ~ ")" ~ #windowing_clause),
             |(t, _, _, spec)| {
@@ -907,7 +1178,7 @@ pub fn window_func_call(i: Input) -> IResult<WindowFuncExpr> {
             |(t, _, e, infos, _, treatment, spec)| {
                 let name = t.text();
                 let mut args = vec![e];
-                if infos.len() > 0 {
+                if !infos.is_empty() {
                     args.extend(infos);
                 }
 
@@ -925,7 +1196,7 @@ pub fn window_func_call(i: Input) -> IResult<WindowFuncExpr> {
             |(t, _, e, infos, _, treatment, spec)| {
                 let name = t.text();
                 let mut args = vec![e];
-                if infos.len() > 0 {
+                if !infos.is_empty() {
                     args.extend(infos);
                 }
 
@@ -978,78 +1249,81 @@ pub fn window_func_call(i: Input) -> IResult<WindowFuncExpr> {
                 expr
             },
         ),
-    ))(i)
+    )))(i)
 }
 
 pub fn opt_windowing_clause(i: Input) -> IResult<Option<WindowSpec>> {
-    map(rule!(#windowing_clause?), |(spec)| spec)(i)
+    map(rule!(#windowing_clause?), |spec| spec)(i)
 }
 
 pub fn windowing_clause(i: Input) -> IResult<WindowSpec> {
-    map(rule!(OVER ~ #window_name_or_spec), |(_, spec)| spec)(i)
+    spanned(check_window_function(
+        true,
+        map(rule!(OVER ~ #window_name_or_spec), |(_, spec)| spec),
+    ))(i)
 }
 
 pub fn window_name_or_spec(i: Input) -> IResult<WindowSpec> {
-    alt((
-        map(rule!(#window_name), |(name)| {
+    spanned(alt((
+        map(rule!(#window_name), |name| {
             let mut spec = WindowSpec::default();
             spec.name = name;
             spec
         }),
         map(rule!(#window_spec), |spec| spec),
-    ))(i)
+    )))(i)
 }
 pub fn window_name(i: Input) -> IResult<CIStr> {
-    map(rule!(Ident), |(t)| {
-        let s = t.get_trim_start_end_text('`');
-        CIStr::new(s)
-    })
+    map(rule!(Ident), |t| {
+        let s = t.ident_text();
+        CIStr::new(&s)
+    })(i)
 }
 
 pub fn window_spec(i: Input) -> IResult<WindowSpec> {
-    map(rule!("(" ~ #window_spec_details ~ ")"), |(_, spec, _)| spec)(i)
+    spanned(map(
+        rule!("(" ~ #window_spec_details ~ ")"),
+        |(_, spec, _)| spec,
+    ))(i)
 }
 
 pub fn window_spec_details(i: Input) -> IResult<WindowSpec> {
-    map(
+    spanned(map(
         rule!(#opt_existing_window_name ~ #opt_partition_clause? ~ #opt_window_order_by_clause? ~ #opt_window_frame_clause?),
-        |(name, partition_clause, order_by_clause, frame_clause)| {
-            let mut spec = WindowSpec {
-                name: CIStr::default(),
-                references: name,
-                partition_by: partition_clause,
-                order_by: order_by_clause,
-                frame: frame_clause,
-                only_alias: false,
-            };
-
-            spec
+        |(name, partition_clause, order_by_clause, frame_clause)| WindowSpec {
+            span: None,
+            name: CIStr::default(),
+            references: name,
+            partition_by: partition_clause,
+            order_by: order_by_clause,
+            frame: frame_clause,
+            only_alias: false,
         },
-    )(i)
+    ))(i)
 }
 
 pub fn opt_existing_window_name(i: Input) -> IResult<CIStr> {
-    map(rule!(#window_name?), |(name)| {
+    map(rule!(#window_name?), |name| {
         name.unwrap_or_else(|| CIStr::new(""))
-    })
+    })(i)
 }
 
 pub fn opt_partition_clause(i: Input) -> IResult<PartitionByClause> {
-    map(rule!(PARTITION ~ BY ~ #by_list), |(_, _, items)| {
-        PartitionByClause { items }
-    })
+    spanned(map(rule!(PARTITION ~ BY ~ #by_list), |(_, _, items)| {
+        PartitionByClause { span: None, items }
+    }))(i)
 }
 
 pub fn order_by_optional(i: Input) -> IResult<Option<OrderByClause>> {
-    map(rule!(#order_by?), |(clause)| clause)(i)
+    map(rule!(#order_by?), |clause| clause)(i)
 }
 
 pub fn order_by(i: Input) -> IResult<OrderByClause> {
-    map(rule!(ORDER ~ BY ~ #by_list), |(_, _, items)| {
+    spanned(map(rule!(ORDER ~ BY ~ #by_list), |(_, _, items)| {
         let mut order_by_clause = OrderByClause::default();
         order_by_clause.items = items;
         order_by_clause
-    })(i)
+    }))(i)
 }
 
 pub fn by_list(i: Input) -> IResult<Vec<ByItem>> {
@@ -1057,48 +1331,27 @@ pub fn by_list(i: Input) -> IResult<Vec<ByItem>> {
 }
 
 pub fn by_item(i: Input) -> IResult<ByItem> {
-    alt((
-        map(rule!(#expression), |(expr)| {
-            let new_expr = if let ExprNode::ValueExpr(value_expr) = expr {
-                if let Some(position) = value_expr.get_value_i64() {
-                    ExprNode::PositionExpr(PositionExpr {
-                        n: position as isize,
-                        p: None,
-                    })
-                } else {
-                    expr
-                }
-            } else {
-                expr
-            };
-
-            ByItem {
-                expr: Some(Box::new(new_expr)),
-                desc: false,
-                null_order: true,
-            }
-        }),
-        map(rule!(#expression ~ #order), |(expr, desc)| {
-            let new_expr = if let ExprNode::ValueExpr(value_expr) = expr {
-                if let Some(position) = value_expr.get_value_i64() {
-                    ExprNode::PositionExpr(PositionExpr {
-                        n: position as isize,
-                        p: None,
-                    })
-                } else {
-                    expr
-                }
-            } else {
-                expr
-            };
-
-            ByItem {
-                expr: Some(Box::new(new_expr)),
-                desc,
-                null_order: false,
-            }
-        }),
-    ))(i)
+    spanned(map(rule!(#expression ~ #order?), |(expr, desc)| {
+        // A bare integer in ORDER BY or GROUP BY refers to a select field.
+        let expr = match expr {
+            ExprNode::ValueExpr(value_expr) => match value_expr.get_value_i64() {
+                Some(position) => ExprNode::PositionExpr(PositionExpr {
+                    span: value_expr.span,
+                    n: position as isize,
+                    p: None,
+                }),
+                None => ExprNode::ValueExpr(value_expr),
+            },
+            expr => expr,
+        };
+
+        ByItem {
+            span: None,
+            expr: Some(Box::new(expr)),
+            desc: desc.unwrap_or(false),
+            null_order: desc.is_none(),
+        }
+    }))(i)
 }
 
 pub fn order(i: Input) -> IResult<bool> {
@@ -1106,26 +1359,28 @@ pub fn order(i: Input) -> IResult<bool> {
 }
 
 pub fn opt_order(i: Input) -> IResult<bool> {
-    map(rule!(#order?), |(b)| b.unwrap_or_else(|| false))(i)
+    map(rule!(#order?), |b| b.unwrap_or(false))(i)
 }
 
 pub fn opt_window_order_by_clause(i: Input) -> IResult<OrderByClause> {
-    map(rule!(ORDER ~ BY ~ #by_list), |(_, _, items)| {
+    spanned(map(rule!(ORDER ~ BY ~ #by_list), |(_, _, items)| {
         OrderByClause {
+            span: None,
             items,
             for_union: false,
         }
-    })
+    }))(i)
 }
 
 pub fn opt_window_frame_clause(i: Input) -> IResult<FrameClause> {
-    map(
+    spanned(map(
         rule!(#window_frame_units ~ #window_frame_extent),
         |(tp, items)| FrameClause {
+            span: None,
             tp,
             extent: Some(items),
         },
-    )
+    ))(i)
 }
 
 pub fn window_frame_units(i: Input) -> IResult<FrameType> {
@@ -1137,23 +1392,26 @@ pub fn window_frame_units(i: Input) -> IResult<FrameType> {
 }
 
 pub fn window_frame_extent(i: Input) -> IResult<FrameExtent> {
-    alt((
-        map(rule!(#window_frame_start), |(frame)| FrameExtent {
+    spanned(alt((
+        map(rule!(#window_frame_start), |frame| FrameExtent {
+            span: None,
             start: Some(frame),
             end: Some(FrameBound {
+                span: None,
                 tp: BoundType::CurrentRow,
                 un_bounded: false,
                 expr: None,
                 unit: TimeUnitType::TimeUnitInvalid,
             }),
         }),
-        map(rule!(#window_frame_between), |(extent)| extent),
-    ))(i)
+        map(rule!(#window_frame_between), |extent| extent),
+    )))(i)
 }
 
 pub fn window_frame_start(i: Input) -> IResult<FrameBound> {
-    alt((
+    spanned(alt((
         map(rule!(UNBOUNDED ~ PRECEDING), |_| FrameBound {
+            span: None,
             tp: BoundType::Preceding,
             un_bounded: true,
             expr: None,
@@ -1161,6 +1419,7 @@ pub fn window_frame_start(i: Input) -> IResult<FrameBound> {
         }),
         map(rule!(#num_literal ~ PRECEDING), |(value_expr, _)| {
             FrameBound {
+                span: None,
                 tp: BoundType::Preceding,
                 un_bounded: false,
                 expr: Some(Box::new(ExprNode::ValueExpr(value_expr))),
@@ -1168,9 +1427,11 @@ pub fn window_frame_start(i: Input) -> IResult<FrameBound> {
             }
         }),
         map(rule!("?" ~ PRECEDING), |(t, _)| FrameBound {
+            span: None,
             tp: BoundType::Preceding,
             un_bounded: false,
             expr: Some(Box::new(ExprNode::ParamMarkerExpr(ParamMarkerExpr {
+                span: None,
                 offset: 0,
                 order: 0,
                 in_execute: false,
@@ -1184,6 +1445,7 @@ pub fn window_frame_start(i: Input) -> IResult<FrameBound> {
         map(
             rule!(INTERVAL ~ #expression ~ #time_unit ~ PRECEDING),
             |(_, expr, tu, _)| FrameBound {
+                span: None,
                 tp: BoundType::Preceding,
                 un_bounded: false,
                 expr: Some(Box::new(expr)),
@@ -1191,43 +1453,49 @@ pub fn window_frame_start(i: Input) -> IResult<FrameBound> {
             },
         ),
         map(rule!(CURRENT ~ ROW), |(_, _)| FrameBound {
+            span: None,
             tp: BoundType::CurrentRow,
             un_bounded: false,
             expr: None,
             unit: TimeUnitType::TimeUnitInvalid,
         }),
-    ))(i)
+    )))(i)
 }
 
 pub fn window_frame_between(i: Input) -> IResult<FrameExtent> {
-    map(
+    spanned(map(
         rule!(BETWEEN ~ #window_frame_bound ~ AND ~ #window_frame_bound),
         |(_, start, _, end)| FrameExtent {
+            span: None,
             start: Some(start),
             end: Some(end),
         },
-    )(i)
+    ))(i)
 }
 
 pub fn window_frame_bound(i: Input) -> IResult<FrameBound> {
-    alt((
-        map(rule!(#window_frame_start), |(bound)| bound),
+    spanned(alt((
+        map(rule!(#window_frame_start), |bound| bound),
         map(rule!(UNBOUNDED ~ FOLLOWING), |(_, _)| FrameBound {
+            span: None,
             tp: BoundType::Following,
             un_bounded: true,
             expr: None,
             unit: TimeUnitType::TimeUnitInvalid,
         }),
         map(rule!(#num_literal ~ FOLLOWING), |(num, _)| FrameBound {
+            span: None,
             tp: BoundType::Following,
             un_bounded: false,
             expr: Some(Box::new(ExprNode::ValueExpr(num))),
             unit: TimeUnitType::TimeUnitInvalid,
         }),
         map(rule!("?" ~ FOLLOWING), |(t, _)| FrameBound {
+            span: None,
             tp: BoundType::Following,
             un_bounded: false,
             expr: Some(Box::new(ExprNode::ParamMarkerExpr(ParamMarkerExpr {
+                span: None,
                 offset: 0,
                 order: 0,
                 in_execute: false,
@@ -1241,13 +1509,14 @@ pub fn window_frame_bound(i: Input) -> IResult<FrameBound> {
         map(
             rule!(INTERVAL ~ #expression ~ #time_unit ~ FOLLOWING),
             |(_, expr, tu, _)| FrameBound {
+                span: None,
                 tp: BoundType::Following,
                 un_bounded: false,
                 expr: Some(Box::new(expr)),
                 unit: tu,
             },
         ),
-    ))(i)
+    )))(i)
 }
 pub fn opt_lead_lag_info(i: Input) -> IResult<Vec<ExprNode>> {
     alt((
@@ -1262,7 +1531,7 @@ pub fn opt_lead_lag_info(i: Input) -> IResult<Vec<ExprNode>> {
                 args
             },
         ),
-        map(rule!("," ~ "?" ~ #opt_ll_default), |(_, t, expr)| {
+        map(rule!("," ~ "?" ~ #opt_ll_default?), |(_, t, expr)| {
             let mut mark_expr = ParamMarkerExpr::default();
             mark_expr.token_index = t.pos;
             mark_expr.start_pos = t.span.start as usize;
@@ -1280,15 +1549,15 @@ pub fn opt_lead_lag_info(i: Input) -> IResult<Vec<ExprNode>> {
 }
 
 pub fn opt_ll_default(i: Input) -> IResult<ExprNode> {
-    map(rule!("," ~ #expression), |(_, expr)| expr)
+    map(rule!("," ~ #expression), |(_, expr)| expr)(i)
 }
 
 pub fn sum_expr(i: Input) -> IResult<ExprNode> {
-    map(rule!(#sum_expr_1 | #sum_expr_2), |(expr)| expr)(i)
+    spanned(map(rule!(#sum_expr_1 | #sum_expr_2), |expr| expr))(i)
 }
 
 pub fn sum_expr_1(i: Input) -> IResult<ExprNode> {
-    alt((
+    spanned(alt((
         map(
             rule!(AVG ~ "(" ~ #buggy_default_false_distinct_opt ~ #expression ~ ")" ~ #opt_windowing_clause),
             |(avg_token, _, distinct, expr, _, spec)| match spec {
@@ -1347,7 +1616,7 @@ pub fn sum_expr_1(i: Input) -> IResult<ExprNode> {
         ),
         map(
             rule!(BIT_AND ~ "(" ~ ALL ~ #expression ~ ")" ~ #opt_windowing_clause),
-            |(t, _, distinct, expr, _, spec)| match spec {
+            |(t, _, _, expr, _, spec)| match spec {
                 None => {
                     let mut agg_expr = AggregateFuncExpr::default();
                     agg_expr.f = t.text().to_string();
@@ -1383,7 +1652,7 @@ pub fn sum_expr_1(i: Input) -> IResult<ExprNode> {
         ),
         map(
             rule!(BIT_OR ~ "(" ~ ALL ~ #expression ~ ")" ~ #opt_windowing_clause),
-            |(t, _, distinct, expr, _, spec)| match spec {
+            |(t, _, _, expr, _, spec)| match spec {
                 None => {
                     let mut agg_expr = AggregateFuncExpr::default();
                     agg_expr.f = t.text().to_string();
@@ -1419,7 +1688,7 @@ pub fn sum_expr_1(i: Input) -> IResult<ExprNode> {
         ),
         map(
             rule!(BIT_XOR ~ "(" ~ ALL ~ #expression ~ ")" ~ #opt_windowing_clause),
-            |(t, _, distinct, expr, _, spec)| match spec {
+            |(t, _, _, expr, _, spec)| match spec {
                 None => {
                     let mut agg_expr = AggregateFuncExpr::default();
                     agg_expr.f = t.text().to_string();
@@ -1447,7 +1716,7 @@ pub fn sum_expr_1(i: Input) -> IResult<ExprNode> {
         ),
         map(
             rule!(COUNT ~ "(" ~ ALL ~ #expression ~ ")" ~ #opt_windowing_clause),
-            |(t, _, distinct, expr, _, spec)| match spec {
+            |(t, _, _, expr, _, spec)| match spec {
                 None => {
                     let mut agg_expr = AggregateFuncExpr::default();
                     agg_expr.f = t.text().to_string();
@@ -1533,11 +1802,11 @@ pub fn sum_expr_1(i: Input) -> IResult<ExprNode> {
                 }
             },
         ),
-    ))(i)
+    )))(i)
 }
 
 pub fn sum_expr_2(i: Input) -> IResult<ExprNode> {
-    alt((
+    spanned(alt((
         map(
             rule!(MAX ~ "(" ~ #buggy_default_false_distinct_opt ~ #expression ~ ")" ~ #opt_windowing_clause),
             |(t, _, distinct, expr, _, spec)| match spec {
@@ -1786,5 +2055,5 @@ pub fn sum_expr_2(i: Input) -> IResult<ExprNode> {
                 }
             },
         ),
-    ))(i)
+    )))(i)
 }
@@ -0,0 +1,402 @@
+use crate::ast::field_type::{DataType, FieldType};
+use crate::parser::common::*;
+use crate::parser::error::{Error, ErrorKind};
+use crate::parser::input::Input;
+use crate::parser::statements::common::{charset_name, length_num, string_name, string_value};
+use crate::parser::token_kind::TokenKind::*;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom::multi::many0;
+use nom_rule::rule;
+
+// FieldType is the data type of a column definition.
+// See https://dev.mysql.com/doc/refman/8.0/en/data-types.html
+pub fn field_type(i: Input) -> IResult<FieldType> {
+    alt((
+        numeric_type,
+        string_type,
+        date_and_time_type,
+        map(rule!(JSON), |_| FieldType::new(DataType::Json)),
+        map(spatial_type, FieldType::new),
+    ))(i)
+}
+
+// The largest fractional seconds precision, and precision and scale of
+// DECIMAL, MySQL fails the statement for a larger one.
+const MAX_FSP: u64 = 6;
+const MAX_DECIMAL_PRECISION: u64 = 65;
+const MAX_DECIMAL_SCALE: u64 = 30;
+
+// CastType is the target type of CAST and CONVERT, the optional ARRAY makes
+// the cast of a multi-valued index.
+// See https://dev.mysql.com/doc/refman/8.0/en/cast-functions.html#function_cast
+pub fn cast_type(i: Input) -> IResult<FieldType> {
+    let array = check_multi_valued_index(true, rule!(ARRAY));
+    let (rest, ft) = map(rule!(#cast_type_basic ~ #array?), |(mut ft, array)| {
+        ft.array = array.is_some();
+        ft
+    })(i)?;
+    // The lengths out of range are reported at the type without trying
+    // other branches.
+    match check_cast_type(&ft) {
+        Some(msg) => Err(nom::Err::Failure(Error::from_error_kind(
+            i,
+            ErrorKind::Other(msg),
+        ))),
+        None => Ok((rest, ft)),
+    }
+}
+
+// CheckCastType returns the error of MySQL for the lengths of a cast target
+// out of range, they are ER_TOO_BIG_PRECISION, ER_TOO_BIG_SCALE and
+// ER_M_BIGGER_THAN_D.
+fn check_cast_type(ft: &FieldType) -> Option<&'static str> {
+    match ft.tp {
+        DataType::DateTime | DataType::Timestamp | DataType::Time
+            if ft.decimal.is_some_and(|fsp| fsp > MAX_FSP) =>
+        {
+            Some("Too-big precision specified for CAST. Maximum is 6.")
+        }
+        DataType::Decimal => {
+            let flen = ft.flen.unwrap_or_default();
+            let decimal = ft.decimal.unwrap_or_default();
+            if flen < decimal {
+                Some("For decimal(M,D), M must be >= D.")
+            } else if flen > MAX_DECIMAL_PRECISION {
+                Some("Too-big precision specified for CAST. Maximum is 65.")
+            } else if decimal > MAX_DECIMAL_SCALE {
+                Some("Too big scale specified for CAST. Maximum is 30.")
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+fn cast_type_basic(i: Input) -> IResult<FieldType> {
+    let binary = map(rule!(BINARY ~ #length_opt), |(_, flen)| {
+        let mut ft = FieldType::new(DataType::VarBinary);
+        ft.flen = flen;
+        ft.charset = "binary".to_string();
+        ft
+    });
+    let char = map(
+        rule!(CHAR ~ #length_opt ~ #string_options),
+        |(_, flen, opts)| new_string_type(DataType::VarChar, flen, opts),
+    );
+    let nchar = map(rule!(NCHAR ~ #length_opt), |(_, flen)| {
+        let mut ft = FieldType::new(DataType::VarChar);
+        ft.flen = flen;
+        ft.charset = "utf8mb3".to_string();
+        ft
+    });
+    let integer = map(rule!((SIGNED | UNSIGNED) ~ INTEGER?), |(t, _)| {
+        let mut ft = FieldType::new(DataType::BigInt);
+        ft.unsigned = t.kind == UNSIGNED;
+        ft
+    });
+    let decimal = map(rule!(DECIMAL ~ #float_opt), |(_, (flen, decimal))| {
+        let mut ft = FieldType::new(DataType::Decimal);
+        ft.flen = flen;
+        ft.decimal = decimal;
+        ft
+    });
+    let float = map(rule!(FLOAT ~ #float_opt), |(_, (flen, _))| {
+        // FLOAT(p) is a DOUBLE if the precision p is greater than 24.
+        match flen {
+            Some(p) if p > 24 => FieldType::new(DataType::Double),
+            _ => FieldType::new(DataType::Float),
+        }
+    });
+    let double = map(rule!(DOUBLE), |_| FieldType::new(DataType::Double));
+    let real = map(rule!(REAL), |_| FieldType::new(real_type(i)));
+
+    alt((
+        binary,
+        char,
+        nchar,
+        integer,
+        decimal,
+        float,
+        double,
+        real,
+        date_and_time_type,
+        map(rule!(JSON), |_| FieldType::new(DataType::Json)),
+        map(spatial_type, FieldType::new),
+    ))(i)
+}
+
+fn numeric_type(i: Input) -> IResult<FieldType> {
+    let integer = map(
+        rule!(#integer_type ~ #length_opt ~ #field_options),
+        |(tp, flen, (unsigned, zerofill))| {
+            let mut ft = FieldType::new(tp);
+            ft.flen = flen;
+            ft.unsigned = unsigned;
+            ft.zerofill = zerofill;
+            ft
+        },
+    );
+    let boolean = map(rule!(BOOL | BOOLEAN), |_| {
+        let mut ft = FieldType::new(DataType::TinyInt);
+        ft.flen = Some(1);
+        ft
+    });
+    let fixed_point = map(
+        rule!((DECIMAL | NUMERIC | DEC | FIXED) ~ #float_opt ~ #field_options),
+        |(_, (flen, decimal), (unsigned, zerofill))| {
+            let mut ft = FieldType::new(DataType::Decimal);
+            ft.flen = flen;
+            ft.decimal = decimal;
+            ft.unsigned = unsigned;
+            ft.zerofill = zerofill;
+            ft
+        },
+    );
+    let floating_point = map(
+        rule!(#floating_point_type ~ #float_opt ~ #field_options),
+        |(tp, (flen, decimal), (unsigned, zerofill))| {
+            // FLOAT(p) is a DOUBLE if the precision p is greater than 24.
+            let tp = match (tp, flen, decimal) {
+                (DataType::Float, Some(p), None) if p > 24 => DataType::Double,
+                _ => tp,
+            };
+            let mut ft = FieldType::new(tp);
+            if tp == DataType::Float || decimal.is_some() {
+                ft.flen = flen;
+            }
+            ft.decimal = decimal;
+            ft.unsigned = unsigned;
+            ft.zerofill = zerofill;
+            ft
+        },
+    );
+    let bit = map(rule!(BIT ~ #length_opt), |(_, flen)| {
+        let mut ft = FieldType::new(DataType::Bit);
+        ft.flen = flen;
+        ft
+    });
+
+    alt((integer, boolean, fixed_point, floating_point, bit))(i)
+}
+
+fn integer_type(i: Input) -> IResult<DataType> {
+    alt((
+        map(rule!(TINYINT | INT1), |_| DataType::TinyInt),
+        map(rule!(SMALLINT | INT2), |_| DataType::SmallInt),
+        map(rule!(MEDIUMINT | MIDDLEINT | INT3), |_| DataType::MediumInt),
+        map(rule!(INT | INTEGER | INT4), |_| DataType::Int),
+        map(rule!(BIGINT | INT8), |_| DataType::BigInt),
+    ))(i)
+}
+
+// REAL is a synonym of FLOAT with REAL_AS_FLOAT, and of DOUBLE otherwise.
+fn real_type(i: Input) -> DataType {
+    if i.sql_mode.has_real_as_float_mode() {
+        DataType::Float
+    } else {
+        DataType::Double
+    }
+}
+
+fn floating_point_type(i: Input) -> IResult<DataType> {
+    alt((
+        map(rule!(FLOAT), |_| DataType::Float),
+        map(rule!(REAL), |_| real_type(i)),
+        map(rule!(DOUBLE ~ PRECISION?), |_| DataType::Double),
+    ))(i)
+}
+
+fn string_type(i: Input) -> IResult<FieldType> {
+    let var_char = map(
+        rule!(#var_char_type ~ "(" ~ #length_num ~ ")" ~ #string_options),
+        |(_, _, flen, _, opts)| new_string_type(DataType::VarChar, Some(flen), opts),
+    );
+    let char = map(
+        rule!(#char_type ~ #length_opt ~ #string_options),
+        |(_, flen, opts)| new_string_type(DataType::Char, flen, opts),
+    );
+    let binary = map(rule!(BINARY ~ #length_opt), |(_, flen)| {
+        let mut ft = FieldType::new(DataType::Binary);
+        ft.flen = flen;
+        ft
+    });
+    let var_binary = map(
+        rule!(VARBINARY ~ "(" ~ #length_num ~ ")"),
+        |(_, _, flen, _)| {
+            let mut ft = FieldType::new(DataType::VarBinary);
+            ft.flen = Some(flen);
+            ft
+        },
+    );
+    let blob = alt((
+        map(rule!(TINYBLOB), |_| FieldType::new(DataType::TinyBlob)),
+        map(rule!(BLOB ~ #length_opt), |(_, flen)| {
+            let mut ft = FieldType::new(DataType::Blob);
+            ft.flen = flen;
+            ft
+        }),
+        map(rule!(MEDIUMBLOB), |_| FieldType::new(DataType::MediumBlob)),
+        map(rule!(LONG ~ VARBINARY), |_| {
+            FieldType::new(DataType::MediumBlob)
+        }),
+        map(rule!(LONGBLOB), |_| FieldType::new(DataType::LongBlob)),
+    ));
+    let text = map(rule!(#text_type ~ #string_options), |((tp, flen), opts)| {
+        new_string_type(tp, flen, opts)
+    });
+    let enum_set = map(
+        rule!((ENUM | SET) ~ "(" ~ #comma_separated_list1(string_value) ~ ")" ~ #string_options),
+        |(t, _, elems, _, opts)| {
+            let tp = if t.kind == ENUM {
+                DataType::Enum
+            } else {
+                DataType::Set
+            };
+            let mut ft = new_string_type(tp, None, opts);
+            ft.elems = elems;
+            ft
+        },
+    );
+
+    alt((var_char, char, binary, var_binary, blob, text, enum_set))(i)
+}
+
+fn var_char_type(i: Input) -> IResult<()> {
+    alt((
+        map(rule!(VARCHAR | VARCHARACTER | NVARCHAR), |_| ()),
+        map(rule!(NATIONAL? ~ (CHAR | CHARACTER) ~ VARYING), |_| ()),
+        map(rule!(NATIONAL ~ VARCHAR), |_| ()),
+        map(rule!(NCHAR ~ (VARCHAR | VARYING)), |_| ()),
+    ))(i)
+}
+
+fn char_type(i: Input) -> IResult<()> {
+    alt((
+        map(rule!(NATIONAL? ~ (CHAR | CHARACTER)), |_| ()),
+        map(rule!(NCHAR), |_| ()),
+    ))(i)
+}
+
+fn text_type(i: Input) -> IResult<(DataType, Option<u64>)> {
+    alt((
+        map(rule!(TINYTEXT), |_| (DataType::TinyText, None)),
+        map(rule!(TEXT ~ #length_opt), |(_, flen)| {
+            (DataType::Text, flen)
+        }),
+        map(rule!(MEDIUMTEXT), |_| (DataType::MediumText, None)),
+        map(rule!(LONG ~ VARCHAR?), |_| (DataType::MediumText, None)),
+        map(rule!(LONGTEXT), |_| (DataType::LongText, None)),
+    ))(i)
+}
+
+fn date_and_time_type(i: Input) -> IResult<FieldType> {
+    alt((
+        map(rule!(DATE), |_| FieldType::new(DataType::Date)),
+        map(rule!(YEAR ~ #length_opt), |(_, flen)| {
+            let mut ft = FieldType::new(DataType::Year);
+            ft.flen = flen;
+            ft
+        }),
+        map(
+            rule!((DATETIME | TIMESTAMP | TIME) ~ #length_opt),
+            |(t, fsp)| {
+                let tp = match t.kind {
+                    DATETIME => DataType::DateTime,
+                    TIMESTAMP => DataType::Timestamp,
+                    _ => DataType::Time,
+                };
+                let mut ft = FieldType::new(tp);
+                ft.decimal = fsp;
+                ft
+            },
+        ),
+    ))(i)
+}
+
+fn spatial_type(i: Input) -> IResult<DataType> {
+    alt((
+        map(rule!(GEOMETRY), |_| DataType::Geometry),
+        map(rule!(POINT), |_| DataType::Point),
+        map(rule!(LINESTRING), |_| DataType::LineString),
+        map(rule!(POLYGON), |_| DataType::Polygon),
+        map(rule!(MULTIPOINT), |_| DataType::MultiPoint),
+        map(rule!(MULTILINESTRING), |_| DataType::MultiLineString),
+        map(rule!(MULTIPOLYGON), |_| DataType::MultiPolygon),
+        map(rule!(GEOMETRYCOLLECTION), |_| DataType::GeometryCollection),
+    ))(i)
+}
+
+// StringOption is an attribute of character types.
+enum StringOption {
+    Binary,
+    Charset(String),
+    Collate(String),
+}
+
+fn string_options(i: Input) -> IResult<Vec<StringOption>> {
+    many0(alt((
+        map(rule!(BINARY), |_| StringOption::Binary),
+        map(rule!(#charset_kw ~ #charset_name), |(_, cs)| {
+            StringOption::Charset(cs)
+        }),
+        map(rule!(ASCII), |_| {
+            StringOption::Charset("latin1".to_string())
+        }),
+        map(rule!(UNICODE), |_| {
+            StringOption::Charset("ucs2".to_string())
+        }),
+        map(rule!(BYTE), |_| StringOption::Charset("binary".to_string())),
+        map(rule!(COLLATE ~ #string_name), |(_, collate)| {
+            StringOption::Collate(collate)
+        }),
+    )))(i)
+}
+
+fn charset_kw(i: Input) -> IResult<()> {
+    alt((
+        map(rule!((CHARACTER | CHAR) ~ SET), |_| ()),
+        map(rule!(CHARSET), |_| ()),
+    ))(i)
+}
+
+fn new_string_type(tp: DataType, flen: Option<u64>, opts: Vec<StringOption>) -> FieldType {
+    let mut ft = FieldType::new(tp);
+    ft.flen = flen;
+    for opt in opts {
+        match opt {
+            StringOption::Binary => ft.binary = true,
+            StringOption::Charset(cs) => ft.charset = cs,
+            StringOption::Collate(collate) => ft.collate = collate,
+        }
+    }
+    ft
+}
+
+// FieldOptions are UNSIGNED, SIGNED and ZEROFILL of numeric types, ZEROFILL
+// implies UNSIGNED.
+fn field_options(i: Input) -> IResult<(bool, bool)> {
+    map(many0(rule!(UNSIGNED | SIGNED | ZEROFILL)), |opts| {
+        let zerofill = opts.iter().any(|t| t.kind == ZEROFILL);
+        let unsigned = zerofill || opts.iter().any(|t| t.kind == UNSIGNED);
+        (unsigned, zerofill)
+    })(i)
+}
+
+fn length_opt(i: Input) -> IResult<Option<u64>> {
+    map(rule!(("(" ~ #length_num ~ ")")?), |len| {
+        len.map(|(_, len, _)| len)
+    })(i)
+}
+
+// FloatOpt is the `(M)` or `(M, D)` of DECIMAL and floating-point types.
+fn float_opt(i: Input) -> IResult<(Option<u64>, Option<u64>)> {
+    map(
+        rule!(("(" ~ #length_num ~ ("," ~ #length_num)? ~ ")")?),
+        |opt| match opt {
+            Some((_, flen, decimal, _)) => (Some(flen), decimal.map(|(_, d)| d)),
+            None => (None, None),
+        },
+    )(i)
+}
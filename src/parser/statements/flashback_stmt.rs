@@ -0,0 +1,88 @@
+use crate::ast::ci_str::CIStr;
+use crate::ast::expr_node::ExprNode;
+use crate::ast::flashback_stmt::{
+    FlashBackDatabaseStmt, FlashBackTableStmt, FlashBackToTimestampStmt,
+};
+use crate::ast::statement::Statement;
+use crate::parser::common::*;
+use crate::parser::input::Input;
+use crate::parser::statements::common::{identifier, length_num};
+use crate::parser::statements::literal::string_literal;
+use crate::parser::statements::table_name::table_name;
+use crate::parser::token_kind::TokenKind::*;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom_rule::rule;
+
+// See https://docs.pingcap.com/tidb/stable/sql-statement-flashback-cluster
+// and https://docs.pingcap.com/tidb/stable/sql-statement-flashback-table
+pub fn flashback_statement(i: Input) -> IResult<Statement> {
+    // `TO TIMESTAMP` is tried before `TO new_name` as TIMESTAMP isn't
+    // reserved.
+    check_tidb_flashback(
+        true,
+        alt((
+            map(
+                rule!(FLASHBACK ~ CLUSTER ~ #flashback_to_timestamp),
+                |(_, _, stmt)| Statement::FlashBackToTimestampStmt(Box::new(stmt)),
+            ),
+            map(
+                rule!(
+                    FLASHBACK ~ TABLE ~ #comma_separated_list1(table_name)
+                    ~ #flashback_to_timestamp
+                ),
+                |(_, _, tables, mut stmt)| {
+                    stmt.tables = tables;
+                    Statement::FlashBackToTimestampStmt(Box::new(stmt))
+                },
+            ),
+            map(
+                rule!(FLASHBACK ~ (DATABASE | SCHEMA) ~ #identifier ~ #flashback_to_timestamp),
+                |(_, _, db_name, mut stmt)| {
+                    stmt.db_name = CIStr::new(&db_name);
+                    Statement::FlashBackToTimestampStmt(Box::new(stmt))
+                },
+            ),
+            map(
+                rule!(FLASHBACK ~ TABLE ~ #table_name ~ (TO ~ #identifier)?),
+                |(_, _, table, new_name)| {
+                    let mut stmt = FlashBackTableStmt::default();
+                    stmt.table = table;
+                    if let Some((_, new_name)) = new_name {
+                        stmt.new_name = CIStr::new(&new_name);
+                    }
+
+                    Statement::FlashBackTableStmt(Box::new(stmt))
+                },
+            ),
+            map(
+                rule!(FLASHBACK ~ (DATABASE | SCHEMA) ~ #identifier ~ (TO ~ #identifier)?),
+                |(_, _, db_name, new_name)| {
+                    let mut stmt = FlashBackDatabaseStmt::default();
+                    stmt.db_name = CIStr::new(&db_name);
+                    if let Some((_, new_name)) = new_name {
+                        stmt.new_name = CIStr::new(&new_name);
+                    }
+
+                    Statement::FlashBackDatabaseStmt(Box::new(stmt))
+                },
+            ),
+        )),
+    )(i)
+}
+
+// FlashbackToTimestamp is `TO TIMESTAMP 'timestamp'` or `TO TSO tso`.
+fn flashback_to_timestamp(i: Input) -> IResult<FlashBackToTimestampStmt> {
+    alt((
+        map(rule!(TO ~ TIMESTAMP ~ #string_literal), |(_, _, ts)| {
+            let mut stmt = FlashBackToTimestampStmt::default();
+            stmt.flashback_ts = Some(ExprNode::ValueExpr(ts));
+            stmt
+        }),
+        map(rule!(TO ~ TSO ~ #length_num), |(_, _, tso)| {
+            let mut stmt = FlashBackToTimestampStmt::default();
+            stmt.flashback_tso = tso;
+            stmt
+        }),
+    ))(i)
+}
@@ -0,0 +1,108 @@
+use crate::ast::ci_str::CIStr;
+use crate::ast::expr_node::ExprNode;
+use crate::ast::handler_stmt::{HandlerReadDirection, HandlerStmt, HandlerStmtType};
+use crate::ast::op_code::OpCode;
+use crate::ast::statement::Statement;
+use crate::parser::common::*;
+use crate::parser::input::Input;
+use crate::parser::statements::common::identifier;
+use crate::parser::statements::expression::{expression, expression_list};
+use crate::parser::statements::limit::limit_clause;
+use crate::parser::statements::table_name::table_name;
+use crate::parser::token_kind::TokenKind::*;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom_rule::rule;
+
+// See https://dev.mysql.com/doc/refman/8.0/en/handler.html
+pub fn handler_statement(i: Input) -> IResult<Statement> {
+    alt((
+        map(
+            rule!(HANDLER ~ #table_name ~ OPEN ~ (AS? ~ #identifier)?),
+            |(_, table, _, alias)| {
+                let mut stmt = HandlerStmt::default();
+                stmt.tp = HandlerStmtType::Open;
+                stmt.table = table;
+                if let Some((_, name)) = alias {
+                    stmt.as_name = CIStr::new(&name);
+                }
+
+                Statement::HandlerStmt(Box::new(stmt))
+            },
+        ),
+        map(rule!(HANDLER ~ #table_name ~ CLOSE), |(_, table, _)| {
+            let mut stmt = HandlerStmt::default();
+            stmt.tp = HandlerStmtType::Close;
+            stmt.table = table;
+
+            Statement::HandlerStmt(Box::new(stmt))
+        }),
+        map(
+            rule!(HANDLER ~ #table_name ~ READ ~ #handler_read_spec ~ #handler_where_opt ~ #limit_clause?),
+            |(_, table, _, mut stmt, where_clause, limit)| {
+                stmt.tp = HandlerStmtType::Read;
+                stmt.table = table;
+                stmt.where_clause = where_clause;
+                stmt.limit = limit;
+
+                Statement::HandlerStmt(Box::new(stmt))
+            },
+        ),
+    ))(i)
+}
+
+pub fn handler_read_spec(i: Input) -> IResult<HandlerStmt> {
+    alt((
+        map(
+            rule!(#identifier ~ #handler_compare_op ~ "(" ~ #expression_list ~ ")"),
+            |(index_name, op, _, values, _)| {
+                let mut stmt = HandlerStmt::default();
+                stmt.index_name = CIStr::new(&index_name);
+                stmt.op = Some(op);
+                stmt.values = values;
+                stmt
+            },
+        ),
+        map(
+            rule!(#identifier ~ #handler_read_direction),
+            |(index_name, direction)| {
+                let mut stmt = HandlerStmt::default();
+                stmt.index_name = CIStr::new(&index_name);
+                stmt.read_direction = Some(direction);
+                stmt
+            },
+        ),
+        map(rule!(FIRST | NEXT), |t| {
+            let mut stmt = HandlerStmt::default();
+            stmt.read_direction = Some(if t.kind == FIRST {
+                HandlerReadDirection::First
+            } else {
+                HandlerReadDirection::Next
+            });
+            stmt
+        }),
+    ))(i)
+}
+
+pub fn handler_read_direction(i: Input) -> IResult<HandlerReadDirection> {
+    alt((
+        map(rule!(FIRST), |_| HandlerReadDirection::First),
+        map(rule!(NEXT), |_| HandlerReadDirection::Next),
+        map(rule!(PREV), |_| HandlerReadDirection::Prev),
+        map(rule!(LAST), |_| HandlerReadDirection::Last),
+    ))(i)
+}
+
+pub fn handler_compare_op(i: Input) -> IResult<OpCode> {
+    alt((
+        map(rule!("<="), |_| OpCode::LE),
+        map(rule!(">="), |_| OpCode::GE),
+        map(rule!("="), |_| OpCode::EQ),
+        map(rule!("<"), |_| OpCode::LT),
+        map(rule!(">"), |_| OpCode::GT),
+    ))(i)
+}
+
+pub fn handler_where_opt(i: Input) -> IResult<Option<ExprNode>> {
+    map(rule!((WHERE ~ #expression)?), |w| w.map(|(_, expr)| expr))(i)
+}
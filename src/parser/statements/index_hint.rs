@@ -0,0 +1,70 @@
+use crate::ast::ci_str::CIStr;
+use crate::ast::index_hint::{IndexHint, IndexHintScope, IndexHintType};
+use crate::parser::common::*;
+use crate::parser::error::ErrorKind;
+use crate::parser::input::Input;
+use crate::parser::statements::common::identifier;
+use crate::parser::token_kind::TokenKind::*;
+use nom::branch::alt;
+use nom::combinator::{map, opt};
+use nom::multi::many0;
+use nom_rule::rule;
+
+// IndexHintListOpt is the index hints after a table name, e.g.
+// `USE INDEX (idx1) IGNORE INDEX FOR ORDER BY (idx2)`.
+// See https://dev.mysql.com/doc/refman/8.0/en/index-hints.html
+pub fn index_hint_list_opt(i: Input) -> IResult<Vec<IndexHint>> {
+    many0(index_hint)(i)
+}
+
+pub fn index_hint(i: Input) -> IResult<IndexHint> {
+    spanned(map_res(
+        rule!(
+            #index_hint_type ~ #index_hint_scope ~ "(" ~ #comma_separated_list0(index_name) ~ ")"
+        ),
+        |(hint_type, hint_scope, _, index_names, _)| {
+            // Only `USE INDEX ()` may have an empty index list.
+            if index_names.is_empty() && !matches!(hint_type, IndexHintType::HintUse) {
+                return Err(nom::Err::Error(ErrorKind::Other(
+                    "index hint requires at least one index name",
+                )));
+            }
+
+            Ok(IndexHint {
+                span: None,
+                index_names,
+                hint_type,
+                hint_scope,
+            })
+        },
+    ))(i)
+}
+
+pub fn index_hint_type(i: Input) -> IResult<IndexHintType> {
+    map(
+        rule!((USE | IGNORE | FORCE) ~ (INDEX | KEY)),
+        |(t, _)| match t.kind {
+            USE => IndexHintType::HintUse,
+            IGNORE => IndexHintType::HintIgnore,
+            _ => IndexHintType::HintForce,
+        },
+    )(i)
+}
+
+pub fn index_hint_scope(i: Input) -> IResult<IndexHintScope> {
+    map(
+        opt(alt((
+            map(rule!(FOR ~ JOIN), |_| IndexHintScope::HintForJoin),
+            map(rule!(FOR ~ ORDER ~ BY), |_| IndexHintScope::HintForOrderBy),
+            map(rule!(FOR ~ GROUP ~ BY), |_| IndexHintScope::HintForGroupBy),
+        ))),
+        |scope| scope.unwrap_or(IndexHintScope::HintForScan),
+    )(i)
+}
+
+fn index_name(i: Input) -> IResult<CIStr> {
+    alt((
+        map(rule!(#identifier), |name| CIStr::new(&name)),
+        map(rule!(PRIMARY), |t| CIStr::new(t.text())),
+    ))(i)
+}
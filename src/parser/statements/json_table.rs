@@ -0,0 +1,89 @@
+use crate::ast::ci_str::CIStr;
+use crate::ast::json_table::{
+    JsonTable, JsonTableColumn, JsonTableColumnKind, JsonTableOnResponse,
+};
+use crate::parser::common::*;
+use crate::parser::input::Input;
+use crate::parser::statements::common::{identifier, string_value};
+use crate::parser::statements::expression::expression;
+use crate::parser::statements::field_type::field_type;
+use crate::parser::token_kind::TokenKind::*;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom_rule::rule;
+
+// JsonTable is `JSON_TABLE(expr, path COLUMNS (column_list))`.
+// See https://dev.mysql.com/doc/refman/8.0/en/json-table-functions.html
+pub fn json_table(i: Input) -> IResult<JsonTable> {
+    spanned(map(
+        rule!(
+            JSON_TABLE ~ "(" ~ #expression ~ "," ~ #string_value ~ #json_table_columns ~ ")"
+        ),
+        |(_, _, expr, _, path, columns, _)| JsonTable {
+            span: None,
+            expr: Box::new(expr),
+            path,
+            columns,
+        },
+    ))(i)
+}
+
+fn json_table_columns(i: Input) -> IResult<Vec<JsonTableColumn>> {
+    map(
+        rule!(COLUMNS ~ "(" ~ #comma_separated_list1(json_table_column) ~ ")"),
+        |(_, _, columns, _)| columns,
+    )(i)
+}
+
+fn json_table_column(i: Input) -> IResult<JsonTableColumn> {
+    let nested = map(
+        rule!(NESTED ~ PATH? ~ #string_value ~ #json_table_columns),
+        |(_, _, path, nested_columns)| {
+            let mut column = JsonTableColumn::new(JsonTableColumnKind::Nested, CIStr::default());
+            column.path = path;
+            column.nested_columns = nested_columns;
+            column
+        },
+    );
+    let for_ordinality = map(rule!(#identifier ~ FOR ~ ORDINALITY), |(name, _, _)| {
+        JsonTableColumn::new(JsonTableColumnKind::ForOrdinality, CIStr::new(&name))
+    });
+    let exists_path = map(
+        rule!(#identifier ~ #field_type ~ EXISTS ~ PATH ~ #string_value),
+        |(name, tp, _, _, path)| {
+            let mut column =
+                JsonTableColumn::new(JsonTableColumnKind::ExistsPath, CIStr::new(&name));
+            column.tp = Some(tp);
+            column.path = path;
+            column
+        },
+    );
+    let path = map(
+        rule!(
+            #identifier ~ #field_type ~ PATH ~ #string_value
+            ~ (#json_table_on_response ~ ON ~ EMPTY)?
+            ~ (#json_table_on_response ~ ON ~ ERROR)?
+        ),
+        |(name, tp, _, path, on_empty, on_error)| {
+            let mut column = JsonTableColumn::new(JsonTableColumnKind::Path, CIStr::new(&name));
+            column.tp = Some(tp);
+            column.path = path;
+            column.on_empty = on_empty.map(|(response, _, _)| response);
+            column.on_error = on_error.map(|(response, _, _)| response);
+            column
+        },
+    );
+
+    // NESTED is a non-reserved keyword, try it before the column name.
+    spanned(alt((nested, for_ordinality, exists_path, path)))(i)
+}
+
+fn json_table_on_response(i: Input) -> IResult<JsonTableOnResponse> {
+    alt((
+        map(rule!(NULL), |_| JsonTableOnResponse::Null),
+        map(rule!(ERROR), |_| JsonTableOnResponse::Error),
+        map(rule!(DEFAULT ~ #string_value), |(_, value)| {
+            JsonTableOnResponse::Default(value)
+        }),
+    ))(i)
+}
@@ -1,6 +1,7 @@
 use crate::ast::functions::{GetFormatSelectorType, TimeUnitType, TrimDirectionType};
 use crate::parser::common::*;
-use crate::parser::input::Input;
+use crate::parser::error::{Error, ErrorKind};
+use crate::parser::input::{Dialect, Input};
 use crate::parser::token_kind::TokenKind::*;
 use nom::branch::alt;
 use nom::combinator::map;
@@ -516,12 +517,89 @@ pub fn un_reserved_keyword_2(i: Input) -> IResult<String> {
                     | COMPRESSION_TYPE
                     | ENCRYPTION_METHOD
                     | ENCRYPTION_KEYFILE
+                    | PREV
+                    | EMPTY
+                    | JSON_TABLE
+            ),
+            |t| t.text().to_string(),
+        ),
+        map(
+            rule!(
+                NESTED
+                    | ORDINALITY
+                    | PATH
+                    | GEOMETRY
+                    | GEOMETRYCOLLECTION
+                    | LINESTRING
+                    | MULTILINESTRING
+                    | MULTIPOINT
+                    | MULTIPOLYGON
+                    | POLYGON
+                    | SOUNDS
+                    | PREVIOUS
             ),
             |t| t.text().to_string(),
         ),
     ))(i)
 }
 
+// DialectUnReservedKeyword is the keywords that are reserved by MySQL 8.0 but
+// not by the dialect, they can be identifiers then.
+pub fn dialect_un_reserved_keyword(i: Input) -> IResult<String> {
+    match i.dialect {
+        Dialect::MySQL57 => map(
+            rule!(
+                CUME_DIST
+                    | DENSE_RANK
+                    | EXCEPT
+                    | FIRST_VALUE
+                    | GROUPS
+                    | INTERSECT
+                    | LAG
+                    | LAST_VALUE
+                    | LATERAL
+                    | LEAD
+                    | NTH_VALUE
+                    | NTILE
+                    | OF
+                    | OVER
+                    | PERCENT_RANK
+                    | RANK
+                    | RECURSIVE
+                    | ROW_NUMBER
+                    | TABLESAMPLE
+                    | WINDOW
+            ),
+            |t| t.text().to_string(),
+        )(i),
+        Dialect::MariaDB10 | Dialect::MariaDB11 => map(
+            rule!(
+                CUME_DIST
+                    | DENSE_RANK
+                    | FIRST_VALUE
+                    | GROUPS
+                    | LAG
+                    | LAST_VALUE
+                    | LATERAL
+                    | LEAD
+                    | NTH_VALUE
+                    | NTILE
+                    | OF
+                    | PERCENT_RANK
+                    | RANK
+                    | ROW_NUMBER
+                    | TABLESAMPLE
+            ),
+            |t| t.text().to_string(),
+        )(i),
+        Dialect::MySQL80 => map(rule!(TABLESAMPLE), |t| t.text().to_string())(i),
+        Dialect::MySQL84 | Dialect::TiDB => Err(nom::Err::Error(Error::from_error_kind(
+            i,
+            ErrorKind::Other("reserved keyword"),
+        ))),
+    }
+}
+
 pub fn tidb_keyword(i: Input) -> IResult<String> {
     alt((
         map(
@@ -740,11 +818,11 @@ pub fn not_keyword_token(i: Input) -> IResult<String> {
 }
 
 pub fn function_name_date_arith(i: Input) -> IResult<String> {
-    map(rule!(DATE_ADD | DATE_SUB), |(t)| t.text().to_string())(i)
+    map(rule!(DATE_ADD | DATE_SUB), |t| t.text().to_string())(i)
 }
 
 pub fn function_name_date_arith_multi_forms(i: Input) -> IResult<String> {
-    map(rule!(ADDDATE | SUBDATE), |(t)| t.text().to_string())(i)
+    map(rule!(ADDDATE | SUBDATE), |t| t.text().to_string())(i)
 }
 
 pub fn function_name_conflict(i: Input) -> IResult<String> {
@@ -858,10 +936,10 @@ pub fn time_unit_1(i: Input) -> IResult<TimeUnitType> {
 
 pub fn get_format_selector(i: Input) -> IResult<GetFormatSelectorType> {
     alt((
-        map(rule!(DATE), |(_)| GetFormatSelectorType::Date),
-        map(rule!(DATETIME), |(_)| GetFormatSelectorType::Datetime),
-        map(rule!(TIME), |(_)| GetFormatSelectorType::Time),
-        map(rule!(TIMESTAMP), |(_)| GetFormatSelectorType::Datetime),
+        map(rule!(DATE), |_| GetFormatSelectorType::Date),
+        map(rule!(DATETIME), |_| GetFormatSelectorType::Datetime),
+        map(rule!(TIME), |_| GetFormatSelectorType::Time),
+        map(rule!(TIMESTAMP), |_| GetFormatSelectorType::Datetime),
     ))(i)
 }
 
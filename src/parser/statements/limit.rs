@@ -0,0 +1,55 @@
+use crate::ast::expr_node::{ExprNode, ParamMarkerExpr, ValueExpr, ValueExprKind};
+use crate::ast::limit::Limit;
+use crate::parser::common::*;
+use crate::parser::input::Input;
+use crate::parser::statements::common::length_num;
+use crate::parser::token_kind::TokenKind::*;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom_rule::rule;
+
+pub fn limit_clause(i: Input) -> IResult<Limit> {
+    spanned(alt((
+        map(
+            rule!(LIMIT ~ #limit_option ~ "," ~ #limit_option),
+            |(_, offset, _, count)| Limit {
+                span: None,
+                count: Box::new(count),
+                offset: Box::new(offset),
+            },
+        ),
+        map(
+            rule!(LIMIT ~ #limit_option ~ OFFSET ~ #limit_option),
+            |(_, count, _, offset)| Limit {
+                span: None,
+                count: Box::new(count),
+                offset: Box::new(offset),
+            },
+        ),
+        map(rule!(LIMIT ~ #limit_option), |(_, count)| Limit {
+            span: None,
+            count: Box::new(count),
+            offset: Box::new(ExprNode::Default),
+        }),
+    )))(i)
+}
+
+pub fn limit_option(i: Input) -> IResult<ExprNode> {
+    spanned(alt((
+        map(rule!(#length_num), |n| {
+            ExprNode::ValueExpr(ValueExpr::new(
+                &n.to_string(),
+                ValueExprKind::U64(n),
+                i.charset,
+                i.collation,
+            ))
+        }),
+        map(rule!("?"), |t| {
+            let mut mark_expr = ParamMarkerExpr::default();
+            mark_expr.token_index = t.pos;
+            mark_expr.start_pos = t.span.start as usize;
+            mark_expr.end_pos = t.span.end as usize;
+            ExprNode::ParamMarkerExpr(mark_expr)
+        }),
+    )))(i)
+}
@@ -1,156 +1,183 @@
-use crate::ast::expr_node::{ExprNode, ValueExpr, ValueExprKind};
+use crate::ast::expr_node::{ExprNode, LiteralPrefix, ValueExpr, ValueExprKind};
 use crate::charset::charset;
+use crate::charset::encoding::{find_encoding, OP_DECODE};
+use crate::charset::encoding_trait::EncodingTrait;
+use crate::common::escape::unescape_string;
 use crate::parser::common::*;
 use crate::parser::error::ErrorKind;
 use crate::parser::input::Input;
-use crate::parser::statements::common::string_lit;
+use crate::parser::statements::common::string_value;
+use crate::parser::statements::num_literal::num_literal;
 use crate::parser::token_kind::TokenKind::*;
 use nom::branch::alt;
 use nom::combinator::map;
 use nom_rule::rule;
 
+// StringLiteral is a quoted string with an optional charset introducer or
+// the N prefix of national strings, the adjacent strings are concatenated,
+// e.g. `_latin1 'a' 'b'` is `_latin1 'ab'`.
 pub fn string_literal(i: Input) -> IResult<ValueExpr> {
-    map(rule!(#string_literal_sub), |ss| ValueExpr {
-        s: ss.join(""),
-        kind: ValueExprKind::String,
-        charset: i.charset.to_string(),
-        collation: i.collation.to_string(),
-    })(i)
-}
-pub fn string_literal_sub(i: Input) -> IResult<Vec<String>> {
-    separated_list1(map(rule!(""), |_| ()), string_lit)(i)
+    spanned(alt((
+        map(
+            rule!(#underscore_charset ~ #string_value+),
+            |((cs, co), ss)| ValueExpr::new(&ss.concat(), ValueExprKind::String, &cs, &co),
+        ),
+        map_res(rule!(LiteralNationalString ~ #string_value*), |(t, ss)| {
+            let co = charset::get_default_collation(charset::CHARSET_UTF8MB3)
+                .map_err(|_| nom::Err::Error(ErrorKind::Other("Unknown charset utf8mb3")))?;
+            let s = unescape_string(&t.text()[1..], i.sql_mode.has_no_backslash_escapes_mode())
+                + &ss.concat();
+            let mut value =
+                ValueExpr::new(&s, ValueExprKind::String, charset::CHARSET_UTF8MB3, &co);
+            value.prefix = LiteralPrefix::National;
+            Ok(value)
+        }),
+        map(rule!(#string_value+), |ss| {
+            ValueExpr::new(&ss.concat(), ValueExprKind::String, i.charset, i.collation)
+        }),
+    )))(i)
 }
 
-pub fn literal(i: Input) -> IResult<ValueExpr> {
-    alt((
-        map(rule!(FALSE), |(t)| ValueExpr {
-            s: t.text().to_string(),
-            kind: ValueExprKind::Bool(false),
-            charset: i.charset.to_string(),
-            collation: i.collation.to_string(),
+pub fn literal(i: Input) -> IResult<ExprNode> {
+    spanned(alt((
+        map(rule!(FALSE), |t| {
+            ExprNode::ValueExpr(ValueExpr::new(
+                t.text(),
+                ValueExprKind::Bool(false),
+                i.charset,
+                i.collation,
+            ))
         }),
-        map(rule!(NULL), |(t)| ValueExpr {
-            s: t.text().to_string(),
-            kind: ValueExprKind::None,
-            charset: i.charset.to_string(),
-            collation: i.collation.to_string(),
+        map(rule!(NULL), |t| {
+            ExprNode::ValueExpr(ValueExpr::new(
+                t.text(),
+                ValueExprKind::None,
+                i.charset,
+                i.collation,
+            ))
         }),
-        map(rule!(TRUE), |(t)| ValueExpr {
-            s: t.text().to_string(),
-            kind: ValueExprKind::Bool(true),
-            charset: i.charset.to_string(),
-            collation: i.collation.to_string(),
+        map(rule!(TRUE), |t| {
+            ExprNode::ValueExpr(ValueExpr::new(
+                t.text(),
+                ValueExprKind::Bool(true),
+                i.charset,
+                i.collation,
+            ))
         }),
-        map_res(rule!(LiteralFloat), |(t)| {
-            let s = t.text().to_string();
-            let v = get_f64_form_num(&s);
-            match v {
-                Ok(val) => Ok(ValueExpr {
-                    s,
-                    kind: ValueExprKind::F64(val),
-                    charset: i.charset.to_string(),
-                    collation: i.collation.to_string(),
-                }),
-                Err(e) => Err(nom::Err::Error(ErrorKind::ExpectText(&format!(
-                    "{} parse to f64 error. {}",
-                    &s,
-                    e.to_string()
-                )))),
+        map(rule!(#num_literal), ExprNode::ValueExpr),
+        map(rule!(#string_literal), ExprNode::ValueExpr),
+        map(rule!(#binary_literal), ExprNode::ValueExpr),
+    )))(i)
+}
+
+// BinaryLiteral is a hexadecimal or bit-value literal, e.g. `0x0A`,
+// `X'0A'`, `0b101` or `B'101'`. It's a binary string unless a charset
+// introducer is given, whose encoding decodes the bytes then, e.g.
+// `_latin1 X'E9'` is `_latin1 'é'`.
+fn binary_literal(i: Input) -> IResult<ValueExpr> {
+    spanned(map_res(
+        rule!(#underscore_charset? ~ (LiteralHex | LiteralBit)),
+        |(introducer, t)| {
+            let bytes = if t.kind == LiteralHex {
+                hex_literal_bytes(t.text())
+            } else {
+                bit_literal_bytes(t.text())
+            };
+            match introducer {
+                Some((cs, co)) if cs != charset::CHARSET_BIN => {
+                    let decoded = decode_introduced_bytes(&cs, &bytes).ok_or(nom::Err::Error(
+                        ErrorKind::Other("Invalid string for the character set of the introducer"),
+                    ))?;
+                    Ok(ValueExpr::new(&decoded, ValueExprKind::String, &cs, &co))
+                }
+                _ => {
+                    let kind = if t.kind == LiteralHex {
+                        ValueExprKind::HexLiteral(bytes)
+                    } else {
+                        ValueExprKind::BitLiteral(bytes)
+                    };
+                    let mut value = ValueExpr::new(
+                        t.text(),
+                        kind,
+                        charset::CHARSET_BIN,
+                        charset::COLLATION_BIN,
+                    );
+                    if introducer.is_some() {
+                        value.prefix = LiteralPrefix::Binary;
+                    }
+                    Ok(value)
+                }
             }
-        }),
-        map_res(rule!(LiteralInteger), |(t)| {
-            let s = t.text().to_string();
-            let v = get_i64_form_num(&s);
-            match v {
-                Ok(val) => Ok(ValueExpr {
-                    s,
-                    kind: ValueExprKind::I64(val),
-                    charset: i.charset.to_string(),
-                    collation: i.collation.to_string(),
-                }),
-                Err(e) => Err(nom::Err::Error(ErrorKind::ExpectText(&format!(
-                    "{} is out of range [–9223372036854775808,9223372036854775807]. {}",
-                    &s,
-                    e.to_string()
-                )))),
+        },
+    ))(i)
+}
+
+// The bytes of a hexadecimal or bit-value literal decoded by the charset of
+// its introducer. The latin1 encoding keeps the bytes as they are, like
+// TiDB does, so the bytes that aren't UTF-8 are read as MySQL's latin1,
+// which is cp1252.
+fn decode_introduced_bytes(cs: &str, bytes: &[u8]) -> Option<String> {
+    let decoded = find_encoding(cs)
+        .transform(&mut vec![], bytes, OP_DECODE)
+        .ok()?;
+    match String::from_utf8(decoded) {
+        Ok(s) => Some(s),
+        Err(_) if cs == charset::CHARSET_LATIN1 => {
+            let (s, _) = encoding_rs::WINDOWS_1252.decode_without_bom_handling(bytes);
+            Some(s.into_owned())
+        }
+        Err(_) => None,
+    }
+}
+
+// UnderscoreCharset is a charset introducer, e.g. `_utf8mb4`, it returns the
+// charset and its default collation.
+pub fn underscore_charset(i: Input) -> IResult<(String, String)> {
+    map_res(rule!(Ident), |t| {
+        let name = match t.text().strip_prefix('_') {
+            Some(name) => name,
+            None => {
+                return Err(nom::Err::Error(ErrorKind::Other(
+                    "expected a charset introducer",
+                )))
             }
-        }),
-        map(rule!(#string_literal), |(e)| ExprNode::ValueExpr(e)),
-        map_res(rule!("UNDERSCORE_CHARSET" ~ LiteralString), |(t, st)| {
-            let s = t.text();
-            let co = match charset::get_default_collation_legacy(s) {
-                Ok(v) => v,
-                Err(e) => {
-                    return Err(nom::Err::Error(ErrorKind::ExpectText(&format!(
-                        "{}, Unsupported character introducer: '{}'",
-                        e.to_string(),
-                        s
-                    ))))
-                }
-            };
+        };
+        let cs = charset::get_charset_info(name)
+            .map_err(|_| nom::Err::Error(ErrorKind::Other("Unsupported character introducer")))?;
+        Ok((cs.name, cs.default_collation))
+    })(i)
+}
 
-            Ok(ExprNode::ValueExpr(ValueExpr {
-                s: st.text().to_string(),
-                kind: ValueExprKind::String,
-                charset: s.to_string(),
-                collation: co,
-            }))
-        }),
-        map(rule!(LiteralHex), |(t)| {
-            ExprNode::ValueExpr(ValueExpr {
-                s: t.text().to_string(),
-                kind: ValueExprKind::HexLiteral,
-                charset: i.charset.to_string(),
-                collation: i.collation.to_string(),
-            })
-        }),
-        map(rule!(LiteralBit), |(t)| {
-            ExprNode::ValueExpr(ValueExpr {
-                s: t.text().to_string(),
-                kind: ValueExprKind::BitLiteral,
-                charset: i.charset.to_string(),
-                collation: i.collation.to_string(),
-            })
-        }),
-        map_res(rule!("UNDERSCORE_CHARSET" ~ LiteralHex), |(t, st)| {
-            let s = t.text();
-            let co = match charset::get_default_collation_legacy(s) {
-                Ok(v) => v,
-                Err(e) => {
-                    return Err(nom::Err::Error(ErrorKind::ExpectText(&format!(
-                        "{}, Unsupported character introducer: '{}'",
-                        e.to_string(),
-                        s
-                    ))))
-                }
-            };
+// The digits of `0x0A` or `X'0A'`, a leading zero is added to an odd number
+// of digits.
+fn hex_literal_bytes(text: &str) -> Vec<u8> {
+    let digits = literal_digits(text);
+    let digits = if digits.len() % 2 == 1 {
+        format!("0{}", digits)
+    } else {
+        digits.to_string()
+    };
+    (0..digits.len())
+        .step_by(2)
+        .map(|n| u8::from_str_radix(&digits[n..n + 2], 16).unwrap())
+        .collect()
+}
 
-            Ok(ExprNode::ValueExpr(ValueExpr {
-                s: st.text().to_string(),
-                kind: ValueExprKind::HexLiteral,
-                charset: s.to_string(),
-                collation: co,
-            }))
-        }),
-        map_res(rule!("UNDERSCORE_CHARSET" ~ LiteralBit), |(t, st)| {
-            let s = t.text();
-            let co = match charset::get_default_collation_legacy(s) {
-                Ok(v) => v,
-                Err(e) => {
-                    return Err(nom::Err::Error(ErrorKind::ExpectText(&format!(
-                        "{}, Unsupported character introducer: '{}'",
-                        e.to_string(),
-                        s
-                    ))))
-                }
-            };
+// The digits of `0b101` or `B'101'`, they are right aligned in the bytes.
+fn bit_literal_bytes(text: &str) -> Vec<u8> {
+    let digits = literal_digits(text);
+    let padding = (8 - digits.len() % 8) % 8;
+    let digits = "0".repeat(padding) + digits;
+    (0..digits.len())
+        .step_by(8)
+        .map(|n| u8::from_str_radix(&digits[n..n + 8], 2).unwrap())
+        .collect()
+}
 
-            Ok(ExprNode::ValueExpr(ValueExpr {
-                s: st.text().to_string(),
-                kind: ValueExprKind::BitLiteral,
-                charset: s.to_string(),
-                collation: co,
-            }))
-        }),
-    ))(i)
+fn literal_digits(text: &str) -> &str {
+    let digits = &text[1..];
+    let digits = digits
+        .strip_prefix(|c| c == 'x' || c == 'X' || c == 'b' || c == 'B')
+        .unwrap_or(digits);
+    digits.trim_matches('\'')
 }
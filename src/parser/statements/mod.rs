@@ -1,13 +1,34 @@
-mod column_name;
+// The parsers build the nodes with `Default` and fill in the parsed fields,
+// like the TiDB grammar actions they follow.
+#![allow(clippy::field_reassign_with_default)]
+
+pub mod admin_stmt;
+pub mod binding_stmt;
+pub mod call_stmt;
+pub mod column_name;
 pub mod common;
+pub mod do_stmt;
 pub mod expression;
+pub mod field_type;
+pub mod flashback_stmt;
+pub mod handler_stmt;
+pub mod index_hint;
+pub mod json_table;
 pub mod keywords;
+pub mod limit;
 pub mod literal;
 pub mod num_literal;
+pub mod placement_policy_stmt;
+pub mod predicate;
 pub mod select_statement;
+pub mod sequence_stmt;
+pub mod set_config_stmt;
 pub mod set_opr_stmt;
+pub mod show_stmt;
+pub mod split_region_stmt;
 pub mod statement;
 pub mod table_hints;
 pub mod table_name;
+pub mod table_refs;
 pub mod vairable;
-mod with_clause;
+pub mod with_clause;
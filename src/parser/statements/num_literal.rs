@@ -3,52 +3,50 @@ use crate::parser::common::*;
 use crate::parser::error::ErrorKind;
 use crate::parser::input::Input;
 use crate::parser::token_kind::TokenKind::*;
+use bigdecimal::BigDecimal;
 use nom::branch::alt;
 use nom::combinator::map;
 use nom_rule::rule;
+use ordered_float::OrderedFloat;
+use std::str::FromStr;
 
 pub fn i64_num(i: Input) -> IResult<i64> {
-    map_res(rule!(LiteralInteger), |(val)| {
+    map_res(rule!(LiteralInteger), |val| {
         let v = get_i64_form_num(val.text());
         match v {
             Ok(val) => Ok(val),
-            Err(e) => Err(nom::Err::Error(ErrorKind::ExpectText(&format!(
-                "{} is out of range [–9223372036854775808,9223372036854775807]. {}",
-                val.text(),
-                e.to_string()
-            )))),
+            Err(_) => Err(nom::Err::Error(ErrorKind::Other(
+                "integer is out of range [-9223372036854775808, 9223372036854775807]",
+            ))),
         }
     })(i)
 }
 
 pub fn f64_num(i: Input) -> IResult<f64> {
-    map_res(rule!(LiteralFloat), |(val)| {
+    map_res(rule!(LiteralFloat), |val| {
         let v = get_f64_form_num(val.text());
         match v {
             Ok(val) => Ok(val),
-            Err(e) => Err(nom::Err::Error(ErrorKind::ExpectText(&format!(
-                "{} parse to f64 error. {}",
-                val.text(),
-                e.to_string()
-            )))),
+            Err(_) => Err(nom::Err::Error(ErrorKind::Other(
+                "unable to parse float number",
+            ))),
         }
     })(i)
 }
 
 pub fn signed_num(i: Input) -> IResult<i64> {
     alt((
-        map(rule!(#i64_num), |(val)| val),
+        map(rule!(#i64_num), |val| val),
         map(rule!("+" ~ #i64_num), |(_, val)| val),
         map_res(rule!("-" ~ LiteralInteger), |(_, val)| {
             let unsigned_num = get_u64_form_num(val.text());
             if unsigned_num > 9223372036854775808 {
-                return Err(nom::Err::Error(ErrorKind::ExpectText(&format!(
-                    "current value: {}. the Signed Value should be at the range of [-9223372036854775808, 9223372036854775807].",
-                    val.text(),
-                ))));
+                Err(nom::Err::Error(ErrorKind::Other(
+                    "integer is out of range [-9223372036854775808, 9223372036854775807]",
+                )))
             } else if unsigned_num == 9223372036854775808 {
                 let d = 1_i64 << 63;
-                return Ok(d);
+                Ok(d)
             } else {
                 let d = -(unsigned_num as i64);
                 Ok(d)
@@ -56,19 +54,47 @@ pub fn signed_num(i: Input) -> IResult<i64> {
         }),
     ))(i)
 }
+// NumLiteral is an integer, decimal or float literal, its text is kept in
+// `s` so that it's restored as written.
+// See https://dev.mysql.com/doc/refman/8.0/en/number-literals.html
 pub fn num_literal(i: Input) -> IResult<ValueExpr> {
-    alt((
-        map(rule!(#i64_num), |(v)| ValueExpr {
-            s: v.to_string(),
-            kind: ValueExprKind::I64(v),
-            charset: i.charset.to_string(),
-            collation: i.collation.to_string(),
+    spanned(alt((
+        map_res(rule!(LiteralInteger), |t| {
+            let kind = integer_literal_kind(t.text()).ok_or(nom::Err::Error(ErrorKind::Other(
+                "unable to parse integer number",
+            )))?;
+            Ok(ValueExpr::new(t.text(), kind, i.charset, i.collation))
         }),
-        map(rule!(#f64_num), |(v)| ValueExpr {
-            s: v.to_string(),
-            kind: ValueExprKind::F64(v),
-            charset: i.charset.to_string(),
-            collation: i.collation.to_string(),
+        map_res(rule!(LiteralFloat), |t| {
+            let kind = float_literal_kind(t.text()).ok_or(nom::Err::Error(ErrorKind::Other(
+                "unable to parse float number",
+            )))?;
+            Ok(ValueExpr::new(t.text(), kind, i.charset, i.collation))
         }),
-    ))(i)
+    )))(i)
+}
+
+// An integer literal is BIGINT if it fits, BIGINT UNSIGNED above that and
+// DECIMAL above the unsigned range.
+fn integer_literal_kind(text: &str) -> Option<ValueExprKind> {
+    if let Ok(v) = text.parse::<i64>() {
+        Some(ValueExprKind::I64(v))
+    } else if let Ok(v) = text.parse::<u64>() {
+        Some(ValueExprKind::U64(v))
+    } else {
+        BigDecimal::from_str(text).ok().map(ValueExprKind::Decimal)
+    }
+}
+
+// A literal with an exponent like `1e3` is DOUBLE, the others like `1.50`,
+// `.5` or `5.` are DECIMAL with the scale they are written with.
+fn float_literal_kind(text: &str) -> Option<ValueExprKind> {
+    if text.contains(['e', 'E']) {
+        get_f64_form_num(text)
+            .ok()
+            .filter(|v| v.is_finite())
+            .map(|v| ValueExprKind::F64(OrderedFloat(v)))
+    } else {
+        BigDecimal::from_str(text).ok().map(ValueExprKind::Decimal)
+    }
 }
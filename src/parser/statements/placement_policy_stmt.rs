@@ -0,0 +1,145 @@
+use crate::ast::ci_str::CIStr;
+use crate::ast::placement_policy_stmt::{
+    AlterPlacementPolicyStmt, CreatePlacementPolicyStmt, DropPlacementPolicyStmt, PlacementOption,
+    PlacementOptionType,
+};
+use crate::ast::statement::Statement;
+use crate::parser::common::*;
+use crate::parser::error::ErrorKind;
+use crate::parser::input::Input;
+use crate::parser::statements::common::{identifier, length_num, string_value};
+use crate::parser::token_kind::TokenKind::*;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom_rule::rule;
+
+// See https://docs.pingcap.com/tidb/stable/sql-statement-create-placement-policy
+pub fn create_placement_policy_statement(i: Input) -> IResult<Statement> {
+    check_tidb_placement_policy(
+        true,
+        map(
+            rule!(
+                CREATE ~ (OR ~ REPLACE)? ~ PLACEMENT ~ POLICY ~ (IF ~ NOT ~ EXISTS)?
+                ~ #identifier ~ #placement_option_list
+            ),
+            |(_, or_replace, _, _, if_not_exists, policy_name, placement_options)| {
+                Statement::CreatePlacementPolicyStmt(Box::new(CreatePlacementPolicyStmt {
+                    span: None,
+                    or_replace: or_replace.is_some(),
+                    if_not_exists: if_not_exists.is_some(),
+                    policy_name: CIStr::new(&policy_name),
+                    placement_options,
+                }))
+            },
+        ),
+    )(i)
+}
+
+// See https://docs.pingcap.com/tidb/stable/sql-statement-alter-placement-policy
+pub fn alter_placement_policy_statement(i: Input) -> IResult<Statement> {
+    check_tidb_placement_policy(
+        true,
+        map(
+            rule!(
+                ALTER ~ PLACEMENT ~ POLICY ~ (IF ~ EXISTS)? ~ #identifier
+                ~ #placement_option_list
+            ),
+            |(_, _, _, if_exists, policy_name, placement_options)| {
+                Statement::AlterPlacementPolicyStmt(Box::new(AlterPlacementPolicyStmt {
+                    span: None,
+                    if_exists: if_exists.is_some(),
+                    policy_name: CIStr::new(&policy_name),
+                    placement_options,
+                }))
+            },
+        ),
+    )(i)
+}
+
+// See https://docs.pingcap.com/tidb/stable/sql-statement-drop-placement-policy
+pub fn drop_placement_policy_statement(i: Input) -> IResult<Statement> {
+    check_tidb_placement_policy(
+        true,
+        map(
+            rule!(DROP ~ PLACEMENT ~ POLICY ~ (IF ~ EXISTS)? ~ #identifier),
+            |(_, _, _, if_exists, policy_name)| {
+                Statement::DropPlacementPolicyStmt(Box::new(DropPlacementPolicyStmt {
+                    span: None,
+                    if_exists: if_exists.is_some(),
+                    policy_name: CIStr::new(&policy_name),
+                }))
+            },
+        ),
+    )(i)
+}
+
+// PlacementOptionList is separated by spaces or commas.
+fn placement_option_list(i: Input) -> IResult<Vec<PlacementOption>> {
+    map(
+        rule!(#placement_option ~ (","? ~ #placement_option)*),
+        |(first, rest)| {
+            let mut options = vec![first];
+            options.extend(rest.into_iter().map(|(_, option)| option));
+            options
+        },
+    )(i)
+}
+
+fn placement_option(i: Input) -> IResult<PlacementOption> {
+    spanned(alt((
+        map(
+            rule!(
+                (PRIMARY_REGION
+                    | REGIONS
+                    | SCHEDULE
+                    | CONSTRAINTS
+                    | LEADER_CONSTRAINTS
+                    | FOLLOWER_CONSTRAINTS
+                    | VOTER_CONSTRAINTS
+                    | LEARNER_CONSTRAINTS
+                    | SURVIVAL_PREFERENCES)
+                ~ "="? ~ #string_value
+            ),
+            |(t, _, str_value)| {
+                let tp = match t.kind {
+                    PRIMARY_REGION => PlacementOptionType::PrimaryRegion,
+                    REGIONS => PlacementOptionType::Regions,
+                    SCHEDULE => PlacementOptionType::Schedule,
+                    CONSTRAINTS => PlacementOptionType::Constraints,
+                    LEADER_CONSTRAINTS => PlacementOptionType::LeaderConstraints,
+                    FOLLOWER_CONSTRAINTS => PlacementOptionType::FollowerConstraints,
+                    VOTER_CONSTRAINTS => PlacementOptionType::VoterConstraints,
+                    LEARNER_CONSTRAINTS => PlacementOptionType::LearnerConstraints,
+                    _ => PlacementOptionType::SurvivalPreferences,
+                };
+                PlacementOption {
+                    span: None,
+                    tp,
+                    str_value,
+                    uint_value: 0,
+                }
+            },
+        ),
+        map_res(
+            rule!((FOLLOWERS | VOTERS | LEARNERS) ~ "="? ~ #length_num),
+            |(t, _, uint_value)| {
+                let tp = match t.kind {
+                    FOLLOWERS => PlacementOptionType::Followers,
+                    VOTERS => PlacementOptionType::Voters,
+                    _ => PlacementOptionType::Learners,
+                };
+                if tp == PlacementOptionType::Followers && uint_value == 0 {
+                    return Err(nom::Err::Error(ErrorKind::Other(
+                        "Invalid placement option FOLLOWERS, it is not allowed to be 0",
+                    )));
+                }
+                Ok(PlacementOption {
+                    span: None,
+                    tp,
+                    str_value: String::new(),
+                    uint_value,
+                })
+            },
+        ),
+    )))(i)
+}
@@ -0,0 +1,246 @@
+use crate::ast::expr_node::{
+    BetweenExpr, BinaryOperationExpr, CompareSubqueryExpr, ExprNode, IsNullExpr, IsTruthExpr,
+    MemberOfExpr, PatternInExpr, PatternLikeOrIlikeExpr, PatternRegexpExpr, SoundsLikeExpr,
+};
+use crate::ast::op_code::OpCode;
+use crate::ast::spanned::Spanned;
+use crate::common::span::merge_span;
+use crate::parser::common::*;
+use crate::parser::error::ErrorKind;
+use crate::parser::input::Input;
+use crate::parser::statements::common::string_value;
+use crate::parser::statements::expression::{bit_expr, expression_list, simple_expr};
+use crate::parser::statements::select_statement::sub_select;
+use crate::parser::token_kind::TokenKind::*;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom_rule::rule;
+
+// BoolPriTail is a comparison or a null check applied to the expression on
+// its left, e.g. the `>= 1` of `a >= 1`.
+enum BoolPriTail {
+    Compare(OpCode, ExprNode),
+    CompareSubquery(OpCode, bool, ExprNode),
+    IsNull(bool),
+    IsTruth(bool, i64),
+}
+
+impl BoolPriTail {
+    fn apply(self, l: ExprNode) -> ExprNode {
+        match self {
+            BoolPriTail::Compare(op, r) => ExprNode::BinaryOperationExpr(BinaryOperationExpr {
+                span: None,
+                op,
+                l: Some(Box::new(l)),
+                r: Some(Box::new(r)),
+            }),
+            BoolPriTail::CompareSubquery(op, all, r) => {
+                ExprNode::CompareSubqueryExpr(CompareSubqueryExpr {
+                    span: None,
+                    l: Some(Box::new(l)),
+                    op,
+                    r: Some(Box::new(r)),
+                    all,
+                })
+            }
+            BoolPriTail::IsNull(not) => ExprNode::IsNullExpr(IsNullExpr {
+                span: None,
+                expr: Some(Box::new(l)),
+                not,
+            }),
+            BoolPriTail::IsTruth(not, true_val) => ExprNode::IsTruthExpr(IsTruthExpr {
+                span: None,
+                expr: Some(Box::new(l)),
+                not,
+                true_val,
+            }),
+        }
+    }
+}
+
+// PredicateTail is the predicate operator and its operands after the
+// checked expression, e.g. the `NOT IN (1, 2)` of `a NOT IN (1, 2)`.
+#[allow(clippy::large_enum_variant)]
+enum PredicateTail {
+    In(bool, Vec<ExprNode>),
+    InSubquery(bool, ExprNode),
+    Between(bool, ExprNode, ExprNode),
+    SoundsLike(ExprNode),
+    Like(bool, bool, ExprNode, Option<char>),
+    Regexp(bool, ExprNode),
+    MemberOf(ExprNode),
+}
+
+impl PredicateTail {
+    fn apply(self, expr: ExprNode) -> ExprNode {
+        let expr = Some(Box::new(expr));
+        match self {
+            PredicateTail::In(not, list) => ExprNode::PatternInExpr(PatternInExpr {
+                span: None,
+                expr,
+                list,
+                not,
+                sel: None,
+            }),
+            PredicateTail::InSubquery(not, sel) => ExprNode::PatternInExpr(PatternInExpr {
+                span: None,
+                expr,
+                list: vec![],
+                not,
+                sel: Some(Box::new(sel)),
+            }),
+            PredicateTail::Between(not, left, right) => ExprNode::BetweenExpr(BetweenExpr {
+                span: None,
+                expr,
+                left: Some(Box::new(left)),
+                right: Some(Box::new(right)),
+                not,
+            }),
+            PredicateTail::SoundsLike(pattern) => ExprNode::SoundsLikeExpr(SoundsLikeExpr {
+                span: None,
+                expr,
+                pattern: Some(Box::new(pattern)),
+            }),
+            PredicateTail::Like(not, is_like, pattern, escape) => {
+                ExprNode::PatternLikeOrIlikeExpr(PatternLikeOrIlikeExpr {
+                    span: None,
+                    expr,
+                    pattern: Some(Box::new(pattern)),
+                    not,
+                    escape,
+                    is_like,
+                })
+            }
+            PredicateTail::Regexp(not, pattern) => ExprNode::PatternRegexpExpr(PatternRegexpExpr {
+                span: None,
+                expr,
+                pattern: Some(Box::new(pattern)),
+                not,
+            }),
+            PredicateTail::MemberOf(json) => ExprNode::MemberOfExpr(MemberOfExpr {
+                span: None,
+                expr,
+                json: Some(Box::new(json)),
+            }),
+        }
+    }
+}
+
+// BoolPri is a predicate followed by comparisons and IS [NOT] tests, they
+// are left associative, e.g. `a = b IS NULL` is `(a = b) IS NULL`.
+pub fn bool_pri(i: Input) -> IResult<ExprNode> {
+    let tail = with_span(bool_pri_tail);
+    spanned(map(rule!(#predicate ~ #tail*), |(expr, tails)| {
+        tails.into_iter().fold(expr, |l, (tail, span)| {
+            let span = merge_span(l.span(), span);
+            let mut expr = tail.apply(l);
+            expr.set_span(span);
+            expr
+        })
+    }))(i)
+}
+
+fn bool_pri_tail(i: Input) -> IResult<BoolPriTail> {
+    alt((
+        map(rule!(IS ~ NOT? ~ (NULL | UNKNOWN)), |(_, not, _)| {
+            BoolPriTail::IsNull(not.is_some())
+        }),
+        map(rule!(IS ~ NOT? ~ TRUE), |(_, not, _)| {
+            BoolPriTail::IsTruth(not.is_some(), 1)
+        }),
+        map(rule!(IS ~ NOT? ~ FALSE), |(_, not, _)| {
+            BoolPriTail::IsTruth(not.is_some(), 0)
+        }),
+        map(
+            rule!(#comp_op ~ #any_or_all ~ #sub_select),
+            |(op, all, sel)| BoolPriTail::CompareSubquery(op, all, ExprNode::SubQueryExpr(sel)),
+        ),
+        map(rule!(#comp_op ~ #predicate), |(op, r)| {
+            BoolPriTail::Compare(op, r)
+        }),
+    ))(i)
+}
+
+pub fn comp_op(i: Input) -> IResult<OpCode> {
+    alt((
+        map(rule!(">="), |_| OpCode::GE),
+        map(rule!(">"), |_| OpCode::GT),
+        map(rule!("<="), |_| OpCode::LE),
+        map(rule!("<"), |_| OpCode::LT),
+        map(rule!("!=" | "<>"), |_| OpCode::NE),
+        map(rule!("="), |_| OpCode::EQ),
+        map(rule!("<=>"), |_| OpCode::NullEQ),
+    ))(i)
+}
+
+// AnyOrAll returns true for ALL, ANY and SOME are synonyms.
+fn any_or_all(i: Input) -> IResult<bool> {
+    alt((map(rule!(ANY | SOME), |_| false), map(rule!(ALL), |_| true)))(i)
+}
+
+// Predicate is a bit expression optionally checked by IN, BETWEEN, LIKE,
+// REGEXP, SOUNDS LIKE or MEMBER OF.
+pub fn predicate(i: Input) -> IResult<ExprNode> {
+    spanned(map(
+        rule!(#bit_expr ~ #predicate_tail?),
+        |(expr, tail)| match tail {
+            Some(tail) => tail.apply(expr),
+            None => expr,
+        },
+    ))(i)
+}
+
+fn predicate_tail(i: Input) -> IResult<PredicateTail> {
+    alt((
+        map(rule!(NOT? ~ IN ~ #sub_select), |(not, _, sel)| {
+            PredicateTail::InSubquery(not.is_some(), ExprNode::SubQueryExpr(sel))
+        }),
+        map(
+            rule!(NOT? ~ IN ~ "(" ~ #expression_list ~ ")"),
+            |(not, _, _, list, _)| PredicateTail::In(not.is_some(), list),
+        ),
+        map(
+            rule!(NOT? ~ BETWEEN ~ #bit_expr ~ AND ~ #predicate),
+            |(not, _, left, _, right)| PredicateTail::Between(not.is_some(), left, right),
+        ),
+        map(rule!(SOUNDS ~ LIKE ~ #bit_expr), |(_, _, pattern)| {
+            PredicateTail::SoundsLike(pattern)
+        }),
+        map(
+            rule!(NOT? ~ (LIKE | ILIKE) ~ #simple_expr ~ #like_escape_opt),
+            |(not, t, pattern, escape)| {
+                PredicateTail::Like(not.is_some(), t.kind == LIKE, pattern, escape)
+            },
+        ),
+        map(
+            rule!(NOT? ~ (REGEXP | RLIKE) ~ #simple_expr),
+            |(not, _, pattern)| PredicateTail::Regexp(not.is_some(), pattern),
+        ),
+        check_multi_valued_index(
+            true,
+            map(
+                rule!(MEMBER ~ OF ~ "(" ~ #simple_expr ~ ")"),
+                |(_, _, _, json, _)| PredicateTail::MemberOf(json),
+            ),
+        ),
+    ))(i)
+}
+
+// LikeEscapeOpt is the escape character of LIKE, `\` if omitted and None
+// for `ESCAPE ''`, which turns escaping off.
+fn like_escape_opt(i: Input) -> IResult<Option<char>> {
+    map_res(rule!((ESCAPE ~ #string_value)?), |escape| {
+        let escape = match escape {
+            Some((_, escape)) => escape,
+            None => return Ok(Some('\\')),
+        };
+        let mut chars = escape.chars();
+        match (chars.next(), chars.next()) {
+            (None, _) => Ok(None),
+            (Some(c), None) => Ok(Some(c)),
+            _ => Err(nom::Err::Error(ErrorKind::Other(
+                "Incorrect arguments to ESCAPE",
+            ))),
+        }
+    })(i)
+}
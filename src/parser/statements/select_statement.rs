@@ -1,71 +1,340 @@
 use crate::ast::ci_str::CIStr;
+use crate::ast::expr_node::RowExpr;
+use crate::ast::group_by_clause::GroupByClause;
+use crate::ast::line_clause::LinesClause;
 use crate::ast::result_set_node::ResultSetNode;
-use crate::ast::select_field::{Field, SelectField, WildCardField};
+use crate::ast::select_field::{Field, FieldsClause, SelectField, WildCardField};
+use crate::ast::select_into_option::{SelectIntoOption, SelectIntoType};
+use crate::ast::select_lock_info::{SelectLockInfo, SelectLockType};
 use crate::ast::select_stmt::{SelectStmt, SelectStmtKind, SelectStmtOpts};
 use crate::ast::statement::Statement;
 use crate::ast::subquery_expr::SubQueryExpr;
-use crate::ast::table_optimizer_hint::TableOptimizerHint;
+use crate::ast::table_name::TableName;
+use crate::ast::table_refs_clause::TableRefsClause;
+use crate::ast::window_spec::WindowSpec;
 use crate::mysql::consts::PriorityEnum;
 use crate::parser::common::*;
 use crate::parser::error::ErrorKind;
 use crate::parser::input::Input;
-use crate::parser::statements::common::priority;
-use crate::parser::statements::expression::expression;
+use crate::parser::statements::common::{identifier, length_num, priority, string_value};
+use crate::parser::statements::expression::{
+    by_list, expression, expression_list_opt, order_by_optional, window_name, window_spec,
+};
+use crate::parser::statements::limit::limit_clause;
 use crate::parser::statements::set_opr_stmt::set_opr_stmt;
 use crate::parser::statements::table_hints::table_optimizer_hints;
-use crate::parser::token_kind::TokenKind::{
-    Ident, LiteralString, ALL, DISTINCT, DISTINCTROW, SELECT,
-};
+use crate::parser::statements::table_name::table_name;
+use crate::parser::statements::table_refs::table_refs;
+use crate::parser::statements::with_clause::with_clause;
+use crate::parser::token_kind::TokenKind::*;
 use nom::branch::alt;
 use nom::combinator::map;
 use nom::multi::many0;
 use nom_rule::rule;
+use std::rc::Rc;
 
+// SelectStatement is a query: a set operation, a select, a parenthesized
+// select, TABLE or VALUES. The set operation is tried first as it starts with
+// a select too.
 pub fn select_statement(i: Input) -> IResult<Statement> {
-    map(rule!(#select_stmt), |(sel_stmt)| {
-        Statement::SelectStmt(Box::new(sel_stmt))
-    })(i)
+    spanned(alt((
+        map(rule!(#set_opr_stmt), |stmt| {
+            Statement::SetOprStmt(Box::new(stmt))
+        }),
+        map(
+            rule!(#select_stmt | #select_stmt_in_braces | #table_stmt | #values_stmt),
+            |sel_stmt| Statement::SelectStmt(Box::new(sel_stmt)),
+        ),
+    )))(i)
 }
 
-pub fn select_stmt(i: Input) -> IResult<SelectStmt> {
-    let s1 = map(
-        rule!(
-            #select_stmt_basic
-        ),
-        |mut sel_stmt| sel_stmt,
-    );
+// SelectStmtInBraces is `(SELECT ...)` as a statement, the clauses after the
+// parentheses apply to the select inside.
+fn select_stmt_in_braces(i: Input) -> IResult<SelectStmt> {
+    spanned(map(
+        rule!("(" ~ #select_stmt ~ ")" ~ #order_by_optional ~ #limit_clause?),
+        |(_, mut st, _, order_by, limit)| {
+            st.is_in_braces = true;
+            if order_by.is_some() {
+                st.order_by = order_by.map(Rc::new);
+            }
+            if limit.is_some() {
+                st.limit = limit.map(Rc::new);
+            }
+            st
+        },
+    ))(i)
+}
 
-    let s2 = map(
+// SelectStmt is `[WITH ...] SELECT ... [ORDER BY ...] [LIMIT ...] [FOR
+// UPDATE ...] [INTO OUTFILE ...]`.
+// See https://dev.mysql.com/doc/refman/8.0/en/select.html
+pub fn select_stmt(i: Input) -> IResult<SelectStmt> {
+    spanned(map(
         rule!(
-            #select_stmt_basic
+            #with_clause? ~ #select_stmt_basic ~ #order_by_optional ~ #limit_clause?
+            ~ #select_lock? ~ #select_into?
         ),
-        |mut sel_stmt| sel_stmt,
-    );
-
-    alt((s1, s2))(i)
+        |(with, mut st, order_by, limit, lock_info, select_into_opt)| {
+            st.with = with.map(Rc::new);
+            st.order_by = order_by.map(Rc::new);
+            st.limit = limit.map(Rc::new);
+            st.lock_info = lock_info;
+            st.select_into_opt = select_into_opt;
+            st
+        },
+    ))(i)
 }
 
+// SelectStmtBasic is a select up to its WINDOW clause, `FROM DUAL` is the
+// same as no FROM clause.
 pub fn select_stmt_basic(i: Input) -> IResult<SelectStmt> {
-    map(
-        rule!(SELECT ~ #select_stmt_opts ~ #select_stmt_field_list),
-        |(_, sso, fields)| {
+    let from = alt((
+        map(rule!(FROM ~ DUAL), |_| None),
+        map(rule!(FROM ~ #table_refs), |(_, from)| Some(from)),
+    ));
+    spanned(map(
+        rule!(
+            SELECT ~ #select_stmt_opts ~ #select_stmt_field_list ~ #from?
+            ~ (WHERE ~ #expression)? ~ #group_by_clause? ~ (HAVING ~ #expression)?
+            ~ #window_clause?
+        ),
+        |(_, sso, fields, from, where_clause, group_by, having, window_specs)| {
             let mut st = SelectStmt::default();
             st.kind = SelectStmtKind::SelectStmtKindSelect;
             st.select_stmt_opts = sso;
             st.fields = fields;
+            st.from = from.flatten();
+            st.where_clause = where_clause.map(|(_, where_clause)| where_clause);
+            st.group_by = group_by;
+            st.having = having.map(|(_, having)| having);
+            st.window_specs = window_specs.unwrap_or_default();
 
             st
         },
+    ))(i)
+}
+
+// GroupByClause is `GROUP BY expr [ASC | DESC], ... [WITH ROLLUP]`.
+fn group_by_clause(i: Input) -> IResult<GroupByClause> {
+    spanned(map(
+        rule!(GROUP ~ BY ~ #by_list ~ (WITH ~ ROLLUP)?),
+        |(_, _, items, rollup)| GroupByClause {
+            span: None,
+            items,
+            rollup: rollup.is_some(),
+        },
+    ))(i)
+}
+
+// WindowClause is `WINDOW w AS (spec), ...`, it names the windows used by
+// the window functions of the select.
+fn window_clause(i: Input) -> IResult<Vec<WindowSpec>> {
+    let window_definition = spanned(map(
+        rule!(#window_name ~ AS ~ #window_spec),
+        |(name, _, mut spec)| {
+            spec.name = name;
+            spec
+        },
+    ));
+    map(
+        rule!(WINDOW ~ #comma_separated_list1(window_definition)),
+        |(_, specs)| specs,
     )(i)
 }
 
-pub fn select_stmt_basic2(i: Input) -> IResult<SelectStmt> {
-    map(rule!(SELECT), |_| {
-        let mut st = SelectStmt::default();
-        st.kind = SelectStmtKind::SelectStmtKindSelect;
+// SelectLockWait is the NOWAIT, WAIT n or SKIP LOCKED option of a lock.
+enum SelectLockWait {
+    NoWait,
+    WaitN(u64),
+    SkipLocked,
+}
 
-        st
-    })(i)
+fn lock_tables(i: Input) -> IResult<Vec<TableName>> {
+    map(
+        rule!((OF ~ #comma_separated_list1(table_name))?),
+        |tables| tables.map(|(_, tables)| tables).unwrap_or_default(),
+    )(i)
+}
+
+fn lock_wait(i: Input) -> IResult<SelectLockWait> {
+    alt((
+        check_lock_wait(true, map(rule!(NOWAIT), |_| SelectLockWait::NoWait)),
+        map(rule!(WAIT ~ #length_num), |(_, n)| SelectLockWait::WaitN(n)),
+        check_lock_wait(
+            true,
+            map(rule!(SKIP ~ LOCKED), |_| SelectLockWait::SkipLocked),
+        ),
+    ))(i)
+}
+
+// SelectLock is `FOR UPDATE`, `FOR SHARE` with the tables to lock and the
+// wait option, or `LOCK IN SHARE MODE`.
+fn select_lock(i: Input) -> IResult<SelectLockInfo> {
+    spanned(alt((
+        map(
+            rule!(FOR ~ UPDATE ~ #lock_tables ~ #lock_wait?),
+            |(_, _, tables, wait)| {
+                let (lock_type, wait_sec) = match wait {
+                    Some(SelectLockWait::NoWait) => (SelectLockType::SelectLockForUpdateNoWait, 0),
+                    Some(SelectLockWait::WaitN(n)) => (SelectLockType::SelectLockForUpdateWaitN, n),
+                    Some(SelectLockWait::SkipLocked) => {
+                        (SelectLockType::SelectLockForUpdateSkipLocked, 0)
+                    }
+                    None => (SelectLockType::SelectLockForUpdate, 0),
+                };
+                SelectLockInfo {
+                    span: None,
+                    lock_type,
+                    wait_sec,
+                    tables,
+                }
+            },
+        ),
+        check_for_share(
+            true,
+            map_res(
+                rule!(FOR ~ SHARE ~ #lock_tables ~ #lock_wait?),
+                |(_, _, tables, wait)| {
+                    let lock_type = match wait {
+                        Some(SelectLockWait::NoWait) => SelectLockType::SelectLockForShareNoWait,
+                        Some(SelectLockWait::WaitN(_)) => {
+                            return Err(nom::Err::Error(ErrorKind::Other(
+                                "WAIT is only supported by FOR UPDATE",
+                            )))
+                        }
+                        Some(SelectLockWait::SkipLocked) => {
+                            SelectLockType::SelectLockForShareSkipLocked
+                        }
+                        None => SelectLockType::SelectLockForShare,
+                    };
+                    Ok(SelectLockInfo {
+                        span: None,
+                        lock_type,
+                        wait_sec: 0,
+                        tables,
+                    })
+                },
+            ),
+        ),
+        map(rule!(LOCK ~ IN ~ SHARE ~ MODE), |_| SelectLockInfo {
+            span: None,
+            lock_type: SelectLockType::SelectLockForShare,
+            wait_sec: 0,
+            tables: vec![],
+        }),
+    )))(i)
+}
+
+// SelectInto is `INTO OUTFILE 'file' [FIELDS ...] [LINES ...]` or `INTO
+// DUMPFILE 'file'`.
+fn select_into(i: Input) -> IResult<SelectIntoOption> {
+    spanned(alt((
+        map(
+            rule!(INTO ~ OUTFILE ~ #string_value ~ #fields_clause? ~ #lines_clause?),
+            |(_, _, file_name, fields_info, lines_info)| SelectIntoOption {
+                span: None,
+                tp: SelectIntoType::SelectIntoOutfile,
+                file_name,
+                fields_info,
+                lines_info,
+            },
+        ),
+        map(
+            rule!(INTO ~ "DUMPFILE" ~ #string_value),
+            |(_, _, file_name)| SelectIntoOption {
+                span: None,
+                tp: SelectIntoType::SelectIntoDumpfile,
+                file_name,
+                fields_info: None,
+                lines_info: None,
+            },
+        ),
+    )))(i)
+}
+
+// FieldsClause is `{FIELDS | COLUMNS} [TERMINATED BY 's'] [[OPTIONALLY]
+// ENCLOSED BY 'c'] [ESCAPED BY 'c']`.
+fn fields_clause(i: Input) -> IResult<FieldsClause> {
+    map(
+        rule!(
+            (FIELDS | COLUMNS) ~ (TERMINATED ~ BY ~ #string_value)?
+            ~ (OPTIONALLY? ~ ENCLOSED ~ BY ~ #string_value)? ~ (ESCAPED ~ BY ~ #string_value)?
+        ),
+        |(_, terminated, enclosed, escaped)| FieldsClause {
+            terminated: terminated.map(|(_, _, s)| s),
+            opt_enclosed: matches!(enclosed, Some((Some(_), _, _, _))),
+            enclosed: enclosed.map(|(_, _, _, s)| s),
+            escaped: escaped.map(|(_, _, s)| s),
+            defined_null_by: None,
+            null_value_opt_enclosed: false,
+        },
+    )(i)
+}
+
+// LinesClause is `LINES [STARTING BY 's'] [TERMINATED BY 's']`.
+fn lines_clause(i: Input) -> IResult<LinesClause> {
+    map(
+        rule!(LINES ~ (STARTING ~ BY ~ #string_value)? ~ (TERMINATED ~ BY ~ #string_value)?),
+        |(_, starting, terminated)| LinesClause {
+            starting: starting.map(|(_, _, s)| s),
+            terminated: terminated.map(|(_, _, s)| s),
+        },
+    )(i)
+}
+
+// TableStmt is the `TABLE t [ORDER BY ...] [LIMIT ...]` form of select.
+// See https://dev.mysql.com/doc/refman/8.0/en/table.html
+pub fn table_stmt(i: Input) -> IResult<SelectStmt> {
+    spanned(check_table_stmt(
+        true,
+        map(
+            rule!(TABLE ~ #table_name ~ #order_by_optional ~ #limit_clause?),
+            |(_, tbl_name, order_by, limit)| {
+                let mut st = SelectStmt::default();
+                st.kind = SelectStmtKind::SelectStmtKindTable;
+                st.from = Some(TableRefsClause::new_table_source(
+                    ResultSetNode::TableName(Box::new(tbl_name)),
+                    CIStr::default(),
+                ));
+                st.order_by = order_by.map(Rc::new);
+                st.limit = limit.map(Rc::new);
+
+                st
+            },
+        ),
+    ))(i)
+}
+
+// ValuesStmt is the `VALUES ROW(...), ROW(...) [ORDER BY ...] [LIMIT ...]` form of select.
+// See https://dev.mysql.com/doc/refman/8.0/en/values.html
+pub fn values_stmt(i: Input) -> IResult<SelectStmt> {
+    spanned(check_values_stmt(
+        true,
+        map(
+            rule!(VALUES ~ #values_stmt_list ~ #order_by_optional ~ #limit_clause?),
+            |(_, lists, order_by, limit)| {
+                let mut st = SelectStmt::default();
+                st.kind = SelectStmtKind::SelectStmtKindValues;
+                st.lists = lists;
+                st.order_by = order_by.map(Rc::new);
+                st.limit = limit.map(Rc::new);
+
+                st
+            },
+        ),
+    ))(i)
+}
+
+pub fn values_stmt_list(i: Input) -> IResult<Vec<RowExpr>> {
+    comma_separated_list1(row_value)(i)
+}
+
+pub fn row_value(i: Input) -> IResult<RowExpr> {
+    spanned(map(
+        rule!(ROW ~ "(" ~ #expression_list_opt ~ ")"),
+        |(_, _, values, _)| RowExpr { span: None, values },
+    ))(i)
 }
 
 pub fn select_stmt_opts(i: Input) -> IResult<SelectStmtOpts> {
@@ -74,12 +343,8 @@ pub fn select_stmt_opts(i: Input) -> IResult<SelectStmtOpts> {
         new_opt.sql_cache = true;
 
         for opt in &opts {
-            if opt.table_hints.len() > 0 {
-                new_opt.table_hints = opt
-                    .table_hints
-                    .iter()
-                    .map(|hint| hint.clone())
-                    .collect::<Vec<TableOptimizerHint>>()
+            if !opt.table_hints.is_empty() {
+                new_opt.table_hints = opt.table_hints.to_vec()
             }
             if opt.distinct {
                 new_opt.distinct = true
@@ -128,50 +393,58 @@ fn select_stmt_sql_cache(i: Input) -> IResult<bool> {
     ))(i)
 }
 
+// A single select option keeps SQL_CACHE unless it's SQL_NO_CACHE, so that
+// merging the options only clears it for SQL_NO_CACHE.
+fn new_select_stmt_opt() -> SelectStmtOpts {
+    let mut opt = SelectStmtOpts::default();
+    opt.sql_cache = true;
+    opt
+}
+
 pub fn select_stmt_opt(i: Input) -> IResult<SelectStmtOpts> {
     alt((
         map(table_optimizer_hints, |hints| {
-            let mut opt = SelectStmtOpts::default();
+            let mut opt = new_select_stmt_opt();
             opt.table_hints = hints;
             opt
         }),
         map(rule!(ALL? ~ (DISTINCT | DISTINCTROW)), |(_, _)| {
-            let mut opt = SelectStmtOpts::default();
+            let mut opt = new_select_stmt_opt();
             opt.distinct = true;
             opt
         }),
         map(priority, |p| {
-            let mut opt = SelectStmtOpts::default();
+            let mut opt = new_select_stmt_opt();
             opt.priority = p;
             opt
         }),
         map(rule!("SQL_SMALL_RESULT"), |_| {
-            let mut opt = SelectStmtOpts::default();
+            let mut opt = new_select_stmt_opt();
             opt.sql_small_result = true;
             opt
         }),
         map(rule!("SQL_BIG_RESULT"), |_| {
-            let mut opt = SelectStmtOpts::default();
+            let mut opt = new_select_stmt_opt();
             opt.sql_big_result = true;
             opt
         }),
         map(rule!("SQL_BUFFER_RESULT"), |_| {
-            let mut opt = SelectStmtOpts::default();
+            let mut opt = new_select_stmt_opt();
             opt.sql_buffer_result = true;
             opt
         }),
-        map(select_stmt_sql_cache, |_| {
-            let mut opt = SelectStmtOpts::default();
-            opt.sql_cache = true;
+        map(select_stmt_sql_cache, |sql_cache| {
+            let mut opt = new_select_stmt_opt();
+            opt.sql_cache = sql_cache;
             opt
         }),
         map(rule!("SQL_CALC_FOUND_ROWS"), |_| {
-            let mut opt = SelectStmtOpts::default();
+            let mut opt = new_select_stmt_opt();
             opt.calc_found_rows = true;
             opt
         }),
         map(rule!("STRAIGHT_JOIN"), |_| {
-            let mut opt = SelectStmtOpts::default();
+            let mut opt = new_select_stmt_opt();
             opt.straight_join = true;
             opt
         }),
@@ -183,55 +456,61 @@ pub fn select_stmt_field_list(i: Input) -> IResult<Vec<SelectField>> {
 }
 
 pub fn field(i: Input) -> IResult<SelectField> {
-    alt((
-        map(rule!("*"), |_| {
+    spanned(alt((
+        map(rule!(#wild_card_field), |wild_card| {
             let mut sf = SelectField::default();
-            sf.field = Field::WildCardField(Default::default());
+            sf.field = Field::WildCardField(wild_card);
             sf
         }),
+        map(rule!(#expression ~ #field_as_name?), |(expr, as_name)| {
+            let mut sf = SelectField::default();
+            if let Some(v) = as_name {
+                sf.as_name = CIStr::new(&v)
+            }
+            sf.field = Field::Expr(expr);
+            sf
+        }),
+    )))(i)
+}
+
+// WildCardField is `*`, `t.*` or `db.t.*` in the select field list.
+fn wild_card_field(i: Input) -> IResult<WildCardField> {
+    spanned(alt((
+        map(rule!("*"), |_| WildCardField::default()),
         map(rule!(Ident ~ "." ~ "*"), |(table_name, _, _)| {
-            let tb_name = table_name.get_trim_start_end_text('`');
+            let tb_name = table_name.ident_text();
 
-            let mut sf = SelectField::default();
-            sf.field = Field::WildCardField(WildCardField {
+            WildCardField {
+                span: None,
                 schema: Default::default(),
-                table: CIStr::new(tb_name),
-            });
-            sf
+                table: CIStr::new(&tb_name),
+            }
         }),
         map(
             rule!(Ident ~ "." ~ Ident ~ "." ~ "*"),
             |(schema_name, _, table_name, _, _)| {
-                let s_name = schema_name.get_trim_start_end_text('`');
-                let tb_name = table_name.get_trim_start_end_text('`');
-
-                let mut sf = SelectField::default();
-                sf.field = Field::WildCardField(WildCardField {
-                    schema: CIStr::new(s_name),
-                    table: CIStr::new(tb_name),
-                });
-                sf
+                let s_name = schema_name.ident_text();
+                let tb_name = table_name.ident_text();
+
+                WildCardField {
+                    span: None,
+                    schema: CIStr::new(&s_name),
+                    table: CIStr::new(&tb_name),
+                }
             },
         ),
-        map(rule!(#expression ~ #field_as_name?), |(expr, as_name)| {
-            let mut sf = SelectField::default();
-            if let Some(v) = as_name {
-                sf.as_name = CIStr::new(&v)
-            }
-            sf.field = Field::Expr(expr);
-            sf
-        }),
-    ))(i)
+    )))(i)
 }
 
 pub fn field_as_name(i: Input) -> IResult<String> {
-    map(rule!("AS"? ~ (Ident | LiteralString)), |(_, s)| {
-        s.text().to_string()
-    })(i)
+    alt((
+        map(rule!("AS"? ~ #identifier), |(_, s)| s),
+        map(rule!("AS"? ~ #string_value), |(_, s)| s),
+    ))(i)
 }
 
 pub fn sub_select(i: Input) -> IResult<SubQueryExpr> {
-    alt((
+    spanned(alt((
         map(rule!("(" ~ #select_stmt ~ ")"), |(_, stmt, _)| {
             let mut sub_query = SubQueryExpr::default();
             sub_query.query = Some(ResultSetNode::SelectStmt(Box::new(stmt)));
@@ -244,5 +523,5 @@ pub fn sub_select(i: Input) -> IResult<SubQueryExpr> {
 
             sub_query
         }),
-    ))(i)
+    )))(i)
 }
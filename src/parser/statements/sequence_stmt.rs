@@ -0,0 +1,152 @@
+use crate::ast::sequence_stmt::{
+    AlterSequenceStmt, CreateSequenceStmt, DropSequenceStmt, SequenceOption, SequenceOptionType,
+};
+use crate::ast::statement::Statement;
+use crate::parser::common::*;
+use crate::parser::input::Input;
+use crate::parser::statements::num_literal::signed_num;
+use crate::parser::statements::table_name::table_name;
+use crate::parser::token_kind::TokenKind::*;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom::combinator::peek;
+use nom_rule::rule;
+
+// See https://mariadb.com/kb/en/create-sequence/
+pub fn create_sequence_statement(i: Input) -> IResult<Statement> {
+    // The options are checked once SEQUENCE follows them, `CREATE OR REPLACE`
+    // of the other objects is left to their own parsers.
+    let is_sequence = |i| peek(rule!(TEMPORARY? ~ SEQUENCE))(i);
+    let or_replace = check_mariadb_or_replace(true, rule!(OR ~ REPLACE ~ #is_sequence));
+    let temporary = check_mariadb_temporary_sequence(true, rule!(TEMPORARY ~ #is_sequence));
+    check_sequence(
+        true,
+        map(
+            rule!(
+                CREATE ~ #or_replace? ~ #temporary? ~ SEQUENCE ~ (IF ~ NOT ~ EXISTS)?
+                ~ #table_name ~ #sequence_option*
+            ),
+            |(_, or_replace, temporary, _, if_not_exists, name, seq_options)| {
+                Statement::CreateSequenceStmt(Box::new(CreateSequenceStmt {
+                    span: None,
+                    or_replace: or_replace.is_some(),
+                    temporary: temporary.is_some(),
+                    if_not_exists: if_not_exists.is_some(),
+                    name,
+                    seq_options,
+                }))
+            },
+        ),
+    )(i)
+}
+
+// See https://mariadb.com/kb/en/alter-sequence/
+pub fn alter_sequence_statement(i: Input) -> IResult<Statement> {
+    check_sequence(
+        true,
+        map(
+            rule!(ALTER ~ SEQUENCE ~ (IF ~ EXISTS)? ~ #table_name ~ #alter_sequence_option+),
+            |(_, _, if_exists, name, seq_options)| {
+                Statement::AlterSequenceStmt(Box::new(AlterSequenceStmt {
+                    span: None,
+                    if_exists: if_exists.is_some(),
+                    name,
+                    seq_options,
+                }))
+            },
+        ),
+    )(i)
+}
+
+// See https://mariadb.com/kb/en/drop-sequence/
+pub fn drop_sequence_statement(i: Input) -> IResult<Statement> {
+    let temporary = check_mariadb_temporary_sequence(true, rule!(TEMPORARY));
+    check_sequence(
+        true,
+        map(
+            rule!(
+                DROP ~ #temporary? ~ SEQUENCE ~ (IF ~ EXISTS)?
+                ~ #comma_separated_list1(table_name)
+            ),
+            |(_, temporary, _, if_exists, sequences)| {
+                Statement::DropSequenceStmt(Box::new(DropSequenceStmt {
+                    span: None,
+                    temporary: temporary.is_some(),
+                    if_exists: if_exists.is_some(),
+                    sequences,
+                }))
+            },
+        ),
+    )(i)
+}
+
+fn sequence_option(i: Input) -> IResult<SequenceOption> {
+    let option = |tp, int_value| SequenceOption {
+        span: None,
+        tp,
+        int_value,
+    };
+    spanned(alt((
+        map(
+            rule!(INCREMENT ~ ("=" | BY)? ~ #signed_num),
+            move |(_, _, n)| option(SequenceOptionType::IncrementBy, n),
+        ),
+        map(
+            rule!(START ~ ("=" | WITH)? ~ #signed_num),
+            move |(_, _, n)| option(SequenceOptionType::StartWith, n),
+        ),
+        map(rule!(MINVALUE ~ "="? ~ #signed_num), move |(_, _, n)| {
+            option(SequenceOptionType::MinValue, n)
+        }),
+        map(rule!(NO ~ MINVALUE), move |_| {
+            option(SequenceOptionType::NoMinValue, 0)
+        }),
+        map(rule!(NOMINVALUE), move |_| {
+            option(SequenceOptionType::NoMinValue, 0)
+        }),
+        map(rule!(MAXVALUE ~ "="? ~ #signed_num), move |(_, _, n)| {
+            option(SequenceOptionType::MaxValue, n)
+        }),
+        map(rule!(NO ~ MAXVALUE), move |_| {
+            option(SequenceOptionType::NoMaxValue, 0)
+        }),
+        map(rule!(NOMAXVALUE), move |_| {
+            option(SequenceOptionType::NoMaxValue, 0)
+        }),
+        map(rule!(CACHE ~ "="? ~ #signed_num), move |(_, _, n)| {
+            option(SequenceOptionType::Cache, n)
+        }),
+        map(rule!(NO ~ CACHE), move |_| {
+            option(SequenceOptionType::NoCache, 0)
+        }),
+        map(rule!(NOCACHE), move |_| {
+            option(SequenceOptionType::NoCache, 0)
+        }),
+        map(rule!(CYCLE), move |_| option(SequenceOptionType::Cycle, 0)),
+        map(rule!(NO ~ CYCLE), move |_| {
+            option(SequenceOptionType::NoCycle, 0)
+        }),
+        map(rule!(NOCYCLE), move |_| {
+            option(SequenceOptionType::NoCycle, 0)
+        }),
+    )))(i)
+}
+
+// AlterSequenceOption is a sequence option or RESTART.
+fn alter_sequence_option(i: Input) -> IResult<SequenceOption> {
+    spanned(alt((
+        map(rule!(RESTART ~ ("=" | WITH)? ~ #signed_num), |(_, _, n)| {
+            SequenceOption {
+                span: None,
+                tp: SequenceOptionType::RestartWith,
+                int_value: n,
+            }
+        }),
+        map(rule!(RESTART), |_| SequenceOption {
+            span: None,
+            tp: SequenceOptionType::Restart,
+            int_value: 0,
+        }),
+        sequence_option,
+    )))(i)
+}
@@ -0,0 +1,59 @@
+use crate::ast::set_config_stmt::SetConfigStmt;
+use crate::ast::statement::Statement;
+use crate::parser::common::*;
+use crate::parser::input::Input;
+use crate::parser::statements::common::{identifier, string_value};
+use crate::parser::statements::expression::expression;
+use crate::parser::token_kind::TokenKind::*;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom_rule::rule;
+
+// See https://docs.pingcap.com/tidb/stable/sql-statement-set-config
+pub fn set_config_statement(i: Input) -> IResult<Statement> {
+    check_tidb_set_config(
+        true,
+        alt((
+            map(
+                rule!(
+                    SET ~ CONFIG ~ #identifier ~ #config_item_name ~ ("=" | AssignmentEq)
+                    ~ #expression
+                ),
+                |(_, _, tp, name, _, value)| {
+                    let mut stmt = SetConfigStmt::default();
+                    stmt.tp = tp.to_lowercase();
+                    stmt.name = name;
+                    stmt.value = Box::new(value);
+
+                    Statement::SetConfigStmt(Box::new(stmt))
+                },
+            ),
+            map(
+                rule!(
+                    SET ~ CONFIG ~ #string_value ~ #config_item_name ~ ("=" | AssignmentEq)
+                    ~ #expression
+                ),
+                |(_, _, instance, name, _, value)| {
+                    let mut stmt = SetConfigStmt::default();
+                    stmt.instance = instance;
+                    stmt.name = name;
+                    stmt.value = Box::new(value);
+
+                    Statement::SetConfigStmt(Box::new(stmt))
+                },
+            ),
+        )),
+    )(i)
+}
+
+// ConfigItemName is the dotted name of a config item, whose parts may
+// contain dashes, e.g. `raftstore.apply-pool-size`.
+fn config_item_name(i: Input) -> IResult<String> {
+    map(
+        rule!(#identifier ~ (("." | "-") ~ #identifier)*),
+        |(first, rest)| {
+            rest.into_iter()
+                .fold(first, |name, (sep, part)| name + sep.text() + &part)
+        },
+    )(i)
+}
@@ -1,138 +1,82 @@
-use crate::ast::limit::Limit;
 use crate::ast::node::Node;
-use crate::ast::order_by_clause::OrderByClause;
 use crate::ast::result_set_node::ResultSetNode;
 use crate::ast::set_opr_stmt::{SetOprSelectList, SetOprStmt, SetOprType};
 use crate::ast::statement::Statement;
-use crate::ast::with_clause::WithClause;
 use crate::parser::common::*;
+use crate::parser::error::ErrorKind;
 use crate::parser::input::Input;
 use crate::parser::statements::common::default_true_distinct_opt;
+use crate::parser::statements::expression::order_by_optional;
+use crate::parser::statements::limit::limit_clause;
 use crate::parser::statements::select_statement::{select_stmt, sub_select};
 use crate::parser::statements::with_clause::with_clause;
 use crate::parser::token_kind::TokenKind::*;
-use crate::parser::token_kind::TokenKind::*;
 use nom::branch::alt;
 use nom::combinator::map;
 use nom_rule::rule;
 use std::rc::Rc;
 
+// See https://dev.mysql.com/doc/refman/8.0/en/set-operations.html
+// See https://mariadb.com/kb/en/intersect/
+// See https://mariadb.com/kb/en/except/
 pub fn set_opr_stmt(i: Input) -> IResult<SetOprStmt> {
-    alt((
-        map(rule!(#set_opr_stmt_wout_limit_order_by), |(stmt)| stmt),
-        map(rule!(#set_opr_stmt_with_limit_order_by), |(stmt)| stmt),
-        map(
-            rule!(#with_clause ~ #set_opr_stmt_with_limit_order_by),
-            |(with, mut set_opr_with)| {
-                set_opr_with.with = Some(with);
-
-                set_opr_with
-            },
-        ),
-        map(
-            rule!(#with_clause ~ #set_opr_stmt_wout_limit_order_by),
-            |(with, mut set_opr_with)| {
-                set_opr_with.with = Some(with);
+    spanned(map(
+        rule!(#with_clause? ~ #set_opr_stmt_wout_limit_order_by ~ #order_by_optional ~ #limit_clause?),
+        |(with, mut stmt, order_by, limit)| {
+            stmt.with = with.map(Rc::new);
+            // The ORDER BY and LIMIT after a parenthesized last operand
+            // apply to the whole set operation.
+            if order_by.is_some() {
+                stmt.order_by = order_by.map(Rc::new);
+            }
+            if limit.is_some() {
+                stmt.limit = limit.map(Rc::new);
+            }
 
-                set_opr_with
-            },
-        ),
+            stmt
+        },
     ))(i)
 }
 
-// See https://dev.mysql.com/doc/refman/5.7/en/union.html
-// See https://mariadb.com/kb/en/intersect/
-// See https://mariadb.com/kb/en/except/
 pub fn set_opr_stmt_wout_limit_order_by(i: Input) -> IResult<SetOprStmt> {
-    alt((
-        map(
-            rule!(#set_opr_clause_list ~ #set_opr ~ #select_stmt),
-            |(list, op, mut st)| {
-                let mut ssl = SetOprSelectList::default();
-                ssl.selects = list;
-
-                let mut so = SetOprStmt::default();
-                so.select_list = Some(ssl);
-                so.limit = st.limit;
-                so.order_by = st.order_by;
-
-                st.limit = None;
-                st.order_by = None;
-                st.after_set_operator = Some(op);
-
-                so.select_list
-                    .as_mut()
-                    .unwrap()
-                    .selects
-                    .push(Node::new_select_stmt(st));
-
-                so
-            },
-        ),
-        map(
-            rule!(#set_opr_clause_list ~ #set_opr ~ #sub_select),
-            |(mut list, op, mut st)| {
-                let mut set_opr_list2 = Vec::<Node>::new();
-                let mut with2: Option<Rc<WithClause>> = None;
-                let mut limit2: Option<Rc<Limit>> = None;
-                let mut order_by2: Option<Rc<OrderByClause>> = None;
-
-                if let Some(q) = st.query {
-                    match q {
-                        ResultSetNode::SelectStmt(ss) => {
-                            with2 = ss.with.clone();
-                            set_opr_list2.push(Node::new_select_stmt_by_ref(ss));
-                        }
-                        ResultSetNode::SetOprStmt(ss) => {
-                            // child setOprStmt's limit and order should also make sense
-                            // we should separate it out from other normal SetOprSelectList.
-                            with2 = ss.with.clone();
-                            limit2 = ss.limit.clone();
-                            order_by2 = ss.order_by.clone();
-                            set_opr_list2 = match ss.select_list {
-                                None => vec![],
-                                Some(v) => v.selects,
-                            }
-                        }
-                        _ => {}
-                    }
-                }
-
-                let next_set_opr_list = SetOprSelectList {
-                    with: with2,
-                    after_set_operator: Some(op),
-                    selects: set_opr_list2,
-                    limit: limit2,
-                    order_by: order_by2,
-                };
-
-                list.push(Node::new_set_opr_select_list(next_set_opr_list));
-                let mut sosl = SetOprSelectList::default();
-                sosl.selects = list;
-
-                let mut so = SetOprStmt::default();
-                so.select_list = Some(sosl);
-
-                so
-            },
-        ),
-    ))(i)
+    map(
+        with_span(rule!(#set_opr_clause ~ #set_opr_clause_list_sub+)),
+        |((first, rest), span)| {
+            let mut selects = first;
+            rest.into_iter().for_each(|clauses| selects.extend(clauses));
+
+            let mut so = SetOprStmt::default();
+            // The ORDER BY and LIMIT of an unparenthesized last select apply
+            // to the whole set operation.
+            if let Some(Node::Statement(Statement::SelectStmt(st))) = selects.last_mut() {
+                so.order_by = st.order_by.take();
+                so.limit = st.limit.take();
+            }
+            let mut ssl = SetOprSelectList::default();
+            ssl.span = span;
+            ssl.selects = selects;
+            so.select_list = Some(ssl);
+
+            so
+        },
+    )(i)
 }
 
-pub fn set_opr_stmt_with_limit_order_by(i: Input) -> IResult<SetOprStmt> {}
-
 pub fn set_opr_clause(i: Input) -> IResult<Vec<Node>> {
     alt((
-        map(rule!(#select_stmt), |(stmt)| {
+        map(rule!(#select_stmt), |stmt| {
             vec![Node::Statement(Statement::SelectStmt(Box::new(stmt)))]
         }),
-        map(rule!(#sub_select), |(stmt)| match stmt.query {
+        // A parenthesized operand is a select list of its own, spanning the
+        // parentheses.
+        map(rule!(#sub_select), |stmt| match stmt.query {
             None => {
                 vec![]
             }
             Some(v) => match v {
                 ResultSetNode::SelectStmt(s_stmt) => {
                     let mut select_list = SetOprSelectList::default();
+                    select_list.span = stmt.span;
                     select_list.selects = vec![Node::new_select_stmt_by_ref(s_stmt)];
 
                     vec![Node::SetOprSelectList(Box::new(select_list))]
@@ -144,6 +88,7 @@ pub fn set_opr_clause(i: Input) -> IResult<Vec<Node>> {
                     };
 
                     let select_list = SetOprSelectList {
+                        span: stmt.span,
                         with: so_stmt.with,
                         after_set_operator: None,
                         selects,
@@ -162,7 +107,7 @@ pub fn set_opr_clause(i: Input) -> IResult<Vec<Node>> {
 }
 
 pub fn set_opr_opt(i: Input) -> IResult<bool> {
-    map(rule!(#default_true_distinct_opt), |(b)| b)(i)
+    map(rule!(#default_true_distinct_opt), |b| b)(i)
 }
 
 pub fn set_opr(i: Input) -> IResult<SetOprType> {
@@ -174,59 +119,44 @@ pub fn set_opr(i: Input) -> IResult<SetOprType> {
                 SetOprType::UnionAll
             }
         }),
-        map(rule!(EXCEPT ~ #set_opr_opt), |(_, b)| {
-            if b {
-                SetOprType::Except
-            } else {
-                SetOprType::ExceptAll
-            }
-        }),
-        map(rule!(INTERSECT ~ #set_opr_opt), |(_, b)| {
-            if b {
-                SetOprType::Intersect
-            } else {
-                SetOprType::IntersectAll
-            }
-        }),
+        check_intersect_except(
+            true,
+            map_res(rule!(EXCEPT ~ #set_opr_opt), |(_, b)| {
+                if b {
+                    Ok(SetOprType::Except)
+                } else if i.dialect.supports_intersect_except_all() {
+                    Ok(SetOprType::ExceptAll)
+                } else {
+                    Err(nom::Err::Error(ErrorKind::Other(
+                        "EXCEPT ALL is not supported by the dialect",
+                    )))
+                }
+            }),
+        ),
+        check_intersect_except(
+            true,
+            map_res(rule!(INTERSECT ~ #set_opr_opt), |(_, b)| {
+                if b {
+                    Ok(SetOprType::Intersect)
+                } else if i.dialect.supports_intersect_except_all() {
+                    Ok(SetOprType::IntersectAll)
+                } else {
+                    Err(nom::Err::Error(ErrorKind::Other(
+                        "INTERSECT ALL is not supported by the dialect",
+                    )))
+                }
+            }),
+        ),
     ))(i)
 }
-pub fn set_opr_clause_list(i: Input) -> IResult<Vec<Node>> {
-    // 检查是否为空
-    if i.is_empty() {
-        return Ok((i, vec![]));
-    }
-
-    let mut result = Vec::<Node>::new();
-
-    // 解析第一个元素
-    let (_, first_ele) = set_opr_clause(i)?;
-    result.extend(first_ele);
-
-    // 解析后续的元素
-    let (_, rest) = map(rule!(#set_opr_clause_list_sub*), |(clauses)| clauses)(i)?;
-    for clause in rest {
-        result.extend(clause);
-    }
-
-    Ok((i, result))
-}
-
 pub fn set_opr_clause_list_sub(i: Input) -> IResult<Vec<Node>> {
     map(rule!(#set_opr ~ #set_opr_clause), |(opr, mut clauses)| {
-        if clauses.is_empty() {
-            return clauses;
-        }
-
-        let mut first_clause = clauses.get_mut(0).unwrap();
-        match first_clause {
-            Node::Statement(mut v) => match v {
-                Statement::SelectStmt(mut stmt) => {
-                    stmt.after_set_operator = Some(opr);
-                }
-                _ => {}
-            },
-            Node::SetOprSelectList(mut v) => {
-                v.after_set_operator = Some(opr);
+        match clauses.first_mut() {
+            Some(Node::Statement(Statement::SelectStmt(stmt))) => {
+                stmt.after_set_operator = Some(opr);
+            }
+            Some(Node::SetOprSelectList(list)) => {
+                list.after_set_operator = Some(opr);
             }
             _ => {}
         }
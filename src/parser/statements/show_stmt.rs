@@ -0,0 +1,81 @@
+use crate::ast::ci_str::CIStr;
+use crate::ast::expr_node::{ExprNode, PatternLikeOrIlikeExpr};
+use crate::ast::show_stmt::{ShowStmt, ShowStmtType};
+use crate::ast::statement::Statement;
+use crate::parser::common::*;
+use crate::parser::input::Input;
+use crate::parser::statements::common::{global_scope, identifier};
+use crate::parser::statements::expression::{expression, simple_expr};
+use crate::parser::statements::table_name::table_name;
+use crate::parser::statements::table_refs::partition_name_list_opt;
+use crate::parser::statements::vairable::username;
+use crate::parser::token_kind::TokenKind::*;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom_rule::rule;
+
+// See https://dev.mysql.com/doc/refman/8.0/en/show.html
+pub fn show_statement(i: Input) -> IResult<Statement> {
+    alt((
+        check_tidb_binding(
+            true,
+            map(
+                rule!(SHOW ~ #global_scope ~ BINDINGS ~ #show_like_or_where?),
+                |(_, global_scope, _, like_or_where)| {
+                    let mut stmt = ShowStmt::default();
+                    stmt.tp = ShowStmtType::Bindings;
+                    stmt.global_scope = global_scope;
+                    if let Some((pattern, where_clause)) = like_or_where {
+                        stmt.pattern = pattern;
+                        stmt.where_clause = where_clause;
+                    }
+
+                    Statement::ShowStmt(Box::new(stmt))
+                },
+            ),
+        ),
+        check_tidb_show_table_regions(
+            true,
+            map(
+                rule!(
+                    SHOW ~ TABLE ~ #table_name ~ #partition_name_list_opt
+                    ~ (INDEX ~ #identifier)? ~ REGIONS ~ (WHERE ~ #expression)?
+                ),
+                |(_, _, mut table, partition_names, index, _, where_clause)| {
+                    table.partition_names = partition_names;
+
+                    let mut stmt = ShowStmt::default();
+                    stmt.tp = ShowStmtType::Regions;
+                    stmt.table = Some(table);
+                    if let Some((_, index_name)) = index {
+                        stmt.index_name = CIStr::new(&index_name);
+                    }
+                    stmt.where_clause = where_clause.map(|(_, expr)| expr);
+
+                    Statement::ShowStmt(Box::new(stmt))
+                },
+            ),
+        ),
+        map(rule!(SHOW ~ GRANTS ~ (FOR ~ #username)?), |(_, _, user)| {
+            let mut stmt = ShowStmt::default();
+            stmt.tp = ShowStmtType::Grants;
+            stmt.user = user.map(|(_, user)| user);
+
+            Statement::ShowStmt(Box::new(stmt))
+        }),
+    ))(i)
+}
+
+// ShowLikeOrWhere is the `LIKE 'pattern'` or `WHERE expr` filter of SHOW.
+fn show_like_or_where(i: Input) -> IResult<(Option<PatternLikeOrIlikeExpr>, Option<ExprNode>)> {
+    alt((
+        map(rule!(LIKE ~ #simple_expr), |(_, pattern)| {
+            let mut like = PatternLikeOrIlikeExpr::default();
+            like.pattern = Some(Box::new(pattern));
+            like.escape = Some('\\');
+            like.is_like = true;
+            (Some(like), None)
+        }),
+        map(rule!(WHERE ~ #expression), |(_, expr)| (None, Some(expr))),
+    ))(i)
+}
@@ -0,0 +1,78 @@
+use crate::ast::ci_str::CIStr;
+use crate::ast::expr_node::ExprNode;
+use crate::ast::split_region_stmt::{SplitOption, SplitRegionStmt, SplitSyntaxOption};
+use crate::ast::statement::Statement;
+use crate::parser::common::*;
+use crate::parser::input::Input;
+use crate::parser::statements::common::identifier;
+use crate::parser::statements::expression::expression_list;
+use crate::parser::statements::num_literal::i64_num;
+use crate::parser::statements::table_name::table_name;
+use crate::parser::statements::table_refs::partition_name_list_opt;
+use crate::parser::token_kind::TokenKind::*;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom_rule::rule;
+
+// See https://docs.pingcap.com/tidb/stable/sql-statement-split-region
+pub fn split_region_statement(i: Input) -> IResult<Statement> {
+    check_tidb_split_region(
+        true,
+        map(
+            rule!(
+                SPLIT ~ #split_syntax_option ~ TABLE ~ #table_name ~ #partition_name_list_opt
+                ~ (INDEX ~ #identifier)? ~ #split_option
+            ),
+            |(_, split_syntax_opt, _, table, partition_names, index, split_opt)| {
+                let mut stmt = SplitRegionStmt::default();
+                stmt.table = table;
+                if let Some((_, index_name)) = index {
+                    stmt.index_name = CIStr::new(&index_name);
+                }
+                stmt.partition_names = partition_names;
+                stmt.split_syntax_opt = split_syntax_opt;
+                stmt.split_opt = split_opt;
+
+                Statement::SplitRegionStmt(Box::new(stmt))
+            },
+        ),
+    )(i)
+}
+
+fn split_syntax_option(i: Input) -> IResult<SplitSyntaxOption> {
+    map(
+        rule!((REGION ~ FOR)? ~ PARTITION?),
+        |(region_for, partition)| SplitSyntaxOption {
+            has_region_for: region_for.is_some(),
+            has_partition: partition.is_some(),
+        },
+    )(i)
+}
+
+fn split_option(i: Input) -> IResult<SplitOption> {
+    spanned(alt((
+        map(
+            rule!(BETWEEN ~ #row_value ~ AND ~ #row_value ~ REGIONS ~ #i64_num),
+            |(_, lower, _, upper, _, num)| {
+                let mut opt = SplitOption::default();
+                opt.lower = lower;
+                opt.upper = upper;
+                opt.num = num;
+                opt
+            },
+        ),
+        map(
+            rule!(BY ~ #comma_separated_list1(row_value)),
+            |(_, value_lists)| {
+                let mut opt = SplitOption::default();
+                opt.value_lists = value_lists;
+                opt
+            },
+        ),
+    )))(i)
+}
+
+// RowValue is a parenthesized list of column values, e.g. `(1, 'a')`.
+fn row_value(i: Input) -> IResult<Vec<ExprNode>> {
+    map(rule!("(" ~ #expression_list ~ ")"), |(_, values, _)| values)(i)
+}
@@ -1,7 +1,23 @@
 use crate::ast::statement::Statement;
 use crate::parser::common::*;
 use crate::parser::input::Input;
+use crate::parser::statements::admin_stmt::admin_statement;
+use crate::parser::statements::binding_stmt::{create_binding_statement, drop_binding_statement};
+use crate::parser::statements::call_stmt::call_statement;
+use crate::parser::statements::do_stmt::do_statement;
+use crate::parser::statements::flashback_stmt::flashback_statement;
+use crate::parser::statements::handler_stmt::handler_statement;
+use crate::parser::statements::placement_policy_stmt::{
+    alter_placement_policy_statement, create_placement_policy_statement,
+    drop_placement_policy_statement,
+};
 use crate::parser::statements::select_statement::select_statement;
+use crate::parser::statements::sequence_stmt::{
+    alter_sequence_statement, create_sequence_statement, drop_sequence_statement,
+};
+use crate::parser::statements::set_config_stmt::set_config_statement;
+use crate::parser::statements::show_stmt::show_statement;
+use crate::parser::statements::split_region_stmt::split_region_statement;
 use crate::parser::token_kind::TokenKind::EOI;
 use nom::branch::alt;
 use nom::combinator::map;
@@ -17,7 +33,23 @@ pub fn statement(i: Input) -> IResult<Statement> {
 }
 
 pub fn statement_body(i: Input) -> IResult<Statement> {
-    alt((rule!(
-        #select_statement : "`SELECT <statement>`"
-    ),))(i)
+    spanned(alt((
+        rule!(#select_statement : "`SELECT <statement>`"),
+        rule!(#call_statement : "`CALL <procedure>[(<arg>, ...)]`"),
+        rule!(#do_statement : "`DO <expr>, ...`"),
+        rule!(#handler_statement : "`HANDLER <table> {OPEN | READ | CLOSE} ...`"),
+        rule!(#create_sequence_statement : "`CREATE [OR REPLACE] SEQUENCE [IF NOT EXISTS] <sequence> ...`"),
+        rule!(#alter_sequence_statement : "`ALTER SEQUENCE [IF EXISTS] <sequence> ...`"),
+        rule!(#drop_sequence_statement : "`DROP SEQUENCE [IF EXISTS] <sequence>, ...`"),
+        rule!(#admin_statement : "`ADMIN {SHOW DDL [JOBS] | CHECK TABLE | CANCEL DDL JOBS} ...`"),
+        rule!(#create_binding_statement : "`CREATE [GLOBAL | SESSION] BINDING [FOR <statement>] USING <statement>`"),
+        rule!(#drop_binding_statement : "`DROP [GLOBAL | SESSION] BINDING FOR <statement> [USING <statement>]`"),
+        rule!(#show_statement : "`SHOW {BINDINGS | GRANTS | TABLE <table> REGIONS} ...`"),
+        rule!(#split_region_statement : "`SPLIT TABLE <table> [INDEX <index>] {BETWEEN ... REGIONS <num> | BY ...}`"),
+        rule!(#flashback_statement : "`FLASHBACK {CLUSTER | TABLE | DATABASE} ...`"),
+        rule!(#create_placement_policy_statement : "`CREATE [OR REPLACE] PLACEMENT POLICY [IF NOT EXISTS] <policy> <option> ...`"),
+        rule!(#alter_placement_policy_statement : "`ALTER PLACEMENT POLICY [IF EXISTS] <policy> <option> ...`"),
+        rule!(#drop_placement_policy_statement : "`DROP PLACEMENT POLICY [IF EXISTS] <policy>`"),
+        rule!(#set_config_statement : "`SET CONFIG {<type> | '<instance>'} <name> = <value>`"),
+    )))(i)
 }
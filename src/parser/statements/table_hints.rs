@@ -1,22 +1,428 @@
 use crate::ast::ci_str::CIStr;
-use crate::ast::table_optimizer_hint::{HintData, TableOptimizerHint};
+use crate::ast::table_optimizer_hint::{
+    HintData, HintSetVar, HintTable, HintTimeRange, TableOptimizerHint,
+};
 use crate::parser::common::*;
+use crate::parser::error::{Error, ErrorKind};
 use crate::parser::input::Input;
-use crate::parser::token_kind::TokenKind::{HintPrefix, HintSuffix};
-use nom::combinator::map;
+use crate::parser::statements::common::{identifier, length_num};
+use crate::parser::statements::vairable::at_ident_name;
+use crate::parser::token_kind::TokenKind::{
+    HintPrefix, HintSuffix, LiteralFloat, LiteralInteger, LiteralString, SingleAtIdent, EOI,
+    PARTITION, PRIMARY,
+};
+use nom::branch::alt;
+use nom::combinator::{map, opt};
+use nom::multi::many0;
+use nom::sequence::terminated;
+use nom::Slice;
 use nom_rule::rule;
 
+// See https://dev.mysql.com/doc/refman/8.0/en/optimizer-hints.html
+// and https://docs.pingcap.com/tidb/stable/optimizer-hints
+
+const NULLARY_HINTS: &[&str] = &[
+    "JOIN_FIXED_ORDER",
+    "USE_PLAN_CACHE",
+    "HASH_AGG",
+    "STREAM_AGG",
+    "AGG_TO_COP",
+    "LIMIT_TO_COP",
+    "READ_CONSISTENT_REPLICA",
+    "IGNORE_PLAN_CACHE",
+    "STRAIGHT_JOIN",
+    "SEMI_JOIN_REWRITE",
+    "NO_DECORRELATE",
+    "MPP_1PHASE_AGG",
+    "MPP_2PHASE_AGG",
+];
+
+const TABLE_LEVEL_HINTS: &[&str] = &[
+    "JOIN_ORDER",
+    "JOIN_PREFIX",
+    "JOIN_SUFFIX",
+    "BKA",
+    "NO_BKA",
+    "BNL",
+    "NO_BNL",
+    "HASH_JOIN",
+    "NO_HASH_JOIN",
+    "MERGE",
+    "NO_MERGE",
+    "DERIVED_CONDITION_PUSHDOWN",
+    "NO_DERIVED_CONDITION_PUSHDOWN",
+    // TiDB
+    "MERGE_JOIN",
+    "NO_MERGE_JOIN",
+    "BROADCAST_JOIN",
+    "SHUFFLE_JOIN",
+    "INL_JOIN",
+    "NO_INDEX_JOIN",
+    "INL_HASH_JOIN",
+    "NO_INDEX_HASH_JOIN",
+    "INL_MERGE_JOIN",
+    "NO_INDEX_MERGE_JOIN",
+    "SWAP_JOIN_INPUTS",
+    "NO_SWAP_JOIN_INPUTS",
+    "HASH_JOIN_BUILD",
+    "HASH_JOIN_PROBE",
+    "LEADING",
+    "TIDB_SMJ",
+    "TIDB_INLJ",
+    "TIDB_HJ",
+];
+
+const INDEX_LEVEL_HINTS: &[&str] = &[
+    "INDEX",
+    "NO_INDEX",
+    "INDEX_MERGE",
+    "NO_INDEX_MERGE",
+    "ORDER_INDEX",
+    "NO_ORDER_INDEX",
+    "GROUP_INDEX",
+    "NO_GROUP_INDEX",
+    "JOIN_INDEX",
+    "NO_JOIN_INDEX",
+    "MRR",
+    "NO_MRR",
+    "NO_ICP",
+    "SKIP_SCAN",
+    "NO_SKIP_SCAN",
+    "NO_RANGE_OPTIMIZATION",
+    // TiDB
+    "USE_INDEX",
+    "IGNORE_INDEX",
+    "FORCE_INDEX",
+    "USE_INDEX_MERGE",
+    "INDEX_LOOKUP_PUSHDOWN",
+];
+
+const SUBQUERY_HINTS: &[&str] = &["SEMIJOIN", "NO_SEMIJOIN", "SUBQUERY"];
+
+const SUBQUERY_STRATEGIES: &[&str] = &[
+    "DUPSWEEDOUT",
+    "FIRSTMATCH",
+    "LOOSESCAN",
+    "MATERIALIZATION",
+    "INTOEXISTS",
+];
+
+const BOOLEAN_HINTS: &[&str] = &["USE_TOJA", "USE_CASCADES"];
+
+/// Parse the optimizer hints in `/*+ ... */`.
+///
+/// Hints which are unknown or malformed don't fail the statement, they are
+/// skipped and reported as warnings, the same as MySQL.
+///
+/// Only SELECT carries the hints for now. The hints after INSERT, REPLACE,
+/// UPDATE and DELETE are kept by the tokenizer, but the tree has no such
+/// statements to parse them yet.
 pub fn table_optimizer_hints(i: Input) -> IResult<Vec<TableOptimizerHint>> {
-    map(rule!(HintPrefix ~ HintSuffix), |(_, _)| {
-        let mut hints = vec![];
-        let hint = TableOptimizerHint {
-            hint_name: CIStr::new(""),
-            hint_data: HintData::Bool(true),
-            qb_name: CIStr::new(""),
-            tables: vec![],
-            indexes: vec![],
-        };
-        hints.push(hint);
-        hints
+    map(
+        rule!(HintPrefix ~ #table_optimizer_hint_list ~ HintSuffix),
+        |(_, hints, _)| hints,
+    )(i)
+}
+
+fn table_optimizer_hint_list(i: Input) -> IResult<Vec<TableOptimizerHint>> {
+    map(
+        many0(terminated(
+            alt((table_optimizer_hint_opt, ignored_hint)),
+            opt(match_text(",")),
+        )),
+        |hints| hints.into_iter().flatten().collect(),
+    )(i)
+}
+
+fn table_optimizer_hint_opt(i: Input) -> IResult<Vec<TableOptimizerHint>> {
+    let nullary = map(
+        rule!(#hint_name(NULLARY_HINTS) ~ "(" ~ #query_block_opt ~ ")"),
+        |(name, _, qb_name, _)| new_hint(name, None, qb_name),
+    );
+    let table_level = map(
+        rule!(
+            #hint_name(TABLE_LEVEL_HINTS) ~ "(" ~ #query_block_opt
+            ~ #comma_separated_list0(hint_table) ~ ")"
+        ),
+        |(name, _, qb_name, tables, _)| {
+            let mut hint = new_hint(name, None, qb_name);
+            hint.tables = tables;
+            hint
+        },
+    );
+    // MySQL separates the table and the indexes by a space, e.g.
+    // `INDEX(t1 idx1, idx2)`, and TiDB by a comma, e.g. `USE_INDEX(t1, idx1)`.
+    let index_level = map(
+        rule!(
+            #hint_name(INDEX_LEVEL_HINTS) ~ "(" ~ #query_block_opt
+            ~ (#hint_table ~ (","? ~ #comma_separated_list1(hint_index_name))?)? ~ ")"
+        ),
+        |(name, _, qb_name, table_indexes, _)| {
+            let mut hint = new_hint(name, None, qb_name);
+            if let Some((table, indexes)) = table_indexes {
+                hint.tables = vec![table];
+                hint.indexes = indexes
+                    .map(|(_, indexes)| indexes.iter().map(|index| CIStr::new(index)).collect())
+                    .unwrap_or_default();
+            }
+            hint
+        },
+    );
+    let subquery = map(
+        rule!(
+            #hint_name(SUBQUERY_HINTS) ~ "(" ~ #query_block_opt
+            ~ #comma_separated_list0(hint_name(SUBQUERY_STRATEGIES)) ~ ")"
+        ),
+        |(name, _, qb_name, strategies, _)| {
+            // The strategies are kept as tables, the same as TiDB.
+            let mut hint = new_hint(name, None, qb_name);
+            hint.tables = strategies
+                .into_iter()
+                .map(|strategy| HintTable {
+                    db_name: CIStr::default(),
+                    table_name: strategy,
+                    qb_name: CIStr::default(),
+                    partition_list: vec![],
+                })
+                .collect();
+            hint
+        },
+    );
+    let max_execution_time = map(
+        rule!(#hint_name(&["MAX_EXECUTION_TIME"]) ~ "(" ~ #query_block_opt ~ #length_num ~ ")"),
+        |(name, _, qb_name, time, _)| new_hint(name, Some(HintData::Uint64(time)), qb_name),
+    );
+    let nth_plan = map(
+        rule!(#hint_name(&["NTH_PLAN"]) ~ "(" ~ #query_block_opt ~ #length_num ~ ")"),
+        |(name, _, qb_name, n, _)| new_hint(name, Some(HintData::Int64(n as i64)), qb_name),
+    );
+    let memory_quota = map(
+        rule!(
+            #hint_name(&["MEMORY_QUOTA"]) ~ "(" ~ #query_block_opt ~ #length_num
+            ~ #hint_name(&["MB", "GB"]) ~ ")"
+        ),
+        |(name, _, qb_name, quota, unit, _)| {
+            let unit_bytes: i64 = if unit.lower == "gb" { 1 << 30 } else { 1 << 20 };
+            let bytes = (quota as i64).checked_mul(unit_bytes).unwrap_or(i64::MAX);
+            new_hint(name, Some(HintData::Int64(bytes)), qb_name)
+        },
+    );
+    let set_var = map(
+        rule!(#hint_name(&["SET_VAR"]) ~ "(" ~ #identifier ~ "=" ~ #hint_set_var_value ~ ")"),
+        |(name, _, var_name, _, value, _)| {
+            let set_var = HintSetVar { var_name, value };
+            new_hint(name, Some(HintData::HintSetVar(set_var)), CIStr::default())
+        },
+    );
+    let resource_group = map(
+        rule!(#hint_name(&["RESOURCE_GROUP"]) ~ "(" ~ #identifier ~ ")"),
+        |(name, _, group, _)| {
+            let data = HintData::CIStr(CIStr::new(&group));
+            new_hint(name, Some(data), CIStr::default())
+        },
+    );
+    let qb_name = map(
+        rule!(#hint_name(&["QB_NAME"]) ~ "(" ~ #identifier ~ ")"),
+        |(name, _, qb_name, _)| new_hint(name, None, CIStr::new(&qb_name)),
+    );
+    let query_type = map(
+        rule!(
+            #hint_name(&["QUERY_TYPE"]) ~ "(" ~ #query_block_opt
+            ~ #hint_name(&["OLAP", "OLTP"]) ~ ")"
+        ),
+        |(name, _, qb_name, query_type, _)| {
+            new_hint(name, Some(HintData::CIStr(query_type)), qb_name)
+        },
+    );
+    let boolean = map(
+        rule!(
+            #hint_name(BOOLEAN_HINTS) ~ "(" ~ #query_block_opt
+            ~ #hint_name(&["TRUE", "FALSE"]) ~ ")"
+        ),
+        |(name, _, qb_name, value, _)| {
+            new_hint(name, Some(HintData::Bool(value.lower == "true")), qb_name)
+        },
+    );
+    let time_range = map(
+        rule!(#hint_name(&["TIME_RANGE"]) ~ "(" ~ LiteralString ~ ","? ~ LiteralString ~ ")"),
+        |(name, _, from, _, to, _)| {
+            let time_range = HintTimeRange {
+                from: from.get_trim_start_end_text('\'').to_string(),
+                to: to.get_trim_start_end_text('\'').to_string(),
+            };
+            new_hint(
+                name,
+                Some(HintData::HintTimeRange(time_range)),
+                CIStr::default(),
+            )
+        },
+    );
+
+    alt((
+        map(
+            alt((
+                nullary,
+                table_level,
+                index_level,
+                subquery,
+                max_execution_time,
+                nth_plan,
+                memory_quota,
+                set_var,
+                resource_group,
+                qb_name,
+                query_type,
+                boolean,
+                time_range,
+            )),
+            |hint| vec![hint],
+        ),
+        read_from_storage,
+    ))(i)
+}
+
+// READ_FROM_STORAGE(@qb TIFLASH[t1, t2], TIKV[t3]) is split into one hint
+// per storage type.
+fn read_from_storage(i: Input) -> IResult<Vec<TableOptimizerHint>> {
+    map(
+        rule!(
+            #hint_name(&["READ_FROM_STORAGE"]) ~ "(" ~ #query_block_opt
+            ~ #comma_separated_list1(hint_storage_type_and_tables) ~ ")"
+        ),
+        |(name, _, qb_name, storages, _)| {
+            storages
+                .into_iter()
+                .map(|(storage, tables)| {
+                    let mut hint = new_hint(
+                        name.clone(),
+                        Some(HintData::CIStr(storage)),
+                        qb_name.clone(),
+                    );
+                    hint.tables = tables;
+                    hint
+                })
+                .collect()
+        },
+    )(i)
+}
+
+fn hint_storage_type_and_tables(i: Input) -> IResult<(CIStr, Vec<HintTable>)> {
+    map(
+        rule!(
+            #hint_name(&["TIKV", "TIFLASH"]) ~ "[" ~ #comma_separated_list1(hint_table) ~ "]"
+        ),
+        |(storage, _, tables, _)| (storage, tables),
+    )(i)
+}
+
+// Skip a hint which is unknown or malformed, and report a warning.
+fn ignored_hint(i: Input) -> IResult<Vec<TableOptimizerHint>> {
+    let (mut rest, name) = any_token(i)?;
+    if name.kind == HintSuffix {
+        return Err(nom::Err::Error(Error::from_error_kind(
+            i,
+            ErrorKind::Other("expected an optimizer hint"),
+        )));
+    }
+
+    // Skip the arguments of the hint, if any.
+    if rest[0].text() == "(" {
+        let mut depth = 0;
+        while !matches!(rest[0].kind, HintSuffix | EOI) {
+            match rest[0].text() {
+                "(" => depth += 1,
+                ")" => depth -= 1,
+                _ => {}
+            }
+            rest = rest.slice(1..);
+            if depth == 0 {
+                break;
+            }
+        }
+    }
+
+    i.warnings.push(
+        transform_span(&i[..1]),
+        format!("Optimizer hint syntax error near '{}'", name.text()),
+    );
+    Ok((rest, vec![]))
+}
+
+fn new_hint(hint_name: CIStr, hint_data: Option<HintData>, qb_name: CIStr) -> TableOptimizerHint {
+    TableOptimizerHint {
+        span: None,
+        hint_name,
+        hint_data,
+        qb_name,
+        tables: vec![],
+        indexes: vec![],
+    }
+}
+
+// Match one of the hint names case-insensitively, hint names are not
+// reserved so they may be keywords or identifiers.
+fn hint_name(names: &'static [&'static str]) -> impl FnMut(Input) -> IResult<CIStr> {
+    move |i| match i.tokens.first().filter(|token| {
+        names
+            .iter()
+            .any(|name| token.text().eq_ignore_ascii_case(name))
+    }) {
+        Some(token) => Ok((i.slice(1..), CIStr::new(token.text()))),
+        _ => Err(nom::Err::Error(Error::from_error_kind(
+            i,
+            ErrorKind::Other("expected an optimizer hint name"),
+        ))),
+    }
+}
+
+// The query block of a hint or a table, e.g. `@sel_1`.
+fn query_block_opt(i: Input) -> IResult<CIStr> {
+    map(rule!(SingleAtIdent?), |qb_name| match qb_name {
+        Some(qb_name) => CIStr::new(&at_ident_name(i, &qb_name.text()[1..])),
+        None => CIStr::default(),
     })(i)
 }
+
+fn hint_table(i: Input) -> IResult<HintTable> {
+    map(
+        rule!(
+            #identifier ~ ("." ~ #identifier)? ~ #query_block_opt
+            ~ (PARTITION ~ "(" ~ #comma_separated_list1(identifier) ~ ")")?
+        ),
+        |(first, second, qb_name, partitions)| {
+            let (db_name, table_name) = match second {
+                Some((_, table_name)) => (CIStr::new(&first), CIStr::new(&table_name)),
+                None => (CIStr::default(), CIStr::new(&first)),
+            };
+            HintTable {
+                db_name,
+                table_name,
+                qb_name,
+                partition_list: partitions
+                    .map(|(_, _, partitions, _)| partitions.iter().map(|p| CIStr::new(p)).collect())
+                    .unwrap_or_default(),
+            }
+        },
+    )(i)
+}
+
+// The index of an index level hint, the primary key is named by the reserved
+// word PRIMARY, e.g. `INDEX(t1 PRIMARY)`.
+fn hint_index_name(i: Input) -> IResult<String> {
+    alt((identifier, map(rule!(PRIMARY), |t| t.text().to_string())))(i)
+}
+
+fn hint_set_var_value(i: Input) -> IResult<String> {
+    alt((
+        map(rule!(LiteralString), |s| {
+            s.get_trim_start_end_text('\'')
+                .trim_start_matches('"')
+                .trim_end_matches('"')
+                .to_string()
+        }),
+        map(rule!(LiteralInteger | LiteralFloat), |n| {
+            n.text().to_string()
+        }),
+        identifier,
+    ))(i)
+}
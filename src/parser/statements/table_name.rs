@@ -5,18 +5,12 @@ use crate::parser::common::*;
 use crate::parser::error::ErrorKind;
 use crate::parser::input::Input;
 use crate::parser::statements::common::identifier;
-use crate::parser::token_kind::TokenKind::*;
 use nom::branch::alt;
 use nom::combinator::map;
 use nom_rule::rule;
 
 pub fn table_name(i: Input) -> IResult<TableName> {
-    alt((
-        map(rule!(#identifier), |(table_name)| {
-            let mut tbl_name = TableName::default();
-            tbl_name.name = CIStr::new(&table_name);
-            tbl_name
-        }),
+    spanned(alt((
         map_res(
             rule!(#identifier ~ "." ~ #identifier),
             |(schema_name, _, table_name)| {
@@ -40,5 +34,10 @@ pub fn table_name(i: Input) -> IResult<TableName> {
             tbl_name.name = CIStr::new(&table_name);
             tbl_name
         }),
-    ))(i)
+        map(rule!(#identifier), |table_name| {
+            let mut tbl_name = TableName::default();
+            tbl_name.name = CIStr::new(&table_name);
+            tbl_name
+        }),
+    )))(i)
 }
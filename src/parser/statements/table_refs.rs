@@ -0,0 +1,358 @@
+use crate::ast::ci_str::CIStr;
+use crate::ast::column_name::ColumnName;
+use crate::ast::expr_node::ExprNode;
+use crate::ast::result_set_node::ResultSetNode;
+use crate::ast::spanned::Spanned;
+use crate::ast::system_time::{HistoryPoint, SystemTimeClause};
+use crate::ast::table_refs_clause::{JoinType, TableRefsClause};
+use crate::ast::table_sample::{SampleClauseUnitType, SampleMethodType, TableSample};
+use crate::ast::table_source::TableSource;
+use crate::common::span::merge_span;
+use crate::parser::common::*;
+use crate::parser::input::Input;
+use crate::parser::statements::common::identifier;
+use crate::parser::statements::expression::{bit_expr, expression};
+use crate::parser::statements::index_hint::index_hint_list_opt;
+use crate::parser::statements::json_table::json_table;
+use crate::parser::statements::select_statement::select_stmt;
+use crate::parser::statements::set_opr_stmt::set_opr_stmt;
+use crate::parser::statements::table_name::table_name;
+use crate::parser::token_kind::TokenKind::*;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom_rule::rule;
+
+// JoinTail is the part of a join after its left table, e.g. `LEFT JOIN t2 ON ...`.
+struct JoinTail {
+    right: ResultSetNode,
+    join_type: JoinType,
+    on: Option<ExprNode>,
+    using: Vec<ColumnName>,
+    natural_join: bool,
+    straight_join: bool,
+}
+
+impl JoinTail {
+    fn new(right: ResultSetNode, join_type: JoinType) -> Self {
+        JoinTail {
+            right,
+            join_type,
+            on: None,
+            using: vec![],
+            natural_join: false,
+            straight_join: false,
+        }
+    }
+
+    fn with_condition(mut self, condition: Option<JoinCondition>) -> Self {
+        match condition {
+            Some(JoinCondition::On(on)) => self.on = Some(on),
+            Some(JoinCondition::Using(using)) => self.using = using,
+            None => {}
+        }
+        self
+    }
+
+    fn join(self, left: ResultSetNode) -> ResultSetNode {
+        let span = merge_span(left.span(), self.right.span());
+        let mut join = TableRefsClause::new_join(left, Some(self.right), self.join_type);
+        join.span = span;
+        if let Some(on) = self.on {
+            join.on = Box::new(on);
+        }
+        join.using = self.using;
+        join.natural_join = self.natural_join;
+        join.straight_join = self.straight_join;
+        ResultSetNode::TableRefsClause(Box::new(join))
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+enum JoinCondition {
+    On(ExprNode),
+    Using(Vec<ColumnName>),
+}
+
+// TableRefs is the FROM clause of a query, the comma separated table
+// references are cross joined.
+pub fn table_refs(i: Input) -> IResult<TableRefsClause> {
+    spanned(map(comma_separated_list1(table_ref), |refs| {
+        let mut refs = refs.into_iter();
+        let first = refs.next().unwrap();
+        let node = refs.fold(first, |left, right| {
+            JoinTail::new(right, JoinType::CrossJoin).join(left)
+        });
+        match node {
+            ResultSetNode::TableRefsClause(join) => *join,
+            node => TableRefsClause::new_join(node, None, JoinType::CrossJoin),
+        }
+    }))(i)
+}
+
+pub fn table_ref(i: Input) -> IResult<ResultSetNode> {
+    let tail = with_span(join_tail);
+    spanned(map(rule!(#table_factor ~ #tail*), |(left, tails)| {
+        tails.into_iter().fold(left, |left, (tail, span)| {
+            let span = merge_span(left.span(), span);
+            let mut join = tail.join(left);
+            join.set_span(span);
+            join
+        })
+    }))(i)
+}
+
+pub fn table_factor(i: Input) -> IResult<ResultSetNode> {
+    let table_source = |source, as_name| {
+        ResultSetNode::TableSource(Box::new(TableSource {
+            span: None,
+            source: Box::new(source),
+            as_name,
+        }))
+    };
+
+    spanned(alt((
+        check_json_table(
+            true,
+            map(
+                rule!(#json_table ~ #table_alias),
+                move |(json_table, as_name)| {
+                    table_source(ResultSetNode::JsonTable(Box::new(json_table)), as_name)
+                },
+            ),
+        ),
+        check_lateral(
+            true,
+            map(
+                rule!(LATERAL ~ #derived_table ~ #table_alias),
+                move |(_, query, as_name)| {
+                    table_source(ResultSetNode::Lateral(Box::new(query)), as_name)
+                },
+            ),
+        ),
+        map(
+            rule!(#derived_table ~ #table_alias),
+            move |(query, as_name)| table_source(query, as_name),
+        ),
+        map(
+            rule!(
+                #table_name ~ #partition_name_list_opt ~ #system_time_clause? ~ #table_as_name_opt
+                ~ #as_of_clause? ~ #index_hint_list_opt ~ #table_sample?
+            ),
+            move |(
+                mut tbl_name,
+                partition_names,
+                system_time,
+                as_name,
+                as_of,
+                index_hints,
+                table_sample,
+            )| {
+                tbl_name.partition_names = partition_names;
+                tbl_name.system_time = system_time.map(Box::new);
+                tbl_name.as_of = as_of.map(Box::new);
+                tbl_name.index_hints = index_hints;
+                tbl_name.table_sample = table_sample.map(Box::new);
+                table_source(ResultSetNode::TableName(Box::new(tbl_name)), as_name)
+            },
+        ),
+        map(rule!("(" ~ #table_refs ~ ")"), |(_, mut refs, _)| {
+            refs.explicit_parens = true;
+            ResultSetNode::TableRefsClause(Box::new(refs))
+        }),
+    )))(i)
+}
+
+// PartitionNameListOpt is the explicit partition selection, e.g. `t PARTITION (p0, p1)`.
+pub fn partition_name_list_opt(i: Input) -> IResult<Vec<CIStr>> {
+    map(
+        rule!((PARTITION ~ "(" ~ #comma_separated_list1(identifier) ~ ")")?),
+        |partitions| {
+            partitions
+                .map(|(_, _, names, _)| names.iter().map(|name| CIStr::new(name)).collect())
+                .unwrap_or_default()
+        },
+    )(i)
+}
+
+// DerivedTable is a subquery in the FROM clause, e.g. `(SELECT a FROM t) AS dt`.
+fn derived_table(i: Input) -> IResult<ResultSetNode> {
+    spanned(alt((
+        map(rule!("(" ~ #select_stmt ~ ")"), |(_, stmt, _)| {
+            ResultSetNode::SelectStmt(Box::new(stmt))
+        }),
+        map(rule!("(" ~ #set_opr_stmt ~ ")"), |(_, stmt, _)| {
+            ResultSetNode::SetOprStmt(Box::new(stmt))
+        }),
+    )))(i)
+}
+
+// TableAlias is the alias required by derived tables and table functions.
+fn table_alias(i: Input) -> IResult<CIStr> {
+    map(rule!(AS? ~ #identifier), |(_, name)| CIStr::new(&name))(i)
+}
+
+pub fn table_as_name_opt(i: Input) -> IResult<CIStr> {
+    map(rule!((AS? ~ #identifier)?), |as_name| {
+        as_name
+            .map(|(_, name)| CIStr::new(&name))
+            .unwrap_or_default()
+    })(i)
+}
+
+// SystemTimeClause is `FOR SYSTEM_TIME {AS OF | BETWEEN .. AND | FROM .. TO | ALL}`
+// that queries the history of a system-versioned table, e.g.
+// `t FOR SYSTEM_TIME AS OF TIMESTAMP '2016-10-09 08:07:06'`. It's a MariaDB
+// extension.
+pub fn system_time_clause(i: Input) -> IResult<SystemTimeClause> {
+    let clause = alt((
+        map(rule!(AS ~ OF ~ #history_point), |(_, _, point)| {
+            SystemTimeClause::AsOf(point)
+        }),
+        map(
+            rule!(BETWEEN ~ #history_point ~ AND ~ #history_point),
+            |(_, start, _, end)| SystemTimeClause::Between(start, end),
+        ),
+        map(
+            rule!(FROM ~ #history_point ~ TO ~ #history_point),
+            |(_, start, _, end)| SystemTimeClause::FromTo(start, end),
+        ),
+        map(rule!(ALL), |_| SystemTimeClause::All),
+    ));
+    check_mariadb_system_time(
+        true,
+        map(rule!(FOR ~ SYSTEM_TIME ~ #clause), |(_, _, clause)| clause),
+    )(i)
+}
+
+fn history_point(i: Input) -> IResult<HistoryPoint> {
+    spanned(map(
+        rule!((TIMESTAMP | TRANSACTION)? ~ #bit_expr),
+        |(unit, expr)| HistoryPoint {
+            span: None,
+            transaction: unit.is_some_and(|t| t.kind == TRANSACTION),
+            expr: Box::new(expr),
+        },
+    ))(i)
+}
+
+// AsOfClause reads the table as it was at a point in time (stale read), e.g.
+// `t AS OF TIMESTAMP NOW() - INTERVAL 5 SECOND`. It's a TiDB extension.
+pub fn as_of_clause(i: Input) -> IResult<ExprNode> {
+    spanned(check_tidb_as_of_timestamp(
+        true,
+        map(rule!(AS ~ OF ~ TIMESTAMP ~ #expression), |(_, _, _, ts)| ts),
+    ))(i)
+}
+
+// TableSample is `TABLESAMPLE [method] (n [PERCENT|ROWS]) [REPEATABLE(seed)]`,
+// it's a TiDB extension.
+pub fn table_sample(i: Input) -> IResult<TableSample> {
+    spanned(check_tidb_table_sample(
+        true,
+        map(
+            rule!(
+                TABLESAMPLE ~ #table_sample_method ~ "(" ~ (#expression ~ #table_sample_unit)? ~ ")"
+                ~ (REPEATABLE ~ "(" ~ #expression ~ ")")?
+            ),
+            |(_, sample_method, _, expr_unit, _, repeatable)| {
+                let (expr, sample_clause_unit) = expr_unit.unwrap_or((
+                    ExprNode::Default,
+                    SampleClauseUnitType::SampleClauseUnitTypeDefault,
+                ));
+                TableSample {
+                    span: None,
+                    sample_method,
+                    expr: Box::new(expr),
+                    sample_clause_unit,
+                    repeatable_seed: Box::new(
+                        repeatable
+                            .map(|(_, _, seed, _)| seed)
+                            .unwrap_or(ExprNode::Default),
+                    ),
+                }
+            },
+        ),
+    ))(i)
+}
+
+fn table_sample_method(i: Input) -> IResult<SampleMethodType> {
+    map(
+        rule!((SYSTEM | BERNOULLI | REGIONS)?),
+        |method| match method {
+            Some(t) if t.kind == SYSTEM => SampleMethodType::SampleMethodTypeSystem,
+            Some(t) if t.kind == BERNOULLI => SampleMethodType::SampleMethodTypeBernoulli,
+            Some(_) => SampleMethodType::SampleMethodTypeTiDBRegion,
+            None => SampleMethodType::SampleMethodTypeNone,
+        },
+    )(i)
+}
+
+fn table_sample_unit(i: Input) -> IResult<SampleClauseUnitType> {
+    map(rule!((ROWS | PERCENT)?), |unit| match unit {
+        Some(t) if t.kind == ROWS => SampleClauseUnitType::SampleClauseUnitTypeRow,
+        Some(_) => SampleClauseUnitType::SampleClauseUnitTypePercent,
+        None => SampleClauseUnitType::SampleClauseUnitTypeDefault,
+    })(i)
+}
+
+fn join_tail(i: Input) -> IResult<JoinTail> {
+    let cross_join = map(
+        rule!((INNER | CROSS)? ~ JOIN ~ #table_factor ~ #join_condition?),
+        |(_, _, right, condition)| {
+            JoinTail::new(right, JoinType::CrossJoin).with_condition(condition)
+        },
+    );
+    let straight_join = map(
+        rule!(STRAIGHT_JOIN ~ #table_factor ~ (ON ~ #expression)?),
+        |(_, right, on)| {
+            let mut tail = JoinTail::new(right, JoinType::CrossJoin);
+            tail.on = on.map(|(_, on)| on);
+            tail.straight_join = true;
+            tail
+        },
+    );
+    let outer_join = map(
+        rule!(#outer_join_type ~ JOIN ~ #table_factor ~ #join_condition),
+        |(join_type, _, right, condition)| {
+            JoinTail::new(right, join_type).with_condition(Some(condition))
+        },
+    );
+    let natural_join = map(
+        rule!(NATURAL ~ #outer_join_type? ~ INNER? ~ JOIN ~ #table_factor),
+        |(_, join_type, _, _, right)| {
+            let mut tail = JoinTail::new(right, join_type.unwrap_or(JoinType::CrossJoin));
+            tail.natural_join = true;
+            tail
+        },
+    );
+
+    alt((cross_join, straight_join, outer_join, natural_join))(i)
+}
+
+fn outer_join_type(i: Input) -> IResult<JoinType> {
+    alt((
+        map(rule!(LEFT ~ OUTER?), |_| JoinType::LeftJoin),
+        map(rule!(RIGHT ~ OUTER?), |_| JoinType::RightJoin),
+    ))(i)
+}
+
+fn join_condition(i: Input) -> IResult<JoinCondition> {
+    alt((
+        map(rule!(ON ~ #expression), |(_, on)| JoinCondition::On(on)),
+        map(
+            rule!(USING ~ "(" ~ #comma_separated_list1(identifier) ~ ")"),
+            |(_, _, names, _)| {
+                JoinCondition::Using(
+                    names
+                        .iter()
+                        .map(|name| {
+                            let mut column = ColumnName::default();
+                            column.name = CIStr::new(name);
+                            column
+                        })
+                        .collect(),
+                )
+            },
+        ),
+    ))(i)
+}
@@ -1,47 +1,91 @@
-use crate::ast::expr_node::{ExprNode, ValueExpr, VariableExpr};
+use crate::ast::expr_node::{ExprNode, VariableExpr, VariableScope};
+use crate::ast::user_identity::UserIdentity;
+use crate::common::escape::unescape_string;
 use crate::parser::common::*;
 use crate::parser::input::Input;
-use crate::parser::token_kind::TokenKind::{DoubleAtIdent, SingleAtIdent};
+use crate::parser::statements::common::{identifier, string_value};
+use crate::parser::token_kind::TokenKind::{DoubleAtIdent, SingleAtIdent, CURRENT_USER};
+use nom::branch::alt;
 use nom::combinator::map;
 use nom_rule::rule;
 
 pub fn vairable(i: Input) -> IResult<ExprNode> {
-    map(rule!(#system_variable | #user_variable), |(e)| e)
+    map(rule!(#system_variable | #user_variable), |e| e)(i)
 }
 
 pub fn system_variable(i: Input) -> IResult<ExprNode> {
-    map(rule!(DoubleAtIdent), |(t)| {
-        let v = t.text().to_lowercase();
-        let (name, is_global, explicit_scope) = if v.starts_with("@@global.") {
-            (v.trim_start_matches("@@global.").to_string(), true, true)
-        } else if v.starts_with("@@session.") {
-            (v.trim_start_matches("@@global.").to_string(), false, true)
-        } else if v.starts_with("@@local.") {
-            (v.trim_start_matches("@@local.").to_string(), false, true)
-        } else if v.starts_with("@@") {
-            (v.trim_start_matches("@@").to_string(), false, false)
-        } else {
-            (v, false, true)
+    spanned(map(rule!(DoubleAtIdent), |t| {
+        let v = &t.text()[2..];
+        let (name, scope) = match v.split_once('.') {
+            Some((scope, name)) => match scope.to_lowercase().as_str() {
+                "global" => (name, VariableScope::Global),
+                "session" | "local" => (name, VariableScope::Session),
+                "persist" => (name, VariableScope::Persist),
+                "persist_only" => (name, VariableScope::PersistOnly),
+                _ => (v, VariableScope::None),
+            },
+            None => (v, VariableScope::None),
         };
 
+        // `@@persist.x` and `@@persist_only.x` are set globally too.
         ExprNode::VariableExpr(VariableExpr {
-            name,
-            is_global,
+            span: None,
+            name: at_ident_name(i, name).to_lowercase(),
+            is_global: matches!(
+                scope,
+                VariableScope::Global | VariableScope::Persist | VariableScope::PersistOnly
+            ),
             is_system: true,
-            explicit_scope,
+            explicit_scope: scope != VariableScope::None,
+            scope,
             value: None,
         })
-    })(i)
+    }))(i)
 }
 
 pub fn user_variable(i: Input) -> IResult<ExprNode> {
-    map(rule!(SingleAtIdent), |(t)| {
+    spanned(map(rule!(SingleAtIdent), |t| {
         ExprNode::VariableExpr(VariableExpr {
-            name: t.text().trim_start_matches("@").to_string(),
+            span: None,
+            name: at_ident_name(i, &t.text()[1..]),
             is_global: false,
             is_system: false,
             explicit_scope: false,
+            scope: VariableScope::None,
             value: None,
         })
-    })(i)
+    }))(i)
+}
+
+// Username is an account name, e.g. `'u'@'%'`, `u@localhost` or
+// `CURRENT_USER()`, the hostname is `%` if omitted.
+pub fn username(i: Input) -> IResult<UserIdentity> {
+    spanned(alt((
+        map(rule!(CURRENT_USER ~ ("(" ~ ")")?), |_| UserIdentity {
+            current_user: true,
+            ..Default::default()
+        }),
+        map(
+            rule!((#string_value | #identifier) ~ SingleAtIdent?),
+            |(username, host)| UserIdentity {
+                span: None,
+                username,
+                hostname: match host {
+                    Some(t) => at_ident_name(i, &t.text()[1..]).to_lowercase(),
+                    None => "%".to_string(),
+                },
+                current_user: false,
+            },
+        ),
+    )))(i)
+}
+
+// AtIdentName is the name after `@` or `@@`, without its backquotes or
+// string quotes.
+pub fn at_ident_name(i: Input, name: &str) -> String {
+    match name.chars().next() {
+        Some('`') => name[1..name.len() - 1].replace("``", "`"),
+        Some('\'' | '"') => unescape_string(name, i.sql_mode.has_no_backslash_escapes_mode()),
+        _ => name.to_string(),
+    }
 }
@@ -1,6 +1,9 @@
 use crate::common::error::ParseError;
 use crate::common::error::Result;
+use crate::common::escape::quoted_string_len;
 use crate::common::span::Range;
+use crate::mysql::consts::SqlMode;
+use crate::parser::input::Dialect;
 use crate::parser::token_kind::TokenKind;
 use logos::{Lexer, Logos};
 
@@ -26,10 +29,20 @@ impl<'a> Token<'a> {
         &self.source[std::ops::Range::from(self.span)]
     }
 
+    /// The name of an identifier, the backticks or the double quotes of
+    /// ANSI_QUOTES are removed and the doubled quotes are unescaped.
+    pub fn ident_text(&self) -> String {
+        let text = self.text();
+        match text.chars().next() {
+            Some(quote @ ('`' | '"')) if text.len() >= 2 => {
+                text[1..text.len() - 1].replace(&format!("{}{}", quote, quote), &quote.to_string())
+            }
+            _ => text.to_string(),
+        }
+    }
+
     pub fn get_trim_start_end_text(&self, sep: char) -> &'a str {
-        self.text()
-            .trim_start_matches(|c| c == sep)
-            .trim_end_matches(|c| c == sep)
+        self.text().trim_start_matches(sep).trim_end_matches(sep)
     }
 }
 
@@ -39,20 +52,146 @@ impl<'a> std::fmt::Debug for Token<'a> {
     }
 }
 
+/// The server version that executable comments are checked against by
+/// default, in the `MMmmpp` form used by `/*!NNNNN ... */` (8.0.40).
+pub const DEFAULT_SERVER_VERSION: u32 = 80040;
+
 pub struct Tokenizer<'a> {
     source: &'a str,
     lexer: Lexer<'a, TokenKind>,
     prev_token: Option<TokenKind>,
     eoi: bool,
+    // The target server of executable comments, `/*M!` ones are read by
+    // MariaDB only.
+    dialect: Dialect,
+    // The target server version of executable comments.
+    server_version: u32,
+    // Whether the tokens come from the content of an executable comment.
+    in_exec_comment: bool,
+    // Whether the tokens come from an optimizer hint.
+    in_hint: bool,
+    // The sql_mode that changes how string literals are read.
+    sql_mode: SqlMode,
 }
 
 impl<'a> Tokenizer<'a> {
     pub fn new(source: &'a str) -> Self {
+        Self::with_server_version(source, DEFAULT_SERVER_VERSION)
+    }
+
+    /// Create a tokenizer which reads `/*!NNNNN ... */` the way the server of
+    /// `server_version` does: the content is inlined if NNNNN <= server_version,
+    /// and skipped otherwise.
+    pub fn with_server_version(source: &'a str, server_version: u32) -> Self {
+        Self::with_sql_mode(source, server_version, SqlMode::default())
+    }
+
+    /// Create a tokenizer which also honours `sql_mode`, e.g. `\` is an
+    /// ordinary character of string literals with NO_BACKSLASH_ESCAPES.
+    pub fn with_sql_mode(source: &'a str, server_version: u32, sql_mode: SqlMode) -> Self {
+        Self::with_dialect_version(source, Dialect::default(), server_version, sql_mode)
+    }
+
+    /// Create a tokenizer which reads executable comments as `dialect` does,
+    /// `/*M!NNNNNN ... */` is inlined by MariaDB only and checked against the
+    /// MariaDB version, it is a plain comment for the other servers.
+    pub fn with_dialect(source: &'a str, dialect: Dialect, sql_mode: SqlMode) -> Self {
+        Self::with_dialect_version(source, dialect, dialect.server_version(), sql_mode)
+    }
+
+    fn with_dialect_version(
+        source: &'a str,
+        dialect: Dialect,
+        server_version: u32,
+        sql_mode: SqlMode,
+    ) -> Self {
         Tokenizer {
             source,
             lexer: TokenKind::lexer(source),
             eoi: false,
             prev_token: None,
+            dialect,
+            server_version,
+            in_exec_comment: false,
+            in_hint: false,
+            sql_mode,
+        }
+    }
+
+    // Read the quoted string at the start of the current token again, the
+    // lexer treats `\'` as an escaped quote which it isn't with
+    // NO_BACKSLASH_ESCAPES, and `"` quotes identifiers with ANSI_QUOTES. The
+    // lexer continues after the quoted string then.
+    fn rescan_quoted(&mut self) -> Option<Result<Token<'a>>> {
+        let no_backslash_escapes = self.sql_mode.has_no_backslash_escapes_mode();
+        let ansi_quotes = self.sql_mode.has_ansi_quotes_mode();
+        let start = self.lexer.span().start;
+        let rest = &self.source[start..];
+        let (kind, prefix) = if rest.starts_with('"') && ansi_quotes {
+            (TokenKind::Ident, 0)
+        } else if !no_backslash_escapes {
+            return None;
+        } else if rest.starts_with(['n', 'N']) && rest[1..].starts_with('\'') {
+            (TokenKind::LiteralNationalString, 1)
+        } else if rest.starts_with(['\'', '"']) {
+            (TokenKind::LiteralString, 0)
+        } else {
+            return None;
+        };
+        let end = match quoted_string_len(&rest[prefix..], true) {
+            Some(len) => start + prefix + len,
+            None => {
+                return Some(Err(ParseError(
+                    Some((start..self.source.len()).into()),
+                    "unterminated quoted string".to_string(),
+                )))
+            }
+        };
+
+        let mut lexer = TokenKind::lexer(self.source);
+        lexer.bump(end);
+        self.lexer = lexer;
+        self.prev_token = Some(kind);
+        Some(Ok(Token {
+            source: self.source,
+            kind,
+            span: (start..end).into(),
+            pos: 0,
+        }))
+    }
+
+    // The names of builtin functions like COUNT are keywords only if `(`
+    // follows them, spaces may come between with IGNORE_SPACE. They are
+    // identifiers otherwise, e.g. `SELECT count FROM t`.
+    fn is_function_call(&self) -> bool {
+        let rest = self.lexer.remainder();
+        if self.sql_mode.has_ignore_space_mode() {
+            rest.trim_start().starts_with('(')
+        } else {
+            rest.starts_with('(')
+        }
+    }
+
+    // Handle the prefix of an executable comment, the whole comment is skipped
+    // if it requires a newer server, or if it is a MariaDB one and the target
+    // isn't MariaDB.
+    fn enter_exec_comment(&mut self) {
+        let prefix = self.lexer.slice().trim_start_matches("/*");
+        let is_mariadb_comment = prefix.starts_with('M');
+        let version = prefix.trim_start_matches('M').trim_start_matches('!');
+        let enabled = (!is_mariadb_comment || self.dialect.is_mariadb())
+            && version
+                .parse::<u32>()
+                .map_or(true, |version| version <= self.server_version);
+        if enabled {
+            self.in_exec_comment = true;
+        } else {
+            let remainder = self.lexer.remainder();
+            let len = remainder
+                .find("*/")
+                .map(|end| end + "*/".len())
+                .unwrap_or(remainder.len());
+            self.lexer.bump(len);
         }
     }
 
@@ -73,6 +212,9 @@ impl<'a> Iterator for Tokenizer<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         match self.lexer.next() {
             Some(rs) => {
+                if let Some(token) = self.rescan_quoted() {
+                    return Some(token);
+                }
                 match rs {
                     // Ok(TokenKind::Error) => {
                     //     let span = Some((self.lexer.span().start..self.source.len()).into());
@@ -81,7 +223,21 @@ impl<'a> Iterator for Tokenizer<'a> {
                     //         "unable to recognize the rest tokens(token custom error)".to_string(),
                     //     )))
                     // }
+                    Ok(TokenKind::ExecCommentPrefix) => {
+                        self.enter_exec_comment();
+                        self.next()
+                    }
+                    // The end of an inlined executable comment.
+                    Ok(TokenKind::HintSuffix) if self.in_exec_comment && !self.in_hint => {
+                        self.in_exec_comment = false;
+                        self.next()
+                    }
                     Ok(kind) => {
+                        let kind = if kind.is_builtin_function_name() && !self.is_function_call() {
+                            TokenKind::Ident
+                        } else {
+                            kind
+                        };
                         // Skip hint-like comment that is in the invalid position.
                         if !matches!(
                             self.prev_token,
@@ -94,6 +250,7 @@ impl<'a> Iterator for Tokenizer<'a> {
                             )
                         ) && kind == TokenKind::HintPrefix
                         {
+                            self.in_hint = true;
                             loop {
                                 match self.next() {
                                     // Hint-like comment ended. Return the next token.
@@ -111,6 +268,11 @@ impl<'a> Iterator for Tokenizer<'a> {
                                 }
                             }
                         }
+                        match kind {
+                            TokenKind::HintPrefix => self.in_hint = true,
+                            TokenKind::HintSuffix => self.in_hint = false,
+                            _ => {}
+                        }
                         self.prev_token = Some(kind);
                         Some(Ok(Token {
                             source: self.source,
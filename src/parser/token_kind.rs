@@ -13,17 +13,33 @@ pub enum TokenKind {
     #[regex(r"--[^\n\f]*", logos::skip)]
     Comment,
 
-    #[regex(r"/\*[^\+]([^\*]|(\*[^/]))*\*/", logos::skip)]
+    #[regex(r"/\*([^\+!M]|M[^!])([^\*]|(\*[^/]))*\*/", logos::skip)]
     CommentBlock,
 
-    #[regex(r#"[_a-zA-Z][_$a-zA-Z0-9]*"#)]
-    #[regex(r#"`[_a-zA-Z][_$a-zA-Z0-9]*`"#)]
+    // `/*!`, `/*M!` and their versioned forms like `/*!50700`. The tokenizer
+    // either inlines or skips the comment depending on the server version.
+    #[regex(r"/\*M?!([0-9]{5,6})?")]
+    ExecCommentPrefix,
+
+    // Unquoted identifiers may start with digits but aren't only digits, and
+    // may contain U+0080..U+FFFF. Quoted ones contain any character, with
+    // the quote doubled, e.g. `a``b`.
+    #[regex(r#"[0-9]*[_a-zA-Z\u{80}-\u{FFFF}][_$a-zA-Z0-9\u{80}-\u{FFFF}]*"#)]
+    #[regex(r#"`([^`]|``)+`"#)]
     Ident,
 
-    #[regex(r#"@[_a-zA-Z][_$a-zA-Z0-9]*"#)]
+    // User variables and the host of account names, e.g. `@a.b`, `@`my var``,
+    // `@'x'` or `@"x"`.
+    #[regex(r#"@[_$.a-zA-Z0-9\u{80}-\u{FFFF}]+"#)]
+    #[regex(r#"@`([^`]|``)+`"#)]
+    #[regex(r#"@'([^'\\]|\\.|'')*'"#)]
+    #[regex(r#"@"([^"\\]|\\.|"")*""#)]
     SingleAtIdent,
 
-    #[regex(r#"@@[_a-zA-Z][_$a-zA-Z0-9]*"#)]
+    // System variables with an optional scope, e.g. `@@session.sql_mode` or
+    // `@@global.`x``.
+    #[regex(r#"@@[_$.a-zA-Z0-9\u{80}-\u{FFFF}]+"#)]
+    #[regex(r#"@@([a-zA-Z_]+\.)?`([^`]|``)+`"#)]
     DoubleAtIdent,
 
     #[regex(r#"\$[_a-zA-Z][_$a-zA-Z0-9]*"#)]
@@ -36,14 +52,19 @@ pub enum TokenKind {
     #[regex(r#"'([^'\\]|\\.|'')*'"#)]
     LiteralString,
 
+    #[regex(r#"[nN]'([^'\\]|\\.|'')*'"#)]
+    LiteralNationalString,
+
     #[regex(r"0[xX][a-fA-F0-9]+")]
+    #[regex(r"[xX]'([a-fA-F0-9][a-fA-F0-9])*'")]
     LiteralHex,
 
     #[regex(r"0[bB][01]+")]
     #[regex(r"0[bB]'[01]+'")]
+    #[regex(r"[bB]'[01]*'")]
     LiteralBit,
 
-    #[regex(r"[0-9]+(_|[0-9])*")]
+    #[regex(r"[0-9]+")]
     LiteralInteger,
 
     #[regex(r"[0-9]+[eE][+-]?[0-9]+")]
@@ -422,6 +443,8 @@ pub enum TokenKind {
     LAG,
     #[token("LAST_VALUE", ignore(ascii_case))]
     LAST_VALUE,
+    #[token("LATERAL", ignore(ascii_case))]
+    LATERAL,
     #[token("LEAD", ignore(ascii_case))]
     LEAD,
     #[token("LEADING", ignore(ascii_case))]
@@ -866,6 +889,8 @@ pub enum TokenKind {
     DUPLICATE,
     #[token("DYNAMIC", ignore(ascii_case))]
     DYNAMIC,
+    #[token("EMPTY", ignore(ascii_case))]
+    EMPTY,
     #[token("ENABLE", ignore(ascii_case))]
     ENABLE,
     #[token("ENABLED", ignore(ascii_case))]
@@ -936,6 +961,10 @@ pub enum TokenKind {
     FUNCTION,
     #[token("GENERAL", ignore(ascii_case))]
     GENERAL,
+    #[token("GEOMETRY", ignore(ascii_case))]
+    GEOMETRY,
+    #[token("GEOMETRYCOLLECTION", ignore(ascii_case))]
+    GEOMETRYCOLLECTION,
     #[token("GLOBAL", ignore(ascii_case))]
     GLOBAL,
     #[token("GRANTS", ignore(ascii_case))]
@@ -988,6 +1017,8 @@ pub enum TokenKind {
     ISSUER,
     #[token("JSON", ignore(ascii_case))]
     JSON,
+    #[token("JSON_TABLE", ignore(ascii_case))]
+    JSON_TABLE,
     #[token("KEY_BLOCK_SIZE", ignore(ascii_case))]
     KEY_BLOCK_SIZE,
     #[token("LABELS", ignore(ascii_case))]
@@ -1002,6 +1033,8 @@ pub enum TokenKind {
     LAST_BACKUP,
     #[token("LESS", ignore(ascii_case))]
     LESS,
+    #[token("LINESTRING", ignore(ascii_case))]
+    LINESTRING,
     #[token("LEVEL", ignore(ascii_case))]
     LEVEL,
     #[token("LIST", ignore(ascii_case))]
@@ -1056,12 +1089,20 @@ pub enum TokenKind {
     MODIFY,
     #[token("MONTH", ignore(ascii_case))]
     MONTH,
+    #[token("MULTILINESTRING", ignore(ascii_case))]
+    MULTILINESTRING,
+    #[token("MULTIPOINT", ignore(ascii_case))]
+    MULTIPOINT,
+    #[token("MULTIPOLYGON", ignore(ascii_case))]
+    MULTIPOLYGON,
     #[token("NAMES", ignore(ascii_case))]
     NAMES,
     #[token("NATIONAL", ignore(ascii_case))]
     NATIONAL,
     #[token("NCHAR", ignore(ascii_case))]
     NCHAR,
+    #[token("NESTED", ignore(ascii_case))]
+    NESTED,
     #[token("NEVER", ignore(ascii_case))]
     NEVER,
     #[token("NEXT", ignore(ascii_case))]
@@ -1110,6 +1151,8 @@ pub enum TokenKind {
     OPEN,
     #[token("OPTIONAL", ignore(ascii_case))]
     OPTIONAL,
+    #[token("ORDINALITY", ignore(ascii_case))]
+    ORDINALITY,
     #[token("PACK_KEYS", ignore(ascii_case))]
     PACK_KEYS,
     #[token("PAGE", ignore(ascii_case))]
@@ -1126,6 +1169,8 @@ pub enum TokenKind {
     PASSWORD,
     #[token("PASSWORD_LOCK_TIME", ignore(ascii_case))]
     PASSWORD_LOCK_TIME,
+    #[token("PATH", ignore(ascii_case))]
+    PATH,
     #[token("PAUSE", ignore(ascii_case))]
     PAUSE,
     #[token("PERCENT", ignore(ascii_case))]
@@ -1140,12 +1185,18 @@ pub enum TokenKind {
     POINT,
     #[token("POLICY", ignore(ascii_case))]
     POLICY,
+    #[token("POLYGON", ignore(ascii_case))]
+    POLYGON,
     #[token("PRECEDING", ignore(ascii_case))]
     PRECEDING,
     #[token("PREPARE", ignore(ascii_case))]
     PREPARE,
     #[token("PRESERVE", ignore(ascii_case))]
     PRESERVE,
+    #[token("PREV", ignore(ascii_case))]
+    PREV,
+    #[token("PREVIOUS", ignore(ascii_case))]
+    PREVIOUS,
     #[token("PRE_SPLIT_REGIONS", ignore(ascii_case))]
     PRE_SPLIT_REGIONS,
     #[token("PRIVILEGES", ignore(ascii_case))]
@@ -1282,6 +1333,8 @@ pub enum TokenKind {
     SNAPSHOT,
     #[token("SOME", ignore(ascii_case))]
     SOME,
+    #[token("SOUNDS", ignore(ascii_case))]
+    SOUNDS,
     #[token("SOURCE", ignore(ascii_case))]
     SOURCE,
     #[token("SQL_BUFFER_RESULT", ignore(ascii_case))]
@@ -1771,24 +1824,68 @@ pub enum TokenKind {
 }
 
 impl TokenKind {
+    // IsBuiltinFunctionName returns true for the builtin functions whose names
+    // are affected by IGNORE_SPACE.
+    // See https://dev.mysql.com/doc/refman/8.0/en/function-resolution.html
+    pub fn is_builtin_function_name(&self) -> bool {
+        matches!(
+            self,
+            Self::ADDDATE
+                | Self::BIT_AND
+                | Self::BIT_OR
+                | Self::BIT_XOR
+                | Self::CAST
+                | Self::COUNT
+                | Self::CURDATE
+                | Self::CURTIME
+                | Self::DATE_ADD
+                | Self::DATE_SUB
+                | Self::EXTRACT
+                | Self::GROUP_CONCAT
+                | Self::MAX
+                | Self::MIN
+                | Self::NOW
+                | Self::POSITION
+                | Self::STD
+                | Self::STDDEV
+                | Self::STDDEV_POP
+                | Self::STDDEV_SAMP
+                | Self::SUBDATE
+                | Self::SUBSTR
+                | Self::SUBSTRING
+                | Self::SUM
+                | Self::SYSDATE
+                | Self::TRIM
+                | Self::VARIANCE
+                | Self::VAR_POP
+                | Self::VAR_SAMP
+        )
+    }
+
     pub fn is_literal(&self) -> bool {
-        match self {
+        matches!(
+            self,
             Self::LiteralInteger
-            | Self::LiteralFloat
-            | Self::LiteralString
-            | Self::LiteralHex
-            | Self::SingleAtIdent => true,
-            _ => false,
-        }
+                | Self::LiteralFloat
+                | Self::LiteralString
+                | Self::LiteralNationalString
+                | Self::LiteralHex
+                | Self::LiteralBit
+                | Self::SingleAtIdent
+        )
     }
 
+    #[allow(clippy::match_like_matches_macro)]
     pub fn is_keyword(&self) -> bool {
         !match self {
             Self::Ident
             | Self::LiteralString
+            | Self::LiteralNationalString
             | Self::LiteralHex
+            | Self::LiteralBit
             | Self::LiteralInteger
             | Self::LiteralFloat
+            | Self::ExecCommentPrefix
             | Self::HintPrefix
             | Self::HintSuffix
             | Self::DoubleEq
@@ -1853,6 +1950,7 @@ impl TokenKind {
         }
     }
 
+    #[allow(clippy::match_like_matches_macro)]
     pub fn is_reserved_ident(&self, after_as: bool) -> bool {
         match self {
             Self::ADD
@@ -1964,6 +2062,7 @@ impl TokenKind {
             | Self::KILL
             | Self::LAG
             | Self::LAST_VALUE
+            | Self::LATERAL
             | Self::LEAD
             | Self::LEADING
             | Self::LEAVE
@@ -0,0 +1,190 @@
+#[cfg(test)]
+mod tests {
+    use crate::parser::input::Dialect;
+    use crate::parser::parser::{parse_sql, tokenize_sql};
+
+    const ALL_DIALECTS: [Dialect; 6] = [
+        Dialect::MySQL57,
+        Dialect::MySQL80,
+        Dialect::MySQL84,
+        Dialect::MariaDB10,
+        Dialect::MariaDB11,
+        Dialect::TiDB,
+    ];
+
+    fn check(query: &str, supported: &[Dialect]) {
+        for dialect in ALL_DIALECTS {
+            let tokens = tokenize_sql(query).unwrap();
+            let stmt = parse_sql(&tokens, dialect);
+            if supported.contains(&dialect) {
+                assert!(stmt.is_ok(), "{:?} {}: {:?}", dialect, query, stmt);
+            } else {
+                assert!(stmt.is_err(), "{:?} {}: {:?}", dialect, query, stmt);
+            }
+        }
+    }
+
+    #[test]
+    fn reserved_keyword_test() {
+        check(
+            r#"SELECT rank, window, lateral FROM t"#,
+            &[Dialect::MySQL57],
+        );
+        check(
+            r#"SELECT rank, row_number, lateral FROM t"#,
+            &[Dialect::MySQL57, Dialect::MariaDB10, Dialect::MariaDB11],
+        );
+        check(
+            r#"SELECT tablesample FROM t"#,
+            &[
+                Dialect::MySQL57,
+                Dialect::MySQL80,
+                Dialect::MariaDB10,
+                Dialect::MariaDB11,
+            ],
+        );
+        check(
+            r#"SELECT qualify FROM t"#,
+            &[
+                Dialect::MySQL57,
+                Dialect::MySQL80,
+                Dialect::MariaDB10,
+                Dialect::MariaDB11,
+                Dialect::TiDB,
+            ],
+        );
+        check(r#"SELECT `qualify` FROM t"#, &ALL_DIALECTS);
+    }
+
+    #[test]
+    fn dialect_syntax_test() {
+        check(
+            r#"SELECT ROW_NUMBER() OVER (ORDER BY a) FROM t"#,
+            &[
+                Dialect::MySQL80,
+                Dialect::MySQL84,
+                Dialect::MariaDB10,
+                Dialect::MariaDB11,
+                Dialect::TiDB,
+            ],
+        );
+        check(
+            r#"SELECT a FROM t INTERSECT SELECT a FROM s"#,
+            &[
+                Dialect::MySQL80,
+                Dialect::MySQL84,
+                Dialect::MariaDB10,
+                Dialect::MariaDB11,
+                Dialect::TiDB,
+            ],
+        );
+        check(
+            r#"SELECT * FROM t, LATERAL (SELECT t.a) AS l"#,
+            &[Dialect::MySQL80, Dialect::MySQL84],
+        );
+        check(
+            r#"SELECT * FROM JSON_TABLE('[]', '$[*]' COLUMNS (a INT PATH '$')) AS j"#,
+            &[
+                Dialect::MySQL80,
+                Dialect::MySQL84,
+                Dialect::MariaDB10,
+                Dialect::MariaDB11,
+            ],
+        );
+        check(
+            r#"SELECT c->'$.a', c->>'$.b' FROM t"#,
+            &[
+                Dialect::MySQL57,
+                Dialect::MySQL80,
+                Dialect::MySQL84,
+                Dialect::TiDB,
+            ],
+        );
+        check(
+            r#"SELECT 1 MEMBER OF ('[1]'), CAST(j AS UNSIGNED ARRAY) FROM t"#,
+            &[Dialect::MySQL80, Dialect::MySQL84, Dialect::TiDB],
+        );
+        check(r#"SELECT * FROM t TABLESAMPLE REGIONS()"#, &[Dialect::TiDB]);
+        check(
+            r#"WITH RECURSIVE cte (n) AS (SELECT 1) SELECT n FROM cte"#,
+            &[
+                Dialect::MySQL80,
+                Dialect::MySQL84,
+                Dialect::MariaDB10,
+                Dialect::MariaDB11,
+                Dialect::TiDB,
+            ],
+        );
+        check(
+            r#"SELECT a FROM t FOR SHARE"#,
+            &[Dialect::MySQL80, Dialect::MySQL84, Dialect::TiDB],
+        );
+        check(r#"SELECT a FROM t LOCK IN SHARE MODE"#, &ALL_DIALECTS);
+        for query in [
+            r#"SELECT NEXT VALUE FOR s"#,
+            r#"SELECT NEXTVAL(s)"#,
+            r#"SELECT LASTVAL(s), SETVAL(s, 10)"#,
+        ] {
+            check(
+                query,
+                &[Dialect::MariaDB10, Dialect::MariaDB11, Dialect::TiDB],
+            );
+        }
+        for query in [
+            r#"SELECT a FROM t FOR UPDATE NOWAIT"#,
+            r#"SELECT a FROM t FOR UPDATE SKIP LOCKED"#,
+        ] {
+            check(
+                query,
+                &[
+                    Dialect::MySQL80,
+                    Dialect::MySQL84,
+                    Dialect::MariaDB10,
+                    Dialect::MariaDB11,
+                    Dialect::TiDB,
+                ],
+            );
+        }
+        for query in [r#"TABLE t ORDER BY a"#, r#"VALUES ROW(1, 2), ROW(3, 4)"#] {
+            check(query, &[Dialect::MySQL80, Dialect::MySQL84, Dialect::TiDB]);
+        }
+    }
+
+    #[test]
+    fn mysql57_unsupported_syntax_test() {
+        let cases = vec![
+            (r#"WITH cte AS (SELECT 1) SELECT * FROM cte"#, "WITH"),
+            (
+                r#"(WITH cte AS (SELECT 1) SELECT * FROM cte) UNION SELECT 2"#,
+                "WITH",
+            ),
+            (r#"SELECT a FROM t FOR SHARE"#, "FOR SHARE"),
+            (
+                r#"SELECT a FROM t FOR UPDATE NOWAIT"#,
+                "NOWAIT and SKIP LOCKED",
+            ),
+            (
+                r#"SELECT a FROM t FOR UPDATE SKIP LOCKED"#,
+                "NOWAIT and SKIP LOCKED",
+            ),
+            (r#"TABLE t"#, "TABLE"),
+            (r#"VALUES ROW(1)"#, "VALUES"),
+            (r#"SELECT NEXT VALUE FOR s"#, "SEQUENCE"),
+            (r#"SELECT NEXTVAL(s)"#, "SEQUENCE"),
+        ];
+        for (query, feature) in cases {
+            let tokens = tokenize_sql(query).unwrap();
+            let err = parse_sql(&tokens, Dialect::MySQL57).unwrap_err();
+            let msg = format!("{} is not supported by the dialect", feature);
+            assert!(err.1.contains(&msg), "{}: {}", query, err.1);
+        }
+    }
+
+    #[test]
+    fn server_version_test() {
+        assert_eq!(Dialect::default(), Dialect::MySQL80);
+        assert!(Dialect::MySQL57.server_version() < 80000);
+        assert!(Dialect::MySQL84.server_version() > Dialect::MySQL80.server_version());
+        assert!(Dialect::MariaDB10.server_version() > 100000);
+    }
+}
@@ -0,0 +1,389 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr_node::{CastFunctionType, ExprNode, FuncCallExprType};
+    use crate::ast::field_type::DataType;
+    use crate::ast::op_code::OpCode;
+    use crate::ast::restore::{
+        restore_sql, RestoreFlags, RESTORE_KEYWORD_UPPERCASE, RESTORE_NAME_QUOTES_WHEN_NEEDED,
+        RESTORE_STRING_SINGLE_QUOTES,
+    };
+    use crate::ast::select_field::Field;
+    use crate::ast::select_stmt::SelectStmt;
+    use crate::ast::statement::Statement;
+    use crate::parser::input::Dialect;
+    use crate::parser::parser::{parse_sql, tokenize_sql};
+
+    const EXPR_RESTORE_FLAGS: RestoreFlags = RestoreFlags(
+        RESTORE_KEYWORD_UPPERCASE.0
+            | RESTORE_STRING_SINGLE_QUOTES.0
+            | RESTORE_NAME_QUOTES_WHEN_NEEDED.0,
+    );
+
+    fn parse_select(query: &str) -> SelectStmt {
+        let tokens = tokenize_sql(query).unwrap();
+        match parse_sql(&tokens, Dialect::MySQL80) {
+            Ok(Statement::SelectStmt(select)) => *select,
+            stmt => panic!("{}: {:?}", query, stmt),
+        }
+    }
+
+    // Returns the fields of a SELECT written back to SQL.
+    fn restored_fields(query: &str) -> Vec<String> {
+        parse_select(query)
+            .fields
+            .iter()
+            .map(|field| match &field.field {
+                Field::Expr(expr) => restore_sql(expr, EXPR_RESTORE_FLAGS).unwrap(),
+                field => panic!("{}: unexpected field {:?}", query, field),
+            })
+            .collect()
+    }
+
+    // Returns the expression of the first field of a SELECT.
+    fn first_field_expr(query: &str) -> ExprNode {
+        let mut select = parse_select(query);
+        match select.fields.remove(0).field {
+            Field::Expr(expr) => expr,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn case_cast_convert_test() {
+        // (query, the fields written back)
+        let cases = vec![
+            (
+                r#"SELECT CASE a WHEN 1 THEN 'one' WHEN 2 THEN 'two' ELSE 'many' END"#,
+                vec![r#"CASE a WHEN 1 THEN 'one' WHEN 2 THEN 'two' ELSE 'many' END"#],
+            ),
+            (
+                r#"SELECT CASE WHEN a THEN b END"#,
+                vec![r#"CASE WHEN a THEN b END"#],
+            ),
+            (
+                r#"SELECT CAST(a AS SIGNED), CAST(a AS UNSIGNED INTEGER), CAST(a AS DECIMAL(10, 2))"#,
+                vec![
+                    r#"CAST(a AS SIGNED)"#,
+                    r#"CAST(a AS UNSIGNED)"#,
+                    r#"CAST(a AS DECIMAL(10, 2))"#,
+                ],
+            ),
+            (
+                r#"SELECT CAST(a AS CHAR(10) CHARACTER SET utf8mb4), CAST(a AS BINARY(4))"#,
+                vec![
+                    r#"CAST(a AS CHAR(10) CHARACTER SET utf8mb4)"#,
+                    r#"CAST(a AS BINARY(4))"#,
+                ],
+            ),
+            (
+                r#"SELECT CAST(a AS DATETIME(6)), CAST(a AS TIME), CAST(a AS DATE), CAST(a AS YEAR)"#,
+                vec![
+                    r#"CAST(a AS DATETIME(6))"#,
+                    r#"CAST(a AS TIME)"#,
+                    r#"CAST(a AS DATE)"#,
+                    r#"CAST(a AS YEAR)"#,
+                ],
+            ),
+            (
+                // FLOAT(p) is FLOAT for p <= 24.
+                r#"SELECT CAST(a AS JSON), CAST(a AS DOUBLE), CAST(a AS FLOAT(10)), CAST(a AS POINT)"#,
+                vec![
+                    r#"CAST(a AS JSON)"#,
+                    r#"CAST(a AS DOUBLE)"#,
+                    r#"CAST(a AS FLOAT)"#,
+                    r#"CAST(a AS POINT)"#,
+                ],
+            ),
+            (
+                r#"SELECT CAST(a AS UNSIGNED ARRAY)"#,
+                vec![r#"CAST(a AS UNSIGNED ARRAY)"#],
+            ),
+            (
+                r#"SELECT CONVERT(a, CHAR), CONVERT(a USING utf8mb4), BINARY a"#,
+                vec![
+                    r#"CONVERT(a, CHAR)"#,
+                    r#"CONVERT(a USING utf8mb4)"#,
+                    r#"BINARY a"#,
+                ],
+            ),
+        ];
+
+        for (query, expected) in cases {
+            assert_eq!(restored_fields(query), expected, "{}", query);
+        }
+    }
+
+    #[test]
+    fn case_expr_test() {
+        let case = match first_field_expr(r#"SELECT CASE WHEN a THEN 1 WHEN b THEN 2 END"#) {
+            ExprNode::CaseExpr(case) => case,
+            expr => panic!("unexpected expr: {:?}", expr),
+        };
+        assert!(case.value.is_none());
+        assert_eq!(case.when_clauses.len(), 2);
+        assert!(case.else_clause.is_none());
+    }
+
+    #[test]
+    fn cast_expr_test() {
+        let cast = match first_field_expr(r#"SELECT CAST(a AS UNSIGNED)"#) {
+            ExprNode::FuncCastExpr(cast) => cast,
+            expr => panic!("unexpected expr: {:?}", expr),
+        };
+        assert_eq!(cast.function_type, CastFunctionType::CastFunction);
+        assert_eq!(cast.tp.tp, DataType::BigInt);
+        assert!(cast.tp.unsigned);
+
+        let cast = match first_field_expr(r#"SELECT CONVERT(a, CHAR(8) CHARSET latin1)"#) {
+            ExprNode::FuncCastExpr(cast) => cast,
+            expr => panic!("unexpected expr: {:?}", expr),
+        };
+        assert_eq!(cast.function_type, CastFunctionType::CastConvertFunction);
+        assert_eq!(cast.tp.flen, Some(8));
+        assert_eq!(cast.tp.charset, "latin1");
+        assert!(cast.explicit_charset);
+
+        let cast = match first_field_expr(r#"SELECT CAST(a AS CHAR(10) ARRAY)"#) {
+            ExprNode::FuncCastExpr(cast) => cast,
+            expr => panic!("unexpected expr: {:?}", expr),
+        };
+        assert!(cast.tp.array);
+    }
+
+    #[test]
+    fn predicate_test() {
+        // (query, the operator at the root of WHERE, WHERE written back)
+        let cases = vec![
+            (
+                r#"SELECT a FROM t WHERE a IN (1, 2, 3) AND b NOT IN (SELECT b FROM t2)"#,
+                Some(OpCode::LogicAnd),
+                r#"a IN (1, 2, 3) AND b NOT IN (SELECT b FROM t2)"#,
+            ),
+            (
+                r#"SELECT a FROM t WHERE a BETWEEN 1 AND 10 OR a NOT BETWEEN b AND c"#,
+                Some(OpCode::LogicOr),
+                r#"a BETWEEN 1 AND 10 OR a NOT BETWEEN b AND c"#,
+            ),
+            (
+                r#"SELECT a FROM t WHERE a LIKE 'x%' AND b NOT LIKE 'y|%' ESCAPE '|' AND c ILIKE 'z'"#,
+                Some(OpCode::LogicAnd),
+                r#"a LIKE 'x%' AND b NOT LIKE 'y|%' ESCAPE '|' AND c ILIKE 'z'"#,
+            ),
+            (
+                r#"SELECT a FROM t WHERE a REGEXP '^x' AND b NOT RLIKE 'y$' AND c SOUNDS LIKE d"#,
+                Some(OpCode::LogicAnd),
+                r#"a REGEXP '^x' AND b NOT REGEXP 'y$' AND c SOUNDS LIKE d"#,
+            ),
+            (
+                r#"SELECT a FROM t WHERE 1 MEMBER OF (CAST(a AS JSON))"#,
+                None,
+                r#"1 MEMBER OF (CAST(a AS JSON))"#,
+            ),
+            (
+                r#"SELECT a FROM t WHERE a >= ANY (SELECT b FROM t2) AND a <> ALL (SELECT c FROM t3)"#,
+                Some(OpCode::LogicAnd),
+                r#"a >= ANY (SELECT b FROM t2) AND a != ALL (SELECT c FROM t3)"#,
+            ),
+            (
+                r#"SELECT a FROM t WHERE a = SOME (SELECT b FROM t2) AND a <=> NULL"#,
+                Some(OpCode::LogicAnd),
+                r#"a = ANY (SELECT b FROM t2) AND a <=> NULL"#,
+            ),
+        ];
+
+        for (query, op, expected) in cases {
+            let where_clause = parse_select(query).where_clause.unwrap();
+            let root_op = match &where_clause {
+                ExprNode::BinaryOperationExpr(expr) => Some(expr.op.clone()),
+                _ => None,
+            };
+            assert_eq!(root_op, op, "{}", query);
+            assert_eq!(
+                restore_sql(&where_clause, EXPR_RESTORE_FLAGS).unwrap(),
+                expected,
+                "{}",
+                query
+            );
+        }
+
+        // IS UNKNOWN is IS NULL.
+        let query =
+            r#"SELECT a IS NULL, a IS NOT NULL, a IS TRUE, a IS NOT FALSE, a IS UNKNOWN FROM t"#;
+        assert_eq!(
+            restored_fields(query),
+            vec![
+                "a IS NULL",
+                "a IS NOT NULL",
+                "a IS TRUE",
+                "a IS NOT FALSE",
+                "a IS NULL",
+            ]
+        );
+    }
+
+    #[test]
+    fn predicate_nodes_test() {
+        match first_field_expr(r#"SELECT a NOT IN (1, 2)"#) {
+            ExprNode::PatternInExpr(expr) => {
+                assert!(expr.not);
+                assert_eq!(expr.list.len(), 2);
+                assert!(expr.sel.is_none());
+            }
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+        match first_field_expr(r#"SELECT a LIKE 'x|%' ESCAPE '|'"#) {
+            ExprNode::PatternLikeOrIlikeExpr(expr) => {
+                assert!(expr.is_like);
+                assert_eq!(expr.escape, Some('|'));
+            }
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+        match first_field_expr(r#"SELECT a LIKE 'x' ESCAPE ''"#) {
+            ExprNode::PatternLikeOrIlikeExpr(expr) => assert_eq!(expr.escape, None),
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+        match first_field_expr(r#"SELECT a LIKE 'x'"#) {
+            ExprNode::PatternLikeOrIlikeExpr(expr) => assert_eq!(expr.escape, Some('\\')),
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+        match first_field_expr(r#"SELECT a SOUNDS LIKE b"#) {
+            ExprNode::SoundsLikeExpr(expr) => {
+                assert!(matches!(
+                    expr.expr.as_deref(),
+                    Some(ExprNode::ColumnNameExpr(_))
+                ));
+                assert!(matches!(
+                    expr.pattern.as_deref(),
+                    Some(ExprNode::ColumnNameExpr(_))
+                ));
+            }
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+        match first_field_expr(r#"SELECT 1 MEMBER OF ('[1]')"#) {
+            ExprNode::MemberOfExpr(expr) => {
+                assert!(matches!(expr.expr.as_deref(), Some(ExprNode::ValueExpr(_))));
+                assert!(matches!(expr.json.as_deref(), Some(ExprNode::ValueExpr(_))));
+            }
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+        match first_field_expr(r#"SELECT a IS NOT UNKNOWN"#) {
+            ExprNode::IsNullExpr(expr) => assert!(expr.not),
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+        match first_field_expr(r#"SELECT a > ALL (SELECT b FROM t)"#) {
+            ExprNode::CompareSubqueryExpr(expr) => assert!(expr.all),
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+    }
+
+    #[test]
+    fn like_escape_test() {
+        let query = r#"SELECT a LIKE 'x' ESCAPE 'ab'"#;
+        let tokens = tokenize_sql(query).unwrap();
+        assert!(parse_sql(&tokens, Dialect::MySQL80).is_err());
+    }
+
+    #[test]
+    fn json_column_path_test() {
+        let cases = vec![
+            (
+                r#"SELECT c->'$.a', c->>'$.b[0]', t.c->>'$."key with space"[last - 1]' FROM t"#,
+                vec![
+                    r#"c->'$.a'"#,
+                    r#"c->>'$.b[0]'"#,
+                    r#"t.c->>'$."key with space"[last - 1]'"#,
+                ],
+            ),
+            (
+                r#"SELECT c->'$**.a', c->'$[1 to 3]', c->'$.*' FROM t"#,
+                vec![r#"c->'$**.a'"#, r#"c->'$[1 to 3]'"#, r#"c->'$.*'"#],
+            ),
+        ];
+
+        for (query, expected) in cases {
+            assert_eq!(restored_fields(query), expected, "{}", query);
+        }
+
+        let where_clause = parse_select(r#"SELECT 1 FROM t WHERE c->>'$.id' = 'x'"#)
+            .where_clause
+            .unwrap();
+        match &where_clause {
+            ExprNode::BinaryOperationExpr(expr) => {
+                assert_eq!(expr.op, OpCode::EQ);
+                assert!(matches!(
+                    expr.l.as_deref(),
+                    Some(ExprNode::FuncCallExpr(unquote)) if unquote.fn_name.lower == "json_unquote"
+                ));
+            }
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+
+        let unquote = match first_field_expr(r#"SELECT c->>'$.a'"#) {
+            ExprNode::FuncCallExpr(unquote) => unquote,
+            expr => panic!("unexpected expr: {:?}", expr),
+        };
+        assert_eq!(unquote.fn_name.lower, "json_unquote");
+        assert!(matches!(unquote.tp, FuncCallExprType::JsonUnquoteArrow));
+        match &unquote.args[0] {
+            ExprNode::FuncCallExpr(extract) => {
+                assert_eq!(extract.fn_name.lower, "json_extract");
+                assert_eq!(extract.args.len(), 2);
+            }
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+    }
+
+    #[test]
+    fn invalid_json_path_test() {
+        for query in [
+            r#"SELECT c->'a' FROM t"#,
+            r#"SELECT c->>'$.' FROM t"#,
+            r#"SELECT c->'$**' FROM t"#,
+            r#"SELECT c->'$[x]' FROM t"#,
+        ] {
+            let tokens = tokenize_sql(query).unwrap();
+            let err = parse_sql(&tokens, Dialect::MySQL80).unwrap_err();
+            assert!(err.1.contains("Invalid JSON path expression"), "{}", err.1);
+        }
+    }
+
+    #[test]
+    fn invalid_cast_type_test() {
+        let cases = vec![
+            (
+                r#"SELECT CAST(a AS DATETIME(7))"#,
+                "Too-big precision specified for CAST. Maximum is 6.",
+            ),
+            (
+                r#"SELECT CONVERT(a, TIME(10))"#,
+                "Too-big precision specified for CAST. Maximum is 6.",
+            ),
+            (
+                r#"SELECT CAST(a AS DECIMAL(70, 2))"#,
+                "Too-big precision specified for CAST. Maximum is 65.",
+            ),
+            (
+                r#"SELECT CAST(a AS DECIMAL(10, 20))"#,
+                "For decimal(M,D), M must be >= D.",
+            ),
+            (
+                r#"SELECT CAST(a AS DECIMAL(40, 31))"#,
+                "Too big scale specified for CAST. Maximum is 30.",
+            ),
+        ];
+        for (query, msg) in cases {
+            let tokens = tokenize_sql(query).unwrap();
+            let err = parse_sql(&tokens, Dialect::MySQL80).unwrap_err();
+            assert!(err.1.contains(msg), "{}: {}", query, err.1);
+        }
+
+        for query in [
+            r#"SELECT CAST(a AS DATETIME(6))"#,
+            r#"SELECT CAST(a AS DECIMAL(65, 30))"#,
+            r#"SELECT CAST(a AS DECIMAL(10))"#,
+        ] {
+            let tokens = tokenize_sql(query).unwrap();
+            assert!(parse_sql(&tokens, Dialect::MySQL80).is_ok(), "{}", query);
+        }
+    }
+}
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::format::{format_sql, FormatConfig, KeywordCase};
+    use crate::ast::restore::{restore_sql, DEFAULT_RESTORE_FLAGS};
+    use crate::ast::statement::Statement;
+    use crate::ast::structural::StructuralEq;
+    use crate::parser::input::Dialect;
+    use crate::parser::parser::{parse_sql, tokenize_sql};
+
+    fn format(query: &str, config: &FormatConfig) -> String {
+        let sql = format_sql(query, Dialect::TiDB, config);
+        assert!(sql.is_ok(), "{}: {:?}", query, sql);
+        sql.unwrap()
+    }
+
+    fn parse(query: &str) -> Statement {
+        let tokens = tokenize_sql(query).unwrap();
+        let stmt = parse_sql(&tokens, Dialect::TiDB);
+        assert!(stmt.is_ok(), "{}: {:?}", query, stmt);
+        stmt.unwrap()
+    }
+
+    fn restore(query: &str) -> String {
+        restore_sql(&parse(query), DEFAULT_RESTORE_FLAGS).unwrap()
+    }
+
+    #[test]
+    fn format_layout_test() {
+        let comma_first = FormatConfig {
+            max_width: 40,
+            comma_first: true,
+            keyword_case: KeywordCase::Lower,
+            ..FormatConfig::default()
+        };
+        let cases = vec![
+            (
+                "select a, b as c from t1 join t2 on t1.id = t2.id left join t3 on t2.x = t3.x where a = 1 and b in (1, 2) order by a limit 10",
+                FormatConfig::default(),
+                "SELECT\n    a,\n    b AS c\nFROM t1\n    JOIN t2      ON t1.id = t2.id\n    LEFT JOIN t3 ON t2.x = t3.x\nWHERE a = 1 AND b IN (1, 2)\nORDER BY a\nLIMIT 10",
+            ),
+            (
+                "SELECT a, CASE WHEN a > 1 THEN 'big' WHEN a < 0 THEN 'negative' ELSE 'small' END AS c1 FROM t WHERE a IN (100000, 200000, 300000, 400000, 500000, 600000)",
+                comma_first,
+                "select\n      a\n    , case\n        when a > 1 then 'big'\n        when a < 0 then 'negative'\n        else 'small'\n    end as c1\nfrom t\nwhere a in (100000, 200000, 300000,\n    400000, 500000, 600000)",
+            ),
+            (
+                "-- daily report\nSELECT a, /* the total */ b FROM t -- all rows\nWHERE a = 1",
+                FormatConfig::default(),
+                "-- daily report\nSELECT\n    a,\n    /* the total */\n    b\nFROM t\n-- all rows\nWHERE a = 1",
+            ),
+            (
+                "SELECT a FROM t WHERE NOT (first_column = 1 OR second_column = 2 OR third_column = 3 OR fourth_column = 4) AND (b IS NULL OR c = 1) IS TRUE",
+                FormatConfig::default(),
+                "SELECT\n    a\nFROM t\nWHERE NOT (first_column = 1 OR second_column = 2 OR third_column = 3\n        OR fourth_column = 4)\n    AND (b IS NULL OR c = 1) IS TRUE",
+            ),
+        ];
+
+        for (query, config, expected) in cases {
+            assert_eq!(format(query, &config), expected, "{}", query);
+        }
+    }
+
+    #[test]
+    fn format_dialect_test() {
+        let query = "SELECT a /*M!100100 , b */ FROM t";
+        let cases = vec![
+            (Dialect::MariaDB10, "SELECT\n    a,\n    b\nFROM t"),
+            (Dialect::MySQL80, "SELECT\n    a\n/*M!100100 , b */\nFROM t"),
+        ];
+
+        for (dialect, expected) in cases {
+            let sql = format_sql(query, dialect, &FormatConfig::default()).unwrap();
+            assert_eq!(sql, expected, "{:?}", dialect);
+        }
+    }
+
+    #[test]
+    fn format_round_trip_test() {
+        let querys = vec![
+            r#"SELECT DISTINCT a, b + 1 AS c, CASE a WHEN 1 THEN 'one' WHEN 2 THEN 'two' ELSE 'many' END FROM t"#,
+            r#"SELECT * FROM t1, t2 JOIN t3 ON t2.a = t3.a LEFT JOIN t4 USING (a) WHERE t1.a = t2.a"#,
+            r#"SELECT * FROM (SELECT a FROM t1 WHERE a > 1) AS dt JOIN t2 ON dt.a = t2.a"#,
+            r#"SELECT a FROM t WHERE a IN (SELECT a FROM t2 WHERE b = 1) AND (b = 1 OR c = 2 OR d = 3 OR e = 4 OR f = 5)"#,
+            r#"WITH RECURSIVE cte (n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM cte WHERE n < 10) SELECT n FROM cte"#,
+            r#"SELECT a FROM t1 UNION SELECT a FROM t2 ORDER BY a LIMIT 5"#,
+            r#"SELECT a, COUNT(*) FROM t GROUP BY a HAVING COUNT(*) > 1 WINDOW w AS (ORDER BY a) ORDER BY a FOR UPDATE"#,
+            r#"SELECT /*+ HASH_JOIN(t1) */ SQL_NO_CACHE a FROM t1 USE INDEX (i)"#,
+            r#"TABLE t ORDER BY a LIMIT 1"#,
+            r#"DO sleep(1)"#,
+        ];
+        let configs = vec![
+            FormatConfig::default(),
+            FormatConfig {
+                indent_width: 2,
+                max_width: 30,
+                field_per_line: false,
+                align_joins: false,
+                comma_first: true,
+                keyword_case: KeywordCase::Lower,
+            },
+        ];
+
+        for query in querys {
+            for config in &configs {
+                let formatted = format(query, config);
+                assert!(
+                    parse(&formatted).structural_eq(&parse(query)),
+                    "{}",
+                    formatted
+                );
+                assert_eq!(restore(&formatted), restore(query), "{}", formatted);
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::json::{from_json, json_schema, to_json, to_json_pretty};
+    use crate::ast::restore::{restore_sql, DEFAULT_RESTORE_FLAGS};
+    use crate::ast::statement::Statement;
+    use crate::parser::input::Dialect;
+    use crate::parser::parser::{parse_sql, tokenize_sql};
+
+    fn parse(query: &str) -> Statement {
+        let tokens = tokenize_sql(query).unwrap();
+        let stmt = parse_sql(&tokens, Dialect::TiDB);
+        assert!(stmt.is_ok(), "{}: {:?}", query, stmt);
+        stmt.unwrap()
+    }
+
+    #[test]
+    fn json_round_trip_test() {
+        let querys = vec![
+            r#"SELECT DISTINCT a, b + 1 AS c, CASE a WHEN 1 THEN 'one' ELSE 'many' END FROM t"#,
+            r#"SELECT * FROM t1 JOIN t2 ON t1.a = t2.a LEFT JOIN t3 USING (a) WHERE t1.b IN (1, 2.5, NULL)"#,
+            r#"SELECT a FROM t WHERE a IN (SELECT a FROM t2 WHERE b = x'0A') AND c LIKE 'a%' ESCAPE '!'"#,
+            r#"WITH RECURSIVE cte (n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM cte WHERE n < 10) SELECT n FROM cte"#,
+            r#"SELECT a FROM t1 UNION SELECT a FROM t2 ORDER BY a LIMIT 5"#,
+            r#"SELECT a, COUNT(*) FROM t GROUP BY a HAVING COUNT(*) > 1 WINDOW w AS (ORDER BY a) ORDER BY a LIMIT 1, 2 FOR UPDATE"#,
+            r#"SELECT /*+ HASH_JOIN(t1) */ HIGH_PRIORITY SQL_NO_CACHE a FROM t1 USE INDEX (i) INTO OUTFILE 'x'"#,
+            r#"SELECT DATE_ADD(a, INTERVAL 1 DAY), CAST(b AS CHAR(10)), TRIM(LEADING 'x' FROM c) FROM t"#,
+            r#"TABLE t ORDER BY a LIMIT 1"#,
+            r#"DO sleep(1)"#,
+        ];
+
+        for query in querys {
+            let stmt = parse(query);
+            let expected = restore_sql(&stmt, DEFAULT_RESTORE_FLAGS).unwrap();
+            for json in [to_json(&stmt), to_json_pretty(&stmt)] {
+                assert!(json.is_ok(), "{}: {:?}", query, json);
+                let back = from_json(&json.unwrap());
+                assert!(back.is_ok(), "{}: {:?}", query, back);
+                let back = back.unwrap();
+                assert_eq!(back, stmt, "{}", query);
+                let sql = restore_sql(&back, DEFAULT_RESTORE_FLAGS).unwrap();
+                assert_eq!(sql, expected, "{}", query);
+            }
+        }
+    }
+
+    #[test]
+    fn json_schema_test() {
+        let json = to_json(&parse("SELECT `A` FROM t")).unwrap();
+        assert!(
+            json.starts_with(r#"{"version":1,"statement":{"type":"SelectStmt","data":{"#),
+            "{}",
+            json
+        );
+        assert!(json.contains(r#""name":"A""#), "{}", json);
+        assert!(json.contains(r#""span":{"start":"#), "{}", json);
+
+        let err = from_json(&json.replacen(r#""version":1"#, r#""version":99"#, 1));
+        assert!(err.is_err(), "{:?}", err);
+        assert!(from_json(r#"{"version":1,"statement":{"type":"NoSuchStmt"}}"#).is_err());
+
+        let schema = json_schema();
+        assert!(schema.contains(r#""title": "AstDocument""#), "{}", schema);
+        assert!(schema.contains(r#""SelectStmt""#), "{}", schema);
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::json_table::{JsonTableColumnKind, JsonTableOnResponse};
+    use crate::ast::restore::{
+        restore_sql, RestoreFlags, RESTORE_KEYWORD_UPPERCASE, RESTORE_NAME_QUOTES_WHEN_NEEDED,
+        RESTORE_STRING_SINGLE_QUOTES,
+    };
+    use crate::ast::result_set_node::ResultSetNode;
+    use crate::ast::statement::Statement;
+    use crate::parser::input::Dialect;
+    use crate::parser::parser::{parse_sql, tokenize_sql};
+
+    const RESTORE_FLAGS: RestoreFlags = RestoreFlags(
+        RESTORE_KEYWORD_UPPERCASE.0
+            | RESTORE_STRING_SINGLE_QUOTES.0
+            | RESTORE_NAME_QUOTES_WHEN_NEEDED.0,
+    );
+
+    #[test]
+    fn json_table_test() {
+        // (query, the query written back)
+        let cases = vec![
+            (
+                r#"SELECT * FROM JSON_TABLE('[{"a": 1}]', '$[*]' COLUMNS (a INT PATH '$.a')) AS jt"#,
+                r#"SELECT * FROM JSON_TABLE('[{"a": 1}]', '$[*]' COLUMNS (a INT PATH '$.a')) AS jt"#,
+            ),
+            (
+                r#"SELECT * FROM JSON_TABLE(t.doc, '$[*]' COLUMNS (id FOR ORDINALITY, a VARCHAR(10) CHARACTER SET utf8mb4 PATH '$.a' DEFAULT '"x"' ON EMPTY NULL ON ERROR, b INT EXISTS PATH '$.b')) jt"#,
+                r#"SELECT * FROM JSON_TABLE(t.doc, '$[*]' COLUMNS (id FOR ORDINALITY, a VARCHAR(10) CHARACTER SET utf8mb4 PATH '$.a' DEFAULT '"x"' ON EMPTY NULL ON ERROR, b INT EXISTS PATH '$.b')) AS jt"#,
+            ),
+            (
+                r#"SELECT * FROM t, JSON_TABLE(t.doc, '$' COLUMNS (NESTED PATH '$.items[*]' COLUMNS (item JSON PATH '$', NESTED '$.tags[*]' COLUMNS (tag TEXT PATH '$')))) AS jt"#,
+                r#"SELECT * FROM t JOIN JSON_TABLE(t.doc, '$' COLUMNS (NESTED PATH '$.items[*]' COLUMNS (item JSON PATH '$', NESTED PATH '$.tags[*]' COLUMNS (tag TEXT PATH '$')))) AS jt"#,
+            ),
+            (
+                r#"SELECT * FROM t1, LATERAL (SELECT a FROM t2 WHERE t2.a = t1.a) AS dt"#,
+                r#"SELECT * FROM t1 JOIN LATERAL (SELECT a FROM t2 WHERE t2.a = t1.a) AS dt"#,
+            ),
+            (
+                r#"SELECT * FROM (SELECT a FROM t1) dt JOIN t2 ON dt.a = t2.a"#,
+                r#"SELECT * FROM (SELECT a FROM t1) AS dt JOIN t2 ON dt.a = t2.a"#,
+            ),
+        ];
+
+        for (query, expected) in cases {
+            let tokens = tokenize_sql(query).unwrap();
+            let stmt = parse_sql(&tokens, Dialect::MySQL80);
+            assert!(stmt.is_ok(), "{}: {:?}", query, stmt);
+            let restored = restore_sql(&stmt.unwrap(), RESTORE_FLAGS).unwrap();
+            assert_eq!(restored, expected, "{}", query);
+        }
+    }
+
+    #[test]
+    fn json_table_columns_test() {
+        let query = r#"SELECT * FROM JSON_TABLE(@j, '$[*]' COLUMNS (id FOR ORDINALITY, v DECIMAL(10, 2) PATH '$.v' ERROR ON ERROR, NESTED PATH '$.c[*]' COLUMNS (c INT PATH '$'))) AS jt"#;
+        let tokens = tokenize_sql(query).unwrap();
+        let stmt = parse_sql(&tokens, Dialect::MySQL80).unwrap();
+
+        let select = match stmt {
+            Statement::SelectStmt(select) => select,
+            _ => unreachable!(),
+        };
+        let source = match *select.from.unwrap().left {
+            ResultSetNode::TableSource(source) => source,
+            _ => unreachable!(),
+        };
+        assert_eq!(source.as_name.origin, "jt");
+        let json_table = match *source.source {
+            ResultSetNode::JsonTable(json_table) => json_table,
+            _ => unreachable!(),
+        };
+        assert_eq!(json_table.path, "$[*]");
+        assert_eq!(json_table.columns.len(), 3);
+        assert!(matches!(
+            json_table.columns[0].kind,
+            JsonTableColumnKind::ForOrdinality
+        ));
+        assert!(json_table.columns[1].on_empty.is_none());
+        assert!(matches!(
+            json_table.columns[1].on_error,
+            Some(JsonTableOnResponse::Error)
+        ));
+        assert!(matches!(
+            json_table.columns[2].kind,
+            JsonTableColumnKind::Nested
+        ));
+        assert_eq!(json_table.columns[2].nested_columns.len(), 1);
+    }
+
+    #[test]
+    fn derived_table_requires_alias_test() {
+        let query = r#"SELECT * FROM LATERAL (SELECT 1)"#;
+        let tokens = tokenize_sql(query).unwrap();
+        assert!(parse_sql(&tokens, Dialect::MySQL80).is_err());
+    }
+}
@@ -0,0 +1,276 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr_node::{ExprNode, LiteralPrefix, ValueExpr, ValueExprKind};
+    use crate::ast::select_field::Field;
+    use crate::ast::select_stmt::SelectStmt;
+    use crate::ast::statement::Statement;
+    use crate::mysql::consts::MODE_NO_BACKSLASH_ESCAPES;
+    use crate::parser::input::Dialect;
+    use crate::parser::parser::{
+        parse_sql, parse_sql_with_sql_mode, tokenize_sql, tokenize_sql_with_sql_mode,
+    };
+    use crate::parser::token::DEFAULT_SERVER_VERSION;
+
+    fn first_select(query: &str) -> SelectStmt {
+        let tokens = tokenize_sql(query).unwrap();
+        match parse_sql(&tokens, Dialect::MySQL80).unwrap() {
+            Statement::SelectStmt(select) => *select,
+            stmt => panic!("unexpected stmt: {:?}", stmt),
+        }
+    }
+
+    // Returns the expression of the first field of a SELECT.
+    fn first_field_expr(query: &str) -> ExprNode {
+        let mut select = first_select(query);
+        match select.fields.remove(0).field {
+            Field::Expr(expr) => expr,
+            _ => unreachable!(),
+        }
+    }
+
+    fn first_field_value(query: &str) -> ValueExpr {
+        match first_field_expr(query) {
+            ExprNode::ValueExpr(value) => value,
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+    }
+
+    // Returns the values of all fields of a SELECT.
+    fn field_values(query: &str) -> Vec<ValueExpr> {
+        first_select(query)
+            .fields
+            .into_iter()
+            .map(|field| match field.field {
+                Field::Expr(ExprNode::ValueExpr(value)) => value,
+                field => panic!("unexpected field: {:?}", field),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn literal_test() {
+        let values = field_values(r#"SELECT 'a' 'b' "c", N'national', n'x' 'y'"#);
+        assert_eq!(values[0].s, "abc");
+        assert!(matches!(values[0].kind, ValueExprKind::String));
+        for (value, s) in values[1..].iter().zip(["national", "xy"]) {
+            assert_eq!(value.s, s);
+            assert_eq!(value.charset, "utf8mb3");
+        }
+
+        let values = field_values(r#"SELECT _utf8mb4'abc', _latin1 'a' 'b', _binary 'raw'"#);
+        for (value, (s, charset)) in
+            values
+                .iter()
+                .zip([("abc", "utf8mb4"), ("ab", "latin1"), ("raw", "binary")])
+        {
+            assert_eq!(value.s, s);
+            assert_eq!(value.charset, charset);
+            assert!(matches!(value.kind, ValueExprKind::String));
+        }
+
+        let values = field_values(r#"SELECT 0x0A0B, X'0A0B', x'', 0b0101, B'0101', b''"#);
+        for value in &values[..2] {
+            assert!(matches!(value.kind, ValueExprKind::HexLiteral(ref b) if b == &[0x0A, 0x0B]));
+        }
+        assert!(matches!(values[2].kind, ValueExprKind::HexLiteral(ref b) if b.is_empty()));
+        for value in &values[3..5] {
+            assert!(matches!(value.kind, ValueExprKind::BitLiteral(ref b) if b == &[0x05]));
+        }
+        assert!(matches!(values[5].kind, ValueExprKind::BitLiteral(ref b) if b.is_empty()));
+        assert!(values.iter().all(|value| value.charset == "binary"));
+
+        let values = field_values(r#"SELECT _binary 0xFF, _utf8mb4 0x616263, _latin1 B'01100001'"#);
+        assert!(matches!(values[0].kind, ValueExprKind::HexLiteral(ref b) if b == &[0xFF]));
+        assert_eq!(values[0].charset, "binary");
+        for (value, (s, charset)) in values[1..]
+            .iter()
+            .zip([("abc", "utf8mb4"), ("a", "latin1")])
+        {
+            assert!(matches!(value.kind, ValueExprKind::String));
+            assert_eq!(value.s, s);
+            assert_eq!(value.charset, charset);
+        }
+
+        let select = first_select(
+            r#"SELECT _utf8mb4'abc' COLLATE utf8mb4_general_ci, 'abc' COLLATE latin1_bin"#,
+        );
+        for (field, (charset, collate)) in select
+            .fields
+            .iter()
+            .zip([("utf8mb4", "utf8mb4_general_ci"), ("", "latin1_bin")])
+        {
+            match &field.field {
+                Field::Expr(ExprNode::SetCollationExpr(expr)) => {
+                    assert_eq!(expr.collate, collate);
+                    match expr.expr.as_deref() {
+                        Some(ExprNode::ValueExpr(value)) => {
+                            assert_eq!(value.s, "abc");
+                            assert_eq!(value.charset, charset);
+                        }
+                        expr => panic!("unexpected expr: {:?}", expr),
+                    }
+                }
+                field => panic!("unexpected field: {:?}", field),
+            }
+        }
+    }
+
+    #[test]
+    fn string_literal_value_test() {
+        let value = first_field_value(r#"SELECT 'a' 'b' "c""#);
+        assert_eq!(value.s, "abc");
+        assert!(matches!(value.kind, ValueExprKind::String));
+
+        let value = first_field_value(r#"SELECT _latin1 'a' 'b'"#);
+        assert_eq!(value.s, "ab");
+        assert_eq!(value.charset, "latin1");
+        assert_eq!(value.collation, "latin1_bin");
+
+        let value = first_field_value(r#"SELECT N'abc' 'd'"#);
+        assert_eq!(value.s, "abcd");
+        assert_eq!(value.charset, "utf8mb3");
+        assert_eq!(value.prefix, LiteralPrefix::National);
+    }
+
+    #[test]
+    fn binary_literal_value_test() {
+        let value = first_field_value(r#"SELECT X'0A0B'"#);
+        assert!(matches!(value.kind, ValueExprKind::HexLiteral(ref b) if b == &[0x0A, 0x0B]));
+        assert_eq!(value.charset, "binary");
+
+        let value = first_field_value(r#"SELECT 0x123"#);
+        assert!(matches!(value.kind, ValueExprKind::HexLiteral(ref b) if b == &[0x01, 0x23]));
+
+        let value = first_field_value(r#"SELECT B'100000001'"#);
+        assert!(matches!(value.kind, ValueExprKind::BitLiteral(ref b) if b == &[0x01, 0x01]));
+
+        let value = first_field_value(r#"SELECT _utf8mb4 0x616263"#);
+        assert!(matches!(value.kind, ValueExprKind::String));
+        assert_eq!(value.s, "abc");
+        assert_eq!(value.charset, "utf8mb4");
+
+        let value = first_field_value(r#"SELECT _binary 0xFF"#);
+        assert!(matches!(value.kind, ValueExprKind::HexLiteral(ref b) if b == &[0xFF]));
+        assert_eq!(value.prefix, LiteralPrefix::Binary);
+
+        let value = first_field_value(r#"SELECT _latin1 X'E9'"#);
+        assert!(matches!(value.kind, ValueExprKind::String));
+        assert_eq!(value.s, "é");
+        assert_eq!(value.charset, "latin1");
+
+        let value = first_field_value(r#"SELECT _latin1 B'01000001'"#);
+        assert_eq!(value.s, "A");
+        assert_eq!(value.prefix, LiteralPrefix::None);
+    }
+
+    #[test]
+    fn num_literal_value_test() {
+        let value = first_field_value(r#"SELECT 9223372036854775807"#);
+        assert!(matches!(value.kind, ValueExprKind::I64(i64::MAX)));
+
+        let value = first_field_value(r#"SELECT 18446744073709551615"#);
+        assert!(matches!(value.kind, ValueExprKind::U64(u64::MAX)));
+
+        let value = first_field_value(r#"SELECT 18446744073709551616"#);
+        match value.kind {
+            ValueExprKind::Decimal(d) => assert_eq!(d.to_string(), "18446744073709551616"),
+            kind => panic!("unexpected kind: {:?}", kind),
+        }
+        assert_eq!(value.s, "18446744073709551616");
+
+        let value = first_field_value(r#"SELECT 1.50"#);
+        match value.kind {
+            ValueExprKind::Decimal(d) => assert_eq!(d.fractional_digit_count(), 2),
+            kind => panic!("unexpected kind: {:?}", kind),
+        }
+        assert_eq!(value.s, "1.50");
+
+        for query in [r#"SELECT .5"#, r#"SELECT 5."#] {
+            let value = first_field_value(query);
+            assert!(matches!(value.kind, ValueExprKind::Decimal(_)), "{}", query);
+        }
+
+        let value = first_field_value(r#"SELECT 1e3"#);
+        assert!(matches!(value.kind, ValueExprKind::F64(v) if v.0 == 1000.0));
+        assert_eq!(value.s, "1e3");
+
+        let value = first_field_value(r#"SELECT 0x0102030405060708090A"#);
+        assert!(matches!(value.kind, ValueExprKind::HexLiteral(ref b) if b.len() == 10));
+
+        let value = first_field_value(
+            r#"SELECT b'1000000000000000000000000000000000000000000000000000000000000000001'"#,
+        );
+        assert!(matches!(value.kind, ValueExprKind::BitLiteral(ref b) if b.len() == 9));
+
+        let query = r#"SELECT 1e400"#;
+        let tokens = tokenize_sql(query).unwrap();
+        assert!(parse_sql(&tokens, Dialect::MySQL80).is_err());
+    }
+
+    #[test]
+    fn literal_collate_test() {
+        match first_field_expr(r#"SELECT _latin1 'a' COLLATE latin1_swedish_ci"#) {
+            ExprNode::SetCollationExpr(expr) => assert_eq!(expr.collate, "latin1_swedish_ci"),
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+
+        let query = r#"SELECT _latin1 'a' COLLATE utf8mb4_bin"#;
+        let tokens = tokenize_sql(query).unwrap();
+        assert!(parse_sql(&tokens, Dialect::MySQL80).is_err());
+    }
+
+    #[test]
+    fn string_unescape_test() {
+        let cases = vec![
+            (r#"SELECT 'a\nb\tc'"#, "a\nb\tc"),
+            (r#"SELECT 'a\0b\Zc\bd\re'"#, "a\0b\x1ac\x08d\re"),
+            (r#"SELECT 'it''s', "say ""hi""", 'a\'b'"#, "it's"),
+            (r#"SELECT "say ""hi""""#, r#"say "hi""#),
+            (r#"SELECT 'a\'b\"c'"#, r#"a'b"c"#),
+            (r#"SELECT 'a\\b\qc'"#, r#"a\bqc"#),
+            (r#"SELECT 'a\%b\_c'"#, r#"a\%b\_c"#),
+            (r#"SELECT N'a\nb'"#, "a\nb"),
+        ];
+
+        for (query, expected) in cases {
+            let value = first_field_value(query);
+            assert_eq!(value.s, expected, "{}", query);
+        }
+    }
+
+    #[test]
+    fn no_backslash_escapes_test() {
+        let cases = vec![
+            (r#"SELECT 'a\nb'"#, r#"a\nb"#),
+            (r#"SELECT 'a\'"#, r#"a\"#),
+            (r#"SELECT 'a\''b'"#, r#"a\'b"#),
+            (r#"SELECT N'a\', 'b'"#, r#"a\"#),
+            (r#"SELECT "a\""#, r#"a\"#),
+        ];
+
+        for (query, expected) in cases {
+            let tokens = tokenize_sql_with_sql_mode(
+                query,
+                DEFAULT_SERVER_VERSION,
+                MODE_NO_BACKSLASH_ESCAPES,
+            )
+            .unwrap();
+            let (stmt, _) =
+                parse_sql_with_sql_mode(&tokens, Dialect::MySQL80, MODE_NO_BACKSLASH_ESCAPES)
+                    .unwrap();
+            let mut select = match stmt {
+                Statement::SelectStmt(select) => select,
+                _ => unreachable!(),
+            };
+            match select.fields.remove(0).field {
+                Field::Expr(ExprNode::ValueExpr(value)) => {
+                    assert_eq!(value.s, expected, "{}", query)
+                }
+                field => panic!("unexpected field: {:?}", field),
+            }
+        }
+
+        let query = r#"SELECT 'a\'"#;
+        assert!(tokenize_sql(query).is_err());
+    }
+}
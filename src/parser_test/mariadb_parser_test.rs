@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::restore::{
+        restore_sql, RestoreFlags, RESTORE_KEYWORD_UPPERCASE, RESTORE_NAME_QUOTES_WHEN_NEEDED,
+        RESTORE_STRING_SINGLE_QUOTES,
+    };
+    use crate::ast::result_set_node::ResultSetNode;
+    use crate::ast::sequence_stmt::SequenceOptionType;
+    use crate::ast::statement::Statement;
+    use crate::ast::system_time::SystemTimeClause;
+    use crate::parser::input::Dialect;
+    use crate::parser::parser::{parse_sql, tokenize_sql};
+
+    const RESTORE_FLAGS: RestoreFlags = RestoreFlags(
+        RESTORE_KEYWORD_UPPERCASE.0
+            | RESTORE_STRING_SINGLE_QUOTES.0
+            | RESTORE_NAME_QUOTES_WHEN_NEEDED.0,
+    );
+
+    fn restore(query: &str) -> String {
+        let tokens = tokenize_sql(query).unwrap();
+        let stmt = parse_sql(&tokens, Dialect::MariaDB10);
+        assert!(stmt.is_ok(), "{}: {:?}", query, stmt);
+        restore_sql(&stmt.unwrap(), RESTORE_FLAGS).unwrap()
+    }
+
+    #[test]
+    fn sequence_test() {
+        // (query, the query written back)
+        let cases = vec![
+            (r#"CREATE SEQUENCE s"#, r#"CREATE SEQUENCE s"#),
+            (
+                r#"CREATE OR REPLACE TEMPORARY SEQUENCE IF NOT EXISTS db.s START WITH 100 INCREMENT BY 10"#,
+                r#"CREATE OR REPLACE TEMPORARY SEQUENCE IF NOT EXISTS db.s START WITH 100 INCREMENT BY 10"#,
+            ),
+            (
+                r#"CREATE SEQUENCE s INCREMENT = -1 MINVALUE = -100 MAXVALUE 0 START = 0 CACHE 20 CYCLE"#,
+                r#"CREATE SEQUENCE s INCREMENT BY -1 MINVALUE -100 MAXVALUE 0 START WITH 0 CACHE 20 CYCLE"#,
+            ),
+            (
+                r#"CREATE SEQUENCE s NO MINVALUE NOMAXVALUE NOCACHE NO CYCLE"#,
+                r#"CREATE SEQUENCE s NO MINVALUE NO MAXVALUE NOCACHE NOCYCLE"#,
+            ),
+            (
+                r#"ALTER SEQUENCE IF EXISTS s RESTART WITH 5 NOCYCLE"#,
+                r#"ALTER SEQUENCE IF EXISTS s RESTART WITH 5 NOCYCLE"#,
+            ),
+            (r#"ALTER SEQUENCE s RESTART"#, r#"ALTER SEQUENCE s RESTART"#),
+            (
+                r#"DROP TEMPORARY SEQUENCE IF EXISTS s1, db.s2"#,
+                r#"DROP TEMPORARY SEQUENCE IF EXISTS s1, db.s2"#,
+            ),
+            (
+                // NEXTVAL() and LASTVAL() are the same as NEXT and PREVIOUS VALUE FOR.
+                r#"SELECT NEXT VALUE FOR s, PREVIOUS VALUE FOR s, NEXTVAL(s), LASTVAL(s), SETVAL(s, 10)"#,
+                r#"SELECT NEXT VALUE FOR s, LASTVAL(s), NEXT VALUE FOR s, LASTVAL(s), SETVAL(s, 10)"#,
+            ),
+        ];
+
+        for (query, expected) in cases {
+            assert_eq!(restore(query), expected, "{}", query);
+        }
+
+        let query = r#"CREATE SEQUENCE IF NOT EXISTS s START WITH 100 INCREMENT BY 10"#;
+        let tokens = tokenize_sql(query).unwrap();
+        match parse_sql(&tokens, Dialect::MariaDB11).unwrap() {
+            Statement::CreateSequenceStmt(stmt) => {
+                assert!(stmt.if_not_exists);
+                assert!(!stmt.or_replace);
+                assert_eq!(stmt.name.name.origin, "s");
+                let options: Vec<_> = stmt
+                    .seq_options
+                    .iter()
+                    .map(|opt| (opt.tp, opt.int_value))
+                    .collect();
+                assert_eq!(
+                    options,
+                    vec![
+                        (SequenceOptionType::StartWith, 100),
+                        (SequenceOptionType::IncrementBy, 10)
+                    ]
+                );
+            }
+            stmt => panic!("unexpected statement: {:?}", stmt),
+        }
+    }
+
+    #[test]
+    fn sequence_dialect_test() {
+        let query = r#"CREATE SEQUENCE s START WITH 1"#;
+        let tokens = tokenize_sql(query).unwrap();
+        assert!(parse_sql(&tokens, Dialect::TiDB).is_ok());
+        assert!(parse_sql(&tokens, Dialect::MySQL80).is_err());
+
+        let querys = vec![
+            r#"CREATE OR REPLACE SEQUENCE s"#,
+            r#"DROP TEMPORARY SEQUENCE s"#,
+            r#"SELECT PREVIOUS VALUE FOR s"#,
+        ];
+        for query in querys {
+            let tokens = tokenize_sql(query).unwrap();
+            let stmt = parse_sql(&tokens, Dialect::TiDB);
+            assert!(stmt.is_err(), "{}: {:?}", query, stmt);
+        }
+    }
+
+    #[test]
+    fn system_time_test() {
+        // (query, the query written back)
+        let cases = vec![
+            (
+                r#"SELECT * FROM t FOR SYSTEM_TIME AS OF TIMESTAMP '2016-10-09 08:07:06'"#,
+                r#"SELECT * FROM t FOR SYSTEM_TIME AS OF TIMESTAMP '2016-10-09 08:07:06'"#,
+            ),
+            (
+                r#"SELECT * FROM t FOR SYSTEM_TIME AS OF TRANSACTION 42 AS x"#,
+                r#"SELECT * FROM t FOR SYSTEM_TIME AS OF TRANSACTION 42 AS x"#,
+            ),
+            (
+                r#"SELECT * FROM t FOR SYSTEM_TIME BETWEEN (NOW() - INTERVAL 1 YEAR) AND NOW()"#,
+                r#"SELECT * FROM t FOR SYSTEM_TIME BETWEEN TIMESTAMP (DATE_SUB(NOW(), INTERVAL 1 YEAR)) AND TIMESTAMP NOW()"#,
+            ),
+            (
+                r#"SELECT * FROM t FOR SYSTEM_TIME FROM '2016-01-01' TO '2017-01-01' x"#,
+                r#"SELECT * FROM t FOR SYSTEM_TIME FROM TIMESTAMP '2016-01-01' TO TIMESTAMP '2017-01-01' AS x"#,
+            ),
+            (
+                r#"SELECT * FROM t PARTITION (p0) FOR SYSTEM_TIME ALL"#,
+                r#"SELECT * FROM t PARTITION(p0) FOR SYSTEM_TIME ALL"#,
+            ),
+            // FOR UPDATE is a locking read, not a FOR SYSTEM_TIME clause.
+            (
+                r#"SELECT * FROM t FOR UPDATE"#,
+                r#"SELECT * FROM t FOR UPDATE"#,
+            ),
+        ];
+
+        for (query, expected) in cases {
+            assert_eq!(restore(query), expected, "{}", query);
+        }
+
+        let query = r#"SELECT * FROM t FOR SYSTEM_TIME AS OF TRANSACTION 42"#;
+        let tokens = tokenize_sql(query).unwrap();
+        assert!(parse_sql(&tokens, Dialect::MySQL80).is_err());
+        let select = match parse_sql(&tokens, Dialect::MariaDB10).unwrap() {
+            Statement::SelectStmt(select) => select,
+            stmt => panic!("unexpected statement: {:?}", stmt),
+        };
+        let source = match *select.from.unwrap().left {
+            ResultSetNode::TableSource(source) => source,
+            node => panic!("unexpected node: {:?}", node),
+        };
+        match *source.source {
+            ResultSetNode::TableName(name) => match name.system_time.as_deref() {
+                Some(SystemTimeClause::AsOf(point)) => assert!(point.transaction),
+                clause => panic!("unexpected clause: {:?}", clause),
+            },
+            node => panic!("unexpected node: {:?}", node),
+        }
+    }
+
+    #[test]
+    fn set_opr_all_test() {
+        let querys = vec![
+            r#"SELECT a FROM t EXCEPT ALL SELECT a FROM s"#,
+            r#"SELECT a FROM t INTERSECT ALL SELECT a FROM s"#,
+        ];
+
+        for query in querys {
+            let tokens = tokenize_sql(query).unwrap();
+            let stmt = parse_sql(&tokens, Dialect::MariaDB10);
+            assert!(stmt.is_ok(), "{}: {:?}", query, stmt);
+            let stmt = parse_sql(&tokens, Dialect::TiDB);
+            assert!(stmt.is_err(), "{}: {:?}", query, stmt);
+        }
+    }
+}
@@ -0,0 +1,232 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr_node::ExprNode;
+    use crate::ast::handler_stmt::{HandlerReadDirection, HandlerStmtType};
+    use crate::ast::limit::Limit;
+    use crate::ast::op_code::OpCode;
+    use crate::ast::result_set_node::ResultSetNode;
+    use crate::ast::select_stmt::{SelectStmt, SelectStmtKind};
+    use crate::ast::statement::Statement;
+    use crate::parser::input::Dialect;
+    use crate::parser::parser::{parse_sql, tokenize_sql};
+
+    fn parse(query: &str) -> Statement {
+        let tokens = tokenize_sql(query).unwrap();
+        let stmt = parse_sql(&tokens, Dialect::MySQL80);
+        assert!(stmt.is_ok(), "{}: {:?}", query, stmt);
+        stmt.unwrap()
+    }
+
+    fn parse_select(query: &str) -> SelectStmt {
+        match parse(query) {
+            Statement::SelectStmt(select) => *select,
+            stmt => panic!("{}: {:?}", query, stmt),
+        }
+    }
+
+    // Returns the text of a value, e.g. the count of a limit.
+    fn value_text(expr: &ExprNode) -> &str {
+        match expr {
+            ExprNode::ValueExpr(value) => &value.s,
+            expr => panic!("unexpected expression: {:?}", expr),
+        }
+    }
+
+    // Returns the count and the offset of a limit, the offset is None if it is
+    // omitted.
+    fn limit_values(limit: &Limit) -> (&str, Option<&str>) {
+        let offset = match limit.offset.as_ref() {
+            ExprNode::Default => None,
+            offset => Some(value_text(offset)),
+        };
+        (value_text(&limit.count), offset)
+    }
+
+    #[test]
+    fn call_stmt_test() {
+        // (query, schema, procedure, arg count)
+        let cases = vec![
+            (r#"CALL proc"#, "", "proc", 0),
+            (r#"CALL db1.proc()"#, "db1", "proc", 0),
+            (r#"CALL proc(1, @a, 'x')"#, "", "proc", 3),
+        ];
+
+        for (query, schema, name, arg_count) in cases {
+            let procedure = match parse(query) {
+                Statement::CallStmt(stmt) => stmt.procedure.unwrap(),
+                stmt => panic!("{}: {:?}", query, stmt),
+            };
+            assert_eq!(procedure.schema.origin, schema, "{}", query);
+            assert_eq!(procedure.fn_name.origin, name, "{}", query);
+            assert_eq!(procedure.args.len(), arg_count, "{}", query);
+        }
+    }
+
+    #[test]
+    fn do_stmt_test() {
+        let cases = vec![(r#"DO 1"#, 1), (r#"DO sleep(1), @a"#, 2)];
+
+        for (query, expr_count) in cases {
+            match parse(query) {
+                Statement::DoStmt(stmt) => assert_eq!(stmt.exprs.len(), expr_count, "{}", query),
+                stmt => panic!("{}: {:?}", query, stmt),
+            }
+        }
+    }
+
+    #[test]
+    fn handler_stmt_test() {
+        // (query, type, alias, index, read direction, op, value count, has where, limit)
+        let cases = vec![
+            (
+                r#"HANDLER t1 OPEN"#,
+                HandlerStmtType::Open,
+                "",
+                "",
+                None,
+                None,
+                0,
+                false,
+                None,
+            ),
+            (
+                r#"HANDLER t1 OPEN AS h1"#,
+                HandlerStmtType::Open,
+                "h1",
+                "",
+                None,
+                None,
+                0,
+                false,
+                None,
+            ),
+            (
+                r#"HANDLER t1 READ FIRST"#,
+                HandlerStmtType::Read,
+                "",
+                "",
+                Some(HandlerReadDirection::First),
+                None,
+                0,
+                false,
+                None,
+            ),
+            (
+                r#"HANDLER t1 READ NEXT WHERE a = 1 LIMIT 10"#,
+                HandlerStmtType::Read,
+                "",
+                "",
+                Some(HandlerReadDirection::Next),
+                None,
+                0,
+                true,
+                Some(("10", None)),
+            ),
+            (
+                r#"HANDLER t1 READ idx1 PREV"#,
+                HandlerStmtType::Read,
+                "",
+                "idx1",
+                Some(HandlerReadDirection::Prev),
+                None,
+                0,
+                false,
+                None,
+            ),
+            (
+                r#"HANDLER t1 READ idx1 >= (1, 2) LIMIT 5"#,
+                HandlerStmtType::Read,
+                "",
+                "idx1",
+                None,
+                Some(OpCode::GE),
+                2,
+                false,
+                Some(("5", None)),
+            ),
+            (
+                r#"HANDLER t1 CLOSE"#,
+                HandlerStmtType::Close,
+                "",
+                "",
+                None,
+                None,
+                0,
+                false,
+                None,
+            ),
+        ];
+
+        for (query, tp, as_name, index_name, direction, op, value_count, has_where, limit) in cases
+        {
+            let stmt = match parse(query) {
+                Statement::HandlerStmt(stmt) => stmt,
+                stmt => panic!("{}: {:?}", query, stmt),
+            };
+            assert_eq!(stmt.table.name.origin, "t1", "{}", query);
+            assert_eq!(stmt.tp, tp, "{}", query);
+            assert_eq!(stmt.as_name.origin, as_name, "{}", query);
+            assert_eq!(stmt.index_name.origin, index_name, "{}", query);
+            assert_eq!(stmt.read_direction, direction, "{}", query);
+            assert_eq!(stmt.op, op, "{}", query);
+            assert_eq!(stmt.values.len(), value_count, "{}", query);
+            assert_eq!(stmt.where_clause.is_some(), has_where, "{}", query);
+            assert_eq!(stmt.limit.as_ref().map(limit_values), limit, "{}", query);
+        }
+    }
+
+    #[test]
+    fn table_stmt_test() {
+        let select = parse_select(r#"TABLE t1"#);
+        assert_eq!(select.kind, SelectStmtKind::SelectStmtKindTable);
+        assert!(select.order_by.is_none());
+        assert!(select.limit.is_none());
+
+        let query = r#"TABLE db1.t1 ORDER BY a LIMIT 10 OFFSET 2"#;
+        let select = parse_select(query);
+        assert_eq!(select.kind, SelectStmtKind::SelectStmtKindTable);
+        let table = match select.from.as_ref().map(|from| from.left.as_ref()) {
+            Some(ResultSetNode::TableSource(source)) => match source.source.as_ref() {
+                ResultSetNode::TableName(table) => table.clone(),
+                source => panic!("{}: {:?}", query, source),
+            },
+            from => panic!("{}: {:?}", query, from),
+        };
+        assert_eq!(table.schema.origin, "db1");
+        assert_eq!(table.name.origin, "t1");
+        assert_eq!(select.order_by.as_ref().unwrap().items.len(), 1);
+        assert_eq!(
+            select.limit.as_deref().map(limit_values),
+            Some(("10", Some("2")))
+        );
+    }
+
+    #[test]
+    fn values_stmt_test() {
+        // (query, row count, limit)
+        let cases = vec![
+            (r#"VALUES ROW(1, 2), ROW(3, 4)"#, 2, None),
+            (r#"VALUES ROW(1, 2) LIMIT 1"#, 1, Some(("1", None))),
+        ];
+
+        for (query, row_count, limit) in cases {
+            let select = parse_select(query);
+            assert_eq!(
+                select.kind,
+                SelectStmtKind::SelectStmtKindValues,
+                "{}",
+                query
+            );
+            assert_eq!(select.lists.len(), row_count, "{}", query);
+            for row in &select.lists {
+                assert_eq!(row.values.len(), 2, "{}", query);
+            }
+            assert_eq!(
+                select.limit.as_deref().map(limit_values),
+                limit,
+                "{}",
+                query
+            );
+        }
+    }
+}
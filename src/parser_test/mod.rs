@@ -1 +1,20 @@
-mod select_parser_test;
\ No newline at end of file
+mod dialect_parser_test;
+mod expression_parser_test;
+mod format_parser_test;
+mod json_parser_test;
+mod json_table_parser_test;
+mod literal_parser_test;
+mod mariadb_parser_test;
+mod misc_dml_parser_test;
+mod restore_parser_test;
+mod script_parser_test;
+mod select_parser_test;
+mod span_parser_test;
+mod sql_mode_parser_test;
+mod structural_parser_test;
+mod table_hints_parser_test;
+mod table_refs_parser_test;
+mod tidb_parser_test;
+mod tokenizer_test;
+mod variable_parser_test;
+mod visitor_parser_test;
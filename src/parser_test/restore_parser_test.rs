@@ -0,0 +1,213 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::restore::{
+        restore_sql, restore_sql_with_dialect, restore_sql_with_sql_mode, RestoreFlags,
+        DEFAULT_RESTORE_FLAGS, RESTORE_KEYWORD_LOWERCASE, RESTORE_KEYWORD_UPPERCASE,
+        RESTORE_NAME_QUOTES_WHEN_NEEDED, RESTORE_STRING_DOUBLE_QUOTES,
+        RESTORE_STRING_SINGLE_QUOTES, RESTORE_STRING_WITHOUT_CHARSET, RESTORE_WITHOUT_INDEX_HINTS,
+        RESTORE_WITHOUT_OPTIMIZER_HINTS,
+    };
+    use crate::ast::statement::Statement;
+    use crate::ast::structural::StructuralEq;
+    use crate::mysql::consts::{SqlMode, MODE_NO_BACKSLASH_ESCAPES};
+    use crate::parser::input::Dialect;
+    use crate::parser::parser::{
+        parse_sql, parse_sql_with_sql_mode, tokenize_sql, tokenize_sql_with_sql_mode,
+    };
+    use crate::parser::token::DEFAULT_SERVER_VERSION;
+
+    fn parse(query: &str, dialect: Dialect) -> Statement {
+        let tokens = tokenize_sql(query).unwrap();
+        let stmt = parse_sql(&tokens, dialect);
+        assert!(stmt.is_ok(), "{}: {:?}", query, stmt);
+        stmt.unwrap()
+    }
+
+    fn restore_with_dialect(query: &str, dialect: Dialect, flags: RestoreFlags) -> String {
+        let sql =
+            restore_sql_with_dialect(&parse(query, dialect), flags, dialect, SqlMode::default());
+        assert!(sql.is_ok(), "{}: {:?}", query, sql);
+        sql.unwrap()
+    }
+
+    fn restore(query: &str, flags: RestoreFlags) -> String {
+        restore_with_dialect(query, Dialect::TiDB, flags)
+    }
+
+    // check_round_trip parses the restored query again, it must give the same
+    // statement and restore to the same text.
+    fn check_round_trip(query: &str, dialect: Dialect) {
+        let restored = restore_with_dialect(query, dialect, DEFAULT_RESTORE_FLAGS);
+        let stmt = parse(query, dialect);
+        let restored_stmt = parse(&restored, dialect);
+        assert!(
+            stmt.structural_eq(&restored_stmt),
+            "{}\n{}\n{:?}\n{:?}",
+            query,
+            restored,
+            stmt,
+            restored_stmt
+        );
+        assert_eq!(
+            restore_with_dialect(&restored, dialect, DEFAULT_RESTORE_FLAGS),
+            restored,
+            "{}",
+            query
+        );
+    }
+
+    #[test]
+    fn restore_round_trip_test() {
+        let querys = vec![
+            r#"SELECT a + 1 AS x, b FROM t WHERE a = 1 AND b IS NOT NULL"#,
+            r#"SELECT DISTINCT SQL_CALC_FOUND_ROWS t.*, db.t.a FROM db.t"#,
+            r#"SELECT -a, - -a, !a, ~a, NOT a FROM t"#,
+            r#"SELECT @a := 1, @`b c`, @@sql_mode, @@GLOBAL.sql_mode"#,
+            r#"SELECT @@SESSION.a, @@PERSIST.b, @@PERSIST_ONLY.c"#,
+            r#"SELECT a FROM t WHERE a IN (1, 2, 3) AND b NOT IN (SELECT b FROM t2)"#,
+            r#"SELECT a FROM t WHERE a BETWEEN 1 AND 10 OR a NOT BETWEEN b AND c"#,
+            r#"SELECT a FROM t WHERE a LIKE 'x%' AND b NOT LIKE 'y|%' ESCAPE '|' AND c ILIKE 'z'"#,
+            r#"SELECT a FROM t WHERE a REGEXP '^x' AND b NOT RLIKE 'y$'"#,
+            r#"SELECT a IS TRUE, a IS NOT FALSE, EXISTS (SELECT 1), NOT EXISTS (SELECT 2)"#,
+            r#"SELECT a FROM t WHERE a >= ANY (SELECT b FROM t2) AND a <> ALL (SELECT c FROM t3)"#,
+            r#"SELECT CASE a WHEN 1 THEN 'one' ELSE 'many' END, CASE WHEN a THEN b END"#,
+            r#"SELECT CAST(a AS SIGNED), CAST(a AS DECIMAL(10, 2)), CAST(a AS CHAR(10) CHARACTER SET utf8mb4)"#,
+            r#"SELECT CAST(a AS BINARY(4)), CAST(a AS DATETIME(6)), CONVERT(a, CHAR), BINARY a"#,
+            r#"SELECT CONVERT(a USING utf8mb4), CAST(j AS UNSIGNED ARRAY), 1 MEMBER OF ('[1]')"#,
+            r#"SELECT c->'$.a', c->>'$.b' FROM t"#,
+            r#"SELECT _utf8mb4'abc', 'it''s', 0x0A0B, b'0101', 1.50, 1e3, NULL, TRUE"#,
+            r#"SELECT N'abc' 'd', _latin1 X'E9', _binary 0xFF, _binary B'101'"#,
+            r#"SELECT _utf8mb4'\\', 'a\\b', 'a\'b', 'a\nb\rc\0d\Ze\tf', 'a\%b'"#,
+            r#"SELECT DATE '2020-01-01', TIMESTAMP '2020-01-01 00:00:00', d + INTERVAL 1 DAY"#,
+            r#"SELECT DATE_ADD(d, INTERVAL 1 HOUR), ADDDATE(d, 2), EXTRACT(YEAR FROM d)"#,
+            r#"SELECT TRIM(a), TRIM('x' FROM a), TRIM(LEADING 'x' FROM a), POSITION('a' IN b)"#,
+            r#"SELECT COUNT(*), COUNT(DISTINCT a), GROUP_CONCAT(a ORDER BY b SEPARATOR ',') FROM t"#,
+            r#"SELECT ROW_NUMBER() OVER (PARTITION BY a ORDER BY b DESC ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM t"#,
+            r#"SELECT a FROM t GROUP BY a WITH ROLLUP HAVING COUNT(*) > 1 ORDER BY 1 LIMIT 1, 10"#,
+            r#"SELECT * FROM t1, t2 JOIN t3 ON t2.a = t3.a"#,
+            r#"SELECT * FROM t1 LEFT JOIN t2 USING (a) NATURAL JOIN t3 STRAIGHT_JOIN t4 ON t3.b = t4.b"#,
+            r#"SELECT * FROM (t1, t2) JOIN (SELECT a FROM t3) AS dt ON dt.a = t1.a"#,
+            r#"SELECT * FROM t AS x USE INDEX (i1) IGNORE INDEX FOR ORDER BY (PRIMARY)"#,
+            r#"SELECT /*+ HASH_JOIN(t1, t2) MAX_EXECUTION_TIME(1000) */ * FROM t1, t2"#,
+            r#"SELECT /*+ USE_INDEX(t1, idx1, PRIMARY), NO_INDEX(t2) */ * FROM t1, t2"#,
+            r#"WITH RECURSIVE cte (n) AS (SELECT 1) SELECT n FROM cte"#,
+            r#"SELECT a FROM t FOR UPDATE NOWAIT"#,
+            r#"TABLE db1.t1 ORDER BY a LIMIT 10"#,
+            r#"VALUES ROW(1, 2), ROW(3, 4)"#,
+            r#"CALL db1.proc(1, @a, 'x')"#,
+            r#"DO sleep(1), @a"#,
+            r#"HANDLER t1 READ idx1 >= (1, 2) WHERE a = 1 LIMIT 5"#,
+            r#"CREATE SEQUENCE IF NOT EXISTS db.s START WITH 100 INCREMENT BY 10 NOCACHE"#,
+            r#"DROP SEQUENCE IF EXISTS s1, db.s2"#,
+            r#"ADMIN SHOW DDL JOBS 5 WHERE state != 'synced'"#,
+            r#"CREATE GLOBAL BINDING FOR SELECT * FROM t USING SELECT * FROM t USE INDEX (idx)"#,
+            r#"SHOW BINDINGS LIKE 'select%'"#,
+            r#"SPLIT TABLE t INDEX idx BETWEEN (1) AND (100) REGIONS 10"#,
+            r#"FLASHBACK TABLE t1, t2 TO TIMESTAMP '2022-01-01 00:00:00'"#,
+            r#"CREATE PLACEMENT POLICY IF NOT EXISTS p PRIMARY_REGION = 'us-east-1' FOLLOWERS = 2"#,
+            r#"SET CONFIG tikv log.level = 'info'"#,
+        ];
+
+        for query in querys {
+            check_round_trip(query, Dialect::TiDB);
+        }
+
+        // LATERAL and JSON_TABLE are MySQL 8.0 only, and the hints are written
+        // in the MySQL syntax.
+        let querys = vec![
+            r#"SELECT /*+ INDEX(t1 idx1, PRIMARY) BNL(t1, t2) */ * FROM t1, t2"#,
+            r#"SELECT * FROM t1, LATERAL (SELECT a FROM t2 WHERE t2.a = t1.a) AS dt"#,
+            r#"SELECT * FROM JSON_TABLE(t.doc, '$[*]' COLUMNS (id FOR ORDINALITY, a INT PATH '$.a' NULL ON ERROR)) AS jt"#,
+        ];
+        for query in querys {
+            check_round_trip(query, Dialect::MySQL80);
+        }
+
+        check_round_trip(
+            r#"CREATE OR REPLACE TEMPORARY SEQUENCE IF NOT EXISTS db.s START WITH 100 INCREMENT BY 10 NOCACHE"#,
+            Dialect::MariaDB10,
+        );
+    }
+
+    #[test]
+    fn restore_flags_test() {
+        let lower_flags = RESTORE_KEYWORD_LOWERCASE
+            | RESTORE_STRING_DOUBLE_QUOTES
+            | RESTORE_NAME_QUOTES_WHEN_NEEDED;
+        let when_needed_flags = RESTORE_KEYWORD_UPPERCASE
+            | RESTORE_STRING_SINGLE_QUOTES
+            | RESTORE_NAME_QUOTES_WHEN_NEEDED;
+        let cases = vec![
+            (
+                r#"select a from t where b = 'x'"#,
+                DEFAULT_RESTORE_FLAGS,
+                r#"SELECT `a` FROM `t` WHERE `b` = 'x'"#,
+            ),
+            (
+                r#"SELECT a FROM t WHERE b = 'x'"#,
+                lower_flags,
+                r#"select a from t where b = "x""#,
+            ),
+            (
+                r#"SELECT `select`, `a b`, c FROM t"#,
+                when_needed_flags,
+                r#"SELECT `select`, `a b`, c FROM t"#,
+            ),
+            (
+                r#"SELECT /*+ HASH_JOIN(t1) */ a FROM t1 USE INDEX (i)"#,
+                DEFAULT_RESTORE_FLAGS
+                    | RESTORE_WITHOUT_OPTIMIZER_HINTS
+                    | RESTORE_WITHOUT_INDEX_HINTS,
+                r#"SELECT `a` FROM `t1`"#,
+            ),
+            (
+                r#"SELECT _utf8mb4'a'"#,
+                DEFAULT_RESTORE_FLAGS | RESTORE_STRING_WITHOUT_CHARSET,
+                r#"SELECT 'a'"#,
+            ),
+            (
+                r#"SELECT N'a', _binary 0xFF"#,
+                DEFAULT_RESTORE_FLAGS,
+                r#"SELECT N'a', _BINARY 0xFF"#,
+            ),
+            (
+                r#"SELECT N'a', _binary 0xFF"#,
+                DEFAULT_RESTORE_FLAGS | RESTORE_STRING_WITHOUT_CHARSET,
+                r#"SELECT 'a', 0xFF"#,
+            ),
+        ];
+
+        for (query, flags, expected) in cases {
+            assert_eq!(restore(query, flags), expected, "{}", query);
+        }
+    }
+
+    #[test]
+    fn restore_string_escape_test() {
+        let cases = vec![
+            (r#"SELECT _utf8mb4'\\'"#, r#"SELECT _UTF8MB4'\\'"#),
+            (r#"SELECT 'a\\b', 'a\'b'"#, r#"SELECT 'a\\b', 'a''b'"#),
+            (
+                r#"SELECT 'a\nb\rc\0d\Ze\tf'"#,
+                "SELECT 'a\\nb\\rc\\0d\\Ze\tf'",
+            ),
+        ];
+        for (query, expected) in cases {
+            assert_eq!(restore(query, DEFAULT_RESTORE_FLAGS), expected, "{}", query);
+        }
+
+        // A backslash is an ordinary character with NO_BACKSLASH_ESCAPES, it's
+        // escaped only for a server without it.
+        let query = r#"SELECT 'a\b'"#;
+        let tokens =
+            tokenize_sql_with_sql_mode(query, DEFAULT_SERVER_VERSION, MODE_NO_BACKSLASH_ESCAPES)
+                .unwrap();
+        let (stmt, _) =
+            parse_sql_with_sql_mode(&tokens, Dialect::TiDB, MODE_NO_BACKSLASH_ESCAPES).unwrap();
+        let sql =
+            restore_sql_with_sql_mode(&stmt, DEFAULT_RESTORE_FLAGS, MODE_NO_BACKSLASH_ESCAPES);
+        assert_eq!(sql.unwrap(), r#"SELECT 'a\b'"#);
+        let sql = restore_sql(&stmt, DEFAULT_RESTORE_FLAGS);
+        assert_eq!(sql.unwrap(), r#"SELECT 'a\\b'"#);
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::statement::Statement;
+    use crate::mysql::consts::{MODE_ANSI_QUOTES, MODE_NO_BACKSLASH_ESCAPES};
+    use crate::parser::input::Dialect;
+    use crate::parser::script::{parse_script, parse_script_with_sql_mode, ScriptSplitter};
+
+    fn segment_texts(splitter: ScriptSplitter<'_>) -> Vec<&str> {
+        let source = splitter.source();
+        splitter
+            .map(|segment| &source[std::ops::Range::from(segment.span)])
+            .collect()
+    }
+
+    #[test]
+    fn split_script_test() {
+        let script = r#"
+-- a dump header; with a semicolon
+SELECT 'a;b', "c;d", `e;f`;;
+/* a block comment; */
+DELIMITER //
+CREATE PROCEDURE p() BEGIN SELECT 1; SELECT 2; END//
+delimiter ;
+SELECT 3 # trailing; comment
+;
+SELECT 4"#;
+
+        let segments = ScriptSplitter::new(script)
+            .map(|segment| {
+                (
+                    &script[std::ops::Range::from(segment.span)],
+                    segment.delimiter,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            segments,
+            vec![
+                (r#"SELECT 'a;b', "c;d", `e;f`"#, ";".to_string()),
+                (
+                    "CREATE PROCEDURE p() BEGIN SELECT 1; SELECT 2; END",
+                    "//".to_string()
+                ),
+                ("SELECT 3 # trailing; comment", ";".to_string()),
+                ("SELECT 4", "".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_script_test() {
+        let script = "SELECT 1;\nSELECT FROM;\nDO 2;\n/* only a comment */;";
+
+        let stmts = parse_script(script, Dialect::MySQL80).collect::<Vec<_>>();
+        assert_eq!(stmts.len(), 3);
+        assert!(stmts[0].stmt.is_ok(), "{:?}", stmts[0]);
+        assert!(stmts[1].stmt.is_err());
+        assert_eq!(&script[std::ops::Range::from(stmts[1].span)], "SELECT FROM");
+        assert!(stmts[2].stmt.is_ok(), "{:?}", stmts[2]);
+        assert!(matches!(stmts[0].stmt, Ok(Statement::SelectStmt(_))));
+        assert!(matches!(stmts[2].stmt, Ok(Statement::DoStmt(_))));
+        assert_eq!(&script[std::ops::Range::from(stmts[2].span)], "DO 2");
+    }
+
+    #[test]
+    fn split_script_sql_mode_test() {
+        // `\'` escapes the quote by default, the string goes on to the end.
+        let script = r#"SELECT 'a\'; SELECT 'b'; SELECT "c\"; SELECT 1"#;
+        assert_eq!(segment_texts(ScriptSplitter::new(script)), vec![script]);
+        assert_eq!(
+            segment_texts(ScriptSplitter::with_sql_mode(
+                script,
+                MODE_NO_BACKSLASH_ESCAPES
+            )),
+            vec![
+                r#"SELECT 'a\'"#,
+                r#"SELECT 'b'"#,
+                r#"SELECT "c\""#,
+                r#"SELECT 1"#,
+            ]
+        );
+
+        // `"` quotes identifiers with ANSI_QUOTES, `\` doesn't escape in them.
+        let script = r#"SELECT "c\"; SELECT 'd\'; SELECT 2'; SELECT 3"#;
+        assert_eq!(
+            segment_texts(ScriptSplitter::with_sql_mode(script, MODE_ANSI_QUOTES)),
+            vec![r#"SELECT "c\""#, r#"SELECT 'd\'; SELECT 2'"#, "SELECT 3"]
+        );
+
+        let script = r#"SELECT 'a\'; SELECT 'b';"#;
+        let stmts = parse_script_with_sql_mode(script, Dialect::MySQL80, MODE_NO_BACKSLASH_ESCAPES)
+            .collect::<Vec<_>>();
+        assert_eq!(stmts.len(), 2);
+        for stmt in &stmts {
+            assert!(stmt.stmt.is_ok(), "{:?}", stmt);
+        }
+    }
+}
@@ -1,11 +1,14 @@
 #[cfg(test)]
 mod tests {
+    use crate::ast::select_field::Field;
+    use crate::ast::statement::Statement;
+    use crate::mysql::consts::PriorityEnum;
     use crate::parser::input::Dialect;
     use crate::parser::parser::{parse_sql, tokenize_sql};
 
     #[test]
     fn select_basic_01_test() {
-        let querys = vec![
+        let querys = [
             r#"
         SELECT
             "#,
@@ -33,10 +36,43 @@ mod tests {
             "#,
         ];
 
+        // A SELECT needs at least one field.
+        for query in &querys[..4] {
+            let tokens = tokenize_sql(query).unwrap();
+            assert!(parse_sql(&tokens, Dialect::MySQL80).is_err(), "{}", query);
+        }
+
         let query = querys[querys.len() - 1];
         let tokens = tokenize_sql(query).unwrap();
-        let stmt = parse_sql(&tokens, Dialect::MySQL);
+        let select = match parse_sql(&tokens, Dialect::MySQL80) {
+            Ok(Statement::SelectStmt(select)) => select,
+            stmt => panic!("{}: {:?}", query, stmt),
+        };
+
+        let opts = &select.select_stmt_opts;
+        assert!(opts.distinct);
+        // ALL in front of DISTINCT is folded into DISTINCT.
+        assert!(!opts.explicit_all);
+        assert_eq!(opts.priority, PriorityEnum::HighPriority);
+        assert!(opts.sql_small_result);
+        assert!(opts.sql_big_result);
+        assert!(opts.sql_buffer_result);
+        assert!(!opts.sql_cache);
+        assert!(opts.calc_found_rows);
+        assert!(opts.straight_join);
+        assert!(opts.table_hints.is_empty());
 
-        println!("{:#?}", stmt)
+        let fields = select
+            .fields
+            .iter()
+            .map(|field| match &field.field {
+                Field::WildCardField(wildcard) => (
+                    wildcard.schema.origin.as_str(),
+                    wildcard.table.origin.as_str(),
+                ),
+                field => panic!("unexpected field: {:?}", field),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(fields, vec![("db1", "tb1"), ("", "tb1"), ("", "")]);
     }
 }
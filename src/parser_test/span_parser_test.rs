@@ -0,0 +1,182 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr_node::ExprNode;
+    use crate::ast::node::Node;
+    use crate::ast::result_set_node::ResultSetNode;
+    use crate::ast::select_field::Field;
+    use crate::ast::select_stmt::SelectStmt;
+    use crate::ast::spanned::Spanned;
+    use crate::ast::statement::Statement;
+    use crate::common::span::Span;
+    use crate::parser::input::Dialect;
+    use crate::parser::parser::{parse_sql, tokenize_sql};
+
+    fn parse_select(query: &str) -> Box<SelectStmt> {
+        let tokens = tokenize_sql(query).unwrap();
+        match parse_sql(&tokens, Dialect::MySQL80).unwrap() {
+            Statement::SelectStmt(select) => select,
+            stmt => panic!("unexpected statement: {:?}", stmt),
+        }
+    }
+
+    fn first_field_expr(select: &SelectStmt) -> &ExprNode {
+        match &select.fields[0].field {
+            Field::Expr(expr) => expr,
+            field => panic!("unexpected field: {:?}", field),
+        }
+    }
+
+    // Returns the source text covered by the span.
+    fn text(query: &str, span: Span) -> &str {
+        let range = span.expect("span is missing");
+        &query[range.start()..range.end()]
+    }
+
+    #[test]
+    fn statement_span_test() {
+        let querys = vec![
+            (r#"SELECT a FROM t;"#, "SELECT a FROM t"),
+            (r#"  SELECT 1  "#, "SELECT 1"),
+            (
+                r#"SELECT * FROM t1 UNION SELECT * FROM t2"#,
+                "SELECT * FROM t1 UNION SELECT * FROM t2",
+            ),
+        ];
+
+        for (query, expected) in querys {
+            let tokens = tokenize_sql(query).unwrap();
+            let stmt = parse_sql(&tokens, Dialect::MySQL80);
+            assert!(stmt.is_ok(), "{}: {:?}", query, stmt);
+            let stmt = stmt.unwrap();
+            assert_eq!(text(query, stmt.span()), expected, "{}", query);
+        }
+    }
+
+    #[test]
+    fn expression_span_test() {
+        let query = r#"SELECT a + 1 AS x, b FROM t WHERE a = 1 IS NULL"#;
+        let select = parse_select(query);
+        assert_eq!(text(query, select.fields[0].span), "a + 1 AS x");
+        assert_eq!(text(query, select.fields[1].span), "b");
+
+        let expr = first_field_expr(&select);
+        assert_eq!(text(query, expr.span()), "a + 1");
+        match expr {
+            ExprNode::BinaryOperationExpr(expr) => {
+                assert_eq!(text(query, expr.l.as_ref().unwrap().span()), "a");
+                assert_eq!(text(query, expr.r.as_ref().unwrap().span()), "1");
+            }
+            expr => panic!("unexpected expression: {:?}", expr),
+        }
+
+        let where_clause = select.where_clause.as_ref().unwrap();
+        assert_eq!(text(query, where_clause.span()), "a = 1 IS NULL");
+        match where_clause {
+            ExprNode::IsNullExpr(expr) => {
+                assert_eq!(text(query, expr.expr.as_ref().unwrap().span()), "a = 1");
+            }
+            expr => panic!("unexpected expression: {:?}", expr),
+        }
+    }
+
+    #[test]
+    fn desugared_expression_span_test() {
+        let query = r#"SELECT c->>'$.a' FROM t"#;
+        let select = parse_select(query);
+        let unquote = match first_field_expr(&select) {
+            ExprNode::FuncCallExpr(unquote) => unquote,
+            expr => panic!("unexpected expression: {:?}", expr),
+        };
+        assert_eq!(text(query, unquote.span), "c->>'$.a'");
+        let extract = match &unquote.args[0] {
+            ExprNode::FuncCallExpr(extract) => extract,
+            expr => panic!("unexpected expression: {:?}", expr),
+        };
+        assert_eq!(text(query, extract.span), "c->>'$.a'");
+        assert_eq!(text(query, extract.args[0].span()), "c");
+        assert_eq!(text(query, extract.args[1].span()), "'$.a'");
+
+        let query = r#"SELECT d + INTERVAL 1 DAY"#;
+        let select = parse_select(query);
+        let date_add = match first_field_expr(&select) {
+            ExprNode::FuncCallExpr(date_add) => date_add,
+            expr => panic!("unexpected expression: {:?}", expr),
+        };
+        assert_eq!(text(query, date_add.span), "d + INTERVAL 1 DAY");
+        assert_eq!(text(query, date_add.args[2].span()), "DAY");
+    }
+
+    #[test]
+    fn table_refs_span_test() {
+        let query = r#"SELECT * FROM t1 AS a JOIN db.t2 ON a.id = t2.id"#;
+        let select = parse_select(query);
+        let join = select.from.as_ref().unwrap();
+        assert_eq!(text(query, join.span), "t1 AS a JOIN db.t2 ON a.id = t2.id");
+        assert_eq!(text(query, join.left.span()), "t1 AS a");
+        assert_eq!(text(query, join.right.as_ref().unwrap().span()), "db.t2");
+        assert_eq!(text(query, join.on.span()), "a.id = t2.id");
+        match join.right.as_deref() {
+            Some(ResultSetNode::TableSource(source)) => {
+                assert_eq!(text(query, source.source.span()), "db.t2");
+            }
+            node => panic!("unexpected node: {:?}", node),
+        }
+    }
+
+    #[test]
+    fn order_by_position_span_test() {
+        let query = r#"SELECT a, b FROM t ORDER BY 2 DESC, a"#;
+        let select = parse_select(query);
+        let order_by = select.order_by.as_ref().unwrap();
+        assert_eq!(text(query, order_by.items[0].span), "2 DESC");
+        match order_by.items[0].expr.as_deref() {
+            Some(ExprNode::PositionExpr(position)) => {
+                assert_eq!(position.n, 2);
+                assert_eq!(text(query, position.span), "2");
+            }
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+    }
+
+    #[test]
+    fn window_spec_span_test() {
+        let query =
+            r#"SELECT SUM(a) OVER (PARTITION BY b ORDER BY c ROWS UNBOUNDED PRECEDING) FROM t"#;
+        let select = parse_select(query);
+        let spec = match first_field_expr(&select) {
+            ExprNode::WindowFuncExpr(func) => func.spec.as_ref().unwrap(),
+            expr => panic!("unexpected expr: {:?}", expr),
+        };
+        let partition_by = spec.partition_by.as_ref().unwrap();
+        assert_eq!(text(query, partition_by.span), "PARTITION BY b");
+        assert_eq!(text(query, partition_by.items[0].span), "b");
+        let order_by = spec.order_by.as_ref().unwrap();
+        assert_eq!(text(query, order_by.span), "ORDER BY c");
+        let frame = spec.frame.as_ref().unwrap();
+        assert_eq!(text(query, frame.span), "ROWS UNBOUNDED PRECEDING");
+    }
+
+    #[test]
+    fn set_opr_select_list_span_test() {
+        let query = r#"(SELECT a FROM t UNION SELECT b FROM u) UNION ALL SELECT c FROM v"#;
+        let tokens = tokenize_sql(query).unwrap();
+        let stmt = match parse_sql(&tokens, Dialect::MySQL80).unwrap() {
+            Statement::SetOprStmt(stmt) => stmt,
+            stmt => panic!("unexpected statement: {:?}", stmt),
+        };
+        let list = stmt.select_list.as_ref().unwrap();
+        assert_eq!(
+            text(query, list.span),
+            "(SELECT a FROM t UNION SELECT b FROM u) UNION ALL SELECT c FROM v"
+        );
+        match &list.selects[0] {
+            Node::SetOprSelectList(nested) => {
+                assert_eq!(
+                    text(query, nested.span),
+                    "(SELECT a FROM t UNION SELECT b FROM u)"
+                );
+            }
+            node => panic!("unexpected node: {:?}", node),
+        }
+    }
+}
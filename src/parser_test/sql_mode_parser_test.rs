@@ -0,0 +1,183 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr_node::ExprNode;
+    use crate::ast::field_type::DataType;
+    use crate::ast::op_code::OpCode;
+    use crate::ast::select_field::Field;
+    use crate::ast::statement::Statement;
+    use crate::common::error::Result;
+    use crate::mysql::consts::{
+        SqlMode, MODE_ANSI, MODE_ANSI_QUOTES, MODE_HIGH_NOT_PRECEDENCE, MODE_IGNORE_SPACE,
+        MODE_NONE, MODE_NO_ZERO_DATE, MODE_ONLY_FULL_GROUP_BY, MODE_PIPES_AS_CONCAT,
+        MODE_REAL_AS_FLOAT, MODE_STRICT_TRANS_TABLES, MODE_TRADITIONAL,
+    };
+    use crate::parser::input::Dialect;
+    use crate::parser::parser::{parse_sql_with_sql_mode, tokenize_sql_with_sql_mode};
+    use crate::parser::token::DEFAULT_SERVER_VERSION;
+
+    fn parse_with_sql_mode(query: &str, sql_mode: SqlMode) -> Result<Statement> {
+        let tokens = tokenize_sql_with_sql_mode(query, DEFAULT_SERVER_VERSION, sql_mode)?;
+        parse_sql_with_sql_mode(&tokens, Dialect::MySQL80, sql_mode).map(|(stmt, _)| stmt)
+    }
+
+    // Returns the expression of the first field of a SELECT.
+    fn first_field_expr(query: &str, sql_mode: SqlMode) -> ExprNode {
+        let mut select = match parse_with_sql_mode(query, sql_mode).unwrap() {
+            Statement::SelectStmt(select) => select,
+            _ => unreachable!(),
+        };
+        match select.fields.remove(0).field {
+            Field::Expr(expr) => expr,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn sql_mode_str_test() {
+        let sql_mode = SqlMode::from_mode_str("ansi, TRADITIONAL").unwrap();
+        for mode in [
+            MODE_ANSI,
+            MODE_REAL_AS_FLOAT,
+            MODE_PIPES_AS_CONCAT,
+            MODE_ANSI_QUOTES,
+            MODE_IGNORE_SPACE,
+            MODE_ONLY_FULL_GROUP_BY,
+            MODE_TRADITIONAL,
+            MODE_STRICT_TRANS_TABLES,
+            MODE_NO_ZERO_DATE,
+        ] {
+            assert_eq!(sql_mode.0 & mode.0, mode.0, "{:?}", mode);
+        }
+        assert!(!sql_mode.has_high_not_precedence_mode());
+
+        assert_eq!(SqlMode::from_mode_str("").unwrap(), MODE_NONE);
+        assert_eq!(
+            SqlMode::from_mode_str("ANSI_QUOTES,,").unwrap(),
+            MODE_ANSI_QUOTES
+        );
+        assert_eq!(
+            SqlMode::from_mode_str("ANSI_QUOTES,NO_SUCH_MODE"),
+            Err("NO_SUCH_MODE".to_string())
+        );
+    }
+
+    #[test]
+    fn ansi_quotes_test() {
+        match first_field_expr(r#"SELECT "a""b" FROM t"#, MODE_ANSI_QUOTES) {
+            ExprNode::ColumnNameExpr(expr) => assert_eq!(expr.name.name.origin, r#"a"b"#),
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+        match first_field_expr(r#"SELECT "a" FROM t"#, MODE_NONE) {
+            ExprNode::ValueExpr(expr) => assert_eq!(expr.s, "a"),
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+    }
+
+    #[test]
+    fn pipes_as_concat_test() {
+        // `||` is left associative and binds tighter than `*`.
+        match first_field_expr(r#"SELECT a || b || c * 2"#, MODE_PIPES_AS_CONCAT) {
+            ExprNode::BinaryOperationExpr(expr) => {
+                assert!(matches!(expr.op, OpCode::Mul));
+                let concat = match expr.l.as_deref() {
+                    Some(ExprNode::FuncCallExpr(concat)) => concat,
+                    expr => panic!("unexpected expr: {:?}", expr),
+                };
+                assert_eq!(concat.fn_name.lower, "concat");
+                assert_eq!(concat.args.len(), 2);
+                assert!(
+                    matches!(&concat.args[0], ExprNode::FuncCallExpr(inner) if inner.fn_name.lower == "concat")
+                );
+                assert!(
+                    matches!(&concat.args[1], ExprNode::ColumnNameExpr(c) if c.name.name.lower == "c")
+                );
+            }
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+        // `||` is OR without PIPES_AS_CONCAT, so AND binds tighter.
+        match first_field_expr(r#"SELECT a || b AND c"#, MODE_NONE) {
+            ExprNode::BinaryOperationExpr(expr) => {
+                assert!(matches!(expr.op, OpCode::LogicOr));
+                assert!(matches!(
+                    expr.r.as_deref(),
+                    Some(ExprNode::BinaryOperationExpr(and)) if matches!(and.op, OpCode::LogicAnd)
+                ));
+            }
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+    }
+
+    #[test]
+    fn high_not_precedence_test() {
+        // NOT binds like `!` with HIGH_NOT_PRECEDENCE: (NOT a) BETWEEN 1 AND 2.
+        match first_field_expr(r#"SELECT NOT a BETWEEN 1 AND 2"#, MODE_HIGH_NOT_PRECEDENCE) {
+            ExprNode::BetweenExpr(expr) => match expr.expr.as_deref() {
+                Some(ExprNode::UnaryOperationExpr(not)) => {
+                    assert!(matches!(not.op, OpCode::Not2));
+                    assert!(matches!(
+                        not.v.as_deref(),
+                        Some(ExprNode::ColumnNameExpr(_))
+                    ));
+                }
+                expr => panic!("unexpected expr: {:?}", expr),
+            },
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+        // NOT (a BETWEEN 1 AND 2) otherwise.
+        match first_field_expr(r#"SELECT NOT a BETWEEN 1 AND 2"#, MODE_NONE) {
+            ExprNode::UnaryOperationExpr(expr) => {
+                assert!(matches!(expr.op, OpCode::Not));
+                assert!(matches!(expr.v.as_deref(), Some(ExprNode::BetweenExpr(_))))
+            }
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+        match first_field_expr(r#"SELECT NOT a = b"#, MODE_HIGH_NOT_PRECEDENCE) {
+            ExprNode::BinaryOperationExpr(expr) => {
+                assert!(matches!(expr.op, OpCode::EQ));
+                assert!(matches!(
+                    expr.l.as_deref(),
+                    Some(ExprNode::UnaryOperationExpr(_))
+                ));
+            }
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+        match first_field_expr(r#"SELECT NOT EXISTS (SELECT 1)"#, MODE_NONE) {
+            ExprNode::ExistsSubqueryExpr(expr) => assert!(expr.not),
+            expr => panic!("unexpected expr: {:?}", expr),
+        }
+        let stmt = parse_with_sql_mode(
+            r#"SELECT a NOT IN (1, 2), b IS NOT NULL, c NOT LIKE 'x' FROM t"#,
+            MODE_HIGH_NOT_PRECEDENCE,
+        );
+        assert!(stmt.is_ok(), "{:?}", stmt);
+    }
+
+    #[test]
+    fn ignore_space_test() {
+        let query = r#"SELECT COUNT (*) FROM t"#;
+        let stmt = parse_with_sql_mode(query, MODE_IGNORE_SPACE);
+        assert!(stmt.is_ok(), "{}: {:?}", query, stmt);
+        assert!(parse_with_sql_mode(query, MODE_NONE).is_err());
+
+        let querys = vec![r#"SELECT COUNT(*) FROM t"#, r#"SELECT count, sum FROM t"#];
+        for query in querys {
+            let stmt = parse_with_sql_mode(query, MODE_NONE);
+            assert!(stmt.is_ok(), "{}: {:?}", query, stmt);
+        }
+    }
+
+    #[test]
+    fn real_as_float_test() {
+        for (sql_mode, tp) in [
+            (MODE_NONE, DataType::Double),
+            (MODE_REAL_AS_FLOAT, DataType::Float),
+        ] {
+            for query in [r#"SELECT CAST(a AS REAL)"#, r#"SELECT CAST(1 AS REAL)"#] {
+                match first_field_expr(query, sql_mode) {
+                    ExprNode::FuncCastExpr(expr) => assert_eq!(expr.tp.tp, tp, "{}", query),
+                    expr => panic!("unexpected expr: {:?}", expr),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr_node::{ExprNode, ParamMarkerExpr};
+    use crate::ast::restore::{restore_sql, DEFAULT_RESTORE_FLAGS};
+    use crate::ast::statement::Statement;
+    use crate::ast::structural::{Structural, StructuralEq};
+    use crate::ast::visitor::{Accept, VisitAction, VisitorMut};
+    use crate::parser::input::Dialect;
+    use crate::parser::parser::{parse_sql, tokenize_sql};
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
+    use std::hash::{Hash, Hasher};
+
+    fn parse(query: &str) -> Statement {
+        let tokens = tokenize_sql(query).unwrap();
+        let stmt = parse_sql(&tokens, Dialect::TiDB);
+        assert!(stmt.is_ok(), "{}: {:?}", query, stmt);
+        stmt.unwrap()
+    }
+
+    fn hash<T: Hash>(node: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        node.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn strict_eq_test() {
+        let querys = vec![
+            r#"SELECT a, b + 1 AS c FROM t1 JOIN t2 ON t1.id = t2.id WHERE c IN (1, 2.5, 1e3)"#,
+            r#"WITH cte AS (SELECT 1) SELECT * FROM cte UNION SELECT 2 ORDER BY 1 LIMIT 5"#,
+            r#"SELECT a FROM t WHERE a = ? GROUP BY a HAVING COUNT(*) > 1 FOR UPDATE"#,
+        ];
+        for query in querys {
+            let stmt = parse(query);
+            let cloned = stmt.clone();
+            assert_eq!(stmt, cloned, "{}", query);
+            assert_eq!(hash(&stmt), hash(&cloned), "{}", query);
+            assert_eq!(stmt, parse(query), "{}", query);
+        }
+
+        assert_ne!(parse("SELECT a FROM t"), parse("SELECT  a  FROM t"));
+        assert_ne!(parse("SELECT a FROM t"), parse("SELECT b FROM t"));
+    }
+
+    #[test]
+    fn structural_eq_test() {
+        let equal = vec![
+            (
+                "SELECT a, b FROM t WHERE x = 1",
+                "select  A,\n  b FROM `T` -- all rows\nwhere X=01",
+            ),
+            (
+                "SELECT * FROM t WHERE a = 0x0A",
+                "SELECT * FROM t WHERE a = X'0A'",
+            ),
+            (
+                "SELECT a FROM t LIMIT ?, ?",
+                "SELECT /* page */ a FROM t LIMIT ?,?",
+            ),
+            ("SELECT TRUE, NULL", "SELECT true, null"),
+            (
+                "SELECT COUNT(*), SUM(a) OVER w FROM t WINDOW w AS (ORDER BY b)",
+                "select count(*), sum(a) over w from t window w as (order by b)",
+            ),
+        ];
+        for (left, right) in equal {
+            let (left_stmt, right_stmt) = (parse(left), parse(right));
+            assert!(
+                left_stmt.structural_eq(&right_stmt),
+                "{} <> {}",
+                left,
+                right
+            );
+            assert_eq!(
+                hash(&Structural(&left_stmt)),
+                hash(&Structural(&right_stmt)),
+                "{} <> {}",
+                left,
+                right
+            );
+        }
+
+        let different = vec![
+            ("SELECT a FROM t WHERE b = 1", "SELECT a FROM t WHERE b = 2"),
+            ("SELECT 'A'", "SELECT 'a'"),
+            ("SELECT 1.5", "SELECT 1.50"),
+            ("SELECT a FROM t", "SELECT a AS b FROM t"),
+            ("SELECT a FROM t", "SELECT a FROM s.t"),
+        ];
+        for (left, right) in different {
+            assert!(
+                !parse(left).structural_eq(&parse(right)),
+                "{} = {}",
+                left,
+                right
+            );
+        }
+
+        let keys: HashSet<_> = ["SELECT a FROM t", "select A from T", "SELECT `a` FROM `t`"]
+            .into_iter()
+            .map(|query| Structural(parse(query)))
+            .collect();
+        assert_eq!(keys.len(), 1);
+    }
+
+    struct Parameterizer;
+
+    impl VisitorMut for Parameterizer {
+        fn enter_expr_node(&mut self, node: &mut ExprNode) -> VisitAction {
+            if let ExprNode::ValueExpr(_) = node {
+                *node = ExprNode::ParamMarkerExpr(ParamMarkerExpr::default());
+            }
+            VisitAction::Continue
+        }
+    }
+
+    #[test]
+    fn clone_rewrite_test() {
+        // The clone shares the ORDER BY and LIMIT clauses with the original,
+        // rewriting it must leave the original untouched.
+        let stmt = parse("SELECT a FROM t WHERE b = 1 ORDER BY c + 2 LIMIT 10");
+        let mut cloned = stmt.clone();
+        cloned.accept_mut(&mut Parameterizer);
+        assert_eq!(
+            restore_sql(&stmt, DEFAULT_RESTORE_FLAGS).unwrap(),
+            "SELECT `a` FROM `t` WHERE `b` = 1 ORDER BY `c` + 2 LIMIT 10"
+        );
+        assert_eq!(
+            restore_sql(&cloned, DEFAULT_RESTORE_FLAGS).unwrap(),
+            "SELECT `a` FROM `t` WHERE `b` = ? ORDER BY `c` + ? LIMIT ?"
+        );
+        assert_ne!(stmt, cloned);
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::restore::{
+        restore_sql, restore_sql_with_dialect, RestoreFlags, DEFAULT_RESTORE_FLAGS,
+        RESTORE_KEYWORD_UPPERCASE, RESTORE_NAME_QUOTES_WHEN_NEEDED, RESTORE_STRING_SINGLE_QUOTES,
+    };
+    use crate::ast::statement::Statement;
+    use crate::ast::table_optimizer_hint::{HintData, TableOptimizerHint};
+    use crate::mysql::consts::SqlMode;
+    use crate::parser::input::Dialect;
+    use crate::parser::parser::{parse_sql, parse_sql_with_warnings, tokenize_sql};
+
+    const HINT_RESTORE_FLAGS: RestoreFlags = RestoreFlags(
+        RESTORE_KEYWORD_UPPERCASE.0
+            | RESTORE_STRING_SINGLE_QUOTES.0
+            | RESTORE_NAME_QUOTES_WHEN_NEEDED.0,
+    );
+
+    // Returns the optimizer hints of a SELECT, which must parse without
+    // warnings.
+    fn select_hints(query: &str) -> Vec<TableOptimizerHint> {
+        let tokens = tokenize_sql(query).unwrap();
+        let result = parse_sql_with_warnings(&tokens, Dialect::MySQL80);
+        assert!(result.is_ok(), "{}: {:?}", query, result);
+        let (stmt, warnings) = result.unwrap();
+        assert!(warnings.is_empty(), "{}: {:?}", query, warnings);
+        match stmt {
+            Statement::SelectStmt(select) => select.select_stmt_opts.table_hints,
+            stmt => panic!("{}: {:?}", query, stmt),
+        }
+    }
+
+    #[test]
+    fn table_optimizer_hints_test() {
+        // (query, the hints written back one by one)
+        let cases = vec![
+            (
+                r#"SELECT /*+ MAX_EXECUTION_TIME(1000) */ 1"#,
+                vec!["MAX_EXECUTION_TIME(1000)"],
+            ),
+            (
+                r#"SELECT /*+ SET_VAR(sort_buffer_size = 16384) RESOURCE_GROUP(rg1) */ 1"#,
+                vec!["SET_VAR(sort_buffer_size = '16384')", "RESOURCE_GROUP(rg1)"],
+            ),
+            (
+                r#"SELECT /*+ JOIN_ORDER(t1, t2@qb1), JOIN_FIXED_ORDER() */ 1"#,
+                vec!["JOIN_ORDER(t1, t2@qb1)", "JOIN_FIXED_ORDER()"],
+            ),
+            (
+                r#"SELECT /*+ BNL(t1) NO_HASH_JOIN(@qb1 t1, db1.t2) */ 1"#,
+                vec!["BNL(t1)", "NO_HASH_JOIN(@qb1 t1, db1.t2)"],
+            ),
+            (
+                r#"SELECT /*+ INDEX(t1 idx1, idx2) NO_INDEX_MERGE(t1 PARTITION(p0)) */ 1"#,
+                vec!["INDEX(t1 idx1, idx2)", "NO_INDEX_MERGE(t1 PARTITION(p0))"],
+            ),
+            (
+                r#"SELECT /*+ USE_INDEX(t1, idx1) IGNORE_INDEX(db1.t2@qb1) */ 1"#,
+                vec!["USE_INDEX(t1 idx1)", "IGNORE_INDEX(db1.t2@qb1)"],
+            ),
+            (
+                r#"SELECT /*+ ORDER_INDEX(t1 idx1) GROUP_INDEX(t1) JOIN_INDEX(@qb1 t1 idx1) */ 1"#,
+                vec![
+                    "ORDER_INDEX(t1 idx1)",
+                    "GROUP_INDEX(t1)",
+                    "JOIN_INDEX(@qb1 t1 idx1)",
+                ],
+            ),
+            (
+                r#"SELECT /*+ INDEX(t1 PRIMARY, idx1) NO_INDEX(t1, primary) */ 1"#,
+                vec!["INDEX(t1 `PRIMARY`, idx1)", "NO_INDEX(t1 `primary`)"],
+            ),
+            (
+                r#"SELECT /*+ MERGE(dt) NO_MERGE() QB_NAME(qb1) */ 1"#,
+                vec!["MERGE(dt)", "NO_MERGE()", "QB_NAME(qb1)"],
+            ),
+            (
+                r#"SELECT /*+ SEMIJOIN(@qb1 FIRSTMATCH, LOOSESCAN) SUBQUERY(MATERIALIZATION) */ 1"#,
+                vec![
+                    "SEMIJOIN(@qb1 FIRSTMATCH, LOOSESCAN)",
+                    "SUBQUERY(MATERIALIZATION)",
+                ],
+            ),
+            (
+                r#"SELECT /*+ MEMORY_QUOTA(1024 MB) READ_FROM_STORAGE(TIFLASH[t1, t2], TIKV[t3]) */ 1"#,
+                vec![
+                    "MEMORY_QUOTA(1024 MB)",
+                    "READ_FROM_STORAGE(TIFLASH[t1, t2])",
+                    "READ_FROM_STORAGE(TIKV[t3])",
+                ],
+            ),
+        ];
+
+        for (query, expected) in cases {
+            let hints = select_hints(query);
+            let restored = hints
+                .iter()
+                .map(|hint| restore_sql(hint, HINT_RESTORE_FLAGS).unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(restored, expected, "{}", query);
+        }
+    }
+
+    #[test]
+    fn table_optimizer_hint_values_test() {
+        let hints = select_hints(r#"SELECT /*+ INDEX(@qb1 db1.t1 idx1, idx2) */ 1"#);
+        assert_eq!(hints.len(), 1);
+        assert_eq!(hints[0].hint_name.lower, "index");
+        assert_eq!(hints[0].qb_name.lower, "qb1");
+        assert_eq!(hints[0].tables.len(), 1);
+        assert_eq!(hints[0].tables[0].db_name.lower, "db1");
+        assert_eq!(hints[0].tables[0].table_name.lower, "t1");
+        let indexes = hints[0]
+            .indexes
+            .iter()
+            .map(|index| index.lower.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(indexes, vec!["idx1", "idx2"]);
+
+        let hints = select_hints(r#"SELECT /*+ NO_INDEX_MERGE(t1 PARTITION(p0, p1)) */ 1"#);
+        let partitions = hints[0].tables[0]
+            .partition_list
+            .iter()
+            .map(|partition| partition.lower.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(partitions, vec!["p0", "p1"]);
+        assert!(hints[0].indexes.is_empty());
+
+        let hints = select_hints(r#"SELECT /*+ MAX_EXECUTION_TIME(1000) MEMORY_QUOTA(1 GB) */ 1"#);
+        assert_eq!(hints[0].hint_data, Some(HintData::Uint64(1000)));
+        assert_eq!(hints[1].hint_data, Some(HintData::Int64(1 << 30)));
+    }
+
+    #[test]
+    fn table_optimizer_hints_dialect_test() {
+        let query = r#"SELECT /*+ INDEX(t1 idx1, idx2) HASH_JOIN(t1, t2) */ 1"#;
+        let cases = vec![
+            (
+                Dialect::MySQL80,
+                r#"SELECT /*+ INDEX(`t1` `idx1`, `idx2`) HASH_JOIN(`t1`, `t2`) */ 1"#,
+            ),
+            (
+                Dialect::MariaDB10,
+                r#"SELECT /*+ INDEX(`t1` `idx1`, `idx2`) HASH_JOIN(`t1`, `t2`) */ 1"#,
+            ),
+            (
+                Dialect::TiDB,
+                r#"SELECT /*+ INDEX(`t1`, `idx1`, `idx2`), HASH_JOIN(`t1`, `t2`) */ 1"#,
+            ),
+        ];
+
+        for (dialect, expected) in cases {
+            let tokens = tokenize_sql(query).unwrap();
+            let stmt = parse_sql(&tokens, dialect).unwrap();
+            let restored =
+                restore_sql_with_dialect(&stmt, DEFAULT_RESTORE_FLAGS, dialect, SqlMode::default());
+            assert_eq!(restored.unwrap(), expected, "{:?}", dialect);
+        }
+    }
+
+    #[test]
+    fn unknown_table_optimizer_hints_test() {
+        let query = r#"SELECT /*+ NO_SUCH_HINT(t1) MAX_EXECUTION_TIME(abc), HASH_JOIN(t1) */ 1"#;
+        let tokens = tokenize_sql(query).unwrap();
+        let (stmt, warnings) = parse_sql_with_warnings(&tokens, Dialect::MySQL80).unwrap();
+        assert_eq!(warnings.len(), 2, "{:?}", warnings);
+
+        match stmt {
+            Statement::SelectStmt(select) => {
+                let hints = &select.select_stmt_opts.table_hints;
+                assert_eq!(hints.len(), 1);
+                assert_eq!(hints[0].hint_name.lower, "hash_join");
+            }
+            _ => unreachable!(),
+        }
+    }
+}
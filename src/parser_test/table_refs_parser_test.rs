@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::index_hint::{IndexHintScope, IndexHintType};
+    use crate::ast::restore::{
+        restore_sql, RestoreFlags, RESTORE_KEYWORD_UPPERCASE, RESTORE_NAME_QUOTES_WHEN_NEEDED,
+        RESTORE_STRING_SINGLE_QUOTES,
+    };
+    use crate::ast::result_set_node::ResultSetNode;
+    use crate::ast::statement::Statement;
+    use crate::parser::input::Dialect;
+    use crate::parser::parser::{parse_sql, tokenize_sql};
+
+    const RESTORE_FLAGS: RestoreFlags = RestoreFlags(
+        RESTORE_KEYWORD_UPPERCASE.0
+            | RESTORE_STRING_SINGLE_QUOTES.0
+            | RESTORE_NAME_QUOTES_WHEN_NEEDED.0,
+    );
+
+    // Parses the query and writes it back, the joins are written with their
+    // explicit join types.
+    fn restore(query: &str, dialect: Dialect) -> String {
+        let tokens = tokenize_sql(query).unwrap();
+        let stmt = parse_sql(&tokens, dialect);
+        assert!(stmt.is_ok(), "{}: {:?}", query, stmt);
+        restore_sql(&stmt.unwrap(), RESTORE_FLAGS).unwrap()
+    }
+
+    #[test]
+    fn table_refs_test() {
+        // (query, the query written back)
+        let cases = vec![
+            (r#"SELECT a FROM t1"#, r#"SELECT a FROM t1"#),
+            (
+                r#"SELECT a FROM db1.t1 AS x, t2 y WHERE x.a = y.a"#,
+                r#"SELECT a FROM db1.t1 AS x JOIN t2 AS y WHERE x.a = y.a"#,
+            ),
+            (
+                r#"SELECT a FROM t1 JOIN t2 ON t1.a = t2.a LEFT JOIN t3 USING (a, b)"#,
+                r#"SELECT a FROM t1 JOIN t2 ON t1.a = t2.a LEFT JOIN t3 USING (a, b)"#,
+            ),
+            (
+                r#"SELECT a FROM t1 STRAIGHT_JOIN t2 NATURAL RIGHT OUTER JOIN t3"#,
+                r#"SELECT a FROM t1 STRAIGHT_JOIN t2 NATURAL RIGHT JOIN t3"#,
+            ),
+            (
+                r#"SELECT a FROM (t1, t2) CROSS JOIN t3"#,
+                r#"SELECT a FROM (t1 JOIN t2) JOIN t3"#,
+            ),
+            (
+                r#"SELECT a FROM t1 USE INDEX (idx1, PRIMARY) WHERE a = 1"#,
+                r#"SELECT a FROM t1 USE INDEX (idx1, PRIMARY) WHERE a = 1"#,
+            ),
+            (
+                r#"SELECT a FROM t1 USE INDEX ()"#,
+                r#"SELECT a FROM t1 USE INDEX ()"#,
+            ),
+            (
+                r#"SELECT a FROM t1 FORCE KEY FOR JOIN (idx1) IGNORE INDEX FOR ORDER BY (idx2)"#,
+                r#"SELECT a FROM t1 FORCE INDEX FOR JOIN (idx1) IGNORE INDEX FOR ORDER BY (idx2)"#,
+            ),
+            (
+                r#"SELECT a FROM t1 IGNORE INDEX FOR GROUP BY (idx1)"#,
+                r#"SELECT a FROM t1 IGNORE INDEX FOR GROUP BY (idx1)"#,
+            ),
+            (
+                r#"SELECT a FROM t1 PARTITION (p0, p1) AS x USE INDEX (idx1)"#,
+                r#"SELECT a FROM t1 PARTITION(p0, p1) AS x USE INDEX (idx1)"#,
+            ),
+        ];
+
+        for (query, expected) in cases {
+            assert_eq!(restore(query, Dialect::MySQL80), expected, "{}", query);
+        }
+    }
+
+    #[test]
+    fn index_hints_test() {
+        let query = r#"SELECT a FROM t1 PARTITION (p0) FORCE INDEX FOR ORDER BY (idx1, idx2)"#;
+        let tokens = tokenize_sql(query).unwrap();
+        let stmt = parse_sql(&tokens, Dialect::MySQL80).unwrap();
+
+        let select = match stmt {
+            Statement::SelectStmt(select) => select,
+            _ => unreachable!(),
+        };
+        let source = match *select.from.unwrap().left {
+            ResultSetNode::TableSource(source) => source.source,
+            _ => unreachable!(),
+        };
+        let tbl_name = match *source {
+            ResultSetNode::TableName(tbl_name) => tbl_name,
+            _ => unreachable!(),
+        };
+        assert_eq!(tbl_name.partition_names.len(), 1);
+        assert_eq!(tbl_name.index_hints.len(), 1);
+        let hint = &tbl_name.index_hints[0];
+        assert!(matches!(hint.hint_type, IndexHintType::HintForce));
+        assert!(matches!(hint.hint_scope, IndexHintScope::HintForOrderBy));
+        assert_eq!(hint.index_names.len(), 2);
+    }
+
+    #[test]
+    fn invalid_index_hints_test() {
+        let querys = vec![
+            r#"SELECT a FROM t1 FORCE INDEX ()"#,
+            r#"SELECT a FROM t1 IGNORE KEY ()"#,
+        ];
+
+        for query in querys {
+            let tokens = tokenize_sql(query).unwrap();
+            assert!(parse_sql(&tokens, Dialect::MySQL80).is_err(), "{}", query);
+        }
+    }
+
+    #[test]
+    fn tidb_table_sample_and_as_of_test() {
+        // (query, the query written back)
+        let cases = vec![
+            (
+                r#"SELECT a FROM t1 TABLESAMPLE REGIONS()"#,
+                r#"SELECT a FROM t1 TABLESAMPLE REGIONS ()"#,
+            ),
+            (
+                r#"SELECT a FROM t1 TABLESAMPLE SYSTEM(10 PERCENT) REPEATABLE(42)"#,
+                r#"SELECT a FROM t1 TABLESAMPLE SYSTEM (10 PERCENT) REPEATABLE(42)"#,
+            ),
+            (
+                r#"SELECT a FROM t1 AS x TABLESAMPLE BERNOULLI(100 ROWS)"#,
+                r#"SELECT a FROM t1 AS x TABLESAMPLE BERNOULLI (100 ROWS)"#,
+            ),
+            (
+                r#"SELECT a FROM t1 AS OF TIMESTAMP '2024-01-01 00:00:00'"#,
+                r#"SELECT a FROM t1 AS OF TIMESTAMP '2024-01-01 00:00:00'"#,
+            ),
+            (
+                r#"SELECT a FROM t1 x AS OF TIMESTAMP NOW() USE INDEX (idx1), t2"#,
+                r#"SELECT a FROM t1 AS x AS OF TIMESTAMP NOW() USE INDEX (idx1) JOIN t2"#,
+            ),
+        ];
+
+        for (query, expected) in cases {
+            assert_eq!(restore(query, Dialect::TiDB), expected, "{}", query);
+
+            // They are TiDB extensions.
+            let tokens = tokenize_sql(query).unwrap();
+            assert!(parse_sql(&tokens, Dialect::MySQL80).is_err(), "{}", query);
+        }
+    }
+
+    #[test]
+    fn unicode_table_name_test() {
+        let querys = vec![
+            (r#"SELECT * FROM `order items`"#, "order items"),
+            (r#"SELECT * FROM db.`a``b`"#, "a`b"),
+            (r#"SELECT * FROM 用户表"#, "用户表"),
+            (r#"SELECT * FROM 1tbl"#, "1tbl"),
+        ];
+
+        for (query, expected) in querys {
+            let tokens = tokenize_sql(query).unwrap();
+            let select = match parse_sql(&tokens, Dialect::MySQL80).unwrap() {
+                Statement::SelectStmt(select) => select,
+                stmt => panic!("unexpected statement: {:?}", stmt),
+            };
+            let source = match *select.from.unwrap().left {
+                ResultSetNode::TableSource(source) => source,
+                node => panic!("unexpected node: {:?}", node),
+            };
+            match *source.source {
+                ResultSetNode::TableName(name) => assert_eq!(name.name.origin, expected),
+                node => panic!("unexpected node: {:?}", node),
+            }
+        }
+    }
+}
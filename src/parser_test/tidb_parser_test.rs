@@ -0,0 +1,218 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::admin_stmt::AdminStmtType;
+    use crate::ast::placement_policy_stmt::PlacementOptionType;
+    use crate::ast::restore::{
+        restore_sql_with_dialect, RestoreFlags, RESTORE_KEYWORD_UPPERCASE,
+        RESTORE_NAME_QUOTES_WHEN_NEEDED, RESTORE_STRING_SINGLE_QUOTES,
+    };
+    use crate::ast::show_stmt::ShowStmtType;
+    use crate::ast::statement::Statement;
+    use crate::mysql::consts::SqlMode;
+    use crate::parser::input::Dialect;
+    use crate::parser::parser::{parse_sql, tokenize_sql};
+
+    const RESTORE_FLAGS: RestoreFlags = RestoreFlags(
+        RESTORE_KEYWORD_UPPERCASE.0
+            | RESTORE_STRING_SINGLE_QUOTES.0
+            | RESTORE_NAME_QUOTES_WHEN_NEEDED.0,
+    );
+
+    fn parse_tidb(query: &str) -> Statement {
+        let tokens = tokenize_sql(query).unwrap();
+        let stmt = parse_sql(&tokens, Dialect::TiDB);
+        assert!(stmt.is_ok(), "{}: {:?}", query, stmt);
+        stmt.unwrap()
+    }
+
+    #[test]
+    fn tidb_statement_test() {
+        // (query, the query written back)
+        let cases = vec![
+            (r#"ADMIN SHOW DDL"#, r#"ADMIN SHOW DDL"#),
+            (r#"ADMIN SHOW DDL JOBS"#, r#"ADMIN SHOW DDL JOBS"#),
+            (
+                r#"ADMIN SHOW DDL JOBS 5 WHERE state != 'synced'"#,
+                r#"ADMIN SHOW DDL JOBS 5 WHERE state != 'synced'"#,
+            ),
+            (
+                r#"ADMIN CHECK TABLE t1, db.t2"#,
+                r#"ADMIN CHECK TABLE t1, db.t2"#,
+            ),
+            (
+                r#"ADMIN CANCEL DDL JOBS 1, 2"#,
+                r#"ADMIN CANCEL DDL JOBS 1, 2"#,
+            ),
+            (
+                r#"CREATE BINDING FOR SELECT * FROM t WHERE a = 1 USING SELECT * FROM t USE INDEX (idx) WHERE a = 1"#,
+                r#"CREATE SESSION BINDING FOR SELECT * FROM t WHERE a = 1 USING SELECT * FROM t USE INDEX (idx) WHERE a = 1"#,
+            ),
+            (
+                r#"CREATE GLOBAL BINDING USING SELECT /*+ USE_INDEX(t, idx) */ * FROM t WHERE a = 1"#,
+                r#"CREATE GLOBAL BINDING USING SELECT /*+ USE_INDEX(t, idx) */ * FROM t WHERE a = 1"#,
+            ),
+            (
+                r#"DROP SESSION BINDING FOR SELECT * FROM t WHERE a = 1"#,
+                r#"DROP SESSION BINDING FOR SELECT * FROM t WHERE a = 1"#,
+            ),
+            (r#"SHOW GLOBAL BINDINGS"#, r#"SHOW GLOBAL BINDINGS"#),
+            (
+                r#"SHOW BINDINGS LIKE 'select%'"#,
+                r#"SHOW SESSION BINDINGS LIKE 'select%'"#,
+            ),
+            (r#"SHOW TABLE t REGIONS"#, r#"SHOW TABLE t REGIONS"#),
+            (
+                r#"SHOW TABLE t PARTITION (p0) INDEX idx REGIONS WHERE is_index = 1"#,
+                r#"SHOW TABLE t PARTITION(p0) INDEX idx REGIONS WHERE is_index = 1"#,
+            ),
+            (
+                r#"SPLIT TABLE t BETWEEN (0) AND (1000000) REGIONS 16"#,
+                r#"SPLIT TABLE t BETWEEN (0) AND (1000000) REGIONS 16"#,
+            ),
+            (
+                r#"SPLIT REGION FOR PARTITION TABLE t INDEX idx BY ('a', 1), ('b', 2)"#,
+                r#"SPLIT REGION FOR PARTITION TABLE t INDEX idx BY ('a', 1), ('b', 2)"#,
+            ),
+            (
+                r#"SPLIT TABLE t PARTITION (p1, p2) BETWEEN (0) AND (100) REGIONS 4"#,
+                r#"SPLIT TABLE t PARTITION(p1, p2) BETWEEN (0) AND (100) REGIONS 4"#,
+            ),
+            (
+                r#"FLASHBACK CLUSTER TO TIMESTAMP '2022-09-21 16:02:50'"#,
+                r#"FLASHBACK CLUSTER TO TIMESTAMP '2022-09-21 16:02:50'"#,
+            ),
+            (
+                r#"FLASHBACK CLUSTER TO TSO 445494839813079041"#,
+                r#"FLASHBACK CLUSTER TO TSO 445494839813079041"#,
+            ),
+            (
+                r#"FLASHBACK TABLE t1, t2 TO TIMESTAMP '2022-09-21 16:02:50'"#,
+                r#"FLASHBACK TABLE t1, t2 TO TIMESTAMP '2022-09-21 16:02:50'"#,
+            ),
+            (
+                r#"FLASHBACK DATABASE db TO TIMESTAMP '2022-09-21 16:02:50'"#,
+                r#"FLASHBACK DATABASE db TO TIMESTAMP '2022-09-21 16:02:50'"#,
+            ),
+            (
+                r#"FLASHBACK TABLE t TO t_new"#,
+                r#"FLASHBACK TABLE t TO t_new"#,
+            ),
+            (r#"FLASHBACK SCHEMA db"#, r#"FLASHBACK DATABASE db"#),
+            (
+                r#"CREATE PLACEMENT POLICY p PRIMARY_REGION = "us-east-1" REGIONS = "us-east-1,us-west-1" FOLLOWERS = 4"#,
+                r#"CREATE PLACEMENT POLICY p PRIMARY_REGION = 'us-east-1' REGIONS = 'us-east-1,us-west-1' FOLLOWERS = 4"#,
+            ),
+            (
+                r#"CREATE OR REPLACE PLACEMENT POLICY IF NOT EXISTS p CONSTRAINTS="[+disk=ssd]", SURVIVAL_PREFERENCES="[region, zone]""#,
+                r#"CREATE OR REPLACE PLACEMENT POLICY IF NOT EXISTS p CONSTRAINTS = '[+disk=ssd]' SURVIVAL_PREFERENCES = '[region, zone]'"#,
+            ),
+            (
+                r#"ALTER PLACEMENT POLICY IF EXISTS p LEARNERS 1 LEARNER_CONSTRAINTS '[+engine=tiflash]'"#,
+                r#"ALTER PLACEMENT POLICY IF EXISTS p LEARNERS = 1 LEARNER_CONSTRAINTS = '[+engine=tiflash]'"#,
+            ),
+            (
+                r#"DROP PLACEMENT POLICY IF EXISTS p"#,
+                r#"DROP PLACEMENT POLICY IF EXISTS p"#,
+            ),
+            (
+                r#"SET CONFIG tikv log.level = 'info'"#,
+                r#"SET CONFIG TIKV log.level = 'info'"#,
+            ),
+            (
+                r#"SET CONFIG '127.0.0.1:20180' raftstore.apply-pool-size := 4"#,
+                r#"SET CONFIG '127.0.0.1:20180' raftstore.apply-pool-size = 4"#,
+            ),
+        ];
+
+        for (query, expected) in cases {
+            let stmt = parse_tidb(query);
+            let restored =
+                restore_sql_with_dialect(&stmt, RESTORE_FLAGS, Dialect::TiDB, SqlMode::default());
+            let restored = restored.unwrap();
+            assert_eq!(restored, expected, "{}", query);
+        }
+    }
+
+    #[test]
+    fn tidb_statement_value_test() {
+        match parse_tidb(r#"ADMIN SHOW DDL JOBS 5"#) {
+            Statement::AdminStmt(stmt) => {
+                assert_eq!(stmt.tp, AdminStmtType::ShowDdlJobs);
+                assert_eq!(stmt.job_number, 5);
+            }
+            stmt => panic!("unexpected statement: {:?}", stmt),
+        }
+
+        match parse_tidb(r#"SHOW TABLE t INDEX idx REGIONS"#) {
+            Statement::ShowStmt(stmt) => {
+                assert_eq!(stmt.tp, ShowStmtType::Regions);
+                assert_eq!(stmt.index_name.origin, "idx");
+            }
+            stmt => panic!("unexpected statement: {:?}", stmt),
+        }
+
+        match parse_tidb(r#"SPLIT TABLE t BETWEEN (0) AND (100) REGIONS 4"#) {
+            Statement::SplitRegionStmt(stmt) => {
+                assert_eq!(stmt.split_opt.num, 4);
+                assert_eq!(stmt.split_opt.lower.len(), 1);
+            }
+            stmt => panic!("unexpected statement: {:?}", stmt),
+        }
+
+        match parse_tidb(r#"FLASHBACK TABLE t TO TIMESTAMP '2022-09-21 16:02:50'"#) {
+            Statement::FlashBackToTimestampStmt(stmt) => {
+                assert_eq!(stmt.tables.len(), 1);
+                assert!(stmt.flashback_ts.is_some());
+            }
+            stmt => panic!("unexpected statement: {:?}", stmt),
+        }
+
+        match parse_tidb(r#"CREATE PLACEMENT POLICY p FOLLOWERS = 2, SCHEDULE = 'EVEN'"#) {
+            Statement::CreatePlacementPolicyStmt(stmt) => {
+                let options: Vec<_> = stmt.placement_options.iter().map(|opt| opt.tp).collect();
+                assert_eq!(
+                    options,
+                    vec![
+                        PlacementOptionType::Followers,
+                        PlacementOptionType::Schedule
+                    ]
+                );
+                assert_eq!(stmt.placement_options[0].uint_value, 2);
+                assert_eq!(stmt.placement_options[1].str_value, "EVEN");
+            }
+            stmt => panic!("unexpected statement: {:?}", stmt),
+        }
+
+        match parse_tidb(r#"SET CONFIG TiKV split.qps-threshold = 1000"#) {
+            Statement::SetConfigStmt(stmt) => {
+                assert_eq!(stmt.tp, "tikv");
+                assert_eq!(stmt.name, "split.qps-threshold");
+            }
+            stmt => panic!("unexpected statement: {:?}", stmt),
+        }
+    }
+
+    #[test]
+    fn tidb_statement_dialect_test() {
+        let querys = vec![
+            r#"ADMIN SHOW DDL"#,
+            r#"CREATE BINDING USING SELECT * FROM t"#,
+            r#"SHOW BINDINGS"#,
+            r#"SHOW TABLE t REGIONS"#,
+            r#"SPLIT TABLE t BY (1)"#,
+            r#"FLASHBACK TABLE t"#,
+            r#"DROP PLACEMENT POLICY p"#,
+            r#"SET CONFIG tikv log.level = 'info'"#,
+        ];
+
+        for query in querys {
+            let tokens = tokenize_sql(query).unwrap();
+            let stmt = parse_sql(&tokens, Dialect::MySQL80);
+            assert!(stmt.is_err(), "{}: {:?}", query, stmt);
+        }
+
+        let query = r#"CREATE PLACEMENT POLICY p FOLLOWERS = 0"#;
+        let tokens = tokenize_sql(query).unwrap();
+        assert!(parse_sql(&tokens, Dialect::TiDB).is_err());
+    }
+}
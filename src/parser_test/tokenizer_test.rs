@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod tests {
+    use crate::mysql::consts::SqlMode;
+    use crate::parser::input::Dialect;
+    use crate::parser::parser::{
+        tokenize_sql, tokenize_sql_with_dialect, tokenize_sql_with_server_version,
+    };
+    use crate::parser::token_kind::TokenKind;
+
+    fn token_texts(sql: &str, server_version: u32) -> Vec<String> {
+        tokenize_sql_with_server_version(sql, server_version)
+            .unwrap()
+            .iter()
+            .filter(|token| token.kind != TokenKind::EOI)
+            .map(|token| token.text().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn executable_comment_test() {
+        let cases = vec![
+            (
+                "SELECT /*!50700 STRAIGHT_JOIN */ a FROM t",
+                80040,
+                vec!["SELECT", "STRAIGHT_JOIN", "a", "FROM", "t"],
+            ),
+            (
+                "SELECT /*!90000 STRAIGHT_JOIN */ a FROM t",
+                80040,
+                vec!["SELECT", "a", "FROM", "t"],
+            ),
+            (
+                "/*!40101 SET NAMES utf8 */",
+                50700,
+                vec!["SET", "NAMES", "utf8"],
+            ),
+            ("/*!80016 DEFAULT ENCRYPTION='N' */", 50700, vec![]),
+            ("SELECT /*! 1 */", 50700, vec!["SELECT", "1"]),
+            // `/*M!` is a plain comment for MySQL.
+            ("SELECT /*M!100100 1 */, 2", 80040, vec!["SELECT", ",", "2"]),
+            ("SELECT /*M! 1 */, 2", 80040, vec!["SELECT", ",", "2"]),
+            ("SELECT /* 1 */ 2", 80040, vec!["SELECT", "2"]),
+            (
+                "SELECT /*!50700 /*+ MAX_EXECUTION_TIME(1) */ */ 1",
+                80040,
+                vec![
+                    "SELECT",
+                    "/*+",
+                    "MAX_EXECUTION_TIME",
+                    "(",
+                    "1",
+                    ")",
+                    "*/",
+                    "1",
+                ],
+            ),
+        ];
+
+        for (sql, server_version, expected) in cases {
+            assert_eq!(token_texts(sql, server_version), expected, "{}", sql);
+        }
+
+        let cases = vec![
+            (
+                "SELECT /*M!100100 1 */, 2",
+                Dialect::MariaDB10,
+                vec!["SELECT", "1", ",", "2"],
+            ),
+            (
+                "SELECT /*M!110000 1 */, 2",
+                Dialect::MariaDB10,
+                vec!["SELECT", ",", "2"],
+            ),
+            (
+                "SELECT /*M!110000 1 */, 2",
+                Dialect::MariaDB11,
+                vec!["SELECT", "1", ",", "2"],
+            ),
+            (
+                "SELECT /*!50700 1 */, 2",
+                Dialect::MariaDB10,
+                vec!["SELECT", "1", ",", "2"],
+            ),
+            (
+                "SELECT /*M!100100 1 */, 2",
+                Dialect::MySQL80,
+                vec!["SELECT", ",", "2"],
+            ),
+            (
+                "SELECT /*M!100100 1 */, 2",
+                Dialect::TiDB,
+                vec!["SELECT", ",", "2"],
+            ),
+            (
+                "SELECT /*!80040 1 */, 2",
+                Dialect::TiDB,
+                vec!["SELECT", ",", "2"],
+            ),
+        ];
+        for (sql, dialect, expected) in cases {
+            let texts: Vec<_> = tokenize_sql_with_dialect(sql, dialect, SqlMode::default())
+                .unwrap()
+                .iter()
+                .filter(|token| token.kind != TokenKind::EOI)
+                .map(|token| token.text().to_string())
+                .collect();
+            assert_eq!(texts, expected, "{:?} {}", dialect, sql);
+        }
+    }
+
+    #[test]
+    fn identifier_test() {
+        let cases = vec![
+            ("`order items`", "order items"),
+            ("`a``b`", "a`b"),
+            ("`用户 表`", "用户 表"),
+            ("1col", "1col"),
+            ("t$x", "t$x"),
+            ("用户表", "用户表"),
+            ("0x1g", "0x1g"),
+            ("1_000", "1_000"),
+        ];
+
+        for (sql, expected) in cases {
+            let tokens = tokenize_sql(sql).unwrap();
+            assert_eq!(tokens[0].kind, TokenKind::Ident, "{}", sql);
+            assert_eq!(tokens[0].ident_text(), expected, "{}", sql);
+        }
+
+        let cases = vec![
+            ("123", TokenKind::LiteralInteger),
+            ("1e5", TokenKind::LiteralFloat),
+            ("0x1F", TokenKind::LiteralHex),
+        ];
+        for (sql, expected) in cases {
+            let tokens = tokenize_sql(sql).unwrap();
+            assert_eq!(tokens[0].kind, expected, "{}", sql);
+        }
+    }
+}
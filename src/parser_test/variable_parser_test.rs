@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::expr_node::{ExprNode, VariableExpr, VariableScope};
+    use crate::ast::select_field::Field;
+    use crate::ast::show_stmt::ShowStmtType;
+    use crate::ast::statement::Statement;
+    use crate::ast::user_identity::UserIdentity;
+    use crate::parser::input::Dialect;
+    use crate::parser::parser::{parse_sql, tokenize_sql};
+
+    // Returns the variable of the first field of a SELECT.
+    fn first_field_variable(query: &str) -> VariableExpr {
+        let tokens = tokenize_sql(query).unwrap();
+        let stmt = parse_sql(&tokens, Dialect::MySQL80).unwrap();
+        let mut select = match stmt {
+            Statement::SelectStmt(select) => select,
+            stmt => panic!("unexpected statement: {:?}", stmt),
+        };
+        match select.fields.remove(0).field {
+            Field::Expr(ExprNode::VariableExpr(variable)) => variable,
+            field => panic!("unexpected field: {:?}", field),
+        }
+    }
+
+    #[test]
+    fn user_variable_test() {
+        let cases = vec![
+            (r#"SELECT @a"#, "a"),
+            (r#"SELECT @a.b$c"#, "a.b$c"),
+            (r#"SELECT @`my var`"#, "my var"),
+            (r#"SELECT @`a``b`"#, "a`b"),
+            (r#"SELECT @'x'"#, "x"),
+            (r#"SELECT @"x""#, "x"),
+            (r#"SELECT @`my var` := 1"#, "my var"),
+        ];
+
+        for (query, name) in cases {
+            let variable = first_field_variable(query);
+            assert_eq!(variable.name, name, "{}", query);
+            assert!(!variable.is_system, "{}", query);
+        }
+    }
+
+    #[test]
+    fn system_variable_test() {
+        // (query, name, scope, is_global)
+        let cases = vec![
+            (
+                r#"SELECT @@sql_mode"#,
+                "sql_mode",
+                VariableScope::None,
+                false,
+            ),
+            (
+                r#"SELECT @@session.sql_mode"#,
+                "sql_mode",
+                VariableScope::Session,
+                false,
+            ),
+            (
+                r#"SELECT @@LOCAL.sql_mode"#,
+                "sql_mode",
+                VariableScope::Session,
+                false,
+            ),
+            (r#"SELECT @@GLOBAL.X"#, "x", VariableScope::Global, true),
+            (r#"SELECT @@persist.x"#, "x", VariableScope::Persist, true),
+            (
+                r#"SELECT @@persist_only.x"#,
+                "x",
+                VariableScope::PersistOnly,
+                true,
+            ),
+            (r#"SELECT @@global.`x`"#, "x", VariableScope::Global, true),
+            (
+                r#"SELECT @@my_cache.key_buffer_size"#,
+                "my_cache.key_buffer_size",
+                VariableScope::None,
+                false,
+            ),
+        ];
+
+        for (query, name, scope, is_global) in cases {
+            let variable = first_field_variable(query);
+            assert_eq!(variable.name, name, "{}", query);
+            assert!(variable.is_system, "{}", query);
+            assert_eq!(variable.scope, scope, "{}", query);
+            assert_eq!(variable.is_global, is_global, "{}", query);
+            assert_eq!(
+                variable.explicit_scope,
+                scope != VariableScope::None,
+                "{}",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn user_identity_test() {
+        let account = |username: &str, hostname: &str| UserIdentity {
+            span: None,
+            username: username.to_string(),
+            hostname: hostname.to_string(),
+            current_user: false,
+        };
+        let cases = vec![
+            (r#"SHOW GRANTS FOR 'u'@'%'"#, account("u", "%")),
+            (r#"SHOW GRANTS FOR u@localhost"#, account("u", "localhost")),
+            (r#"SHOW GRANTS FOR `u`@`10.%`"#, account("u", "10.%")),
+            (
+                r#"SHOW GRANTS FOR "u"@'LocalHost'"#,
+                account("u", "localhost"),
+            ),
+            (r#"SHOW GRANTS FOR u@10.0.0.1"#, account("u", "10.0.0.1")),
+            (r#"SHOW GRANTS FOR u"#, account("u", "%")),
+            (
+                r#"SHOW GRANTS FOR CURRENT_USER()"#,
+                UserIdentity {
+                    current_user: true,
+                    ..Default::default()
+                },
+            ),
+        ];
+
+        for (query, expected) in cases {
+            // The account follows `SHOW GRANTS FOR ` and ends the query.
+            let span = "SHOW GRANTS FOR ".len()..query.len();
+            let expected = UserIdentity {
+                span: Some(span.into()),
+                ..expected
+            };
+            let tokens = tokenize_sql(query).unwrap();
+            match parse_sql(&tokens, Dialect::MySQL80) {
+                Ok(Statement::ShowStmt(stmt)) => {
+                    assert_eq!(stmt.tp, ShowStmtType::Grants, "{}", query);
+                    assert_eq!(stmt.user, Some(expected), "{}", query);
+                }
+                stmt => panic!("{}: {:?}", query, stmt),
+            }
+        }
+    }
+}
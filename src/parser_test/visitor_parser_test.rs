@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::ci_str::CIStr;
+    use crate::ast::column_name::ColumnName;
+    use crate::ast::expr_node::{ExprNode, ParamMarkerExpr, ValueExpr};
+    use crate::ast::restore::{restore_sql, DEFAULT_RESTORE_FLAGS};
+    use crate::ast::statement::Statement;
+    use crate::ast::subquery_expr::SubQueryExpr;
+    use crate::ast::table_name::TableName;
+    use crate::ast::visitor::{Accept, VisitAction, Visitor, VisitorMut};
+    use crate::parser::input::Dialect;
+    use crate::parser::parser::{parse_sql, tokenize_sql};
+
+    fn parse(query: &str) -> Statement {
+        let tokens = tokenize_sql(query).unwrap();
+        let stmt = parse_sql(&tokens, Dialect::TiDB);
+        assert!(stmt.is_ok(), "{}: {:?}", query, stmt);
+        stmt.unwrap()
+    }
+
+    #[derive(Default)]
+    struct Collector {
+        columns: Vec<String>,
+        tables: Vec<String>,
+        values: usize,
+        subqueries: usize,
+        skip_subqueries: bool,
+        max_tables: Option<usize>,
+    }
+
+    impl Visitor for Collector {
+        fn enter_column_name(&mut self, node: &ColumnName) -> VisitAction {
+            self.columns.push(node.name.origin.clone());
+            VisitAction::Continue
+        }
+
+        fn enter_table_name(&mut self, node: &TableName) -> VisitAction {
+            self.tables.push(node.name.origin.clone());
+            if self.max_tables == Some(self.tables.len()) {
+                return VisitAction::Stop;
+            }
+            VisitAction::Continue
+        }
+
+        fn enter_value_expr(&mut self, _node: &ValueExpr) -> VisitAction {
+            self.values += 1;
+            VisitAction::Continue
+        }
+
+        fn enter_sub_query_expr(&mut self, _node: &SubQueryExpr) -> VisitAction {
+            if self.skip_subqueries {
+                return VisitAction::SkipChildren;
+            }
+            VisitAction::Continue
+        }
+
+        fn leave_sub_query_expr(&mut self, _node: &SubQueryExpr) {
+            self.subqueries += 1;
+        }
+    }
+
+    #[test]
+    fn visitor_test() {
+        let stmt =
+            parse("SELECT a, b + 1 FROM t1 JOIN t2 ON t1.id = t2.id WHERE c IN (1, 2) AND d = 'x'");
+        let mut collector = Collector::default();
+        stmt.accept(&mut collector);
+        collector.columns.sort();
+        collector.tables.sort();
+        assert_eq!(collector.columns, vec!["a", "b", "c", "d", "id", "id"]);
+        assert_eq!(collector.tables, vec!["t1", "t2"]);
+        assert_eq!(collector.values, 4);
+
+        let stmt =
+            parse("SELECT a FROM t1 WHERE a IN (SELECT a FROM t2) AND EXISTS (SELECT 1 FROM t3)");
+        let mut collector = Collector {
+            skip_subqueries: true,
+            ..Collector::default()
+        };
+        stmt.accept(&mut collector);
+        assert_eq!(collector.tables, vec!["t1"]);
+        assert_eq!(collector.subqueries, 2);
+
+        let stmt = parse("SELECT * FROM t1, t2, t3");
+        let mut collector = Collector {
+            max_tables: Some(1),
+            ..Collector::default()
+        };
+        stmt.accept(&mut collector);
+        assert_eq!(collector.tables.len(), 1);
+    }
+
+    // Rewriter replaces the literals with parameter markers and moves the
+    // tables to the archive schema.
+    struct Rewriter;
+
+    impl VisitorMut for Rewriter {
+        fn enter_expr_node(&mut self, node: &mut ExprNode) -> VisitAction {
+            if let ExprNode::ValueExpr(_) = node {
+                *node = ExprNode::ParamMarkerExpr(ParamMarkerExpr::default());
+            }
+            VisitAction::Continue
+        }
+
+        fn enter_table_name(&mut self, node: &mut TableName) -> VisitAction {
+            node.schema = CIStr::new("archive");
+            node.name = CIStr::new(&format!("{}_2024", node.name.origin));
+            VisitAction::Continue
+        }
+    }
+
+    #[test]
+    fn visitor_mut_test() {
+        let cases = vec![
+            (
+                "SELECT a FROM t WHERE b = 1 AND c IN ('x', 'y') LIMIT 10",
+                "SELECT `a` FROM `archive`.`t_2024` WHERE `b` = ? AND `c` IN (?, ?) LIMIT ?",
+            ),
+            (
+                "SELECT a FROM t1 UNION SELECT b FROM t2 WHERE b > 2 ORDER BY a LIMIT 1, 5",
+                "SELECT `a` FROM `archive`.`t1_2024` UNION SELECT `b` FROM `archive`.`t2_2024` WHERE `b` > ? ORDER BY `a` LIMIT ?, ?",
+            ),
+        ];
+
+        for (query, expected) in cases {
+            let mut stmt = parse(query);
+            stmt.accept_mut(&mut Rewriter);
+            let sql = restore_sql(&stmt, DEFAULT_RESTORE_FLAGS);
+            assert!(sql.is_ok(), "{}: {:?}", query, sql);
+            assert_eq!(sql.unwrap(), expected, "{}", query);
+        }
+    }
+}
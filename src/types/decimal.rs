@@ -1,13 +1,11 @@
 use crate::common::error::CustomError;
 use crate::types::helper::{is_space, my_min, pow10};
-use logos::Source;
 use nom::character::is_digit;
 use std::fmt;
 
 const PANIC_INFO: &str = "This branch is not implemented. This is because you are trying to test something specific to TiDB's MyDecimal implementation. It is recommended to do this in TiDB repository.";
 
 // constant values.
-const MAX_WORD_BUF_LEN: isize = 9; // A MyDecimal holds 9 words.
 const DIGITS_PER_WORD: isize = 9; // A word holds 9 digits.
 const DIG_MASK: isize = 100000000;
 const WORD_BUF_LEN: isize = 9;
@@ -15,7 +13,7 @@ const WORD_BUF_LEN: isize = 9;
 // fixWordCntError limits word count in wordBufLen, and returns overflow or truncate error.
 fn fix_word_cnt_error(words_int: isize, words_frac: isize) -> Result<(isize, isize), CustomError> {
     if words_int + words_frac > WORD_BUF_LEN {
-        panic!(PANIC_INFO)
+        panic!("{}", PANIC_INFO)
     }
     Ok((words_int, words_frac))
 }
@@ -26,7 +24,7 @@ countLeadingZeroes returns the number of leading zeroes that can be removed from
 @param   i    start index
 @param   word value to compare against list of powers of 10
 */
-fn count_leading_zeroes(mut i: u32, mut word: u32) -> isize {
+fn count_leading_zeroes(mut i: u32, word: u32) -> isize {
     let mut leading = 0_isize;
     while word < pow10(i) {
         i -= 1;
@@ -73,8 +71,8 @@ impl Decimal {
             i += 1;
         }
 
-        if bytes.len() == 0 {
-            panic!(PANIC_INFO);
+        if bytes.is_empty() {
+            panic!("{}", PANIC_INFO);
         }
 
         match bytes[0] as char {
@@ -94,40 +92,37 @@ impl Decimal {
         }
 
         let mut digits_int = bytes_idx;
-        let mut digits_frac = 0_usize;
-        let mut end_idx = 0_usize;
-        if bytes_idx < bytes.len() && bytes[bytes_idx] == '.' as u8 {
-            end_idx = bytes_idx + 1;
+        let (mut digits_frac, end_idx) = if bytes_idx < bytes.len() && bytes[bytes_idx] == b'.' {
+            let mut end_idx = bytes_idx + 1;
             while end_idx < bytes.len() && is_digit(bytes[end_idx]) {
                 end_idx += 1;
             }
-            digits_frac = end_idx - bytes_idx - 1;
+            (end_idx - bytes_idx - 1, end_idx)
         } else {
-            digits_frac = 0;
-            end_idx = bytes_idx;
-        }
+            (0, bytes_idx)
+        };
 
         if digits_int + digits_frac == 0 {
-            panic!(PANIC_INFO)
+            panic!("{}", PANIC_INFO)
         }
 
         let words_int = digits_to_words(digits_int as isize);
         let words_frac = digits_to_words(digits_frac as isize);
-        let (mut words_int, _) = fix_word_cnt_error(words_int, words_frac).unwrap();
+        let (words_int, _) = fix_word_cnt_error(words_int, words_frac).unwrap();
         decimal.digits_int = digits_int as i8;
         decimal.digits_frac = digits_frac as i8;
         let mut word_idx = words_int;
-        let mut str_idx_tmp = bytes_idx;
+        let str_idx_tmp = bytes_idx;
         let mut word = 0_i32;
         let mut inner_idx = 0_isize;
         while digits_int > 0 {
             digits_int -= 1;
             bytes_idx -= 1;
-            word += (bytes[bytes_idx] - '0' as u8) as i32 * pow10(inner_idx as u32) as i32;
+            word += (bytes[bytes_idx] - b'0') as i32 * pow10(inner_idx as u32) as i32;
             inner_idx += 1;
             if inner_idx == DIGITS_PER_WORD {
                 word_idx -= 1;
-                decimal.word_buf[word_idx] = word;
+                decimal.word_buf[word_idx as usize] = word;
                 word = 0;
                 inner_idx = 0;
             }
@@ -135,7 +130,7 @@ impl Decimal {
 
         if inner_idx != 0 {
             word_idx -= 1;
-            decimal.word_buf[word_idx] = word
+            decimal.word_buf[word_idx as usize] = word
         }
 
         word_idx = words_int;
@@ -145,10 +140,10 @@ impl Decimal {
         while digits_frac > 0 {
             digits_frac -= 1;
             bytes_idx += 1;
-            word = (bytes[bytes_idx] - '0' as u8) as i32 + word * 10;
+            word = (bytes[bytes_idx] - b'0') as i32 + word * 10;
             inner_idx += 1;
             if inner_idx == DIGITS_PER_WORD {
-                decimal.word_buf[word_idx] = word;
+                decimal.word_buf[word_idx as usize] = word;
                 word_idx += 1;
                 word = 0;
                 inner_idx = 0;
@@ -156,17 +151,17 @@ impl Decimal {
         }
 
         if inner_idx != 0 {
-            decimal.word_buf[word_idx] = word * pow10((DIGITS_PER_WORD - inner_idx) as u32) as i32
+            decimal.word_buf[word_idx as usize] = word * pow10((DIGITS_PER_WORD - inner_idx) as u32) as i32
         }
-        if end_idx + 1 <= bytes.len()
-            && (bytes[end_idx] == 'e' as u8 || bytes[end_idx] == 'E' as u8)
+        if end_idx < bytes.len()
+            && (bytes[end_idx] == b'e' || bytes[end_idx] == b'E')
         {
-            panic!(PANIC_INFO)
+            panic!("{}", PANIC_INFO)
         }
         let mut all_zero = true;
         let mut i = 0_isize;
         while i < WORD_BUF_LEN {
-            if decimal.word_buf[i] != 0 {
+            if decimal.word_buf[i as usize] != 0 {
                 all_zero = false;
                 break;
             }
@@ -181,16 +176,12 @@ impl Decimal {
         Ok(decimal)
     }
 
-    fn string_size(&self) -> isize {
-        (self.digits_int + self.digits_frac + 3) as isize
-    }
-
     fn remove_leading_zeros(&self) -> (isize, isize) {
         let mut digits_int = self.digits_int as isize;
         let mut word_idx = 0_isize;
         let mut i = ((digits_int - 1) % DIGITS_PER_WORD) + 1;
 
-        while digits_int > 0 && self.word_buf[word_idx] == 0 {
+        while digits_int > 0 && self.word_buf[word_idx as usize] == 0 {
             digits_int -= i;
             i = DIGITS_PER_WORD;
             word_idx += 1;
@@ -198,16 +189,15 @@ impl Decimal {
 
         if digits_int > 0 {
             let i = ((digits_int - 1) % DIGITS_PER_WORD) as u32;
-            digits_int -= count_leading_zeroes(i, self.word_buf[word_idx])
+            digits_int -= count_leading_zeroes(i, self.word_buf[word_idx as usize] as u32)
         } else {
             digits_int = 0
         }
 
-        (digits_int, word_idx)
+        (word_idx, digits_int)
     }
 
     pub fn to_bytes(&self) -> Vec<u8> {
-        let mut bytes = vec![0_u8; self.string_size() as usize];
         let mut digits_frac = self.digits_frac as isize;
         let (mut word_start_idx, mut digits_int) = self.remove_leading_zeros();
         if digits_int + digits_frac == 0 {
@@ -229,28 +219,28 @@ impl Decimal {
             length += 1;
         }
 
-        let bytes = bytes[..length];
-        let mut bytes_idx = 0_isize;
+        let mut bytes = vec![0_u8; length as usize];
+        let mut bytes_idx = 0_usize;
         if self.negative {
-            bytes[bytes_idx] = '-';
+            bytes[bytes_idx] = b'-';
             bytes_idx += 1;
         }
 
-        let mut fill = 0_isize;
+        let mut fill;
         if digits_frac > 0 {
-            let mut frac_idx = bytes_idx + digits_int_len;
+            let mut frac_idx = bytes_idx + digits_int_len as usize;
             fill = digits_frac_len - digits_frac;
             let mut word_idx = word_start_idx + digits_to_words(digits_int);
-            bytes[frac_idx] = '.';
+            bytes[frac_idx] = b'.';
             frac_idx += 1;
             while digits_frac > 0 {
-                let mut x = self.word_buf[word_idx];
+                let mut x = self.word_buf[word_idx as usize] as isize;
                 word_idx += 1;
 
                 let mut i = my_min(digits_frac, DIGITS_PER_WORD);
                 while i > 0 {
                     let y = x / DIG_MASK;
-                    bytes[frac_idx] = y as u8 + '0' as u8;
+                    bytes[frac_idx] = y as u8 + b'0';
                     frac_idx += 1;
                     x -= y * DIG_MASK;
                     x *= 10;
@@ -259,7 +249,7 @@ impl Decimal {
                 digits_frac -= DIGITS_PER_WORD;
             }
             while fill > 0 {
-                bytes[frac_idx] = '0';
+                bytes[frac_idx] = b'0';
                 frac_idx += 1;
                 fill -= 1;
             }
@@ -271,31 +261,31 @@ impl Decimal {
         }
 
         while fill > 0 {
-            bytes[bytes_idx] = '0';
+            bytes[bytes_idx] = b'0';
             bytes_idx += 1;
             fill -= 1
         }
 
         if digits_int > 0 {
-            bytes_idx += digits_int;
+            bytes_idx += digits_int as usize;
             let mut word_idx = word_start_idx + digits_to_words(digits_int);
 
             while digits_int > 0 {
                 word_idx -= 1;
-                let mut x = self.word_buf[word_idx];
+                let mut x = self.word_buf[word_idx as usize];
 
                 let mut i = my_min(digits_int, DIGITS_PER_WORD);
                 while i > 0 {
                     let y = x / 10;
                     bytes_idx -= 1;
-                    bytes[bytes_idx] = '0' as u8 + (x - y * 10) as u8;
+                    bytes[bytes_idx] = b'0' + (x - y * 10) as u8;
                     x = y;
                     i -= 1;
                 }
                 digits_int -= DIGITS_PER_WORD;
             }
         } else {
-            bytes[bytes_idx] = '0';
+            bytes[bytes_idx] = b'0';
         }
 
         bytes
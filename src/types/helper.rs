@@ -1,9 +1,5 @@
 pub fn is_space(c: u8) -> bool {
-    c == ' ' as u8 || c == '\t' as u8
-}
-
-fn is_digit(c: u8) -> bool {
-    c >= '0' as u8 && c <= '9' as u8
+    c == b' ' || c == b'\t'
 }
 
 pub fn pow10(x: u32) -> u32 {